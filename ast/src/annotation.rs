@@ -36,6 +36,8 @@ pub fn load_annotation(
     match ast_definition {
         Definition::Import(_) => unimplemented!("annotated imports are not supported yet"),
         Definition::Circuit(_) => unimplemented!("annotated circuits are not supported yet"),
+        Definition::Enum(_) => unimplemented!("annotated enums are not supported yet"),
+        Definition::Const(_) => unimplemented!("annotated constants are not supported yet"),
         Definition::Function(_) => unimplemented!("annotated functions are not supported yet"),
         Definition::TestFunction(ast_test) => {
             let test = TestFunction::from(ast_test);
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CircuitMember, Identifier};
+use crate::{CircuitMember, ConstParameter, Identifier};
 use leo_grammar::circuits::Circuit as GrammarCircuit;
 
 use serde::{Deserialize, Serialize};
@@ -23,21 +23,40 @@ use std::fmt;
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Circuit {
     pub circuit_name: Identifier,
+    pub const_parameters: Vec<ConstParameter>,
     pub members: Vec<CircuitMember>,
 }
 
 impl<'ast> From<GrammarCircuit<'ast>> for Circuit {
     fn from(circuit: GrammarCircuit<'ast>) -> Self {
         let circuit_name = Identifier::from(circuit.identifier);
+        let const_parameters = circuit.const_parameters.into_iter().map(ConstParameter::from).collect();
         let members = circuit.members.into_iter().map(CircuitMember::from).collect();
 
-        Self { circuit_name, members }
+        Self {
+            circuit_name,
+            const_parameters,
+            members,
+        }
     }
 }
 
 impl Circuit {
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "circuit {} {{ ", self.circuit_name)?;
+        write!(f, "circuit {}", self.circuit_name)?;
+
+        if !self.const_parameters.is_empty() {
+            let const_parameters = self
+                .const_parameters
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            write!(f, "<{}>", const_parameters)?;
+        }
+
+        writeln!(f, " {{ ")?;
         for field in self.members.iter() {
             writeln!(f, "    {}", field)?;
         }
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, PositiveNumber, Span};
+use leo_grammar::types::ArrayDimension as GrammarArrayDimension;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A single array dimension.
+/// Either a positive number or the name of a const parameter that is resolved when the array is used.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ArrayDimension {
+    Number(PositiveNumber),
+    Const(Identifier),
+}
+
+impl ArrayDimension {
+    ///
+    /// Returns `true` if this dimension is the number zero.
+    ///
+    pub fn is_zero(&self) -> bool {
+        match self {
+            ArrayDimension::Number(number) => number.is_zero(),
+            ArrayDimension::Const(_) => false,
+        }
+    }
+
+    ///
+    /// Returns the span of this dimension.
+    ///
+    pub fn span(&self) -> &Span {
+        match self {
+            ArrayDimension::Number(number) => &number.span,
+            ArrayDimension::Const(identifier) => &identifier.span,
+        }
+    }
+}
+
+/// Create a new [`ArrayDimension`] from a [`GrammarArrayDimension`] in a Leo program file.
+impl<'ast> From<GrammarArrayDimension<'ast>> for ArrayDimension {
+    fn from(dimension: GrammarArrayDimension<'ast>) -> Self {
+        match dimension {
+            GrammarArrayDimension::Number(number) => ArrayDimension::Number(PositiveNumber::from(number)),
+            GrammarArrayDimension::Const(identifier) => ArrayDimension::Const(Identifier::from(identifier)),
+        }
+    }
+}
+
+impl fmt::Display for ArrayDimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArrayDimension::Number(number) => write!(f, "{}", number),
+            ArrayDimension::Const(identifier) => write!(f, "{}", identifier),
+        }
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{PositiveNumber, Span};
+use crate::{ArrayDimension, Identifier, PositiveNumber, Span};
 use leo_grammar::types::ArrayDimensions as GrammarArrayDimensions;
 use leo_input::types::ArrayDimensions as InputArrayDimensions;
 
@@ -24,10 +24,10 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-/// A vector of positive numbers that represent array dimensions.
+/// A vector of array dimensions.
 /// Can be used in an array [`Type`] or an array initializer [`Expression`].
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
-pub struct ArrayDimensions(pub Vec<PositiveNumber>);
+pub struct ArrayDimensions(pub Vec<ArrayDimension>);
 
 impl ArrayDimensions {
     ///
@@ -40,7 +40,7 @@ impl ArrayDimensions {
             span,
         };
 
-        self.0.push(positive_number)
+        self.0.push(ArrayDimension::Number(positive_number))
     }
 
     ///
@@ -70,23 +70,43 @@ impl ArrayDimensions {
     /// Returns `true` if there is an array dimension equal to zero.
     ///
     pub fn is_zero(&self) -> bool {
-        self.0.iter().any(|number| number.is_zero())
+        self.0.iter().any(|dimension| dimension.is_zero())
+    }
+
+    ///
+    /// Returns `true` if there is an array dimension that refers to a const parameter.
+    ///
+    pub fn is_generic(&self) -> bool {
+        self.0.iter().any(|dimension| matches!(dimension, ArrayDimension::Const(_)))
+    }
+
+    ///
+    /// Returns the names of the const parameters used as array dimensions.
+    ///
+    pub fn const_parameters(&self) -> Vec<&Identifier> {
+        self.0
+            .iter()
+            .filter_map(|dimension| match dimension {
+                ArrayDimension::Const(identifier) => Some(identifier),
+                ArrayDimension::Number(_) => None,
+            })
+            .collect()
     }
 
     ///
     /// Returns the first dimension of the array.
     ///
-    pub fn first(&self) -> Option<&PositiveNumber> {
+    pub fn first(&self) -> Option<&ArrayDimension> {
         self.0.first()
     }
 
     ///
     /// Attempts to remove the first dimension from the array.
     ///
-    /// If the first dimension exists, then remove and return `Some(PositiveNumber)`.
+    /// If the first dimension exists, then remove and return `Some(ArrayDimension)`.
     /// If the first dimension does not exist, then return `None`.
     ///
-    pub fn remove_first(&mut self) -> Option<PositiveNumber> {
+    pub fn remove_first(&mut self) -> Option<ArrayDimension> {
         // If there are no dimensions in the array, then return None.
         self.0.first()?;
 
@@ -100,10 +120,10 @@ impl ArrayDimensions {
     ///
     /// Attempts to remove the last dimension from the array.
     ///
-    /// If the last dimension exists, then remove and return `Some(PositiveNumber)`.
+    /// If the last dimension exists, then remove and return `Some(ArrayDimension)`.
     /// If the last dimension does not exist, then return `None`.
     ///
-    pub fn remove_last(&mut self) -> Option<PositiveNumber> {
+    pub fn remove_last(&mut self) -> Option<ArrayDimension> {
         self.0.pop()
     }
 }
@@ -112,9 +132,9 @@ impl ArrayDimensions {
 impl<'ast> From<GrammarArrayDimensions<'ast>> for ArrayDimensions {
     fn from(dimensions: GrammarArrayDimensions<'ast>) -> Self {
         Self(match dimensions {
-            GrammarArrayDimensions::Single(single) => vec![PositiveNumber::from(single.number)],
+            GrammarArrayDimensions::Single(single) => vec![ArrayDimension::from(single.dimension)],
             GrammarArrayDimensions::Multiple(multiple) => {
-                multiple.numbers.into_iter().map(PositiveNumber::from).collect()
+                multiple.dimensions.into_iter().map(ArrayDimension::from).collect()
             }
         })
     }
@@ -124,10 +144,12 @@ impl<'ast> From<GrammarArrayDimensions<'ast>> for ArrayDimensions {
 impl<'ast> From<InputArrayDimensions<'ast>> for ArrayDimensions {
     fn from(dimensions: InputArrayDimensions<'ast>) -> Self {
         Self(match dimensions {
-            InputArrayDimensions::Single(single) => vec![PositiveNumber::from(single.number)],
-            InputArrayDimensions::Multiple(multiple) => {
-                multiple.numbers.into_iter().map(PositiveNumber::from).collect()
-            }
+            InputArrayDimensions::Single(single) => vec![ArrayDimension::Number(PositiveNumber::from(single.number))],
+            InputArrayDimensions::Multiple(multiple) => multiple
+                .numbers
+                .into_iter()
+                .map(|number| ArrayDimension::Number(PositiveNumber::from(number)))
+                .collect(),
         })
     }
 }
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod array_dimension;
+pub use array_dimension::*;
+
 pub mod array_dimensions;
 pub use array_dimensions::*;
 
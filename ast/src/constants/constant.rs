@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Expression, Identifier, Node, Span, Type};
+use leo_grammar::definitions::ConstDefinition as GrammarConstDefinition;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A constant defined at the top level of a Leo file.
+///
+/// The value of a constant is evaluated at compile time and cannot depend on program input.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct Constant {
+    pub identifier: Identifier,
+    pub type_: Type,
+    pub expression: Expression,
+    pub span: Span,
+}
+
+impl<'ast> From<GrammarConstDefinition<'ast>> for Constant {
+    fn from(constant: GrammarConstDefinition<'ast>) -> Self {
+        Self {
+            identifier: Identifier::from(constant.identifier),
+            type_: Type::from(constant.type_),
+            expression: Expression::from(constant.expression),
+            span: Span::from(constant.span),
+        }
+    }
+}
+
+impl fmt::Display for Constant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "const {}: {} = {};", self.identifier, self.type_, self.expression)
+    }
+}
+
+impl Node for Constant {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+
+    fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{EnumVariant, Identifier};
+use leo_grammar::enums::EnumDefinition as GrammarEnum;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Enum {
+    pub enum_name: Identifier,
+    pub variants: Vec<EnumVariant>,
+}
+
+impl<'ast> From<GrammarEnum<'ast>> for Enum {
+    fn from(enum_: GrammarEnum<'ast>) -> Self {
+        let enum_name = Identifier::from(enum_.identifier);
+        let variants = enum_.variants.into_iter().map(EnumVariant::from).collect();
+
+        Self { enum_name, variants }
+    }
+}
+
+impl Enum {
+    ///
+    /// Returns the index of the variant with the given name.
+    ///
+    pub fn variant_index(&self, name: &str) -> Option<usize> {
+        self.variants.iter().position(|variant| variant.identifier.name == name)
+    }
+
+    fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "enum {} {{ ", self.enum_name)?;
+        for variant in self.variants.iter() {
+            writeln!(f, "    {},", variant)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl fmt::Debug for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format(f)
+    }
+}
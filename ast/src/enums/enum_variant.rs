@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Span, Type};
+use leo_grammar::enums::EnumVariant as GrammarEnumVariant;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct EnumVariant {
+    pub identifier: Identifier,
+    pub payload: Option<Type>,
+    pub span: Span,
+}
+
+impl<'ast> From<GrammarEnumVariant<'ast>> for EnumVariant {
+    fn from(variant: GrammarEnumVariant<'ast>) -> Self {
+        Self {
+            identifier: Identifier::from(variant.identifier),
+            payload: variant.payload.map(Type::from),
+            span: Span::from(variant.span),
+        }
+    }
+}
+
+impl fmt::Display for EnumVariant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.payload {
+            Some(ref type_) => write!(f, "{}({})", self.identifier, type_),
+            None => write!(f, "{}", self.identifier),
+        }
+    }
+}
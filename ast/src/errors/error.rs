@@ -41,6 +41,8 @@ pub struct Error {
     pub text: String,
     /// Error explanation
     pub message: String,
+    /// Stable error code, as shown by `leo explain`
+    pub code: Option<&'static str>,
 }
 
 impl Error {
@@ -52,6 +54,14 @@ impl Error {
             end: span.end,
             text: span.text,
             message,
+            code: None,
+        }
+    }
+
+    pub fn new_from_span_with_code(code: &'static str, message: String, span: Span) -> Self {
+        Self {
+            code: Some(code),
+            ..Self::new_from_span(message, span)
         }
     }
 
@@ -63,6 +73,7 @@ impl Error {
             end: span.end,
             text: span.text,
             message,
+            code: None,
         }
     }
 
@@ -124,6 +135,41 @@ impl std::error::Error for Error {
     }
 }
 
+///
+/// Returns the formatted error that caused the given error.
+///
+/// The chain of error sources is searched, so the formatted error can be wrapped in any number
+/// of error enums.
+///
+pub fn formatted_source<'a>(error: &'a (dyn std::error::Error + 'static)) -> Option<&'a Error> {
+    let mut source = Some(error);
+
+    while let Some(error) = source {
+        if let Some(formatted) = error.downcast_ref::<Error>() {
+            return Some(formatted);
+        }
+
+        source = error.source();
+    }
+
+    None
+}
+
+///
+/// Sorts a list of errors by the location of their formatted errors and removes duplicates.
+///
+/// Errors without a location are sorted last.
+///
+pub fn sort_errors<E: std::error::Error + 'static>(errors: &mut Vec<E>) {
+    errors.sort_by_key(|error| {
+        formatted_source(error).map_or((true, None, 0, 0), |formatted| {
+            (false, formatted.path.clone(), formatted.line, formatted.start)
+        })
+    });
+
+    errors.dedup_by(|a, b| a.to_string() == b.to_string());
+}
+
 #[test]
 fn test_error() {
     let err = Error {
@@ -133,6 +179,7 @@ fn test_error() {
         end: 9,
         text: "let a = x;".to_string(),
         message: "undefined value `x`".to_string(),
+        code: None,
     };
 
     assert_eq!(
@@ -22,6 +22,7 @@ pub enum BinaryOperation {
     Sub,
     Mul,
     Div,
+    Rem,
     Pow,
     Or,
     And,
@@ -31,12 +32,18 @@ pub enum BinaryOperation {
     Gt,
     Le,
     Lt,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shl,
+    Shr,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BinaryOperationClass {
     Numeric,
     Boolean,
+    Bitwise,
 }
 
 impl AsRef<str> for BinaryOperation {
@@ -46,6 +53,7 @@ impl AsRef<str> for BinaryOperation {
             BinaryOperation::Sub => "-",
             BinaryOperation::Mul => "*",
             BinaryOperation::Div => "/",
+            BinaryOperation::Rem => "%",
             BinaryOperation::Pow => "**",
             BinaryOperation::Or => "||",
             BinaryOperation::And => "&&",
@@ -55,6 +63,11 @@ impl AsRef<str> for BinaryOperation {
             BinaryOperation::Gt => ">",
             BinaryOperation::Le => "<=",
             BinaryOperation::Lt => "<",
+            BinaryOperation::BitOr => "|",
+            BinaryOperation::BitXor => "^",
+            BinaryOperation::BitAnd => "&",
+            BinaryOperation::Shl => "<<",
+            BinaryOperation::Shr => ">>",
         }
     }
 }
@@ -66,6 +79,7 @@ impl BinaryOperation {
             | BinaryOperation::Sub
             | BinaryOperation::Mul
             | BinaryOperation::Div
+            | BinaryOperation::Rem
             | BinaryOperation::Pow => BinaryOperationClass::Numeric,
             BinaryOperation::Or
             | BinaryOperation::And
@@ -75,6 +89,11 @@ impl BinaryOperation {
             | BinaryOperation::Gt
             | BinaryOperation::Le
             | BinaryOperation::Lt => BinaryOperationClass::Boolean,
+            BinaryOperation::BitOr
+            | BinaryOperation::BitXor
+            | BinaryOperation::BitAnd
+            | BinaryOperation::Shl
+            | BinaryOperation::Shr => BinaryOperationClass::Bitwise,
         }
     }
 }
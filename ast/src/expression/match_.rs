@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchExpression {
+    pub inner: Box<Expression>,
+    pub arms: Vec<MatchArm>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchArm {
+    pub pattern: MatchPattern,
+    pub expression: Expression,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchPattern {
+    // (enum name, variant name, optional payload binding)
+    Variant(Identifier, Identifier, Option<Identifier>),
+    Wildcard(Span),
+}
+
+impl<'ast> From<GrammarMatchArm<'ast>> for MatchArm {
+    fn from(arm: GrammarMatchArm<'ast>) -> Self {
+        let pattern = match arm.pattern {
+            GrammarMatchPattern::Variant(pattern) => MatchPattern::Variant(
+                Identifier::from(pattern.enum_name),
+                Identifier::from(pattern.variant),
+                pattern.binding.map(Identifier::from),
+            ),
+            GrammarMatchPattern::Wildcard(pattern) => MatchPattern::Wildcard(Span::from(pattern.span)),
+        };
+
+        MatchArm {
+            pattern,
+            expression: Expression::from(arm.expression),
+            span: Span::from(arm.span),
+        }
+    }
+}
+
+impl MatchPattern {
+    pub fn span(&self) -> &Span {
+        match self {
+            MatchPattern::Variant(enum_name, _, _) => &enum_name.span,
+            MatchPattern::Wildcard(span) => span,
+        }
+    }
+}
+
+impl fmt::Display for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchPattern::Variant(enum_name, variant, Some(binding)) => {
+                write!(f, "{}::{}({})", enum_name, variant, binding)
+            }
+            MatchPattern::Variant(enum_name, variant, None) => write!(f, "{}::{}", enum_name, variant),
+            MatchPattern::Wildcard(_) => write!(f, "_"),
+        }
+    }
+}
+
+impl fmt::Display for MatchArm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.expression)
+    }
+}
+
+impl fmt::Display for MatchExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "match {} {{ ", self.inner)?;
+        for arm in self.arms.iter() {
+            write!(f, "{}, ", arm)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl Node for MatchExpression {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+
+    fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+}
@@ -24,6 +24,7 @@ use crate::{
     RangeOrExpression,
     Span,
     SpreadOrExpression,
+    Type,
 };
 use leo_grammar::{
     access::{Access, AssigneeAccess},
@@ -32,8 +33,12 @@ use leo_grammar::{
         ArrayInitializerExpression,
         ArrayInlineExpression as GrammarArrayInlineExpression,
         BinaryExpression as GrammarBinaryExpression,
+        CastExpression as GrammarCastExpression,
         CircuitInlineExpression,
         Expression as GrammarExpression,
+        MatchArm as GrammarMatchArm,
+        MatchExpression as GrammarMatchExpression,
+        MatchPattern as GrammarMatchPattern,
         PostfixExpression,
         TernaryExpression,
         UnaryExpression as GrammarUnaryExpression,
@@ -42,10 +47,13 @@ use leo_grammar::{
     values::{
         AddressValue,
         BooleanValue,
+        ByteStringValue,
+        CharValue,
         FieldValue,
         GroupValue as GrammarGroupValue,
         IntegerValue,
         NumberValue as GrammarNumber,
+        StringValue,
         Value,
     },
 };
@@ -60,8 +68,12 @@ mod binary;
 pub use binary::*;
 mod unary;
 pub use unary::*;
+mod cast;
+pub use cast::*;
 mod conditional;
 pub use conditional::*;
+mod match_;
+pub use match_::*;
 mod array_access;
 pub use array_access::*;
 mod array_range_access;
@@ -92,7 +104,9 @@ pub enum Expression {
     Value(ValueExpression),
     Binary(BinaryExpression),
     Unary(UnaryExpression),
+    Cast(CastExpression),
     Conditional(ConditionalExpression),
+    Match(MatchExpression),
 
     ArrayInline(ArrayInlineExpression),
     ArrayInit(ArrayInitExpression),
@@ -117,7 +131,9 @@ impl Node for Expression {
             Value(n) => n.span(),
             Binary(n) => n.span(),
             Unary(n) => n.span(),
+            Cast(n) => n.span(),
             Conditional(n) => n.span(),
+            Match(n) => n.span(),
             ArrayInline(n) => n.span(),
             ArrayInit(n) => n.span(),
             ArrayAccess(n) => n.span(),
@@ -138,7 +154,9 @@ impl Node for Expression {
             Value(n) => n.set_span(span),
             Binary(n) => n.set_span(span),
             Unary(n) => n.set_span(span),
+            Cast(n) => n.set_span(span),
             Conditional(n) => n.set_span(span),
+            Match(n) => n.set_span(span),
             ArrayInline(n) => n.set_span(span),
             ArrayInit(n) => n.set_span(span),
             ArrayAccess(n) => n.set_span(span),
@@ -161,7 +179,9 @@ impl<'ast> fmt::Display for Expression {
             Value(n) => n.fmt(f),
             Binary(n) => n.fmt(f),
             Unary(n) => n.fmt(f),
+            Cast(n) => n.fmt(f),
             Conditional(n) => n.fmt(f),
+            Match(n) => n.fmt(f),
             ArrayInline(n) => n.fmt(f),
             ArrayInit(n) => n.fmt(f),
             ArrayAccess(n) => n.fmt(f),
@@ -258,7 +278,9 @@ impl<'ast> From<GrammarExpression<'ast>> for Expression {
             GrammarExpression::Identifier(variable) => Expression::from(variable),
             GrammarExpression::Unary(expression) => Expression::from(*expression),
             GrammarExpression::Binary(expression) => Expression::from(*expression),
+            GrammarExpression::Cast(expression) => Expression::from(*expression),
             GrammarExpression::Ternary(expression) => Expression::from(*expression),
+            GrammarExpression::Match(expression) => Expression::from(*expression),
             GrammarExpression::ArrayInline(expression) => Expression::from(expression),
             GrammarExpression::ArrayInitializer(expression) => Expression::from(*expression),
             GrammarExpression::Tuple(expression) => Expression::from(expression),
@@ -323,7 +345,13 @@ impl<'ast> From<GrammarBinaryExpression<'ast>> for Expression {
             Sub => BinaryOperation::Sub,
             Mul => BinaryOperation::Mul,
             Div => BinaryOperation::Div,
+            Rem => BinaryOperation::Rem,
             Pow => BinaryOperation::Pow,
+            BitOr => BinaryOperation::BitOr,
+            BitXor => BinaryOperation::BitXor,
+            BitAnd => BinaryOperation::BitAnd,
+            Shl => BinaryOperation::Shl,
+            Shr => BinaryOperation::Shr,
         };
         Expression::Binary(BinaryExpression {
             left: Box::new(Expression::from(expression.left)),
@@ -334,6 +362,16 @@ impl<'ast> From<GrammarBinaryExpression<'ast>> for Expression {
     }
 }
 
+impl<'ast> From<GrammarCastExpression<'ast>> for Expression {
+    fn from(expression: GrammarCastExpression<'ast>) -> Self {
+        Expression::Cast(CastExpression {
+            inner: Box::new(Expression::from(expression.expression)),
+            target_type: Type::from(expression.type_),
+            span: Span::from(expression.span),
+        })
+    }
+}
+
 impl<'ast> From<TernaryExpression<'ast>> for Expression {
     fn from(expression: TernaryExpression<'ast>) -> Self {
         Expression::Conditional(ConditionalExpression {
@@ -345,6 +383,16 @@ impl<'ast> From<TernaryExpression<'ast>> for Expression {
     }
 }
 
+impl<'ast> From<GrammarMatchExpression<'ast>> for Expression {
+    fn from(expression: GrammarMatchExpression<'ast>) -> Self {
+        Expression::Match(MatchExpression {
+            inner: Box::new(Expression::from(expression.expression)),
+            arms: expression.arms.into_iter().map(MatchArm::from).collect(),
+            span: Span::from(expression.span),
+        })
+    }
+}
+
 impl<'ast> From<GrammarArrayInlineExpression<'ast>> for Expression {
     fn from(array: GrammarArrayInlineExpression<'ast>) -> Self {
         Expression::ArrayInline(ArrayInlineExpression {
@@ -378,10 +426,13 @@ impl<'ast> From<Value<'ast>> for Expression {
         match value {
             Value::Address(address) => Expression::from(address),
             Value::Boolean(boolean) => Expression::from(boolean),
+            Value::ByteString(string) => Expression::from(string),
+            Value::Char(character) => Expression::from(character),
             Value::Field(field) => Expression::from(field),
             Value::Group(group) => Expression::from(group),
             Value::Implicit(number) => Expression::from(number),
             Value::Integer(integer) => Expression::from(integer),
+            Value::String(string) => Expression::from(string),
         }
     }
 }
@@ -416,6 +467,15 @@ impl<'ast> From<BooleanValue<'ast>> for Expression {
     }
 }
 
+impl<'ast> From<CharValue<'ast>> for Expression {
+    fn from(character: CharValue<'ast>) -> Self {
+        Expression::Value(ValueExpression::Char(
+            character.character.character(),
+            Span::from(character.span),
+        ))
+    }
+}
+
 impl<'ast> From<FieldValue<'ast>> for Expression {
     fn from(field: FieldValue<'ast>) -> Self {
         Expression::Value(ValueExpression::Field(field.number.to_string(), Span::from(field.span)))
@@ -464,6 +524,56 @@ impl<'ast> From<IntegerValue<'ast>> for Expression {
     }
 }
 
+impl<'ast> From<StringValue<'ast>> for Expression {
+    fn from(string: StringValue<'ast>) -> Self {
+        // Lower the string to an inline array of its utf-8 encoded bytes
+        let elements = string
+            .characters
+            .into_iter()
+            .flat_map(|character| {
+                let span = Span::from(character.span.clone());
+
+                character.bytes().into_iter().map(move |byte| {
+                    SpreadOrExpression::Expression(Expression::Value(ValueExpression::Integer(
+                        IntegerType::U8,
+                        byte.to_string(),
+                        span.clone(),
+                    )))
+                })
+            })
+            .collect();
+
+        Expression::ArrayInline(ArrayInlineExpression {
+            elements,
+            span: Span::from(string.span),
+        })
+    }
+}
+
+impl<'ast> From<ByteStringValue<'ast>> for Expression {
+    fn from(string: ByteStringValue<'ast>) -> Self {
+        // Lower the byte string to an inline array of its bytes
+        let elements = string
+            .characters
+            .into_iter()
+            .map(|character| {
+                let span = Span::from(character.span.clone());
+
+                SpreadOrExpression::Expression(Expression::Value(ValueExpression::Integer(
+                    IntegerType::U8,
+                    character.byte().to_string(),
+                    span,
+                )))
+            })
+            .collect();
+
+        Expression::ArrayInline(ArrayInlineExpression {
+            elements,
+            span: Span::from(string.span),
+        })
+    }
+}
+
 impl<'ast> From<TupleAccess<'ast>> for Expression {
     fn from(tuple: TupleAccess<'ast>) -> Self {
         Expression::Value(ValueExpression::Implicit(
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Block, FunctionInput, Identifier, Span, Type};
+use crate::{Block, ConstParameter, FunctionInput, Identifier, Span, Type};
 use leo_grammar::functions::Function as GrammarFunction;
 
 use serde::{Deserialize, Serialize};
@@ -23,6 +23,7 @@ use std::fmt;
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Function {
     pub identifier: Identifier,
+    pub const_parameters: Vec<ConstParameter>,
     pub input: Vec<FunctionInput>,
     pub output: Option<Type>,
     pub block: Block,
@@ -41,12 +42,14 @@ impl<'ast> From<GrammarFunction<'ast>> for Function {
     fn from(function: GrammarFunction<'ast>) -> Self {
         let function_name = Identifier::from(function.identifier);
 
+        let const_parameters = function.const_parameters.into_iter().map(ConstParameter::from).collect();
         let parameters = function.parameters.into_iter().map(FunctionInput::from).collect();
         let returns = function.returns.map(Type::from);
         let block = Block::from(function.block);
 
         Function {
             identifier: function_name,
+            const_parameters,
             input: parameters,
             output: returns,
             block,
@@ -60,6 +63,13 @@ impl Function {
         &self.identifier.name
     }
 
+    ///
+    /// Returns `true` if the function has const parameters.
+    ///
+    pub fn is_generic(&self) -> bool {
+        !self.const_parameters.is_empty()
+    }
+
     ///
     /// Returns `true` if the function has input `self` or `mut self`.
     /// Returns `false` otherwise.
@@ -86,6 +96,17 @@ impl Function {
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "function {}", self.identifier)?;
 
+        if self.is_generic() {
+            let const_parameters = self
+                .const_parameters
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            write!(f, "<{}>", const_parameters)?;
+        }
+
         let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
         let returns = self.output.as_ref().map(|type_| type_.to_string());
         if returns.is_none() {
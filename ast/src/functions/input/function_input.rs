@@ -23,6 +23,7 @@ use std::fmt;
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FunctionInputVariable {
     pub identifier: Identifier,
+    pub public: bool,
     pub mutable: bool,
     pub type_: Type,
     pub span: Span,
@@ -32,6 +33,7 @@ impl<'ast> From<GrammarFunctionInput<'ast>> for FunctionInputVariable {
     fn from(parameter: GrammarFunctionInput<'ast>) -> Self {
         FunctionInputVariable {
             identifier: Identifier::from(parameter.identifier),
+            public: parameter.public.is_some(),
             mutable: parameter.mutable.is_some(),
             type_: Type::from(parameter.type_),
             span: Span::from(parameter.span),
@@ -41,7 +43,10 @@ impl<'ast> From<GrammarFunctionInput<'ast>> for FunctionInputVariable {
 
 impl FunctionInputVariable {
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // mut var: bool
+        // public mut var: bool
+        if self.public {
+            write!(f, "public ")?;
+        }
         if self.mutable {
             write!(f, "mut ")?;
         }
@@ -18,8 +18,17 @@ use crate::{ArrayDimensions, GroupValue};
 use leo_input::{
     errors::InputParserError,
     expressions::{ArrayInitializerExpression, ArrayInlineExpression, Expression, TupleExpression},
-    types::{ArrayType, DataType, IntegerType, TupleType, Type},
-    values::{Address, AddressValue, BooleanValue, FieldValue, GroupValue as InputGroupValue, NumberValue, Value},
+    types::{ArrayType, DataType, IntegerType, TupleType, Type, UnsignedIntegerType},
+    values::{
+        Address,
+        AddressValue,
+        BooleanValue,
+        CharValue,
+        FieldValue,
+        GroupValue as InputGroupValue,
+        NumberValue,
+        Value,
+    },
 };
 use pest::Span;
 
@@ -29,6 +38,7 @@ use std::fmt;
 pub enum InputValue {
     Address(String),
     Boolean(bool),
+    Char(char),
     Field(String),
     Group(GroupValue),
     Integer(IntegerType, String),
@@ -53,6 +63,10 @@ impl InputValue {
         Ok(InputValue::Boolean(boolean))
     }
 
+    fn from_char(character: CharValue) -> Self {
+        InputValue::Char(character.character.character())
+    }
+
     fn from_number(integer_type: IntegerType, number: String) -> Self {
         InputValue::Integer(integer_type, number)
     }
@@ -69,6 +83,7 @@ impl InputValue {
         match data_type {
             DataType::Address(_) => Err(InputParserError::implicit_type(data_type, implicit)),
             DataType::Boolean(_) => Err(InputParserError::implicit_type(data_type, implicit)),
+            DataType::Char(_) => Err(InputParserError::implicit_type(data_type, implicit)),
             DataType::Integer(integer_type) => Ok(InputValue::from_number(integer_type, implicit.to_string())),
             DataType::Group(_) => Err(InputParserError::implicit_group(implicit)),
             DataType::Field(_) => Ok(InputValue::Field(implicit.to_string())),
@@ -79,6 +94,7 @@ impl InputValue {
         match (data_type, value) {
             (DataType::Address(_), Value::Address(address)) => Ok(InputValue::from_address_value(address)),
             (DataType::Boolean(_), Value::Boolean(boolean)) => InputValue::from_boolean(boolean),
+            (DataType::Char(_), Value::Char(character)) => Ok(InputValue::from_char(character)),
             (DataType::Integer(integer_type), Value::Integer(integer)) => {
                 Ok(InputValue::from_number(integer_type, integer.to_string()))
             }
@@ -99,6 +115,16 @@ impl InputValue {
                 InputValue::from_array_initializer(array_type, initializer)
             }
             (Type::Tuple(tuple_type), Expression::Tuple(tuple)) => InputValue::from_tuple(tuple_type, tuple),
+            (Type::Array(array_type), Expression::Value(Value::String(string))) => {
+                let bytes = string.characters.iter().flat_map(|character| character.bytes()).collect();
+
+                InputValue::from_bytes(array_type, bytes, string.span)
+            }
+            (Type::Array(array_type), Expression::Value(Value::ByteString(string))) => {
+                let bytes = string.characters.iter().map(|character| character.byte()).collect();
+
+                InputValue::from_bytes(array_type, bytes, string.span)
+            }
             (type_, expression) => Err(InputParserError::expression_type_mismatch(type_, expression)),
         }
     }
@@ -141,6 +167,34 @@ impl InputValue {
         Ok(InputValue::Array(elements))
     }
 
+    ///
+    /// Returns a new `InputValue` array of `u8` integers from the bytes of a string literal.
+    ///
+    pub(crate) fn from_bytes(array_type: ArrayType, bytes: Vec<u8>, span: Span) -> Result<Self, InputParserError> {
+        // Create a new `ArrayDimensions` type from the input array_type dimensions.
+        let array_dimensions_type = ArrayDimensions::from(array_type.dimensions.clone());
+
+        // Convert the array dimensions to usize.
+        let array_dimensions = parse_array_dimensions(array_dimensions_type, array_type.span.clone())?;
+
+        // Return an error if the array type is not a `u8` array with one element for each byte.
+        let integer_type = match &*array_type.type_ {
+            Type::Basic(DataType::Integer(IntegerType::Unsigned(UnsignedIntegerType::U8Type(u8_type))))
+                if array_dimensions == [bytes.len()] =>
+            {
+                IntegerType::Unsigned(UnsignedIntegerType::U8Type(u8_type.clone()))
+            }
+            _ => return Err(InputParserError::string_type(array_type, bytes.len(), span)),
+        };
+
+        let elements = bytes
+            .into_iter()
+            .map(|byte| InputValue::Integer(integer_type.clone(), byte.to_string()))
+            .collect();
+
+        Ok(InputValue::Array(elements))
+    }
+
     pub(crate) fn from_array_initializer(
         array_type: ArrayType,
         initializer: ArrayInitializerExpression,
@@ -307,6 +361,7 @@ impl fmt::Display for InputValue {
         match self {
             InputValue::Address(ref address) => write!(f, "{}", address),
             InputValue::Boolean(ref boolean) => write!(f, "{}", boolean),
+            InputValue::Char(ref character) => write!(f, "'{}'", character.escape_debug()),
             InputValue::Group(ref group) => write!(f, "{}", group),
             InputValue::Field(ref field) => write!(f, "{}", field),
             InputValue::Integer(ref type_, ref number) => write!(f, "{}{:?}", number, type_),
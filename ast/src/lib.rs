@@ -29,6 +29,12 @@ pub use self::circuits::*;
 pub mod common;
 pub use self::common::*;
 
+pub mod constants;
+pub use self::constants::*;
+
+pub mod enums;
+pub use self::enums::*;
+
 pub mod errors;
 pub use self::errors::*;
 
@@ -14,10 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! A Leo program consists of import, circuit, and function definitions.
+//! A Leo program consists of import, circuit, enum, constant, and function definitions.
 //! Each defined type consists of ast statements and expressions.
 
-use crate::{load_annotation, Circuit, Function, FunctionInput, Identifier, ImportStatement, TestFunction};
+use crate::{
+    load_annotation,
+    Circuit,
+    Constant,
+    Enum,
+    Function,
+    FunctionInput,
+    Identifier,
+    ImportStatement,
+    TestFunction,
+};
 use leo_grammar::{definitions::Definition, files::File};
 
 use indexmap::IndexMap;
@@ -29,6 +39,8 @@ pub struct Program {
     pub expected_input: Vec<FunctionInput>,
     pub imports: Vec<ImportStatement>,
     pub circuits: IndexMap<Identifier, Circuit>,
+    pub enums: IndexMap<Identifier, Enum>,
+    pub constants: Vec<Constant>,
     pub functions: IndexMap<Identifier, Function>,
     pub tests: IndexMap<Identifier, TestFunction>,
 }
@@ -40,6 +52,8 @@ impl<'ast> Program {
     pub fn from(program_name: &str, program_ast: &File<'ast>) -> Self {
         let mut imports = vec![];
         let mut circuits = IndexMap::new();
+        let mut enums = IndexMap::new();
+        let mut constants = vec![];
         let mut functions = IndexMap::new();
         let mut tests = IndexMap::new();
         let mut expected_input = vec![];
@@ -53,6 +67,10 @@ impl<'ast> Program {
                 Definition::Circuit(circuit) => {
                     circuits.insert(Identifier::from(circuit.identifier.clone()), Circuit::from(circuit));
                 }
+                Definition::Enum(enum_) => {
+                    enums.insert(Identifier::from(enum_.identifier.clone()), Enum::from(enum_));
+                }
+                Definition::Const(constant) => constants.push(Constant::from(constant)),
                 Definition::Function(function_def) => {
                     let function = Function::from(function_def);
                     if function.identifier.name.eq(MAIN_FUNCTION_NAME) {
@@ -81,6 +99,8 @@ impl<'ast> Program {
             expected_input,
             imports,
             circuits,
+            enums,
+            constants,
             functions,
             tests,
         }
@@ -94,6 +114,8 @@ impl Program {
             expected_input: vec![],
             imports: vec![],
             circuits: IndexMap::new(),
+            enums: IndexMap::new(),
+            constants: vec![],
             functions: IndexMap::new(),
             tests: IndexMap::new(),
         }
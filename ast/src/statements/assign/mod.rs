@@ -31,7 +31,13 @@ pub enum AssignOperation {
     Sub,
     Mul,
     Div,
+    Rem,
     Pow,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
 }
 
 impl AsRef<str> for AssignOperation {
@@ -42,7 +48,13 @@ impl AsRef<str> for AssignOperation {
             AssignOperation::Sub => "-=",
             AssignOperation::Mul => "*=",
             AssignOperation::Div => "/=",
+            AssignOperation::Rem => "%=",
             AssignOperation::Pow => "**=",
+            AssignOperation::BitAnd => "&=",
+            AssignOperation::BitOr => "|=",
+            AssignOperation::BitXor => "^=",
+            AssignOperation::Shl => "<<=",
+            AssignOperation::Shr => ">>=",
         }
     }
 }
@@ -70,7 +82,13 @@ impl<'ast> From<GrammarAssignStatement<'ast>> for AssignStatement {
                 GrammarAssignOperation::SubAssign(_) => AssignOperation::Sub,
                 GrammarAssignOperation::MulAssign(_) => AssignOperation::Mul,
                 GrammarAssignOperation::DivAssign(_) => AssignOperation::Div,
+                GrammarAssignOperation::RemAssign(_) => AssignOperation::Rem,
                 GrammarAssignOperation::PowAssign(_) => AssignOperation::Pow,
+                GrammarAssignOperation::BitAndAssign(_) => AssignOperation::BitAnd,
+                GrammarAssignOperation::BitOrAssign(_) => AssignOperation::BitOr,
+                GrammarAssignOperation::BitXorAssign(_) => AssignOperation::BitXor,
+                GrammarAssignOperation::ShlAssign(_) => AssignOperation::Shl,
+                GrammarAssignOperation::ShrAssign(_) => AssignOperation::Shr,
             },
             assignee: Assignee::from(statement.assignee),
             value: Expression::from(statement.expression),
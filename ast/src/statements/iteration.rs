@@ -25,17 +25,18 @@ pub struct IterationStatement {
     pub variable: Identifier,
     pub start: Expression,
     pub stop: Expression,
+    pub condition: Option<Box<Expression>>,
     pub block: Block,
     pub span: Span,
 }
 
 impl fmt::Display for IterationStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "for {} in {}..{} {}",
-            self.variable, self.start, self.stop, self.block
-        )
+        write!(f, "for {} in {}..{} ", self.variable, self.start, self.stop)?;
+        if let Some(ref condition) = self.condition {
+            write!(f, "while {} ", condition)?;
+        }
+        write!(f, "{}", self.block)
     }
 }
 
@@ -45,6 +46,7 @@ impl<'ast> From<ForStatement<'ast>> for IterationStatement {
             variable: Identifier::from(statement.index),
             start: Expression::from(statement.start),
             stop: Expression::from(statement.stop),
+            condition: statement.condition.map(|condition| Box::new(Expression::from(*condition))),
             block: Block::from(statement.block),
             span: Span::from(statement.span),
         }
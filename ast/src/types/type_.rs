@@ -32,6 +32,7 @@ pub enum Type {
     // Data types
     Address,
     Boolean,
+    Char,
     Field,
     Group,
     IntegerType(IntegerType),
@@ -40,6 +41,7 @@ pub enum Type {
     Array(Box<Type>, ArrayDimensions),
     Tuple(Vec<Type>),
     Circuit(Identifier),
+    Enum(Identifier),
     SelfType,
 }
 
@@ -58,6 +60,22 @@ impl Type {
         matches!(self, Type::Circuit(_))
     }
 
+    ///
+    /// Returns the names of the const parameters used as array dimensions in the self `Type`.
+    ///
+    pub fn const_parameters(&self) -> Vec<&Identifier> {
+        match self {
+            Type::Array(type_, dimensions) => {
+                let mut parameters = dimensions.const_parameters();
+
+                parameters.extend(type_.const_parameters());
+                parameters
+            }
+            Type::Tuple(types) => types.iter().flat_map(|type_| type_.const_parameters()).collect(),
+            _ => vec![],
+        }
+    }
+
     ///
     /// Returns `true` if the self `Type` is equal to the other `Type`.
     ///
@@ -67,10 +85,12 @@ impl Type {
         match (self, other) {
             (Type::Address, Type::Address) => true,
             (Type::Boolean, Type::Boolean) => true,
+            (Type::Char, Type::Char) => true,
             (Type::Field, Type::Field) => true,
             (Type::Group, Type::Group) => true,
             (Type::IntegerType(left), Type::IntegerType(right)) => left.eq(&right),
             (Type::Circuit(left), Type::Circuit(right)) => left.eq(&right),
+            (Type::Enum(left), Type::Enum(right)) => left.eq(right),
             (Type::SelfType, Type::SelfType) => true,
             (Type::Array(left_type, left_dim), Type::Array(right_type, right_dim)) => {
                 // Convert array dimensions to owned.
@@ -109,6 +129,7 @@ impl From<DataType> for Type {
         match data_type {
             DataType::Address(_type) => Type::Address,
             DataType::Boolean(_type) => Type::Boolean,
+            DataType::Char(_type) => Type::Char,
             DataType::Field(_type) => Type::Field,
             DataType::Group(_type) => Type::Group,
             DataType::Integer(_type) => Type::IntegerType(IntegerType::from(_type)),
@@ -158,6 +179,7 @@ impl From<InputDataType> for Type {
         match data_type {
             InputDataType::Address(_type) => Type::Address,
             InputDataType::Boolean(_type) => Type::Boolean,
+            InputDataType::Char(_type) => Type::Char,
             InputDataType::Field(_type) => Type::Field,
             InputDataType::Group(_type) => Type::Group,
             InputDataType::Integer(type_) => Type::IntegerType(IntegerType::from(type_)),
@@ -197,10 +219,12 @@ impl fmt::Display for Type {
         match *self {
             Type::Address => write!(f, "address"),
             Type::Boolean => write!(f, "bool"),
+            Type::Char => write!(f, "char"),
             Type::Field => write!(f, "field"),
             Type::Group => write!(f, "group"),
             Type::IntegerType(ref integer_type) => write!(f, "{}", integer_type),
             Type::Circuit(ref variable) => write!(f, "circuit {}", variable),
+            Type::Enum(ref variable) => write!(f, "enum {}", variable),
             Type::SelfType => write!(f, "SelfType"),
             Type::Array(ref array, ref dimensions) => write!(f, "[{}; {}]", *array, dimensions),
             Type::Tuple(ref tuple) => {
@@ -17,17 +17,20 @@
 //! Compiles a Leo program from a file path.
 
 use crate::{
-    constraints::{generate_constraints, generate_test_constraints},
+    constraints::{fold_constants, generate_constraints, generate_public_inputs, generate_test_constraints},
     errors::CompilerError,
+    ConstrainedProgram,
     GroupType,
     OutputBytes,
     OutputFile,
+    PublicInputsBytes,
 };
 use leo_ast::{Ast, Input, MainInput, Program};
+use leo_control_flow::ControlFlow;
 use leo_grammar::Grammar;
 use leo_imports::ImportParser;
 use leo_input::LeoInputParser;
-use leo_package::inputs::InputPairs;
+use leo_package::{inputs::InputPairs, outputs::BuildManifest};
 use leo_state::verify_local_data_commitment;
 use leo_symbol_table::SymbolTable;
 use leo_type_inference::TypeInference;
@@ -41,6 +44,7 @@ use snarkos_models::{
 
 use sha2::{Digest, Sha256};
 use std::{
+    collections::BTreeMap,
     fs,
     marker::PhantomData,
     path::{Path, PathBuf},
@@ -55,6 +59,8 @@ pub struct Compiler<F: Field + PrimeField, G: GroupType<F>> {
     program: Program,
     program_input: Input,
     imported_programs: ImportParser,
+    resolved_program: ConstrainedProgram<F, G>,
+    input_files: Vec<PathBuf>,
     _engine: PhantomData<F>,
     _group: PhantomData<G>,
 }
@@ -71,6 +77,8 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
             program: Program::new(package_name),
             program_input: Input::new(),
             imported_programs: ImportParser::default(),
+            resolved_program: ConstrainedProgram::new(),
+            input_files: vec![],
             _engine: PhantomData,
             _group: PhantomData,
         }
@@ -95,6 +103,30 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
         Ok(compiler)
     }
 
+    ///
+    /// Returns a new `Compiler` from the given main file path.
+    ///
+    /// Parses and stores the public program input from the public inputs file path.
+    /// Parses and stores a program from the main file path.
+    /// Parses and stores all imported programs.
+    /// Performs type inference checking on the program, imported programs, and public program input.
+    ///
+    pub fn parse_program_with_public_inputs(
+        package_name: String,
+        main_file_path: PathBuf,
+        output_directory: PathBuf,
+        public_inputs_string: &str,
+        public_inputs_path: &Path,
+    ) -> Result<Self, CompilerError> {
+        let mut compiler = Self::new(package_name, main_file_path, output_directory);
+
+        compiler.parse_public_inputs(public_inputs_string, public_inputs_path)?;
+
+        compiler.parse_and_check_program()?;
+
+        Ok(compiler)
+    }
+
     ///
     /// Returns a new `Compiler` from the given main file path.
     ///
@@ -121,6 +153,36 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
         Ok(compiler)
     }
 
+    ///
+    /// Returns a new `Compiler` from the given main file path.
+    ///
+    /// Parses and stores program input from the input file path and state file path
+    /// Parses and stores a program from the main file path.
+    /// Parses and stores all imported programs.
+    /// Performs type inference checking on the program, imported programs, and program input.
+    ///
+    /// Constants are not evaluated, so the returned `Compiler` can be inspected but cannot generate constraints.
+    ///
+    pub fn type_check_program_with_input(
+        package_name: String,
+        main_file_path: PathBuf,
+        output_directory: PathBuf,
+        input_string: &str,
+        input_path: &Path,
+        state_string: &str,
+        state_path: &Path,
+    ) -> Result<Self, CompilerError> {
+        let mut compiler = Self::new(package_name, main_file_path, output_directory);
+
+        compiler.parse_input(input_string, input_path, state_string, state_path)?;
+
+        compiler.parse_program()?;
+
+        compiler.type_check_program()?;
+
+        Ok(compiler)
+    }
+
     ///
     /// Parses and stores program input from from the input file path and state file path
     ///
@@ -155,6 +217,38 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
             e
         })?;
 
+        self.input_files.push(input_path.to_path_buf());
+        self.input_files.push(state_path.to_path_buf());
+
+        Ok(())
+    }
+
+    ///
+    /// Parses and stores the public program input from the public inputs file path.
+    ///
+    /// Calls `set_path()` on compiler errors with the given public inputs file path
+    ///
+    pub fn parse_public_inputs(
+        &mut self,
+        public_inputs_string: &str,
+        public_inputs_path: &Path,
+    ) -> Result<(), CompilerError> {
+        let public_inputs_syntax_tree = LeoInputParser::parse_file(public_inputs_string).map_err(|mut e| {
+            e.set_path(public_inputs_path);
+
+            e
+        })?;
+
+        self.program_input
+            .parse_input(public_inputs_syntax_tree)
+            .map_err(|mut e| {
+                e.set_path(public_inputs_path);
+
+                e
+            })?;
+
+        self.input_files.push(public_inputs_path.to_path_buf());
+
         Ok(())
     }
 
@@ -203,15 +297,33 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
     /// First, a symbol table of all user defined types is created.
     /// Second, a type inference check is run on the program - inferring a data type for all implicit types and
     /// catching type mismatch errors.
+    /// Third, all constants are evaluated at compile time - catching constant expressions that allocate variables.
+    /// The resolved constants are stored and reused when the circuit is synthesized.
     ///
-    pub(crate) fn check_program(&self) -> Result<(), CompilerError> {
+    pub(crate) fn check_program(&mut self) -> Result<(), CompilerError> {
+        self.type_check_program()?;
+
+        // Evaluate all constants in program.
+        self.resolved_program = fold_constants::<F, G>(&self.program, &self.imported_programs).map_err(|mut e| {
+            e.set_path(&self.main_file_path);
+
+            e
+        })?;
+
+        tracing::debug!("Program checks complete");
+
+        Ok(())
+    }
+
+    ///
+    /// Runs the symbol table, control flow, and type inference checks on the program, imports, and input.
+    ///
+    fn type_check_program(&self) -> Result<(), CompilerError> {
         // Create a new symbol table from the program, imported_programs, and program_input.
-        let symbol_table =
-            SymbolTable::new(&self.program, &self.imported_programs, &self.program_input).map_err(|mut e| {
-                e.set_path(&self.main_file_path);
+        let symbol_table = self.symbol_table()?;
 
-                e
-            })?;
+        // Run control flow check on program and imported programs.
+        self.check_control_flow()?;
 
         // Run type inference check on program.
         TypeInference::new(&self.program, symbol_table).map_err(|mut e| {
@@ -220,11 +332,61 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
             e
         })?;
 
-        tracing::debug!("Program checks complete");
+        Ok(())
+    }
+
+    ///
+    /// Runs a control flow check on the main program and every imported program.
+    ///
+    /// Errors in an imported program are reported with the path of the imported file.
+    ///
+    fn check_control_flow(&self) -> Result<(), CompilerError> {
+        ControlFlow::new(&self.program).map_err(|mut e| {
+            e.set_path(&self.main_file_path);
+
+            e
+        })?;
+
+        for (file_name, program) in self.imported_programs.imports() {
+            ControlFlow::new(program).map_err(|mut e| {
+                let path = self.imported_programs.get_import_file(file_name).unwrap_or(&self.main_file_path);
+                e.set_path(path);
+
+                e
+            })?;
+        }
 
         Ok(())
     }
 
+    ///
+    /// Returns the program parsed from the main file.
+    ///
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    ///
+    /// Returns the programs imported by the main program.
+    ///
+    pub fn imported_programs(&self) -> &ImportParser {
+        &self.imported_programs
+    }
+
+    ///
+    /// Returns a new symbol table of the names and types defined by the program, its imports, and its input.
+    ///
+    pub fn symbol_table(&self) -> Result<SymbolTable, CompilerError> {
+        let symbol_table =
+            SymbolTable::new(&self.program, &self.imported_programs, &self.program_input).map_err(|mut e| {
+                e.set_path(&self.main_file_path);
+
+                e
+            })?;
+
+        Ok(symbol_table)
+    }
+
     ///
     /// Equivalent to parse_and_check_program but uses the given program_string instead of a main
     /// file path.
@@ -252,9 +414,15 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
         // Create a new symbol table from the program, imported programs, and program input.
         let symbol_table = SymbolTable::new(&self.program, &self.imported_programs, &self.program_input)?;
 
+        // Run control flow check on program and imported programs.
+        self.check_control_flow()?;
+
         // Run type inference check on program.
         TypeInference::new(&self.program, symbol_table)?;
 
+        // Evaluate all constants in program.
+        self.resolved_program = fold_constants::<F, G>(&self.program, &self.imported_programs)?;
+
         tracing::debug!("Program parsing complete\n{:#?}", self.program);
 
         Ok(())
@@ -282,19 +450,73 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
     }
 
     ///
-    /// Returns a Sha256 checksum of the program file.
+    /// Returns a Sha256 checksum of the program, its imports, its input files, and the compiler version.
     ///
     pub fn checksum(&self) -> Result<String, CompilerError> {
-        // Read in the main file as string
-        let unparsed_file = fs::read_to_string(&self.main_file_path)
-            .map_err(|_| CompilerError::FileReadError(self.main_file_path.clone()))?;
+        Ok(self.fingerprint()?.checksum)
+    }
 
-        // Hash the file contents
+    ///
+    /// Returns a Sha256 checksum of the program, its imports, and the compiler version.
+    ///
+    /// Unlike `checksum`, the input files are left out, so changing an input value keeps the checksum.
+    ///
+    pub fn circuit_checksum(&self) -> Result<String, CompilerError> {
+        let source_files = std::iter::once(&self.main_file_path).chain(self.imported_programs.source_files());
+
+        Ok(self.manifest(source_files)?.checksum)
+    }
+
+    ///
+    /// Returns a manifest of Sha256 checksums over every file the build depends on.
+    ///
+    /// The manifest covers the main file, every file resolved by the `ImportParser`, and the
+    /// parsed input files. Paths are stored relative to the package directory.
+    ///
+    pub fn fingerprint(&self) -> Result<BuildManifest, CompilerError> {
+        let source_files = std::iter::once(&self.main_file_path)
+            .chain(self.imported_programs.source_files())
+            .chain(self.input_files.iter());
+
+        self.manifest(source_files)
+    }
+
+    ///
+    /// Returns a manifest of Sha256 checksums over the given files and the compiler version.
+    ///
+    fn manifest<'a>(&self, source_files: impl Iterator<Item = &'a PathBuf>) -> Result<BuildManifest, CompilerError> {
+        // The main file lives in `src/`, so the package directory is two levels up
+        let package_directory = self.main_file_path.parent().and_then(Path::parent);
+
+        let mut files = BTreeMap::new();
+        for file_path in source_files {
+            let bytes = fs::read(file_path).map_err(|_| CompilerError::FileReadError(file_path.clone()))?;
+
+            let relative_path = package_directory
+                .and_then(|directory| file_path.strip_prefix(directory).ok())
+                .unwrap_or(file_path);
+
+            files.insert(relative_path.display().to_string(), hex::encode(Sha256::digest(&bytes)));
+        }
+
+        let compiler_version = env!("CARGO_PKG_VERSION").to_string();
+
+        // Hash the compiler version together with every file checksum
         let mut hasher = Sha256::new();
-        hasher.update(unparsed_file.as_bytes());
-        let hash = hasher.finalize();
+        hasher.update(compiler_version.as_bytes());
+        for (file, checksum) in &files {
+            hasher.update(b"\n");
+            hasher.update(file.as_bytes());
+            hasher.update(b"\0");
+            hasher.update(checksum.as_bytes());
+        }
+        let checksum = hex::encode(hasher.finalize());
 
-        Ok(hex::encode(hash))
+        Ok(BuildManifest {
+            compiler_version,
+            checksum,
+            files,
+        })
     }
 
     ///
@@ -303,7 +525,31 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
     pub fn compile_constraints<CS: ConstraintSystem<F>>(self, cs: &mut CS) -> Result<OutputBytes, CompilerError> {
         let path = self.main_file_path;
 
-        generate_constraints::<F, G, CS>(cs, self.program, self.program_input, &self.imported_programs).map_err(
+        generate_constraints::<F, G, CS>(cs, self.program, self.program_input, self.resolved_program).map_err(
+            |mut error| {
+                error.set_path(&path);
+
+                error
+            },
+        )
+    }
+
+    ///
+    /// Returns the public main function input and the given program output in the `.in` syntax.
+    ///
+    pub fn public_inputs(&self, output: &OutputBytes) -> PublicInputsBytes {
+        PublicInputsBytes::new_from_input(&self.program.expected_input, &self.program_input, output)
+    }
+
+    ///
+    /// Allocates the public program input without synthesizing the circuit.
+    ///
+    /// Used to construct the public input vector of a proof from a public inputs file.
+    ///
+    pub fn compile_public_inputs<CS: ConstraintSystem<F>>(self, cs: &mut CS) -> Result<(), CompilerError> {
+        let path = self.main_file_path;
+
+        generate_public_inputs::<F, G, CS>(cs, self.program, self.program_input, self.resolved_program).map_err(
             |mut error| {
                 error.set_path(&path);
 
@@ -319,7 +565,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
         generate_test_constraints::<F, G>(
             self.program,
             input_pairs,
-            &self.imported_programs,
+            self.resolved_program,
             &self.main_file_path,
             &self.output_directory,
         )
@@ -333,7 +579,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> Compiler<F, G> {
         cs: &mut CS,
     ) -> Result<OutputBytes, CompilerError> {
         let path = self.main_file_path;
-        generate_constraints::<_, G, _>(cs, self.program, self.program_input, &self.imported_programs).map_err(
+        generate_constraints::<_, G, _>(cs, self.program, self.program_input, self.resolved_program).map_err(
             |mut error| {
                 error.set_path(&path);
                 error
@@ -40,14 +40,11 @@ pub fn generate_constraints<F: Field + PrimeField, G: GroupType<F>, CS: Constrai
     cs: &mut CS,
     program: Program,
     input: Input,
-    imported_programs: &ImportParser,
+    mut resolved_program: ConstrainedProgram<F, G>,
 ) -> Result<OutputBytes, CompilerError> {
-    let mut resolved_program = ConstrainedProgram::<F, G>::new();
     let program_name = program.get_name();
     let main_function_name = new_scope(&program_name, "main");
 
-    resolved_program.store_definitions(&program, imported_programs)?;
-
     let main = resolved_program.get(&main_function_name).ok_or(CompilerError::NoMain)?;
 
     match main.clone() {
@@ -59,21 +56,59 @@ pub fn generate_constraints<F: Field + PrimeField, G: GroupType<F>, CS: Constrai
     }
 }
 
+///
+/// Allocates the public inputs of the main function in the order of the verifying key.
+///
+pub fn generate_public_inputs<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    program: Program,
+    input: Input,
+    mut resolved_program: ConstrainedProgram<F, G>,
+) -> Result<(), CompilerError> {
+    let program_name = program.get_name();
+    let main_function_name = new_scope(&program_name, "main");
+
+    let main = resolved_program.get(&main_function_name).ok_or(CompilerError::NoMain)?;
+
+    match main.clone() {
+        ConstrainedValue::Function(_circuit_identifier, function) => {
+            resolved_program.allocate_main_public_inputs(cs, *function, input)?;
+            Ok(())
+        }
+        _ => Err(CompilerError::NoMainFunction),
+    }
+}
+
+///
+/// Evaluates every constant in the program and its imports at compile time.
+///
+/// Constants are evaluated in the order they are defined. Returns an error if a constant expression
+/// cannot be evaluated without allocating variables or enforcing constraints.
+///
+/// Returns the program definitions with every constant value resolved.
+///
+pub fn fold_constants<F: Field + PrimeField, G: GroupType<F>>(
+    program: &Program,
+    imported_programs: &ImportParser,
+) -> Result<ConstrainedProgram<F, G>, CompilerError> {
+    let mut resolved_program = ConstrainedProgram::<F, G>::new();
+
+    resolved_program.store_definitions(program, imported_programs)?;
+
+    Ok(resolved_program)
+}
+
 pub fn generate_test_constraints<F: Field + PrimeField, G: GroupType<F>>(
     program: Program,
     input: InputPairs,
-    imported_programs: &ImportParser,
+    mut resolved_program: ConstrainedProgram<F, G>,
     main_file_path: &Path,
     output_directory: &Path,
 ) -> Result<(u32, u32), CompilerError> {
-    let mut resolved_program = ConstrainedProgram::<F, G>::new();
     let program_name = program.get_name();
 
     let tests = program.tests.clone();
 
-    // Store definitions
-    resolved_program.store_definitions(&program, imported_programs)?;
-
     // Get default input
     let default = input.pairs.get(&program_name);
 
@@ -18,3 +18,6 @@
 
 pub mod constraints;
 pub use self::constraints::*;
+
+pub mod public_constraint_system;
+pub use self::public_constraint_system::*;
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Evaluates a constant definition in a compiled Leo program.
+
+use crate::{
+    errors::ExpressionError,
+    program::{new_scope, ConstrainedProgram},
+    GroupType,
+};
+use leo_ast::Constant;
+
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::r1cs::ConstraintCounter,
+};
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    ///
+    /// Evaluates the expression of a constant at compile time and stores the resulting value.
+    ///
+    /// Returns an error if evaluating the expression allocates any variables or enforces any constraints.
+    ///
+    pub fn store_constant(&mut self, scope: &str, constant: &Constant) -> Result<(), ExpressionError> {
+        let constant_name = new_scope(scope, &constant.identifier.name);
+
+        // Evaluate the expression outside of the program constraint system without allocating definitions
+        let cs = &mut ConstraintCounter::new();
+        self.constant_evaluation = true;
+        let value = self.enforce_expression(
+            cs,
+            scope,
+            &constant_name,
+            Some(constant.type_.clone()),
+            constant.expression.clone(),
+        );
+        self.constant_evaluation = false;
+        let value = value?;
+
+        if cs.num_inputs > 0 || cs.num_aux > 0 || cs.num_constraints > 0 {
+            return Err(ExpressionError::non_constant_expression(constant.span.clone()));
+        }
+
+        self.store_constant_value(scope, &constant.identifier.name, value);
+
+        Ok(())
+    }
+}
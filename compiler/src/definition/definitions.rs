@@ -35,6 +35,8 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     ) -> Result<(), ImportError> {
         let program_name = program.name.trim_end_matches(".leo");
 
+        self.store_file(program_name);
+
         // evaluate all import statements and store imported definitions
         program
             .imports
@@ -51,6 +53,12 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             );
         });
 
+        // evaluate and store all enum definitions
+        program.enums.iter().for_each(|(identifier, enum_)| {
+            let resolved_enum_name = new_scope(program_name, &identifier.name);
+            self.store(resolved_enum_name, ConstrainedValue::EnumDefinition(enum_.clone()));
+        });
+
         // evaluate and store all function definitions
         program.functions.iter().for_each(|(function_name, function)| {
             let resolved_function_name = new_scope(program_name, &function_name.name);
@@ -60,6 +68,11 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             );
         });
 
+        // evaluate and store all constant definitions in the order they are defined
+        for constant in program.constants.iter() {
+            self.store_constant(program_name, constant)?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,1139 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The stable error codes of `leo-compiler` and their descriptions, as shown by `leo explain`.
+pub const ERROR_CODES: &[(&str, &str)] = &[
+    (
+        "E0400",
+        "A function does not return a value on every path, or contains unreachable code.
+
+A function with an output type must end every path with a `return`
+statement, and all returned values must have the same type. Statements after
+a `return` are never executed:
+
+    function main(a: bool) -> u8 {
+        if a {
+            return 1u8
+        }
+    }    // missing `return` when `a` is false",
+    ),
+    (
+        "E0401",
+        "An imported package could not be parsed.
+
+The compiler could not find or parse the files of an imported package. Check
+that the package is in the `imports` directory or the source directory and
+that its files have no syntax errors.",
+    ),
+    (
+        "E0402",
+        "A program input or state file could not be parsed.
+
+Input files in the `inputs` directory must contain the sections and values
+expected by the `main` function, for example:
+
+    [main]
+    a: u32 = 1;
+
+    [registers]
+    r0: u32 = 0;",
+    ),
+    (
+        "E0403",
+        "A test refers to an input file that does not exist.
+
+The `@test(name)` annotation selects the input file `inputs/name.in`. Check
+that the file exists in the inputs directory of the package.",
+    ),
+    (
+        "E0404",
+        "A program file could not be read.
+
+The compiler could not read the `.leo` file at the given path. Check that the
+file exists and can be read.",
+    ),
+    (
+        "E0405",
+        "The program state could not be verified.
+
+The local data commitment of the state file did not match the given record
+and state leaf values. Check the values in the `.state` input file.",
+    ),
+    (
+        "E0406",
+        "The program does not define a `main` function.
+
+Every program compiled by `leo build` must define the function that is called
+when the circuit is run:
+
+    function main() {}",
+    ),
+    (
+        "E0407",
+        "The name `main` is not defined as a function.
+
+The entry point of a program must be a function named `main`. It cannot be a
+circuit, enum, or constant.",
+    ),
+    (
+        "E0408",
+        "A test has no input file.
+
+A `@test` function without a named context needs the default input file of the
+package. Add an input file to the `inputs` directory or name one with
+`@test(name)`.",
+    ),
+    (
+        "E0409",
+        "A compiled program could not be serialized.
+
+The compiler failed to encode or decode a program with `bincode`. This
+usually indicates a bug in the compiler rather than in the program.",
+    ),
+    (
+        "E0410",
+        "An external crate failed while writing the program output.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0411",
+        "The program output file could not be created.
+
+Check that the `outputs` directory of the package exists and is writable.",
+    ),
+    (
+        "E0412",
+        "The program output file could not be read.
+
+Check that the file exists in the `outputs` directory and can be read.",
+    ),
+    (
+        "E0413",
+        "The program output file could not be removed.
+
+Check that the file in the `outputs` directory is not in use and that the
+directory is writable.",
+    ),
+    (
+        "E0414",
+        "The program output could not be written to its file.
+
+Check that the `outputs` directory of the package is writable and that the
+disk is not full.",
+    ),
+    (
+        "E0415",
+        "A core package function failed while evaluating an expression.
+
+A call to a function of an imported core package, such as
+`Blake2s::hash`, failed. Check the number and types of the arguments.",
+    ),
+    (
+        "E0416",
+        "A core package could not be imported.
+
+The compiler could not load the circuits of an imported core package. Check
+the spelling of the package path.",
+    ),
+    (
+        "E0417",
+        "A console string has a different number of containers than parameters.
+
+Each `{}` container in the string of `console.log` or `console.error` is
+replaced by one parameter, so their numbers must match:
+
+    console.log(\"{} {}\", a);    // two containers, one parameter",
+    ),
+    (
+        "E0418",
+        "An assertion could not be evaluated because an input value is missing.
+
+`console.assert` is evaluated when the circuit is run with input values. The
+assertion depends on an input that has no value in the input file. Check
+the `[main]` section of the input file.",
+    ),
+    (
+        "E0419",
+        "An assertion does not hold.
+
+The expression of a `console.assert` evaluated to `false` for the given
+input values:
+
+    console.assert(1u8 == 2u8);    // fails",
+    ),
+    (
+        "E0420",
+        "An assertion is not a boolean expression.
+
+`console.assert` takes a single expression that evaluates to `true` or
+`false`:
+
+    console.assert(1u8);    // not a boolean",
+    ),
+    (
+        "E0421",
+        "A gadget failed to synthesize the constraints of an expression.
+
+The constraint system rejected the constraints of an operation. This
+usually indicates a bug in the compiler or its gadgets rather than in the
+program.",
+    ),
+    (
+        "E0422",
+        "A comparison could not be enforced on its operands.
+
+The operands of `==`, `<`, `<=`, `>`, and `>=` must have the same type, and
+the ordering operators only compare integers and field elements:
+
+    let a = 1u8 < 2u16;    // mismatched types",
+    ),
+    (
+        "E0423",
+        "The condition of a ternary expression is not a boolean.
+
+The condition of `? :` must evaluate to `true` or `false`:
+
+    let a = 1u8 ? 2u8 : 3u8;    // not a boolean",
+    ),
+    (
+        "E0424",
+        "A const parameter is inferred as two different values.
+
+Each const parameter takes a single value at each call. When it is used as
+the dimension of several input arrays, the arrays must have the same
+length:
+
+    function zip<const N: u32>(a: [u8; N], b: [u8; N]) {}
+
+    zip([1u8; 2], [1u8; 3]);    // `N` is both 2 and 3",
+    ),
+    (
+        "E0425",
+        "An enum variant is given a payload of the wrong type.
+
+The payload of a variant must have the type that the variant declares:
+
+    enum Shape { Circle(u32) }
+
+    let a = Shape::Circle(true);    // expected a `u32` payload",
+    ),
+    (
+        "E0426",
+        "A circuit expression does not give a value to a circuit variable.
+
+A circuit expression must give a value to every variable of the circuit:
+
+    circuit Point { x: u32, y: u32 }
+
+    let p = Point { x: 1u32 };    // missing `y`",
+    ),
+    (
+        "E0427",
+        "An operation is not implemented for the types of its operands.
+
+Operators and casts are only defined for some types. For example, `+`
+requires two values of the same integer, field, or group type:
+
+    let a = true + false;    // no implementation for `+` on booleans",
+    ),
+    (
+        "E0428",
+        "A tuple index is out of bounds.
+
+Tuple elements are numbered from `0`, so the last element of a tuple of
+length `n` has the index `n - 1`:
+
+    let a = (1u8, 2u8);
+    let b = a.2;    // the tuple has no index `2`",
+    ),
+    (
+        "E0429",
+        "An array has different dimensions than its type.
+
+The dimensions of an array value must match the dimensions of its declared
+type:
+
+    let a: [u8; (2, 2)] = [0u8; 4];    // expected dimensions (2, 2)",
+    ),
+    (
+        "E0430",
+        "The first dimension of an array has a different length than expected.
+
+The length of an array value must match the first dimension of its declared
+type:
+
+    let a: [u8; 3] = [0u8; 2];    // expected length 3",
+    ),
+    (
+        "E0431",
+        "An array index is not an integer.
+
+Array elements and ranges are accessed with unsigned integer indices:
+
+    let a = [1u8, 2u8];
+    let b = a[true];    // not an integer",
+    ),
+    (
+        "E0432",
+        "An array has a different length than its type.
+
+The number of elements of an array value must match the length of its
+declared type:
+
+    let a: [u8; 3] = [1u8, 2u8];    // expected length 3",
+    ),
+    (
+        "E0433",
+        "The spread operator is applied to a value that is not an array.
+
+The spread operator `...` inserts the elements of an array into another
+array:
+
+    let a = [...1u8];    // `u8` is not an array",
+    ),
+    (
+        "E0434",
+        "A non-static circuit member is accessed with `::`.
+
+Circuit variables and functions that take `self` belong to a circuit value,
+so they are accessed with `.`:
+
+    let a = Point::x;    // must use `.`",
+    ),
+    (
+        "E0435",
+        "A static circuit member is accessed with `.`.
+
+Static circuit functions do not take `self`, so they are accessed through
+the circuit name with `::`:
+
+    let a = p.new();    // must use `::`",
+    ),
+    (
+        "E0436",
+        "A called function did not return a value.
+
+A function call is used as a value, but the called function returned
+without a value. Check that every path of the function ends with a
+`return` statement.",
+    ),
+    (
+        "E0437",
+        "A program constant cannot be evaluated at compile time.
+
+The value of a `const` definition must be computable without enforcing
+constraints, so it can only use literals, other constants, and operations
+on them:
+
+    const A: u32 = 1u32 + 2u32;    // ok",
+    ),
+    (
+        "E0438",
+        "The `Self` keyword is used outside of a circuit function.
+
+`Self` refers to the enclosing circuit, so it can only be used inside a
+circuit function.",
+    ),
+    (
+        "E0439",
+        "An array access refers to a variable that is not an array.
+
+An index or range access can only be applied to a variable that holds an
+array:
+
+    let a = 1u8;
+    let b = a[0];    // `a` is not an array",
+    ),
+    (
+        "E0440",
+        "A tuple access refers to a variable that is not a tuple.
+
+A tuple index can only be applied to a variable that holds a tuple:
+
+    let a = 1u8;
+    let b = a.0;    // `a` is not a tuple",
+    ),
+    (
+        "E0441",
+        "An expression refers to a circuit that is not defined.
+
+Circuit expressions and member accesses must name a circuit that is defined
+by the program or brought in by an import.",
+    ),
+    (
+        "E0442",
+        "An enum has no variant with the given name.
+
+Enum variants are accessed with `::` and must be defined by the enum:
+
+    let c = Color::Blue;    // `Color` has no variant `Blue`",
+    ),
+    (
+        "E0443",
+        "The first dimension of an array type is not a number.
+
+The dimension of an array must be a number, or a const parameter that is
+known where the array is created:
+
+    let a = [0u8; N];    // `N` has no value here",
+    ),
+    (
+        "E0444",
+        "A call refers to a function that is not defined.
+
+Function calls must name a function that is defined by the program or
+brought in by an import.",
+    ),
+    (
+        "E0445",
+        "An expression refers to a value that is not in scope.
+
+Variables must be defined before they are used and are only visible in the
+block that defines them and its nested blocks:
+
+    function main() -> u8 {
+        return a    // `a` is not defined
+    }",
+    ),
+    (
+        "E0446",
+        "A circuit has no member with the given name.
+
+Circuit variables and functions must be defined by the circuit before they
+are accessed:
+
+    let p = Point { x: 1u32, y: 2u32 };
+    let z = p.z;    // `Point` has no member `z`",
+    ),
+    (
+        "E0447",
+        "No arm of a match expression can be selected.
+
+The value of a `match` did not match the pattern of any arm. Add an arm for
+each variant of the enum, or a wildcard arm `_`.",
+    ),
+    (
+        "E0448",
+        "A circuit has no static member with the given name.
+
+Static circuit functions are called through the circuit name and must be
+defined by the circuit:
+
+    let p = Point::origin();    // `Point` has no static member `origin`",
+    ),
+    (
+        "E0449",
+        "The value of a const parameter cannot be resolved.
+
+Const parameters take their value from the length of an input array at each
+call. The compiler could not find the value of the parameter where it is
+used. Check that the parameter is used as an array dimension of an input
+type.",
+    ),
+    (
+        "E0450",
+        "An array is given where another type is expected.
+
+The array expression is used where its context requires a value of a
+different type:
+
+    let a: u8 = [1u8, 2u8];    // expected `u8`, found an array",
+    ),
+    (
+        "E0451",
+        "A tuple is given where another type is expected.
+
+The tuple expression is used where its context requires a value of a
+different type, or a tuple with a different number of elements:
+
+    let a: u8 = (1u8, 2u8);    // expected `u8`, found a tuple",
+    ),
+    (
+        "E0452",
+        "An array input has the wrong value.
+
+The value of an array input of `main` in the input file must be an array
+with the length and element type of the input type:
+
+    [main]
+    a: [u8; 2] = 1u8;    // expected an array",
+    ),
+    (
+        "E0453",
+        "A tuple input has the wrong value.
+
+The value of a tuple input of `main` in the input file must be a tuple with
+one value for each element of the input type:
+
+    [main]
+    a: (u8, bool) = 1u8;    // expected a tuple",
+    ),
+    (
+        "E0454",
+        "A function returns a different number of values than its output type.
+
+A function that returns a tuple must return one value for each element of
+its output type.",
+    ),
+    (
+        "E0455",
+        "A function returns a value of the wrong type.
+
+The returned value of a core package function does not match the output
+type of the function. This usually indicates a bug in the core package
+rather than in the program.",
+    ),
+    (
+        "E0456",
+        "The `main` function declares const parameters.
+
+Const parameters are inferred from the arguments of a call, but `main` is
+called with the values of the input file, so it cannot have const
+parameters:
+
+    function main<const N: u32>(a: [u8; N]) {}    // not allowed",
+    ),
+    (
+        "E0457",
+        "An output of `main` could not be allocated as a public input.
+
+The values returned by `main` are allocated as public inputs of the circuit.
+This usually indicates a bug in the compiler rather than in the program.",
+    ),
+    (
+        "E0458",
+        "A `main` input has no value in the input file.
+
+Every input of the `main` function needs a value in the `[main]` section of
+the input file:
+
+    function main(a: u32) {}
+
+    [main]
+    a: u32 = 1;",
+    ),
+    (
+        "E0459",
+        "An imported package cannot be found.
+
+The package of an import statement must be a `.leo` file in the source
+directory or a package in the `imports` directory:
+
+    import lib.add;    // requires `src/lib.leo` or `imports/lib`",
+    ),
+    (
+        "E0460",
+        "An imported symbol is not defined by the imported file.
+
+The symbols of an import statement must be circuits, enums, constants, or
+functions defined by the imported file.",
+    ),
+    (
+        "E0461",
+        "The program output could not be written to its registers.
+
+The `main` function returns more values than there are output registers in
+the `[registers]` section of the input file. Add a register for each returned
+value.",
+    ),
+    (
+        "E0462",
+        "A returned value has a different type than the function output.
+
+The values of a `return` statement must have the output type of the
+function:
+
+    function main() -> u32 {
+        return true    // expected `u32`, found `bool`
+    }",
+    ),
+    (
+        "E0463",
+        "A single index of a value that is not an array is assigned to.
+
+Index assignments are only valid on array variables:
+
+    let mut a = 1u8;
+    a[0] = 2u8;    // `a` is not an array",
+    ),
+    (
+        "E0464",
+        "An interior index of a value that is not an array is assigned to.
+
+Each index of a nested index assignment must access an array:
+
+    let mut a = [1u8; 2];
+    a[0][0] = 2u8;    // `a[0]` is not an array",
+    ),
+    (
+        "E0465",
+        "A range of an array is assigned a single value.
+
+A range assignment replaces several elements, so it must be given an array
+with one value for each element of the range:
+
+    let mut a = [1u8; 4];
+    a[0..2] = 2u8;           // expected an array
+    a[0..2] = [2u8, 3u8];    // ok",
+    ),
+    (
+        "E0466",
+        "An assigned array index is out of bounds.
+
+Array elements are numbered from `0`, so the last element of an array of
+length `n` has the index `n - 1`:
+
+    let mut a = [1u8; 2];
+    a[2] = 3u8;    // out of range",
+    ),
+    (
+        "E0467",
+        "An assigned array range is out of bounds.
+
+The start of a range assignment must not be after its end, and its end must
+not be after the end of the array:
+
+    let mut a = [1u8; 2];
+    a[1..3] = [2u8, 3u8];    // out of range",
+    ),
+    (
+        "E0468",
+        "The condition of an `if` statement is not a boolean.
+
+The condition of `if` must evaluate to `true` or `false`:
+
+    if 1u8 {}    // not a boolean",
+    ),
+    (
+        "E0469",
+        "A variable that is not mutable is assigned to.
+
+Variables are immutable unless they are defined with `let mut`:
+
+    let a = 1u8;
+    a = 2u8;        // `a` is immutable
+
+    let mut b = 1u8;
+    b = 2u8;        // ok",
+    ),
+    (
+        "E0470",
+        "A circuit function is assigned to.
+
+Circuit functions are part of the circuit definition and cannot be replaced
+by an assignment:
+
+    p.add = 1u32;    // `add` is a circuit function",
+    ),
+    (
+        "E0471",
+        "An immutable circuit variable is assigned to.
+
+Circuit variables can only be assigned through a mutable circuit value,
+such as a variable defined with `let mut` or `mut self`.",
+    ),
+    (
+        "E0472",
+        "The selector of a conditional branch could not be computed.
+
+The constraint system failed to combine the conditions of nested `if`
+statements. This usually indicates a bug in the compiler rather than in the
+program.",
+    ),
+    (
+        "E0473",
+        "A definition has a different number of variables than values.
+
+A definition of several variables must be given one value for each
+variable:
+
+    let (a, b) = (1u8, 2u8, 3u8);    // expected 2 values, found 3",
+    ),
+    (
+        "E0474",
+        "A `return` statement returns a different number of values than expected.
+
+A function that returns a tuple must return one value for each element of
+its output type:
+
+    function main() -> (u8, u8) {
+        return (1u8, 2u8, 3u8)    // expected 2 values, found 3
+    }",
+    ),
+    (
+        "E0475",
+        "Several variables are defined from a single value.
+
+A definition of several variables must be given a tuple, or a call to a
+function that returns a tuple:
+
+    let (a, b) = 1u8;    // expected a tuple",
+    ),
+    (
+        "E0476",
+        "A function does not return a value on any path.
+
+A function with an output type must return a value. No branch of the
+function reached a `return` statement for the given inputs:
+
+    function main(a: bool) -> u8 {
+        if a {}
+    }    // missing `return`",
+    ),
+    (
+        "E0477",
+        "The return values of two branches could not be combined.
+
+When a function returns from several branches, the returned values are
+combined with a conditional select. This error is reported when the values
+have different types or shapes:
+
+    if a {
+        return [1u8; 2]
+    } else {
+        return [1u8; 3]    // arrays of different lengths
+    }",
+    ),
+    (
+        "E0478",
+        "A single index of a value that is not a tuple is assigned to.
+
+Tuple index assignments are only valid on tuple variables:
+
+    let mut a = 1u8;
+    a.0 = 2u8;    // `a` is not a tuple",
+    ),
+    (
+        "E0479",
+        "An assigned tuple index is out of bounds.
+
+Tuple elements are numbered from `0`, so the last element of a tuple of
+length `n` has the index `n - 1`:
+
+    let mut a = (1u8, 2u8);
+    a.2 = 3u8;    // out of range",
+    ),
+    (
+        "E0480",
+        "A value that is not a tuple is used where a tuple is expected.
+
+The value must be a tuple, for example when several variables are defined
+from a single value.",
+    ),
+    (
+        "E0481",
+        "The return values of an expression statement are not assigned.
+
+A function call that returns values must be assigned to variables when it
+is used as a statement:
+
+    foo();            // the returned value is discarded
+    let a = foo();    // ok",
+    ),
+    (
+        "E0482",
+        "An assignment refers to a variable that is not defined.
+
+Variables must be defined with `let mut` before they are assigned to:
+
+    a = 1u8;    // `a` is not defined",
+    ),
+    (
+        "E0483",
+        "A member assignment refers to a value that is not a circuit.
+
+Member assignments are only valid on circuit values:
+
+    let mut a = 1u8;
+    a.x = 2u8;    // `a` is not a circuit",
+    ),
+    (
+        "E0484",
+        "A circuit has no variable with the given name.
+
+A member assignment must name a variable of the circuit:
+
+    let mut p = Point { x: 1u32, y: 2u32 };
+    p.z = 3u32;    // `Point` has no variable `z`",
+    ),
+    (
+        "E0485",
+        "An address value is not a valid account address.
+
+Addresses must be valid Aleo account addresses, for example:
+
+    let a: address = aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8sta57j8;",
+    ),
+    (
+        "E0486",
+        "A gadget failed to synthesize the constraints of an address operation.
+
+The constraint system rejected the constraints of an address comparison or
+allocation. This usually indicates a bug in the compiler rather than in the
+program.",
+    ),
+    (
+        "E0487",
+        "An operation is not implemented for addresses.
+
+Addresses can only be compared with `==` and `!=` and selected with a
+conditional.",
+    ),
+    (
+        "E0488",
+        "An address input has a value of another type.
+
+The value of an `address` input in the input file must be an address:
+
+    [main]
+    a: address = 1u8;    // expected an address",
+    ),
+    (
+        "E0489",
+        "An address input has no value in the input file.
+
+Every `address` input of the `main` function needs a value in the `[main]`
+section of the input file.",
+    ),
+    (
+        "E0490",
+        "A gadget failed to synthesize the constraints of a boolean operation.
+
+The constraint system rejected the constraints of a boolean operation. This
+usually indicates a bug in the compiler rather than in the program.",
+    ),
+    (
+        "E0491",
+        "An operation is not implemented for booleans.
+
+Booleans support `!`, `&&`, `||`, `==`, and `!=`.",
+    ),
+    (
+        "E0492",
+        "A boolean input has a value of another type.
+
+The value of a `bool` input in the input file must be `true` or `false`:
+
+    [main]
+    a: bool = 1u8;    // expected a boolean",
+    ),
+    (
+        "E0493",
+        "A boolean input has no value in the input file.
+
+Every `bool` input of the `main` function needs a value in the `[main]`
+section of the input file.",
+    ),
+    (
+        "E0494",
+        "A gadget failed to synthesize the constraints of a character operation.
+
+The constraint system rejected the constraints of a character operation.
+This usually indicates a bug in the compiler rather than in the program.",
+    ),
+    (
+        "E0495",
+        "A character input has a value of another type.
+
+The value of a `char` input in the input file must be a character between
+single quotes:
+
+    [main]
+    a: char = 1u8;    // expected a character",
+    ),
+    (
+        "E0496",
+        "A character is not a valid Unicode scalar value.
+
+Characters must be a single Unicode scalar value, for example `'a'` or
+`'\\u{2764}'`. Surrogate code points and values above `10FFFF` are not
+valid characters.",
+    ),
+    (
+        "E0497",
+        "A character input has no value in the input file.
+
+Every `char` input of the `main` function needs a value in the `[main]`
+section of the input file.",
+    ),
+    (
+        "E0498",
+        "A gadget failed to synthesize the constraints of a field negation.
+
+The constraint system rejected the constraints of `-` on a field element.
+This usually indicates a bug in the compiler rather than in the program.",
+    ),
+    (
+        "E0499",
+        "A gadget failed to synthesize the constraints of a field operation.
+
+The constraint system rejected the constraints of an operation on two field
+elements. Division by an allocated field element that is zero is reported
+with this error.",
+    ),
+    (
+        "E0500",
+        "A field input has a value of another type.
+
+The value of a `field` input in the input file must be an integer within
+the scalar field:
+
+    [main]
+    a: field = true;    // expected a field element",
+    ),
+    (
+        "E0501",
+        "A field input has no value in the input file.
+
+Every `field` input of the `main` function needs a value in the `[main]`
+section of the input file.",
+    ),
+    (
+        "E0502",
+        "A field element has no multiplicative inverse.
+
+Zero has no inverse in the field, so a constant field element cannot be
+divided by zero:
+
+    let a = 1field / 0field;    // no inverse",
+    ),
+    (
+        "E0503",
+        "A gadget failed to synthesize the constraints of a field value.
+
+The constraint system rejected the constraints of a field element. This
+usually indicates a bug in the compiler rather than in the program.",
+    ),
+    (
+        "E0504",
+        "A gadget failed to synthesize the constraints of a group negation.
+
+The constraint system rejected the constraints of `-` on a group element.
+This usually indicates a bug in the compiler rather than in the program.",
+    ),
+    (
+        "E0505",
+        "A gadget failed to synthesize the constraints of a group operation.
+
+The constraint system rejected the constraints of an operation on two group
+elements. This usually indicates a bug in the compiler rather than in the
+program.",
+    ),
+    (
+        "E0506",
+        "A group input has a value of another type.
+
+The value of a `group` input in the input file must be a group element,
+written either as a single coordinate such as `1group` or as a pair
+`(x, y)group`:
+
+    [main]
+    a: group = 1u8;    // expected a group element",
+    ),
+    (
+        "E0507",
+        "A group input has no value in the input file.
+
+Every `group` input of the `main` function needs a value in the `[main]`
+section of the input file.",
+    ),
+    (
+        "E0508",
+        "A gadget failed to synthesize the constraints of a group value.
+
+The constraint system rejected the constraints of a group element. This
+usually indicates a bug in the compiler rather than in the program.",
+    ),
+    (
+        "E0509",
+        "The x coordinate of a group element is not a field element.
+
+The coordinates of a group element must be integers within the base field
+of the curve.",
+    ),
+    (
+        "E0510",
+        "The y coordinate of a group element is not a field element.
+
+The coordinates of a group element must be integers within the base field
+of the curve.",
+    ),
+    (
+        "E0511",
+        "A group element is not on the curve.
+
+The coordinates of a pair `(x, y)group` must describe a point on the
+supported curve. Check both coordinates, or give one coordinate and infer
+the other with `+`, `-`, or `_`.",
+    ),
+    (
+        "E0512",
+        "A group element cannot be recovered from its x coordinate.
+
+No point on the curve has the given x coordinate. Check the coordinate of
+the `(x, _)group` pair.",
+    ),
+    (
+        "E0513",
+        "A group element cannot be recovered from its y coordinate.
+
+No point on the curve has the given y coordinate. Check the coordinate of
+the `(_, y)group` pair.",
+    ),
+    (
+        "E0514",
+        "The group generator cannot be multiplied by a number.
+
+A single coordinate such as `2group` multiplies the generator of the group
+by the number, which must be an integer within the scalar field.",
+    ),
+    (
+        "E0515",
+        "A gadget failed to synthesize the constraints of an integer operation.
+
+The constraint system rejected the constraints of an integer operation.
+This usually indicates a bug in the compiler rather than in the program.",
+    ),
+    (
+        "E0516",
+        "A signed integer operation overflows or is undefined.
+
+Signed integer operations fail when the result does not fit the type or
+when the operation is undefined:
+
+    let a = 127i8 + 1i8;    // overflows `i8`
+    let b = 1i8 / 0i8;      // division by zero",
+    ),
+    (
+        "E0517",
+        "An integer operation failed to synthesize its constraints.
+
+Integer operations fail when an allocated result does not fit the type or
+when an allocated divisor is zero:
+
+    function main(a: u8) -> u8 {
+        return a + 255u8    // overflows for `a > 0`
+    }",
+    ),
+    (
+        "E0518",
+        "A signed integer operation failed.
+
+A gadget of a signed integer operation rejected its operands. Check that the
+result of the operation fits the type and that divisors are not zero.",
+    ),
+    (
+        "E0519",
+        "An unsigned integer is negated.
+
+Only signed integers can be negated:
+
+    let a = -1u8;    // `u8` cannot be negated
+    let b = -1i8;    // ok",
+    ),
+    (
+        "E0520",
+        "An integer operation has operands of different types.
+
+The operands of an integer operation must have the same integer type. Cast
+one of the operands first:
+
+    let a = 1u8 + 1u16;              // mismatched types
+    let b = 1u8 as u16 + 1u16;       // ok",
+    ),
+    (
+        "E0521",
+        "A constant integer operation overflows or is undefined.
+
+Operations on constant integers are evaluated at compile time and fail when
+the result does not fit the type or when the operation is undefined:
+
+    let a = 255u8 + 1u8;    // overflows `u8`
+    let b = 1u8 / 0u8;      // division by zero",
+    ),
+    (
+        "E0522",
+        "A cast changes the value of an integer.
+
+A cast between integer types must keep the value of the integer:
+
+    let a = 256u16 as u8;    // 256 does not fit in `u8`",
+    ),
+    (
+        "E0523",
+        "An index is not a constant unsigned integer.
+
+Array indices must be known when the circuit is built, because an allocated
+index would produce a circuit of unknown size:
+
+    function main(i: u32) {
+        let a = [1u8; 4];
+        let b = a[i];    // `i` is an input
+    }",
+    ),
+    (
+        "E0524",
+        "An integer value does not fit its type.
+
+Integer literals and input values must be within the range of their type:
+
+    let a = 256u8;    // does not fit in `u8`",
+    ),
+    (
+        "E0525",
+        "An integer has a different integer type than expected.
+
+The type suffix of an integer must match the type that its context
+requires:
+
+    let a: u8 = 1u16;    // expected `u8`, found `u16`",
+    ),
+    (
+        "E0526",
+        "An integer is used where a value of another type is expected.
+
+The integer literal is used where its context requires a value that is not
+an integer:
+
+    let a: bool = 1;    // expected `bool`",
+    ),
+    (
+        "E0527",
+        "An integer input has no value in the input file.
+
+Every integer input of the `main` function needs a value in the `[main]`
+section of the input file.",
+    ),
+    (
+        "E0528",
+        "A value needs an explicit type.
+
+The type of a literal could not be inferred from its context. Add a type
+suffix or a type annotation:
+
+    let a = 1;       // unknown integer type
+    let b = 1u8;     // ok",
+    ),
+    (
+        "E0529",
+        "A group element is written in an invalid format.
+
+Group elements are written either as a single coordinate such as `1group` or
+as a pair `(x, y)group`.",
+    ),
+];
@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::errors::{FunctionError, ImportError, OutputBytesError, OutputFileError};
+use leo_control_flow::ControlFlowError;
 use leo_grammar::ParserError;
 use leo_imports::ImportParserError;
 use leo_input::InputParserError;
@@ -27,6 +28,9 @@ use std::path::{Path, PathBuf};
 
 #[derive(Debug, Error)]
 pub enum CompilerError {
+    #[error("{}", _0)]
+    ControlFlowError(#[from] ControlFlowError),
+
     #[error("{}", _0)]
     ImportError(#[from] ImportError),
 
@@ -76,8 +80,35 @@ pub enum CompilerError {
 }
 
 impl CompilerError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            CompilerError::ControlFlowError(_) => "E0400",
+            CompilerError::ImportError(error) => error.error_code(),
+            CompilerError::ImportParserError(_) => "E0401",
+            CompilerError::InputParserError(_) => "E0402",
+            CompilerError::InvalidTestContext(_) => "E0403",
+            CompilerError::FunctionError(error) => error.error_code(),
+            CompilerError::FileReadError(_) => "E0404",
+            CompilerError::LocalDataVerificationError(_) => "E0405",
+            CompilerError::NoMain => "E0406",
+            CompilerError::NoMainFunction => "E0407",
+            CompilerError::NoTestInput => "E0408",
+            CompilerError::OutputError(error) => error.error_code(),
+            CompilerError::OutputStringError(error) => error.error_code(),
+            CompilerError::ParserError(error) => error.error_code(),
+            CompilerError::SerdeError(_) => "E0409",
+            CompilerError::SymbolTableError(error) => error.error_code(),
+            CompilerError::TypeInferenceError(error) => error.error_code(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
+            CompilerError::ControlFlowError(error) => error.set_path(path),
+            CompilerError::ImportError(error) => error.set_path(path),
             CompilerError::InputParserError(error) => error.set_path(path),
             CompilerError::FunctionError(error) => error.set_path(path),
             CompilerError::OutputStringError(error) => error.set_path(path),
@@ -86,4 +117,15 @@ impl CompilerError {
             _ => {}
         }
     }
+
+    ///
+    /// Returns the number of independent errors reported by this error.
+    ///
+    pub fn error_count(&self) -> usize {
+        match self {
+            CompilerError::SymbolTableError(SymbolTableError::Errors(errors)) => errors.len(),
+            CompilerError::TypeInferenceError(TypeInferenceError::Errors(errors)) => errors.len(),
+            _ => 1,
+        }
+    }
 }
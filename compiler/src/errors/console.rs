@@ -29,6 +29,16 @@ pub enum ConsoleError {
 }
 
 impl ConsoleError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ConsoleError::Error(error) => error.code.unwrap_or_default(),
+            ConsoleError::Expression(error) => error.error_code(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
             ConsoleError::Expression(error) => error.set_path(path),
@@ -36,8 +46,8 @@ impl ConsoleError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        ConsoleError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ConsoleError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn length(containers: usize, parameters: usize, span: Span) -> Self {
@@ -46,25 +56,25 @@ impl ConsoleError {
             containers, parameters
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0417", message, span)
     }
 
     pub fn assertion_depends_on_input(span: Span) -> Self {
         let message =
             "console.assert() failed to evaluate. This error is caused by empty input file values".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0418", message, span)
     }
 
     pub fn assertion_failed(expression: String, span: Span) -> Self {
         let message = format!("Assertion `{}` failed", expression);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0419", message, span)
     }
 
     pub fn assertion_must_be_boolean(expression: String, span: Span) -> Self {
         let message = format!("Assertion expression `{}` must evaluate to a boolean value", expression);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0420", message, span)
     }
 }
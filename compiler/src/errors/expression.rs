@@ -14,8 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::{AddressError, BooleanError, FieldError, FunctionError, GroupError, IntegerError, ValueError};
-use leo_ast::{ArrayDimensions, Error as FormattedError, Identifier, PositiveNumber, Span};
+use crate::errors::{
+    AddressError,
+    BooleanError,
+    CharError,
+    FieldError,
+    FunctionError,
+    GroupError,
+    IntegerError,
+    ValueError,
+};
+use leo_ast::{ArrayDimension, ArrayDimensions, Error as FormattedError, Identifier, Span};
 use leo_core::LeoCorePackageError;
 
 use snarkos_errors::gadgets::SynthesisError;
@@ -29,6 +38,9 @@ pub enum ExpressionError {
     #[error("{}", _0)]
     BooleanError(#[from] BooleanError),
 
+    #[error("{}", _0)]
+    CharError(#[from] CharError),
+
     #[error("{}", _0)]
     Error(#[from] FormattedError),
 
@@ -52,10 +64,29 @@ pub enum ExpressionError {
 }
 
 impl ExpressionError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ExpressionError::AddressError(error) => error.error_code(),
+            ExpressionError::BooleanError(error) => error.error_code(),
+            ExpressionError::CharError(error) => error.error_code(),
+            ExpressionError::Error(error) => error.code.unwrap_or_default(),
+            ExpressionError::FieldError(error) => error.error_code(),
+            ExpressionError::FunctionError(error) => error.error_code(),
+            ExpressionError::GroupError(error) => error.error_code(),
+            ExpressionError::IntegerError(error) => error.error_code(),
+            ExpressionError::LeoCoreError(_) => "E0415",
+            ExpressionError::ValueError(error) => error.error_code(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
             ExpressionError::AddressError(error) => error.set_path(path),
             ExpressionError::BooleanError(error) => error.set_path(path),
+            ExpressionError::CharError(error) => error.set_path(path),
             ExpressionError::Error(error) => error.set_path(path),
             ExpressionError::FieldError(error) => error.set_path(path),
             ExpressionError::FunctionError(error) => error.set_path(path),
@@ -66,8 +97,8 @@ impl ExpressionError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        ExpressionError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ExpressionError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -76,37 +107,52 @@ impl ExpressionError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0421", message, span)
     }
 
     pub fn cannot_evaluate(operation: String, span: Span) -> Self {
         let message = format!("Mismatched types found for operation `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0422", message, span)
     }
 
     pub fn conditional_boolean(actual: String, span: Span) -> Self {
         let message = format!("if, else conditional must resolve to a boolean, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0423", message, span)
+    }
+
+    pub fn const_parameter_mismatch(name: String, expected: usize, actual: usize, span: Span) -> Self {
+        let message = format!(
+            "const parameter `{}` is inferred as both `{}` and `{}`",
+            name, expected, actual
+        );
+
+        Self::new_from_span("E0424", message, span)
+    }
+
+    pub fn enum_variant_payload(enum_name: String, variant: String, span: Span) -> Self {
+        let message = format!("enum variant `{}::{}` was given the wrong payload", enum_name, variant);
+
+        Self::new_from_span("E0425", message, span)
     }
 
     pub fn expected_circuit_member(expected: String, span: Span) -> Self {
         let message = format!("expected circuit member `{}`, not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0426", message, span)
     }
 
     pub fn incompatible_types(operation: String, span: Span) -> Self {
         let message = format!("no implementation for `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0427", message, span)
     }
 
     pub fn index_out_of_bounds(index: usize, span: Span) -> Self {
         let message = format!("cannot access index {} of tuple out of bounds", index);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0428", message, span)
     }
 
     pub fn invalid_dimensions(expected: &ArrayDimensions, actual: &ArrayDimensions, span: Span) -> Self {
@@ -115,70 +161,76 @@ impl ExpressionError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0429", message, span)
     }
 
-    pub fn invalid_first_dimension(expected: &PositiveNumber, actual: &PositiveNumber) -> Self {
+    pub fn invalid_first_dimension(expected: &ArrayDimension, actual: &ArrayDimension) -> Self {
         let message = format!(
             "expected array dimension {}, found array dimension {}",
             expected, actual
         );
 
-        Self::new_from_span(message, actual.span.to_owned())
+        Self::new_from_span("E0430", message, actual.span().to_owned())
     }
 
     pub fn invalid_index(actual: String, span: &Span) -> Self {
         let message = format!("index must resolve to an integer, found `{}`", actual);
 
-        Self::new_from_span(message, span.to_owned())
+        Self::new_from_span("E0431", message, span.to_owned())
     }
 
     pub fn invalid_length(expected: usize, actual: usize, span: Span) -> Self {
         let message = format!("expected array length {}, found one with length {}", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0432", message, span)
     }
 
     pub fn invalid_spread(actual: String, span: Span) -> Self {
         let message = format!("spread should contain an array, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0433", message, span)
     }
 
     pub fn invalid_member_access(member: String, span: Span) -> Self {
         let message = format!("non-static member `{}` must be accessed using `.` syntax", member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0434", message, span)
     }
 
     pub fn invalid_static_access(member: String, span: Span) -> Self {
         let message = format!("static member `{}` must be accessed using `::` syntax", member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0435", message, span)
     }
 
     pub fn function_no_return(function: String, span: Span) -> Self {
         let message = format!("inline function call to `{}` did not return", function);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0436", message, span)
+    }
+
+    pub fn non_constant_expression(span: Span) -> Self {
+        let message = "constant expression cannot be evaluated without enforcing constraints".to_string();
+
+        Self::new_from_span("E0437", message, span)
     }
 
     pub fn self_keyword(span: Span) -> Self {
         let message = "cannot call keyword `Self` outside of a circuit function".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0438", message, span)
     }
 
     pub fn undefined_array(actual: String, span: Span) -> Self {
         let message = format!("array `{}` must be declared before it is used in an expression", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0439", message, span)
     }
 
     pub fn undefined_tuple(actual: String, span: Span) -> Self {
         let message = format!("tuple `{}` must be declared before it is used in an expression", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0440", message, span)
     }
 
     pub fn undefined_circuit(actual: String, span: Span) -> Self {
@@ -187,13 +239,19 @@ impl ExpressionError {
             actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0441", message, span)
+    }
+
+    pub fn undefined_enum_variant(enum_name: String, variant: String, span: Span) -> Self {
+        let message = format!("Enum `{}` has no variant `{}`", enum_name, variant);
+
+        Self::new_from_span("E0442", message, span)
     }
 
     pub fn undefined_first_dimension(span: Span) -> Self {
         let message = "the first dimension of the array must be a number".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0443", message, span)
     }
 
     pub fn undefined_function(function: String, span: Span) -> Self {
@@ -202,36 +260,48 @@ impl ExpressionError {
             function
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0444", message, span)
     }
 
     pub fn undefined_identifier(identifier: Identifier) -> Self {
         let message = format!("Cannot find value `{}` in this scope", identifier.name);
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0445", message, identifier.span)
     }
 
     pub fn undefined_member_access(circuit: String, member: String, span: Span) -> Self {
         let message = format!("Circuit `{}` has no member `{}`", circuit, member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0446", message, span)
+    }
+
+    pub fn unmatched_value(value: String, span: Span) -> Self {
+        let message = format!("no match arm can be selected for value `{}`", value);
+
+        Self::new_from_span("E0447", message, span)
     }
 
     pub fn undefined_static_access(circuit: String, member: String, span: Span) -> Self {
         let message = format!("Circuit `{}` has no static member `{}`", circuit, member);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0448", message, span)
+    }
+
+    pub fn unresolved_const_parameter(identifier: &Identifier) -> Self {
+        let message = format!("cannot resolve the value of const parameter `{}`", identifier.name);
+
+        Self::new_from_span("E0449", message, identifier.span.to_owned())
     }
 
     pub fn unexpected_array(expected: String, span: Span) -> Self {
         let message = format!("expected type `{}`, found array with elements", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0450", message, span)
     }
 
     pub fn unexpected_tuple(expected: String, actual: String, span: Span) -> Self {
         let message = format!("expected type `{}`, found tuple with values `{}`", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0451", message, span)
     }
 }
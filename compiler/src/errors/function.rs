@@ -17,6 +17,7 @@
 use crate::errors::{
     AddressError,
     BooleanError,
+    CharError,
     ExpressionError,
     FieldError,
     GroupError,
@@ -37,6 +38,9 @@ pub enum FunctionError {
     #[error("{}", _0)]
     BooleanError(#[from] BooleanError),
 
+    #[error("{}", _0)]
+    CharError(#[from] CharError),
+
     #[error("{}", _0)]
     ExpressionError(#[from] ExpressionError),
 
@@ -63,10 +67,30 @@ pub enum FunctionError {
 }
 
 impl FunctionError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            FunctionError::AddressError(error) => error.error_code(),
+            FunctionError::BooleanError(error) => error.error_code(),
+            FunctionError::CharError(error) => error.error_code(),
+            FunctionError::ExpressionError(error) => error.error_code(),
+            FunctionError::Error(error) => error.code.unwrap_or_default(),
+            FunctionError::FieldError(error) => error.error_code(),
+            FunctionError::GroupError(error) => error.error_code(),
+            FunctionError::IntegerError(error) => error.error_code(),
+            FunctionError::OutputStringError(error) => error.error_code(),
+            FunctionError::StatementError(error) => error.error_code(),
+            FunctionError::ValueError(error) => error.error_code(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
             FunctionError::AddressError(error) => error.set_path(path),
             FunctionError::BooleanError(error) => error.set_path(path),
+            FunctionError::CharError(error) => error.set_path(path),
             FunctionError::ExpressionError(error) => error.set_path(path),
             FunctionError::Error(error) => error.set_path(path),
             FunctionError::FieldError(error) => error.set_path(path),
@@ -78,37 +102,49 @@ impl FunctionError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        FunctionError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        FunctionError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn invalid_array(actual: String, span: Span) -> Self {
         let message = format!("Expected function input array, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0452", message, span)
     }
 
     pub fn invalid_tuple(actual: String, span: Span) -> Self {
         let message = format!("Expected function input tuple, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0453", message, span)
     }
 
     pub fn return_arguments_length(expected: usize, actual: usize, span: Span) -> Self {
         let message = format!("function expected {} returns, found {} returns", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0454", message, span)
     }
 
     pub fn return_argument_type(expected: String, actual: String, span: Span) -> Self {
         let message = format!("Expected function return type `{}`, found `{}`", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0455", message, span)
+    }
+
+    pub fn generic_main(span: Span) -> Self {
+        let message = "main function cannot have const parameters".to_string();
+
+        Self::new_from_span("E0456", message, span)
+    }
+
+    pub fn public_output(value: String, span: Span) -> Self {
+        let message = format!("main function output `{}` cannot be allocated as a public input", value);
+
+        Self::new_from_span("E0457", message, span)
     }
 
     pub fn input_not_found(expected: String, span: Span) -> Self {
         let message = format!("main function input {} not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0458", message, span)
     }
 }
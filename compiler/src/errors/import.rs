@@ -14,21 +14,46 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::errors::ExpressionError;
 use leo_ast::{Error as FormattedError, Identifier, ImportSymbol, Span};
 use leo_core::LeoCorePackageError;
 
+use std::path::Path;
+
 #[derive(Debug, Error)]
 pub enum ImportError {
     #[error("{}", _0)]
     Error(#[from] FormattedError),
 
+    #[error("{}", _0)]
+    ExpressionError(#[from] ExpressionError),
+
     #[error("{}", _0)]
     LeoCoreError(#[from] LeoCorePackageError),
 }
 
 impl ImportError {
-    fn new_from_span(message: String, span: Span) -> Self {
-        ImportError::Error(FormattedError::new_from_span(message, span))
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ImportError::Error(error) => error.code.unwrap_or_default(),
+            ImportError::ExpressionError(error) => error.error_code(),
+            ImportError::LeoCoreError(_) => "E0416",
+        }
+    }
+
+    pub fn set_path(&mut self, path: &Path) {
+        match self {
+            ImportError::Error(error) => error.set_path(path),
+            ImportError::ExpressionError(error) => error.set_path(path),
+            ImportError::LeoCoreError(error) => error.set_path(path),
+        }
+    }
+
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ImportError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn unknown_package(identifier: Identifier) -> Self {
@@ -37,12 +62,12 @@ impl ImportError {
             identifier.name
         );
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0459", message, identifier.span)
     }
 
     pub fn unknown_symbol(symbol: ImportSymbol, file: String) -> Self {
         let message = format!("cannot find imported symbol `{}` in imported file `{}`", symbol, file);
-        let error = FormattedError::new_from_span(message, symbol.span);
+        let error = FormattedError::new_from_span_with_code("E0460", message, symbol.span);
 
         ImportError::Error(error)
     }
@@ -25,19 +25,28 @@ pub enum OutputBytesError {
 }
 
 impl OutputBytesError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            OutputBytesError::Error(error) => error.code.unwrap_or_default(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
             OutputBytesError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        OutputBytesError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        OutputBytesError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn not_enough_registers(span: Span) -> Self {
         let message = "number of input registers must be greater than or equal to output registers".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0461", message, span)
     }
 }
@@ -34,6 +34,21 @@ pub enum OutputFileError {
     Writing(io::Error),
 }
 
+impl OutputFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            OutputFileError::Crate(..) => "E0410",
+            OutputFileError::Creating(_) => "E0411",
+            OutputFileError::FileReadError(_) => "E0412",
+            OutputFileError::FileRemovalError(_) => "E0413",
+            OutputFileError::Writing(_) => "E0414",
+        }
+    }
+}
+
 impl From<std::io::Error> for OutputFileError {
     fn from(error: std::io::Error) -> Self {
         OutputFileError::Crate("std::io", error.to_string())
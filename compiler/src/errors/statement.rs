@@ -44,6 +44,21 @@ pub enum StatementError {
 }
 
 impl StatementError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            StatementError::AddressError(error) => error.error_code(),
+            StatementError::BooleanError(error) => error.error_code(),
+            StatementError::Error(error) => error.code.unwrap_or_default(),
+            StatementError::ExpressionError(error) => error.error_code(),
+            StatementError::IntegerError(error) => error.error_code(),
+            StatementError::MacroError(error) => error.error_code(),
+            StatementError::ValueError(error) => error.error_code(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
             StatementError::AddressError(error) => error.set_path(path),
@@ -56,32 +71,32 @@ impl StatementError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        StatementError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        StatementError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn arguments_type(expected: &Type, actual: &Type, span: Span) -> Self {
         let message = format!("expected return argument type `{}`, found type `{}`", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0462", message, span)
     }
 
     pub fn array_assign_index(span: Span) -> Self {
         let message = "Cannot assign single index to array of values".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0463", message, span)
     }
 
     pub fn array_assign_interior_index(span: Span) -> Self {
         let message = "Cannot assign single index to interior of array of values".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0464", message, span)
     }
 
     pub fn array_assign_range(span: Span) -> Self {
         let message = "Cannot assign range of array values to single value".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0465", message, span)
     }
 
     pub fn array_assign_index_bounds(index: usize, length: usize, span: Span) -> Self {
@@ -90,7 +105,7 @@ impl StatementError {
             index, length
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0466", message, span)
     }
 
     pub fn array_assign_range_order(start: usize, stop: usize, length: usize, span: Span) -> Self {
@@ -99,31 +114,31 @@ impl StatementError {
             start, stop, length
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0467", message, span)
     }
 
     pub fn conditional_boolean(actual: String, span: Span) -> Self {
         let message = format!("If, else conditional must resolve to a boolean, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0468", message, span)
     }
 
     pub fn immutable_assign(name: String, span: Span) -> Self {
         let message = format!("Cannot assign to immutable variable `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0469", message, span)
     }
 
     pub fn immutable_circuit_function(name: String, span: Span) -> Self {
         let message = format!("Cannot mutate circuit function, `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0470", message, span)
     }
 
     pub fn immutable_circuit_variable(name: String, span: Span) -> Self {
         let message = format!("Circuit member variable `{}` is immutable", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0471", message, span)
     }
 
     pub fn indicator_calculation(name: String, span: Span) -> Self {
@@ -132,7 +147,7 @@ impl StatementError {
             name
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0472", message, span)
     }
 
     pub fn invalid_number_of_definitions(expected: usize, actual: usize, span: Span) -> Self {
@@ -141,7 +156,7 @@ impl StatementError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0473", message, span)
     }
 
     pub fn invalid_number_of_returns(expected: usize, actual: usize, span: Span) -> Self {
@@ -150,20 +165,13 @@ impl StatementError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0474", message, span)
     }
 
     pub fn multiple_definition(value: String, span: Span) -> Self {
         let message = format!("cannot assign multiple variables to a single value: {}", value,);
 
-        Self::new_from_span(message, span)
-    }
-
-    pub fn multiple_returns(span: Span) -> Self {
-        let message = "This function returns multiple times and produces unreachable circuits with undefined behavior."
-            .to_string();
-
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0475", message, span)
     }
 
     pub fn no_returns(expected: Type, span: Span) -> Self {
@@ -172,7 +180,7 @@ impl StatementError {
             expected
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0476", message, span)
     }
 
     pub fn select_fail(first: String, second: String, span: Span) -> Self {
@@ -181,13 +189,13 @@ impl StatementError {
             first, second
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0477", message, span)
     }
 
     pub fn tuple_assign_index(span: Span) -> Self {
         let message = "Cannot assign single index to tuple of values".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0478", message, span)
     }
 
     pub fn tuple_assign_index_bounds(index: usize, length: usize, span: Span) -> Self {
@@ -196,36 +204,36 @@ impl StatementError {
             index, length
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0479", message, span)
     }
 
     pub fn tuple_type(type_: String, span: Span) -> Self {
         let message = format!("Expected tuple type, found type `{}`", type_);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0480", message, span)
     }
 
     pub fn unassigned(name: String, span: Span) -> Self {
         let message = format!("Expected assignment of return values for expression `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0481", message, span)
     }
 
     pub fn undefined_variable(name: String, span: Span) -> Self {
         let message = format!("Attempted to assign to unknown variable `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0482", message, span)
     }
 
     pub fn undefined_circuit(name: String, span: Span) -> Self {
         let message = format!("Attempted to assign to unknown circuit `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0483", message, span)
     }
 
     pub fn undefined_circuit_variable(name: String, span: Span) -> Self {
         let message = format!("Attempted to assign to unknown circuit member variable `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0484", message, span)
     }
 }
@@ -26,20 +26,29 @@ pub enum AddressError {
 }
 
 impl AddressError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            AddressError::Error(error) => error.code.unwrap_or_default(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
             AddressError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        AddressError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        AddressError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn account_error(error: AccountError, span: Span) -> Self {
         let message = format!("account creation failed due to `{}`", error);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0485", message, span)
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -48,24 +57,24 @@ impl AddressError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0486", message, span)
     }
 
     pub fn cannot_evaluate(operation: String, span: Span) -> Self {
         let message = format!("no implementation found for `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0487", message, span)
     }
 
     pub fn invalid_address(actual: String, span: Span) -> Self {
         let message = format!("expected address input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0488", message, span)
     }
 
     pub fn missing_address(span: Span) -> Self {
         let message = "expected address input not found".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0489", message, span)
     }
 }
@@ -26,14 +26,23 @@ pub enum BooleanError {
 }
 
 impl BooleanError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            BooleanError::Error(error) => error.code.unwrap_or_default(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
             BooleanError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        BooleanError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        BooleanError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -42,24 +51,24 @@ impl BooleanError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0490", message, span)
     }
 
     pub fn cannot_evaluate(operation: String, span: Span) -> Self {
         let message = format!("no implementation found for `{}`", operation);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0491", message, span)
     }
 
     pub fn invalid_boolean(actual: String, span: Span) -> Self {
         let message = format!("expected boolean input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0492", message, span)
     }
 
     pub fn missing_boolean(expected: String, span: Span) -> Self {
         let message = format!("expected boolean input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0493", message, span)
     }
 }
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Error as FormattedError, Span};
+
+use snarkos_errors::gadgets::SynthesisError;
+use std::path::Path;
+
+#[derive(Debug, Error)]
+pub enum CharError {
+    #[error("{}", _0)]
+    Error(#[from] FormattedError),
+}
+
+impl CharError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            CharError::Error(error) => error.code.unwrap_or_default(),
+        }
+    }
+
+    pub fn set_path(&mut self, path: &Path) {
+        match self {
+            CharError::Error(error) => error.set_path(path),
+        }
+    }
+
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        CharError::Error(FormattedError::new_from_span_with_code(code, message, span))
+    }
+
+    pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
+        let message = format!(
+            "the char operation `{}` failed due to the synthesis error `{:?}`",
+            operation, error,
+        );
+
+        Self::new_from_span("E0494", message, span)
+    }
+
+    pub fn invalid_char(actual: String, span: Span) -> Self {
+        let message = format!("expected char input type, found `{}`", actual);
+
+        Self::new_from_span("E0495", message, span)
+    }
+
+    pub fn invalid_scalar_value(value: String, span: Span) -> Self {
+        let message = format!("the value `{}` is not a valid unicode scalar value", value);
+
+        Self::new_from_span("E0496", message, span)
+    }
+
+    pub fn missing_char(span: Span) -> Self {
+        let message = "expected char input not found".to_string();
+
+        Self::new_from_span("E0497", message, span)
+    }
+}
@@ -26,20 +26,29 @@ pub enum FieldError {
 }
 
 impl FieldError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            FieldError::Error(error) => error.code.unwrap_or_default(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
             FieldError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        FieldError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        FieldError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn negate_operation(error: SynthesisError, span: Span) -> Self {
         let message = format!("field negation failed due to synthesis error `{:?}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0498", message, span)
     }
 
     pub fn binary_operation(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -48,30 +57,30 @@ impl FieldError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0499", message, span)
     }
 
     pub fn invalid_field(actual: String, span: Span) -> Self {
         let message = format!("expected field element input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0500", message, span)
     }
 
     pub fn missing_field(expected: String, span: Span) -> Self {
         let message = format!("expected field input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0501", message, span)
     }
 
     pub fn no_inverse(field: String, span: Span) -> Self {
         let message = format!("no multiplicative inverse found for field `{}`", field);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0502", message, span)
     }
 
     pub fn synthesis_error(error: SynthesisError, span: Span) -> Self {
         let message = format!("compilation failed due to field synthesis error `{:?}`", error);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0503", message, span)
     }
 }
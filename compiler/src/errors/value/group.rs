@@ -26,20 +26,29 @@ pub enum GroupError {
 }
 
 impl GroupError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            GroupError::Error(error) => error.code.unwrap_or_default(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
             GroupError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        GroupError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        GroupError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn negate_operation(error: SynthesisError, span: Span) -> Self {
         let message = format!("group negation failed due to the synthesis error `{:?}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0504", message, span)
     }
 
     pub fn binary_operation(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -48,60 +57,60 @@ impl GroupError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0505", message, span)
     }
 
     pub fn invalid_group(actual: String, span: Span) -> Self {
         let message = format!("expected group affine point input type, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0506", message, span)
     }
 
     pub fn missing_group(expected: String, span: Span) -> Self {
         let message = format!("expected group input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0507", message, span)
     }
 
     pub fn synthesis_error(error: SynthesisError, span: Span) -> Self {
         let message = format!("compilation failed due to group synthesis error `{:?}`", error);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0508", message, span)
     }
 
     pub fn x_invalid(x: String, span: Span) -> Self {
         let message = format!("invalid x coordinate `{}`", x);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0509", message, span)
     }
 
     pub fn y_invalid(y: String, span: Span) -> Self {
         let message = format!("invalid y coordinate `{}`", y);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0510", message, span)
     }
 
     pub fn not_on_curve(element: String, span: Span) -> Self {
         let message = format!("group element `{}` is not on the supported curve", element);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0511", message, span)
     }
 
     pub fn x_recover(span: Span) -> Self {
         let message = "could not recover group element from x coordinate".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0512", message, span)
     }
 
     pub fn y_recover(span: Span) -> Self {
         let message = "could not recover group element from y coordinate".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0513", message, span)
     }
 
     pub fn n_group(number: String, span: Span) -> Self {
         let message = format!("cannot multiply group generator by \"{}\"", number);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0514", message, span)
     }
 }
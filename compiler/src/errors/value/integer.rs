@@ -27,14 +27,23 @@ pub enum IntegerError {
 }
 
 impl IntegerError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            IntegerError::Error(error) => error.code.unwrap_or_default(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
             IntegerError::Error(error) => error.set_path(path),
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        IntegerError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        IntegerError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn cannot_enforce(operation: String, error: SynthesisError, span: Span) -> Self {
@@ -43,19 +52,19 @@ impl IntegerError {
             operation, error,
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0515", message, span)
     }
 
     pub fn signed(error: SignedIntegerError, span: Span) -> Self {
         let message = format!("integer operation failed due to the signed integer error `{:?}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0516", message, span)
     }
 
     pub fn synthesis(error: SynthesisError, span: Span) -> Self {
         let message = format!("integer operation failed due to the synthesis error `{}`", error,);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0517", message, span)
     }
 
     pub fn signed_error(operation: String, error: SignedIntegerError, span: Span) -> Self {
@@ -64,13 +73,13 @@ impl IntegerError {
             operation, error
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0518", message, span)
     }
 
     pub fn negate_operation(span: Span) -> Self {
         let message = "integer negation can only be enforced on signed integers".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0519", message, span)
     }
 
     pub fn binary_operation(operation: String, span: Span) -> Self {
@@ -79,7 +88,19 @@ impl IntegerError {
             operation
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0520", message, span)
+    }
+
+    pub fn constant_operation(operation: String, span: Span) -> Self {
+        let message = format!("the constant integer operation `{}` overflows or is undefined", operation);
+
+        Self::new_from_span("E0521", message, span)
+    }
+
+    pub fn cast_overflow(value: String, target: &IntegerType, span: Span) -> Self {
+        let message = format!("cannot cast `{}` to type `{}` without overflow", value, target);
+
+        Self::new_from_span("E0522", message, span)
     }
 
     pub fn invalid_index(span: Span) -> Self {
@@ -87,30 +108,30 @@ impl IntegerError {
             "index must be a constant value unsigned integer. allocated indices produce a circuit of unknown size"
                 .to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0523", message, span)
     }
 
     pub fn invalid_integer(actual: String, span: Span) -> Self {
         let message = format!("failed to parse `{}` as expected integer type", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0524", message, span)
     }
 
     pub fn invalid_integer_type(expected: &IntegerType, actual: &IntegerType, span: Span) -> Self {
         let message = format!("expected integer type {} found integer type {}", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0525", message, span)
     }
 
     pub fn invalid_type(actual: &Type, span: Span) -> Self {
         let message = format!("expected type {}, found type IntegerType", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0526", message, span)
     }
 
     pub fn missing_integer(expected: String, span: Span) -> Self {
         let message = format!("expected integer input `{}` not found", expected);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0527", message, span)
     }
 }
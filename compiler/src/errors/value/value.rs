@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::{AddressError, BooleanError, FieldError, GroupError, IntegerError};
+use crate::errors::{AddressError, BooleanError, CharError, FieldError, GroupError, IntegerError};
 use leo_ast::{Error as FormattedError, Span};
 
 use std::path::Path;
@@ -27,6 +27,9 @@ pub enum ValueError {
     #[error("{}", _0)]
     BooleanError(#[from] BooleanError),
 
+    #[error("{}", _0)]
+    CharError(#[from] CharError),
+
     #[error("{}", _0)]
     Error(#[from] FormattedError),
 
@@ -41,10 +44,26 @@ pub enum ValueError {
 }
 
 impl ValueError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ValueError::AddressError(error) => error.error_code(),
+            ValueError::BooleanError(error) => error.error_code(),
+            ValueError::CharError(error) => error.error_code(),
+            ValueError::Error(error) => error.code.unwrap_or_default(),
+            ValueError::FieldError(error) => error.error_code(),
+            ValueError::GroupError(error) => error.error_code(),
+            ValueError::IntegerError(error) => error.error_code(),
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         match self {
             ValueError::AddressError(error) => error.set_path(path),
             ValueError::BooleanError(error) => error.set_path(path),
+            ValueError::CharError(error) => error.set_path(path),
             ValueError::Error(error) => error.set_path(path),
             ValueError::FieldError(error) => error.set_path(path),
             ValueError::GroupError(error) => error.set_path(path),
@@ -52,19 +71,19 @@ impl ValueError {
         }
     }
 
-    fn new_from_span(message: String, span: Span) -> Self {
-        ValueError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        ValueError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     pub fn implicit(value: String, span: Span) -> Self {
         let message = format!("explicit type needed for `{}`", value);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0528", message, span)
     }
 
     pub fn implicit_group(span: Span) -> Self {
         let message = "group coordinates should be in (x, y)group format".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0529", message, span)
     }
 }
@@ -31,5 +31,8 @@ pub use self::mul::*;
 pub mod div;
 pub use self::div::*;
 
+pub mod rem;
+pub use self::rem::*;
+
 pub mod pow;
 pub use self::pow::*;
@@ -22,7 +22,7 @@ use crate::{
     value::ConstrainedValue,
     GroupType,
 };
-use leo_ast::{ArrayDimensions, Expression, PositiveNumber, Span, SpreadOrExpression, Type};
+use leo_ast::{ArrayDimension, ArrayDimensions, Expression, PositiveNumber, Span, SpreadOrExpression, Type};
 
 use snarkos_models::{
     curves::{Field, PrimeField},
@@ -47,17 +47,17 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             match type_ {
                 Type::Array(type_, mut dimensions) => {
                     // Remove the first dimension of the array.
-                    let first = match dimensions.remove_first() {
-                        Some(number) => {
+                    match dimensions.remove_first() {
+                        // The length of an array passed to a const parameter is not known yet.
+                        Some(ArrayDimension::Const(_)) => {}
+                        Some(dimension) => {
                             // Parse the array dimension into a `usize`.
-                            parse_index(&number, &span)?
+                            // Update the expected dimension to the first dimension.
+                            expected_dimension = Some(parse_dimension(&dimension, &span)?);
                         }
                         None => return Err(ExpressionError::unexpected_array(type_.to_string(), span)),
                     };
 
-                    // Update the expected dimension to the first dimension.
-                    expected_dimension = Some(first);
-
                     // Update the expected type to a new array type with the first dimension removed.
                     expected_type = Some(inner_array_type(*type_, dimensions));
                 }
@@ -121,6 +121,15 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         mut actual_dimensions: ArrayDimensions,
         span: Span,
     ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+        // Take the dimensions of const parameters that are not known yet from the initializer.
+        let expected_type = match expected_type {
+            Some(Type::Array(type_, expected_dimensions)) if expected_dimensions.is_generic() => Some(Type::Array(
+                type_,
+                fill_const_dimensions(expected_dimensions, &actual_dimensions),
+            )),
+            expected_type => expected_type,
+        };
+
         // Compare dimensions
         // Case 1: expected == actual => enforce expression with array element type
         // Case 2: expected first dimension == actual first dimension => enforce expression with updated array type
@@ -135,7 +144,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 // Allocate the array.
                 while let Some(dimension) = actual_dimensions.remove_last() {
                     // Parse the dimension into a `usize`.
-                    let dimension_usize = parse_index(&dimension, &span)?;
+                    let dimension_usize = parse_dimension(&dimension, &span)?;
 
                     // Allocate the array dimension.
                     let array = vec![value; dimension_usize];
@@ -148,9 +157,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             } else if expected_dimensions.first().eq(&actual_dimensions.first()) {
                 // Case 2 - enforce expression with updated array type.
                 let dimension = match expected_dimensions.remove_first() {
-                    Some(number) => {
+                    Some(dimension) => {
                         // Parse the array dimension into a `usize`.
-                        parse_index(&number, &span)?
+                        parse_dimension(&dimension, &span)?
                     }
                     None => return Err(ExpressionError::unexpected_array(type_.to_string(), span)),
                 };
@@ -211,7 +220,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             // Allocate the array.
             while let Some(dimension) = actual_dimensions.remove_last() {
                 // Parse the dimension into a `usize`.
-                let dimension_usize = parse_index(&dimension, &span)?;
+                let dimension_usize = parse_dimension(&dimension, &span)?;
 
                 // Allocate the array dimension.
                 let array = vec![value; dimension_usize];
@@ -235,6 +244,36 @@ pub fn parse_index(number: &PositiveNumber, span: &Span) -> Result<usize, Expres
         .map_err(|_| ExpressionError::invalid_index(number.value.to_owned(), span))
 }
 
+///
+/// Returns the array dimension as a usize.
+///
+/// Dimensions that refer to a const parameter must be resolved before they are parsed.
+///
+pub fn parse_dimension(dimension: &ArrayDimension, span: &Span) -> Result<usize, ExpressionError> {
+    match dimension {
+        ArrayDimension::Number(number) => parse_index(number, span),
+        ArrayDimension::Const(identifier) => Err(ExpressionError::unresolved_const_parameter(identifier)),
+    }
+}
+
+///
+/// Returns the expected array dimensions with each const parameter replaced by the actual dimension
+/// at the same position.
+///
+fn fill_const_dimensions(expected: ArrayDimensions, actual: &ArrayDimensions) -> ArrayDimensions {
+    ArrayDimensions(
+        expected
+            .0
+            .into_iter()
+            .enumerate()
+            .map(|(i, dimension)| match (dimension, actual.0.get(i)) {
+                (ArrayDimension::Const(_), Some(actual)) => actual.clone(),
+                (dimension, _) => dimension,
+            })
+            .collect(),
+    )
+}
+
 ///
 /// Returns the type of the inner array given an array element and array dimensions.
 ///
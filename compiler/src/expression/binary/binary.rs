@@ -17,7 +17,7 @@
 //! Enforces a binary expression in a compiled Leo program.
 
 use crate::{errors::ExpressionError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
-use leo_ast::{Expression, Span, Type};
+use leo_ast::{Expression, IntegerType, Span, Type};
 
 use snarkos_models::{
     curves::{Field, PrimeField},
@@ -47,4 +47,26 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
 
         Ok((resolved_left, resolved_right))
     }
+
+    /// Enforces the operands of a shift, where the shift amount may be an integer of any unsigned type.
+    #[allow(clippy::too_many_arguments)]
+    pub fn enforce_shift_expression<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        file_scope: &str,
+        function_scope: &str,
+        expected_type: Option<Type>,
+        left: Expression,
+        right: Expression,
+        span: &Span,
+    ) -> Result<ConstrainedValuePair<F, G>, ExpressionError> {
+        let resolved_left = self.enforce_operand(cs, file_scope, function_scope, expected_type, left, span)?;
+
+        // An implicit shift amount is a `u32`.
+        let amount_type = Some(Type::IntegerType(IntegerType::U32));
+        let mut resolved_right = self.enforce_operand(cs, file_scope, function_scope, None, right, span)?;
+        resolved_right.resolve_type(amount_type, span)?;
+
+        Ok((resolved_left, resolved_right))
+    }
 }
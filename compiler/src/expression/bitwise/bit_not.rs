@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces a bitwise NOT `!` operator in a resolved Leo program.
+
+use crate::{errors::ExpressionError, value::ConstrainedValue, GroupType};
+use leo_ast::Span;
+
+use snarkos_models::curves::{Field, PrimeField};
+
+pub fn enforce_bit_not<F: Field + PrimeField, G: GroupType<F>>(
+    value: ConstrainedValue<F, G>,
+    span: &Span,
+) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+    match value {
+        ConstrainedValue::Integer(integer) => Ok(ConstrainedValue::Integer(integer.bit_not())),
+        value => Err(ExpressionError::incompatible_types(format!("!{}", value), span.to_owned())),
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces an `as` cast expression in a compiled Leo program.
+
+use crate::{errors::ExpressionError, value::ConstrainedValue, Char, GroupType, Integer};
+use leo_ast::{IntegerType, Span, Type};
+
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::r1cs::ConstraintSystem,
+};
+
+pub fn enforce_cast<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    value: ConstrainedValue<F, G>,
+    target_type: Type,
+    span: &Span,
+) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+    match (value, target_type) {
+        (ConstrainedValue::Integer(integer), Type::IntegerType(integer_type)) => {
+            Ok(ConstrainedValue::Integer(integer.cast(cs, &integer_type, span)?))
+        }
+        (ConstrainedValue::Integer(integer), Type::Field) => Ok(ConstrainedValue::Field(integer.to_field::<F, CS>())),
+        (ConstrainedValue::Field(field), Type::IntegerType(integer_type)) => Ok(ConstrainedValue::Integer(
+            Integer::from_field(cs, field, &integer_type, span)?,
+        )),
+        (ConstrainedValue::Field(field), Type::Field) => Ok(ConstrainedValue::Field(field)),
+        (ConstrainedValue::Boolean(boolean), Type::IntegerType(integer_type)) => Ok(ConstrainedValue::Integer(
+            Integer::from_boolean(boolean, &integer_type),
+        )),
+        (ConstrainedValue::Boolean(boolean), Type::Boolean) => Ok(ConstrainedValue::Boolean(boolean)),
+        (ConstrainedValue::Integer(integer), Type::Char) => {
+            let integer = integer.cast(cs, &IntegerType::U32, span)?;
+
+            Ok(ConstrainedValue::Char(Char::from_integer(cs, integer, span)?))
+        }
+        (ConstrainedValue::Char(character), Type::IntegerType(integer_type)) => Ok(ConstrainedValue::Integer(
+            character.integer.cast(cs, &integer_type, span)?,
+        )),
+        (ConstrainedValue::Char(character), Type::Char) => Ok(ConstrainedValue::Char(character)),
+        (ConstrainedValue::Unresolved(string), target_type) => {
+            Ok(ConstrainedValue::from_type(string, &target_type, span)?)
+        }
+        (value, target_type) => Err(ExpressionError::incompatible_types(
+            format!("{} as {}", value, target_type),
+            span.to_owned(),
+        )),
+    }
+}
@@ -70,9 +70,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     ConstrainedValue::Function(ref _circuit_identifier, ref function) => {
                         // Check for function input `self` or `mut self`.
                         if function.contains_self() {
+                            let circuit_scope = new_scope(&file_scope, &circuit_name.name);
+
+                            // Infer the const parameters of the circuit from its member variables
+                            let program_scope = self.file_scope(file_scope).unwrap_or(file_scope);
+                            self.store_circuit_const_parameters(
+                                program_scope,
+                                &circuit_scope,
+                                &circuit_name,
+                                &members,
+                            )?;
+
                             // Pass circuit members into function call by value
                             for stored_member in members {
-                                let circuit_scope = new_scope(&file_scope, &circuit_name.name);
                                 let self_keyword = new_scope(&circuit_scope, SELF_KEYWORD);
                                 let variable = new_scope(&self_keyword, &stored_member.0.name);
 
@@ -24,6 +24,7 @@ use crate::{
 };
 use leo_ast::{CircuitMember, CircuitVariableDefinition, Identifier, Span};
 
+use indexmap::IndexMap;
 use snarkos_models::{
     curves::{Field, PrimeField},
     gadgets::r1cs::ConstraintSystem,
@@ -39,12 +40,13 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         members: Vec<CircuitVariableDefinition>,
         span: Span,
     ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
-        // Circuit definitions are located at the minimum file scope
-        let minimum_scope = file_scope.split('_').next().unwrap();
+        // Circuit definitions are located at the scope of the file
+        let program_scope = self.file_scope(file_scope).unwrap_or(file_scope);
         let identifier_string = identifier.to_string();
-        let mut program_identifier = new_scope(minimum_scope, &identifier_string);
+        let mut program_identifier = new_scope(program_scope, &identifier_string);
 
-        if identifier.is_self() {
+        let identifier_is_self = identifier.is_self();
+        if identifier_is_self {
             program_identifier = file_scope.to_string();
         }
 
@@ -56,9 +58,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         let circuit_identifier = circuit.circuit_name.clone();
         let mut resolved_members = Vec::with_capacity(circuit.members.len());
 
+        // Check that the const parameters inferred from the member variables are consistent
+        let mut const_parameters = IndexMap::new();
+
         for member in circuit.members.into_iter() {
             match member {
                 CircuitMember::CircuitVariable(identifier, type_) => {
+                    // The const parameters of the circuit are only known inside its own functions
+                    let type_ = if identifier_is_self {
+                        self.resolve_type(file_scope, function_scope, type_)
+                    } else {
+                        type_
+                    };
+
                     let matched_variable = members
                         .clone()
                         .into_iter()
@@ -74,6 +86,8 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                                 variable.expression,
                             )?;
 
+                            Self::bind_const_parameters(&mut const_parameters, &type_, &variable_value, &span)?;
+
                             resolved_members.push(ConstrainedCircuitMember(identifier, variable_value))
                         }
                         None => return Err(ExpressionError::expected_circuit_member(identifier.to_string(), span)),
@@ -37,7 +37,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         span: Span,
     ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
         // Get defined circuit
-        let circuit = match circuit_identifier {
+        let value = match circuit_identifier {
             Expression::Identifier(identifier) => {
                 // Use the "Self" keyword to access a static circuit function
                 if identifier.is_self() {
@@ -51,8 +51,22 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 }
             }
             expression => self.enforce_expression(cs, file_scope, function_scope, expected_type, expression)?,
+        };
+
+        // Construct an enum variant without a payload
+        if let Some(enum_) = value.enum_definition() {
+            return self.enforce_enum_variant(
+                cs,
+                file_scope,
+                function_scope,
+                enum_.clone(),
+                circuit_member,
+                None,
+                span,
+            );
         }
-        .extract_circuit(&span)?;
+
+        let circuit = value.extract_circuit(&span)?;
 
         // Find static circuit function
         let matched_function = circuit.members.into_iter().find(|member| match member {
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces a match expression in a compiled Leo program.
+
+use crate::{
+    errors::ExpressionError,
+    program::{new_scope, ConstrainedProgram},
+    value::{ConstrainedEnumVariant, ConstrainedValue},
+    GroupType,
+};
+use leo_ast::{Expression, MatchArm, MatchPattern, Span, Type};
+
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{boolean::Boolean, select::CondSelectGadget},
+    },
+};
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Enforce a match expression.
+    /// Every arm that can be selected is evaluated with an indicator that is only set if the arm is selected,
+    /// and the arm values are combined with a chain of conditional selects on the variant bits of the matched
+    /// enum value.
+    #[allow(clippy::too_many_arguments)]
+    pub fn enforce_match_expression<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        file_scope: &str,
+        function_scope: &str,
+        expected_type: Option<Type>,
+        inner: Expression,
+        arms: Vec<MatchArm>,
+        span: &Span,
+    ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+        let mut value = self.enforce_expression(cs, file_scope, function_scope, None, inner)?;
+        value.get_inner_mut();
+
+        let mut arm_values: Vec<(Option<Boolean>, ConstrainedValue<F, G>)> = Vec::with_capacity(arms.len());
+
+        for (i, arm) in arms.into_iter().enumerate() {
+            let arm_span = arm.span.clone();
+
+            // The arm binding shadows any variable of the same name until the arm is evaluated
+            let mut shadowed = None;
+
+            let indicator = match arm.pattern {
+                MatchPattern::Variant(_enum_name, variant, binding) => {
+                    let ConstrainedEnumVariant(_, is_active, payload) = match &value {
+                        ConstrainedValue::EnumExpression(identifier, variants) => variants
+                            .iter()
+                            .find(|enum_variant| enum_variant.0.name == variant.name)
+                            .ok_or_else(|| {
+                                ExpressionError::undefined_enum_variant(
+                                    identifier.to_string(),
+                                    variant.to_string(),
+                                    variant.span.clone(),
+                                )
+                            })?,
+                        value => {
+                            return Err(ExpressionError::incompatible_types(
+                                format!("match {}", value),
+                                span.to_owned(),
+                            ));
+                        }
+                    };
+
+                    // Skip arms for variants that are never active
+                    if let Boolean::Constant(false) = is_active {
+                        continue;
+                    }
+
+                    // Store the payload in the current function scope for the duration of the arm
+                    if let Some(binding) = binding {
+                        match payload {
+                            Some(payload) => {
+                                let name = new_scope(function_scope, &binding.name);
+                                let previous = self.get(&name).cloned();
+
+                                self.store(name.clone(), payload.clone());
+                                shadowed = Some((name, previous));
+                            }
+                            None => continue,
+                        }
+                    }
+
+                    Some(*is_active)
+                }
+                MatchPattern::Wildcard(_) => None,
+            };
+
+            // A wildcard arm is selected if no other arm is selected
+            let arm_indicator = match indicator {
+                Some(indicator) => indicator,
+                None => {
+                    let mut selected = Boolean::constant(false);
+
+                    for (j, other) in arm_values.iter().filter_map(|(indicator, _)| indicator.as_ref()).enumerate() {
+                        selected = Boolean::or(cs.ns(|| format!("match arm {} selected {}", i, j)), &selected, other)
+                            .map_err(|e| {
+                                ExpressionError::cannot_enforce("match arm indicator".to_string(), e, arm_span.clone())
+                            })?;
+                    }
+
+                    selected.not()
+                }
+            };
+
+            // Functions called from the arm are only selected if the arm is selected
+            let outer_indicator = self.indicator;
+            self.indicator = Boolean::and(
+                cs.ns(|| format!("match arm indicator {} {}:{}", i, arm_span.line, arm_span.start)),
+                &outer_indicator,
+                &arm_indicator,
+            )
+            .map_err(|e| ExpressionError::cannot_enforce("match arm indicator".to_string(), e, arm_span.clone()))?;
+
+            let arm_value = self.enforce_operand(
+                cs,
+                file_scope,
+                function_scope,
+                expected_type.clone(),
+                arm.expression,
+                &arm_span,
+            );
+
+            self.indicator = outer_indicator;
+            let arm_value = arm_value?;
+
+            // Restore the shadowed variable once the arm has been evaluated
+            if let Some((name, previous)) = shadowed {
+                match previous {
+                    Some(previous) => self.store(name, previous),
+                    None => self.remove(&name),
+                }
+            }
+
+            arm_values.push((indicator, arm_value));
+        }
+
+        // The last arm is selected if no other arm is active
+        let (_, mut result) = arm_values
+            .pop()
+            .ok_or_else(|| ExpressionError::unmatched_value(value.to_string(), span.to_owned()))?;
+
+        for (i, (indicator, arm_value)) in arm_values.into_iter().enumerate().rev() {
+            result = match indicator {
+                Some(indicator) => {
+                    let unique_namespace =
+                        cs.ns(|| format!("select match arm {} {}:{}", i, span.line, span.start));
+
+                    ConstrainedValue::conditionally_select(unique_namespace, &indicator, &arm_value, &result)
+                        .map_err(|e| ExpressionError::cannot_enforce("match select".to_string(), e, span.to_owned()))?
+                }
+                None => arm_value,
+            };
+        }
+
+        Ok(result)
+    }
+}
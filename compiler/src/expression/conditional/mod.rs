@@ -18,3 +18,6 @@
 
 pub mod conditional;
 pub use self::conditional::*;
+
+pub mod match_;
+pub use self::match_::*;
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces an enum variant expression in a compiled Leo program.
+
+use crate::{
+    errors::ExpressionError,
+    program::ConstrainedProgram,
+    value::{ConstrainedEnumVariant, ConstrainedValue},
+    GroupType,
+};
+use leo_ast::{Enum, Expression, Identifier, Span};
+
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{r1cs::ConstraintSystem, utilities::boolean::Boolean},
+};
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Enforce an enum variant expression.
+    /// Every variant of the enum is stored with a constant bit that is only set for the given variant.
+    #[allow(clippy::too_many_arguments)]
+    pub fn enforce_enum_variant<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        file_scope: &str,
+        function_scope: &str,
+        enum_: Enum,
+        variant: Identifier,
+        arguments: Option<Vec<Expression>>,
+        span: Span,
+    ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+        let index = enum_.variant_index(&variant.name).ok_or_else(|| {
+            ExpressionError::undefined_enum_variant(enum_.enum_name.to_string(), variant.to_string(), span.clone())
+        })?;
+
+        // Evaluate the variant payload
+        let payload = match (&enum_.variants[index].payload, arguments) {
+            (Some(type_), Some(mut arguments)) if arguments.len() == 1 => Some(self.enforce_expression(
+                cs,
+                file_scope,
+                function_scope,
+                Some(type_.clone()),
+                arguments.remove(0),
+            )?),
+            (None, None) => None,
+            _ => {
+                return Err(ExpressionError::enum_variant_payload(
+                    enum_.enum_name.to_string(),
+                    variant.to_string(),
+                    span,
+                ));
+            }
+        };
+
+        let mut payload = Some(payload);
+        let variants = enum_
+            .variants
+            .into_iter()
+            .enumerate()
+            .map(|(i, enum_variant)| {
+                let is_active = i == index;
+                let payload = if is_active { payload.take().flatten() } else { None };
+
+                ConstrainedEnumVariant(enum_variant.identifier, Boolean::constant(is_active), payload)
+            })
+            .collect();
+
+        Ok(ConstrainedValue::EnumExpression(enum_.enum_name, variants))
+    }
+}
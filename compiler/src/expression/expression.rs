@@ -18,12 +18,15 @@
 
 use crate::{
     arithmetic::*,
+    bitwise::*,
+    cast::*,
     errors::ExpressionError,
     logical::*,
     program::ConstrainedProgram,
     relational::*,
     value::{boolean::input::new_bool_constant, implicit::*, ConstrainedValue},
     Address,
+    Char,
     FieldType,
     GroupType,
     Integer,
@@ -57,6 +60,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             Expression::Value(ValueExpression::Boolean(boolean, span)) => {
                 Ok(ConstrainedValue::Boolean(new_bool_constant(boolean, &span)?))
             }
+            Expression::Value(ValueExpression::Char(character, _span)) => {
+                Ok(ConstrainedValue::Char(Char::constant(character)))
+            }
             Expression::Value(ValueExpression::Field(field, span)) => {
                 Ok(ConstrainedValue::Field(FieldType::constant(field, &span)?))
             }
@@ -72,25 +78,36 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
 
             // Binary operations
             Expression::Binary(BinaryExpression { left, right, op, span }) => {
-                let (resolved_left, resolved_right) = self.enforce_binary_expression(
-                    cs,
-                    file_scope,
-                    function_scope,
-                    if op.class() == BinaryOperationClass::Numeric {
-                        expected_type
-                    } else {
-                        None
-                    },
-                    *left,
-                    *right,
-                    &span,
-                )?;
+                let (resolved_left, resolved_right) = match op {
+                    BinaryOperation::Shl | BinaryOperation::Shr => self.enforce_shift_expression(
+                        cs,
+                        file_scope,
+                        function_scope,
+                        expected_type,
+                        *left,
+                        *right,
+                        &span,
+                    )?,
+                    _ => self.enforce_binary_expression(
+                        cs,
+                        file_scope,
+                        function_scope,
+                        match op.class() {
+                            BinaryOperationClass::Numeric | BinaryOperationClass::Bitwise => expected_type,
+                            BinaryOperationClass::Boolean => None,
+                        },
+                        *left,
+                        *right,
+                        &span,
+                    )?,
+                };
 
                 match op {
                     BinaryOperation::Add => enforce_add(cs, resolved_left, resolved_right, &span),
                     BinaryOperation::Sub => enforce_sub(cs, resolved_left, resolved_right, &span),
                     BinaryOperation::Mul => enforce_mul(cs, resolved_left, resolved_right, &span),
                     BinaryOperation::Div => enforce_div(cs, resolved_left, resolved_right, &span),
+                    BinaryOperation::Rem => enforce_rem(cs, resolved_left, resolved_right, &span),
                     BinaryOperation::Pow => enforce_pow(cs, resolved_left, resolved_right, &span),
                     BinaryOperation::Or => {
                         enforce_or(cs, resolved_left, resolved_right, &span).map_err(ExpressionError::BooleanError)
@@ -105,6 +122,11 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     BinaryOperation::Gt => evaluate_gt(cs, resolved_left, resolved_right, &span),
                     BinaryOperation::Le => evaluate_le(cs, resolved_left, resolved_right, &span),
                     BinaryOperation::Lt => evaluate_lt(cs, resolved_left, resolved_right, &span),
+                    BinaryOperation::BitOr => enforce_bit_or(cs, resolved_left, resolved_right, &span),
+                    BinaryOperation::BitXor => enforce_bit_xor(cs, resolved_left, resolved_right, &span),
+                    BinaryOperation::BitAnd => enforce_bit_and(cs, resolved_left, resolved_right, &span),
+                    BinaryOperation::Shl => enforce_shl(cs, resolved_left, resolved_right, &span),
+                    BinaryOperation::Shr => enforce_shr(cs, resolved_left, resolved_right, &span),
                 }
             }
 
@@ -115,12 +137,27 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                         self.enforce_expression(cs, file_scope, function_scope, expected_type, *inner)?;
                     enforce_negate(cs, resolved_inner, &span)
                 }
-                UnaryOperation::Not => Ok(evaluate_not(
-                    self.enforce_operand(cs, file_scope, function_scope, expected_type, *inner, &span)?,
-                    span,
-                )?),
+                UnaryOperation::Not => {
+                    let resolved_inner =
+                        self.enforce_operand(cs, file_scope, function_scope, expected_type, *inner, &span)?;
+                    match resolved_inner {
+                        ConstrainedValue::Integer(_) => enforce_bit_not(resolved_inner, &span),
+                        _ => Ok(evaluate_not(resolved_inner, span)?),
+                    }
+                }
             },
 
+            // Casts
+            Expression::Cast(CastExpression {
+                inner,
+                target_type,
+                span,
+            }) => {
+                let resolved_inner = self.enforce_expression(cs, file_scope, function_scope, None, *inner)?;
+
+                enforce_cast(cs, resolved_inner, target_type, &span)
+            }
+
             Expression::Conditional(ConditionalExpression {
                 condition,
                 if_true,
@@ -136,6 +173,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 *if_false,
                 &span,
             ),
+            Expression::Match(MatchExpression { inner, arms, span }) => {
+                self.enforce_match_expression(cs, file_scope, function_scope, expected_type, *inner, arms, &span)
+            }
 
             // Arrays
             Expression::ArrayInline(ArrayInlineExpression { elements, span }) => {
@@ -145,15 +185,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 element,
                 dimensions,
                 span,
-            }) => self.enforce_array_initializer(
-                cs,
-                file_scope,
-                function_scope,
-                expected_type,
-                *element,
-                dimensions,
-                span,
-            ),
+            }) => {
+                let dimensions = self.resolve_dimensions(file_scope, function_scope, dimensions);
+
+                self.enforce_array_initializer(
+                    cs,
+                    file_scope,
+                    function_scope,
+                    expected_type,
+                    *element,
+                    dimensions,
+                    span,
+                )
+            }
             Expression::ArrayAccess(ArrayAccessExpression { array, index, span }) => {
                 self.enforce_array_access(cs, file_scope, function_scope, expected_type, *array, *index, &span)
             }
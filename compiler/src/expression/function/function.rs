@@ -17,7 +17,12 @@
 //! Enforce a function call expression in a compiled Leo program.
 
 use crate::{errors::ExpressionError, new_scope, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
-use leo_ast::{expression::CircuitMemberAccessExpression, Expression, Span, Type};
+use leo_ast::{
+    expression::{CircuitMemberAccessExpression, CircuitStaticFunctionAccessExpression},
+    Expression,
+    Span,
+    Type,
+};
 
 use snarkos_models::{
     curves::{Field, PrimeField},
@@ -36,6 +41,27 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         arguments: Vec<Expression>,
         span: Span,
     ) -> Result<ConstrainedValue<F, G>, ExpressionError> {
+        // Construct an enum variant with a payload
+        if let Expression::CircuitStaticFunctionAccess(CircuitStaticFunctionAccessExpression { circuit, name, .. }) =
+            &function
+        {
+            if let Expression::Identifier(identifier) = circuit.as_ref() {
+                let value = self.evaluate_identifier(file_scope, function_scope, None, identifier.clone());
+
+                if let Some(enum_) = value.as_ref().ok().and_then(|value| value.enum_definition()) {
+                    return self.enforce_enum_variant(
+                        cs,
+                        file_scope,
+                        function_scope,
+                        enum_.clone(),
+                        name.clone(),
+                        Some(arguments),
+                        span,
+                    );
+                }
+            }
+        }
+
         let (declared_circuit_reference, function_value) = match function {
             Expression::CircuitMemberAccess(CircuitMemberAccessExpression { circuit, name, span }) => {
                 // Call a circuit function that can mutate self.
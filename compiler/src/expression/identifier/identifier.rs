@@ -46,6 +46,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         } else if let Some(value) = self.get(&identifier_name) {
             // Check global scope (function and circuit names)
             value.clone()
+        } else if let Some(value) = self.get_constant(file_scope, &unresolved_identifier.name) {
+            // Check the constants of the enclosing file
+            value.clone()
         } else if let Some(value) = self.get(&unresolved_identifier.name) {
             // Check imported file scope
             value.clone()
@@ -36,6 +36,9 @@ pub fn evaluate_ge<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<
         (ConstrainedValue::Integer(num_1), ConstrainedValue::Integer(num_2)) => {
             num_1.greater_than_or_equal(unique_namespace, &num_2)
         }
+        (ConstrainedValue::Char(char_1), ConstrainedValue::Char(char_2)) => {
+            char_1.greater_than_or_equal(unique_namespace, &char_2)
+        }
         (ConstrainedValue::Unresolved(string), val_2) => {
             let val_1 = ConstrainedValue::from_other(string, &val_2, span)?;
             return evaluate_ge(&mut unique_namespace, val_1, val_2, span);
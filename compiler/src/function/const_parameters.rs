@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Infers and resolves the const parameters of functions and circuits in a compiled Leo program.
+
+use crate::{
+    errors::ExpressionError,
+    inner_array_type,
+    program::{new_scope, ConstrainedProgram},
+    value::{ConstrainedCircuitMember, ConstrainedValue},
+    GroupType,
+    Integer,
+};
+use leo_ast::{ArrayDimension, ArrayDimensions, CircuitMember, Identifier, IntegerType, PositiveNumber, Span, Type};
+
+use indexmap::IndexMap;
+use snarkos_models::curves::{Field, PrimeField};
+
+impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    ///
+    /// Infers the values of the const parameters in the given type from the array lengths of the given value.
+    ///
+    /// Returns an error if a const parameter is inferred with two different values.
+    ///
+    pub(crate) fn bind_const_parameters(
+        bindings: &mut IndexMap<String, usize>,
+        type_: &Type,
+        value: &ConstrainedValue<F, G>,
+        span: &Span,
+    ) -> Result<(), ExpressionError> {
+        match (type_, value) {
+            (type_, ConstrainedValue::Mutable(value)) => Self::bind_const_parameters(bindings, type_, value, span),
+            (Type::Array(element_type, dimensions), ConstrainedValue::Array(values)) => {
+                let mut dimensions = dimensions.to_owned();
+
+                if let Some(ArrayDimension::Const(identifier)) = dimensions.remove_first() {
+                    match bindings.get(&identifier.name) {
+                        Some(expected) if *expected != values.len() => {
+                            return Err(ExpressionError::const_parameter_mismatch(
+                                identifier.name,
+                                *expected,
+                                values.len(),
+                                span.to_owned(),
+                            ));
+                        }
+                        Some(_) => {}
+                        None => {
+                            bindings.insert(identifier.name, values.len());
+                        }
+                    }
+                }
+
+                // Infer the const parameters of the inner array dimensions.
+                let inner_type = inner_array_type(*element_type.to_owned(), dimensions);
+
+                for value in values {
+                    Self::bind_const_parameters(bindings, &inner_type, value, span)?;
+                }
+
+                Ok(())
+            }
+            (Type::Tuple(types), ConstrainedValue::Tuple(values)) => {
+                for (type_, value) in types.iter().zip(values) {
+                    Self::bind_const_parameters(bindings, type_, value, span)?;
+                }
+
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Stores the inferred values of const parameters as `u32` constants in the given scope.
+    ///
+    pub(crate) fn store_const_parameters(
+        &mut self,
+        scope: &str,
+        bindings: IndexMap<String, usize>,
+        span: &Span,
+    ) -> Result<(), ExpressionError> {
+        for (name, value) in bindings {
+            let integer = Integer::new_constant(&IntegerType::U32, value.to_string(), span)?;
+
+            self.store(new_scope(scope, &name), ConstrainedValue::Integer(integer));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Infers the const parameters of a circuit from the member variables of a circuit instance and
+    /// stores them in the given circuit scope.
+    ///
+    /// The circuit definition is looked up in the scope of the given program file.
+    ///
+    pub(crate) fn store_circuit_const_parameters(
+        &mut self,
+        program_scope: &str,
+        circuit_scope: &str,
+        circuit_name: &Identifier,
+        members: &[ConstrainedCircuitMember<F, G>],
+    ) -> Result<(), ExpressionError> {
+        let circuit = match self.get(&new_scope(program_scope, &circuit_name.name)) {
+            Some(value) => value.clone().extract_circuit(&circuit_name.span)?,
+            None => return Ok(()),
+        };
+
+        if circuit.const_parameters.is_empty() {
+            return Ok(());
+        }
+
+        let mut bindings = IndexMap::new();
+
+        for member in circuit.members {
+            if let CircuitMember::CircuitVariable(identifier, type_) = member {
+                if let Some(ConstrainedCircuitMember(_, value)) = members.iter().find(|member| member.0 == identifier) {
+                    Self::bind_const_parameters(&mut bindings, &type_, value, &circuit_name.span)?;
+                }
+            }
+        }
+
+        self.store_const_parameters(circuit_scope, bindings, &circuit_name.span)
+    }
+
+    ///
+    /// Returns the given type with every array dimension that refers to a stored const parameter
+    /// replaced by the value of the const parameter.
+    ///
+    /// Enum names are parsed as circuit types, so a circuit type that names an enum is returned as an enum type.
+    ///
+    pub(crate) fn resolve_type(&self, file_scope: &str, function_scope: &str, type_: Type) -> Type {
+        match type_ {
+            Type::Circuit(identifier) => {
+                let program_scope = self.file_scope(file_scope).unwrap_or(file_scope);
+
+                match self
+                    .get(&new_scope(program_scope, &identifier.name))
+                    .and_then(|value| value.enum_definition())
+                {
+                    Some(_) => Type::Enum(identifier),
+                    None => Type::Circuit(identifier),
+                }
+            }
+            Type::Array(type_, dimensions) => Type::Array(
+                Box::new(self.resolve_type(file_scope, function_scope, *type_)),
+                self.resolve_dimensions(file_scope, function_scope, dimensions),
+            ),
+            Type::Tuple(types) => Type::Tuple(
+                types
+                    .into_iter()
+                    .map(|type_| self.resolve_type(file_scope, function_scope, type_))
+                    .collect(),
+            ),
+            type_ => type_,
+        }
+    }
+
+    ///
+    /// Returns the given array dimensions with every dimension that refers to a stored const parameter
+    /// replaced by the value of the const parameter.
+    ///
+    /// Const parameters of functions are stored in the function scope.
+    /// Const parameters of circuits are stored in the circuit scope.
+    ///
+    pub(crate) fn resolve_dimensions(
+        &self,
+        file_scope: &str,
+        function_scope: &str,
+        dimensions: ArrayDimensions,
+    ) -> ArrayDimensions {
+        let dimensions = dimensions
+            .0
+            .into_iter()
+            .map(|dimension| match dimension {
+                ArrayDimension::Const(identifier) => {
+                    let function_parameter = new_scope(function_scope, &identifier.name);
+                    let circuit_parameter = new_scope(file_scope, &identifier.name);
+
+                    let value = match self.get(&function_parameter).or_else(|| self.get(&circuit_parameter)) {
+                        Some(ConstrainedValue::Integer(integer)) => integer.get_value(),
+                        _ => None,
+                    };
+
+                    match value {
+                        Some(value) => ArrayDimension::Number(PositiveNumber {
+                            value,
+                            span: identifier.span,
+                        }),
+                        None => ArrayDimension::Const(identifier),
+                    }
+                }
+                dimension => dimension,
+            })
+            .collect();
+
+        ArrayDimensions(dimensions)
+    }
+}
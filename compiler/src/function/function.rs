@@ -17,17 +17,20 @@
 //! Enforces constraints on a function in a compiled Leo program.
 
 use crate::{
-    errors::FunctionError,
+    errors::{ExpressionError, FunctionError},
+    indicator_after_returns,
     program::{new_scope, ConstrainedProgram},
     value::ConstrainedValue,
     GroupType,
 };
 
-use leo_ast::{Expression, Function, FunctionInput};
+use leo_ast::{Expression, Function, FunctionInput, Node};
+use leo_control_flow::Termination;
 
+use indexmap::IndexMap;
 use snarkos_models::{
     curves::{Field, PrimeField},
-    gadgets::{r1cs::ConstraintSystem, utilities::boolean::Boolean},
+    gadgets::r1cs::ConstraintSystem,
 };
 
 impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
@@ -45,6 +48,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         // Store if function contains input `mut self`.
         let mut_self = function.contains_mut_self();
 
+        // Store the const parameters inferred from the input values.
+        let mut const_parameters = IndexMap::new();
+
         // Store input values as new variables in resolved program
         for (input_model, input_expression) in function.filter_self_inputs().zip(input.into_iter()) {
             let (name, value) = match input_model {
@@ -77,6 +83,13 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                         input_expression,
                     )?;
 
+                    Self::bind_const_parameters(
+                        &mut const_parameters,
+                        &input_model.type_,
+                        &input_value,
+                        &input_model.span,
+                    )?;
+
                     if input_model.mutable {
                         input_value = ConstrainedValue::Mutable(Box::new(input_value))
                     }
@@ -90,9 +103,25 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             self.store(input_program_identifier, value);
         }
 
+        // Store const parameters as variables with {function_name}_{parameter_name}
+        if let Some(parameter) = function
+            .const_parameters
+            .iter()
+            .find(|parameter| !const_parameters.contains_key(&parameter.identifier.name))
+        {
+            return Err(ExpressionError::unresolved_const_parameter(&parameter.identifier).into());
+        }
+
+        self.store_const_parameters(&function_name, const_parameters, &function.span)?;
+
+        // Resolve the const parameters of the return type
+        let output = function
+            .output
+            .map(|type_| self.resolve_type(scope, &function_name, type_));
+
         // Evaluate every statement in the function and save all potential results
         let mut results = vec![];
-        let indicator = Boolean::constant(true);
+        let mut indicator = self.indicator;
 
         for statement in function.block.statements.iter() {
             let mut result = self.enforce_statement(
@@ -101,16 +130,28 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 &function_name,
                 &indicator,
                 statement.clone(),
-                function.output.clone(),
+                output.clone(),
                 declared_circuit_reference,
                 mut_self,
             )?;
 
+            // Only select the following statements if this statement did not return early
+            if Termination::of_statement(statement) == Termination::Sometimes {
+                let span = statement.span();
+
+                indicator = indicator_after_returns(
+                    cs.ns(|| format!("early return {}:{}", span.line, span.start)),
+                    &indicator,
+                    &result,
+                    span,
+                )?;
+            }
+
             results.append(&mut result);
         }
 
         // Conditionally select a result based on returned indicators
-        Self::conditionally_select_result(cs, function.output, results, &function.span)
+        Self::conditionally_select_result(cs, output, results, &function.span)
             .map_err(FunctionError::StatementError)
     }
 }
@@ -19,7 +19,7 @@
 use crate::{
     errors::FunctionError,
     inner_array_type,
-    parse_index,
+    parse_dimension,
     program::{new_scope, ConstrainedProgram},
     value::ConstrainedValue,
     GroupType,
@@ -44,9 +44,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         span: &Span,
     ) -> Result<ConstrainedValue<F, G>, FunctionError> {
         let expected_length = match array_dimensions.remove_first() {
-            Some(number) => {
+            Some(dimension) => {
                 // Parse the array dimension into a `usize`.
-                parse_index(&number, &span)?
+                parse_dimension(&dimension, &span)?
             }
             None => {
                 return Err(FunctionError::ExpressionError(ExpressionError::unexpected_array(
@@ -18,6 +18,7 @@
 
 use crate::{
     address::Address,
+    char::Char,
     errors::FunctionError,
     program::ConstrainedProgram,
     value::{
@@ -49,6 +50,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         match type_ {
             Type::Address => Ok(Address::from_input(cs, name, input_option, span)?),
             Type::Boolean => Ok(bool_from_input(cs, name, input_option, span)?),
+            Type::Char => Ok(Char::from_input(cs, name, input_option, span)?),
             Type::Field => Ok(field_from_input(cs, name, input_option, span)?),
             Type::Group => Ok(group_from_input(cs, name, input_option, span)?),
             Type::IntegerType(integer_type) => Ok(ConstrainedValue::Integer(Integer::from_input(
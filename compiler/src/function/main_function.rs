@@ -21,13 +21,17 @@ use crate::{
     program::{new_scope, ConstrainedProgram},
     GroupType,
     OutputBytes,
+    PublicConstraintSystem,
 };
 
-use leo_ast::{Expression, Function, FunctionInput, Identifier, Input};
+use leo_ast::{Expression, Function, FunctionInput, Identifier, Input, Parameter, Type};
 
 use snarkos_models::{
     curves::{Field, PrimeField},
-    gadgets::r1cs::ConstraintSystem,
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{boolean::Boolean, eq::ConditionalEqGadget},
+    },
 };
 
 impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
@@ -41,6 +45,11 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         let function_name = new_scope(scope, function.get_name());
         let registers = input.get_registers();
 
+        // The const parameters of the main function cannot be inferred from its inputs
+        if function.is_generic() {
+            return Err(FunctionError::generic_main(function.span));
+        }
+
         // Iterate over main function input variables and allocate new values
         let mut input_variables = Vec::with_capacity(function.input.len());
         for input_model in function.input.clone().into_iter() {
@@ -58,8 +67,14 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     let input_option = input
                         .get(&name)
                         .ok_or_else(|| FunctionError::input_not_found(name.clone(), function.span.clone()))?;
-                    let input_value =
-                        self.allocate_main_function_input(cs, input_model.type_, &name, input_option, &function.span)?;
+                    let input_value = if input_model.public {
+                        // Every variable of a public input is allocated as a public input
+                        let cs = &mut PublicConstraintSystem::new(cs);
+
+                        self.allocate_main_function_input(cs, input_model.type_, &name, input_option, &function.span)?
+                    } else {
+                        self.allocate_main_function_input(cs, input_model.type_, &name, input_option, &function.span)?
+                    };
 
                     (input_model.identifier, input_value)
                 }
@@ -76,8 +91,71 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
 
         let span = function.span.clone();
         let result_value = self.enforce_function(cs, scope, &function_name, function, input_variables, "")?;
+
+        // The outputs written to registers are public inputs after the public main function inputs
+        let public_value = result_value.allocate_public(cs.ns(|| "public output"), &span)?;
+        result_value
+            .conditional_enforce_equal(cs.ns(|| "enforce public output"), &public_value, &Boolean::constant(true))
+            .map_err(|_| FunctionError::public_output(result_value.to_string(), span.clone()))?;
+
         let output_bytes = OutputBytes::new_from_constrained_value(registers, result_value, span)?;
 
         Ok(output_bytes)
     }
+
+    ///
+    /// Allocates the public inputs of the main function without enforcing its constraints.
+    ///
+    /// Public main function inputs are allocated in the order they are declared, followed by the
+    /// outputs in the order of their registers. This is the order of the public inputs in the verifying key.
+    ///
+    pub fn allocate_main_public_inputs<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        function: Function,
+        input: Input,
+    ) -> Result<(), FunctionError> {
+        let cs = &mut PublicConstraintSystem::new(cs);
+
+        for input_model in function.input.iter() {
+            if let FunctionInput::Variable(input_model) = input_model {
+                if !input_model.public {
+                    continue;
+                }
+
+                let name = input_model.identifier.name.clone();
+                let input_option = input
+                    .get(&name)
+                    .ok_or_else(|| FunctionError::input_not_found(name.clone(), function.span.clone()))?;
+
+                self.allocate_main_function_input(cs, input_model.type_.clone(), &name, input_option, &function.span)?;
+            }
+        }
+
+        let output_count = match function.output {
+            Some(Type::Tuple(ref types)) => types.len(),
+            Some(_) => 1,
+            None => 0,
+        };
+
+        // Create vector of register values in alphabetical order
+        let mut register_values = input.get_registers().values().into_iter().collect::<Vec<_>>();
+
+        register_values.sort_by(|a, b| a.0.variable.name.cmp(&b.0.variable.name));
+
+        for (i, (parameter, value)) in register_values.into_iter().take(output_count).enumerate() {
+            let Parameter { variable, type_, .. } = parameter;
+            let name = variable.name;
+
+            self.allocate_main_function_input(
+                &mut cs.ns(|| format!("public output {}", i)),
+                type_,
+                &name,
+                value,
+                &function.span,
+            )?;
+        }
+
+        Ok(())
+    }
 }
@@ -19,6 +19,9 @@
 pub mod input;
 pub use self::input::*;
 
+pub mod const_parameters;
+pub use self::const_parameters::*;
+
 pub mod function;
 pub use self::function::*;
 
@@ -16,14 +16,7 @@
 
 //! Enforces that one return value is produced in a compiled Leo program.
 
-use crate::{
-    check_return_type,
-    errors::StatementError,
-    get_indicator_value,
-    program::ConstrainedProgram,
-    value::ConstrainedValue,
-    GroupType,
-};
+use crate::{check_return_type, errors::StatementError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
 
 use leo_ast::{Span, Type};
 
@@ -40,22 +33,23 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     /// Returns a conditionally selected result from the given possible function returns and
     /// given function return type.
     ///
+    /// The control flow check guarantees that a function with a return type returns on every path,
+    /// and statements following an early return are evaluated with a gated indicator. At most one
+    /// result indicator is true, so the last result can be used as the default selection.
+    ///
     pub fn conditionally_select_result<CS: ConstraintSystem<F>>(
         cs: &mut CS,
         expected_return: Option<Type>,
         results: Vec<(Boolean, ConstrainedValue<F, G>)>,
         span: &Span,
     ) -> Result<ConstrainedValue<F, G>, StatementError> {
-        // Initialize empty return value.
-        let mut return_value = ConstrainedValue::Tuple(vec![]);
-
         // If the function does not expect a return type, then make sure there are no returned results.
         let return_type = match expected_return {
             Some(return_type) => return_type,
             None => {
                 if results.is_empty() {
                     // If the function has no returns, then return an empty tuple.
-                    return Ok(return_value);
+                    return Ok(ConstrainedValue::Tuple(vec![]));
                 } else {
                     return Err(StatementError::invalid_number_of_returns(
                         0,
@@ -66,43 +60,24 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             }
         };
 
-        // Error if the function or one of its branches does not return.
-        if results
-            .iter()
-            .find(|(indicator, _res)| get_indicator_value(indicator))
-            .is_none()
-        {
-            return Err(StatementError::no_returns(return_type, span.to_owned()));
-        }
-
-        // Find the return value
-        let mut ignored = vec![];
-        let mut found_return = false;
-        for (indicator, result) in results.into_iter() {
-            // Error if a statement returned a result with an incorrect type
+        // Error if a statement returned a result with an incorrect type
+        for (_indicator, result) in results.iter() {
             let result_type = result.to_type(span)?;
             check_return_type(&return_type, &result_type, span)?;
-
-            if get_indicator_value(&indicator) {
-                // Error if we already have a return value.
-                if found_return {
-                    return Err(StatementError::multiple_returns(span.to_owned()));
-                } else {
-                    // Set the function return value.
-                    return_value = result;
-                    found_return = true;
-                }
-            } else {
-                // Ignore a possible function return value.
-                ignored.push((indicator, result))
-            }
         }
 
-        // Conditionally select out the ignored results in the circuit.
+        let mut results = results.into_iter().rev();
+
+        // Select the last result unless an earlier result was returned.
+        let mut return_value = match results.next() {
+            Some((_indicator, result)) => result,
+            None => return Err(StatementError::no_returns(return_type, span.to_owned())),
+        };
+
+        // Conditionally select out the earlier results in the circuit.
         //
-        // If there are branches in the function we need to use the `ConditionalSelectGadget` to parse through and select the correct one.
         // This can be thought of as de-multiplexing all previous wires that may have returned results into one.
-        for (i, (indicator, result)) in ignored.into_iter().enumerate() {
+        for (i, (indicator, result)) in results.enumerate() {
             return_value = ConstrainedValue::conditionally_select(
                 cs.ns(|| format!("select result {} {}:{}", i, span.line, span.start)),
                 &indicator,
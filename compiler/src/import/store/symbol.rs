@@ -40,6 +40,27 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 self.store(name, value);
             });
 
+            // evaluate and store all enum definitions
+            program.enums.iter().for_each(|(identifier, enum_)| {
+                let name = new_scope(scope, &identifier.name);
+                let value = ConstrainedValue::Import(
+                    program_name.to_owned(),
+                    Box::new(ConstrainedValue::EnumDefinition(enum_.clone())),
+                );
+
+                self.store(name, value);
+            });
+
+            // store all constant values
+            let program_scope = program.name.trim_end_matches(".leo");
+            program.constants.iter().for_each(|constant| {
+                let name = &constant.identifier.name;
+
+                if let Some(value) = self.get_constant(program_scope, name).cloned() {
+                    self.store_constant_value(scope, name, value);
+                }
+            });
+
             // evaluate and store all function definitions
             program.functions.iter().for_each(|(identifier, function)| {
                 let name = new_scope(scope, &identifier.name);
@@ -63,18 +84,47 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                     Box::new(ConstrainedValue::CircuitDefinition(circuit.clone())),
                 ),
                 None => {
+                    // see if the imported symbol is an enum
+                    let matched_enum = program
+                        .enums
+                        .iter()
+                        .find(|(enum_name, _enum)| symbol.symbol == **enum_name)
+                        .map(|(_enum_name, enum_)| enum_);
+
+                    // see if the imported symbol is a constant
+                    let program_scope = program.name.trim_end_matches(".leo");
+                    let matched_constant = program
+                        .constants
+                        .iter()
+                        .find(|constant| symbol.symbol == constant.identifier)
+                        .and_then(|constant| self.get_constant(program_scope, &constant.identifier.name))
+                        .cloned();
+
                     // see if the imported symbol is a function
                     let matched_function = program
                         .functions
                         .iter()
                         .find(|(function_name, _function)| symbol.symbol == **function_name);
 
-                    match matched_function {
-                        Some((_function_name, function)) => ConstrainedValue::Import(
+                    match (matched_enum, matched_constant, matched_function) {
+                        (Some(enum_), _, _) => ConstrainedValue::Import(
+                            program_name.to_owned(),
+                            Box::new(ConstrainedValue::EnumDefinition(enum_.clone())),
+                        ),
+                        (None, Some(value), _) => {
+                            // store imported constant under imported name
+                            let id = symbol.alias.as_ref().unwrap_or(&symbol.symbol);
+                            self.store_constant_value(scope, &id.name, value);
+
+                            return Ok(());
+                        }
+                        (None, None, Some((_function_name, function))) => ConstrainedValue::Import(
                             program_name.to_owned(),
                             Box::new(ConstrainedValue::Function(None, Box::new(function.clone()))),
                         ),
-                        None => return Err(ImportError::unknown_symbol(symbol.to_owned(), program_name.to_owned())),
+                        (None, None, None) => {
+                            return Err(ImportError::unknown_symbol(symbol.to_owned(), program_name.to_owned()));
+                        }
                     }
                 }
             };
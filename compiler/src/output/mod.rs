@@ -19,3 +19,6 @@ pub use self::output_file::*;
 
 pub mod output_bytes;
 pub use self::output_bytes::*;
+
+pub mod public_inputs_bytes;
+pub use self::public_inputs_bytes::*;
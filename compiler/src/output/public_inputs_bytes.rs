@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::OutputBytes;
+use leo_ast::{FunctionInput, Input, InputValue};
+
+use serde::{Deserialize, Serialize};
+
+/// Serialized public program input.
+///
+/// Contains the public main function input and the program output in the `.in` syntax.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct PublicInputsBytes(Vec<u8>);
+
+impl PublicInputsBytes {
+    pub fn bytes(&self) -> &Vec<u8> {
+        &self.0
+    }
+
+    pub fn new_from_input(expected_input: &[FunctionInput], input: &Input, output: &OutputBytes) -> Self {
+        // Manually construct result string
+        let mut string = String::from("[main]\n");
+
+        // format: "a: u32 = 1;"
+        for input_model in expected_input {
+            if let FunctionInput::Variable(variable) = input_model {
+                if !variable.public {
+                    continue;
+                }
+
+                let name = &variable.identifier.name;
+
+                if let Some(Some(value)) = input.get(name) {
+                    let format = format!("{}: {} = {};\n", name, variable.type_, value_string(&value));
+
+                    string.push_str(&format);
+                }
+            }
+        }
+
+        string.push('\n');
+
+        let mut bytes = string.into_bytes();
+        bytes.extend_from_slice(output.bytes());
+
+        Self(bytes)
+    }
+}
+
+impl From<Vec<u8>> for PublicInputsBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+/// Returns the given input value in the `.in` syntax.
+fn value_string(value: &InputValue) -> String {
+    match value {
+        InputValue::Group(group) => format!("{}group", group),
+        InputValue::Integer(_type, number) => number.to_string(),
+        InputValue::Array(array) => {
+            let values = array.iter().map(value_string).collect::<Vec<_>>().join(", ");
+
+            format!("[{}]", values)
+        }
+        InputValue::Tuple(tuple) => {
+            let values = tuple.iter().map(value_string).collect::<Vec<_>>().join(", ");
+
+            format!("({})", values)
+        }
+        value => value.to_string(),
+    }
+}
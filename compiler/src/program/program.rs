@@ -18,18 +18,31 @@
 
 use crate::{value::ConstrainedValue, GroupType};
 
-use snarkos_models::curves::{Field, PrimeField};
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::utilities::boolean::Boolean,
+};
 
 use indexmap::IndexMap;
 
+#[derive(Clone)]
 pub struct ConstrainedProgram<F: Field + PrimeField, G: GroupType<F>> {
     pub identifiers: IndexMap<String, ConstrainedValue<F, G>>,
+    /// Program constants, keyed by the name of the file that defines them and then by constant name.
+    pub(crate) constants: IndexMap<String, IndexMap<String, ConstrainedValue<F, G>>>,
+    /// Set while a program constant is evaluated, so that definitions are not allocated.
+    pub(crate) constant_evaluation: bool,
+    /// The indicator that selects the statements of called functions, set while a match arm is evaluated.
+    pub(crate) indicator: Boolean,
 }
 
 impl<F: Field + PrimeField, G: GroupType<F>> Default for ConstrainedProgram<F, G> {
     fn default() -> Self {
         Self {
             identifiers: IndexMap::new(),
+            constants: IndexMap::new(),
+            constant_evaluation: false,
+            indicator: Boolean::constant(true),
         }
     }
 }
@@ -51,6 +64,10 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         self.identifiers.insert(name, value);
     }
 
+    pub(crate) fn remove(&mut self, name: &str) {
+        self.identifiers.shift_remove(name);
+    }
+
     pub(crate) fn get(&self, name: &str) -> Option<&ConstrainedValue<F, G>> {
         self.identifiers.get(name)
     }
@@ -58,4 +75,40 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     pub(crate) fn get_mut(&mut self, name: &str) -> Option<&mut ConstrainedValue<F, G>> {
         self.identifiers.get_mut(name)
     }
+
+    /// Registers a program file so that the scopes inside it can be resolved to it.
+    pub(crate) fn store_file(&mut self, file_name: &str) {
+        self.constants.entry(file_name.to_owned()).or_default();
+    }
+
+    pub(crate) fn store_constant_value(&mut self, file_name: &str, name: &str, value: ConstrainedValue<F, G>) {
+        self.constants
+            .entry(file_name.to_owned())
+            .or_default()
+            .insert(name.to_owned(), value);
+    }
+
+    ///
+    /// Returns the constant with the given name that is defined in, or imported into, the file
+    /// that contains the given scope.
+    ///
+    pub(crate) fn get_constant(&self, scope: &str, name: &str) -> Option<&ConstrainedValue<F, G>> {
+        let file_name = self.file_scope(scope)?;
+
+        self.constants.get(file_name).and_then(|constants| constants.get(name))
+    }
+
+    ///
+    /// Returns the name of the registered program file that contains the given scope.
+    ///
+    /// The scopes of circuit functions are nested inside the scope of the file that defines the circuit,
+    /// so the longest file name that the scope starts with is returned.
+    ///
+    pub(crate) fn file_scope<'a>(&self, scope: &'a str) -> Option<&'a str> {
+        self.constants
+            .keys()
+            .filter(|file_name| scope == file_name.as_str() || scope.starts_with(&format!("{}_", file_name)))
+            .map(|file_name| &scope[..file_name.len()])
+            .max_by_key(|file_name| file_name.len())
+    }
 }
@@ -18,13 +18,14 @@
 
 use crate::{
     arithmetic::*,
+    bitwise::*,
     errors::StatementError,
     new_scope,
     program::ConstrainedProgram,
     value::ConstrainedValue,
     GroupType,
 };
-use leo_ast::{AssignOperation, AssignStatement, AssigneeAccess, Span};
+use leo_ast::{AssignOperation, AssignStatement, AssigneeAccess, IntegerType, Span, Type};
 
 use snarkos_models::{
     curves::{Field, PrimeField},
@@ -47,7 +48,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         statement: AssignStatement,
     ) -> Result<(), StatementError> {
         // Get the name of the variable we are assigning to
-        let mut new_value = self.enforce_expression(cs, file_scope, function_scope, None, statement.value)?;
+        let new_value = self.enforce_expression(cs, file_scope, function_scope, None, statement.value)?;
         let mut resolved_assignee = self.resolve_assignee(
             cs,
             file_scope,
@@ -58,8 +59,6 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         )?;
 
         if resolved_assignee.len() == 1 {
-            new_value.resolve_type(Some(resolved_assignee[0].to_type(&statement.span)?), &statement.span)?;
-
             let span = statement.span.clone();
 
             Self::enforce_assign_operation(
@@ -133,7 +132,12 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         mut new_value: ConstrainedValue<F, G>,
         span: &Span,
     ) -> Result<(), StatementError> {
-        new_value.resolve_type(Some(target.to_type(span)?), span)?;
+        let value_type = match operation {
+            // An implicit shift amount is a `u32`.
+            AssignOperation::Shl | AssignOperation::Shr => Type::IntegerType(IntegerType::U32),
+            _ => target.to_type(span)?,
+        };
+        new_value.resolve_type(Some(value_type), span)?;
 
         let new_value = match operation {
             AssignOperation::Assign => new_value,
@@ -141,7 +145,13 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             AssignOperation::Sub => enforce_sub(cs, target.clone(), new_value, span)?,
             AssignOperation::Mul => enforce_mul(cs, target.clone(), new_value, span)?,
             AssignOperation::Div => enforce_div(cs, target.clone(), new_value, span)?,
+            AssignOperation::Rem => enforce_rem(cs, target.clone(), new_value, span)?,
             AssignOperation::Pow => enforce_pow(cs, target.clone(), new_value, span)?,
+            AssignOperation::BitAnd => enforce_bit_and(cs, target.clone(), new_value, span)?,
+            AssignOperation::BitOr => enforce_bit_or(cs, target.clone(), new_value, span)?,
+            AssignOperation::BitXor => enforce_bit_xor(cs, target.clone(), new_value, span)?,
+            AssignOperation::Shl => enforce_shl(cs, target.clone(), new_value, span)?,
+            AssignOperation::Shr => enforce_shr(cs, target.clone(), new_value, span)?,
         };
         let selected_value = ConstrainedValue::conditionally_select(cs.ns(|| scope), condition, &new_value, target)
             .map_err(|_| StatementError::select_fail(new_value.to_string(), target.to_string(), span.clone()))?;
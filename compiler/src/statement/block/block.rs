@@ -16,8 +16,15 @@
 
 //! Enforces a branch of a conditional or iteration statement in a compiled Leo program.
 
-use crate::{program::ConstrainedProgram, GroupType, IndicatorAndConstrainedValue, StatementResult};
-use leo_ast::{Block, Type};
+use crate::{
+    indicator_after_returns,
+    program::ConstrainedProgram,
+    GroupType,
+    IndicatorAndConstrainedValue,
+    StatementResult,
+};
+use leo_ast::{Block, Node, Type};
+use leo_control_flow::Termination;
 
 use snarkos_models::{
     curves::{Field, PrimeField},
@@ -27,6 +34,8 @@ use snarkos_models::{
 impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
     /// Evaluates a branch of one or more statements and returns a result in
     /// the given scope.
+    /// Statements following a statement that may return early are evaluated with an indicator
+    /// that is false if it returned.
     #[allow(clippy::too_many_arguments)]
     pub fn evaluate_block<CS: ConstraintSystem<F>>(
         &mut self,
@@ -40,19 +49,34 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         mut_self: bool,
     ) -> StatementResult<Vec<IndicatorAndConstrainedValue<F, G>>> {
         let mut results = Vec::with_capacity(block.statements.len());
+        let mut indicator = *indicator;
+
         // Evaluate statements. Only allow a single return argument to be returned.
         for statement in block.statements.into_iter() {
+            let termination = Termination::of_statement(&statement);
+            let span = statement.span().clone();
+
             let value = self.enforce_statement(
                 cs,
                 file_scope,
                 function_scope,
-                indicator,
+                &indicator,
                 statement,
                 return_type.clone(),
                 declared_circuit_reference,
                 mut_self,
             )?;
 
+            // The control flow check rejects statements after a statement that always returns.
+            if termination == Termination::Sometimes {
+                indicator = indicator_after_returns(
+                    cs.ns(|| format!("early return {}:{}", span.line, span.start)),
+                    &indicator,
+                    &value,
+                    &span,
+                )?;
+            }
+
             results.extend(value);
         }
 
@@ -39,7 +39,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 variable_name.to_string(),
                 span.to_owned(),
             ));
-        } else {
+        } else if !self.constant_evaluation {
             value.allocate_value(cs, span)?
         }
 
@@ -85,8 +85,10 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
             Declare::Let => false,
             Declare::Const => true,
         };
-        let expression =
-            self.enforce_expression(cs, file_scope, function_scope, statement.type_.clone(), statement.value)?;
+        let expected_type = statement
+            .type_
+            .map(|type_| self.resolve_type(file_scope, function_scope, type_));
+        let expression = self.enforce_expression(cs, file_scope, function_scope, expected_type, statement.value)?;
 
         if num_variables == 1 {
             // Define a single variable with a single value
@@ -17,6 +17,8 @@
 //! Enforces an iteration statement in a compiled Leo program.
 
 use crate::{
+    errors::StatementError,
+    indicator_after_returns,
     new_scope,
     program::ConstrainedProgram,
     value::ConstrainedValue,
@@ -26,6 +28,7 @@ use crate::{
     StatementResult,
 };
 use leo_ast::{IterationStatement, Type};
+use leo_control_flow::Termination;
 
 use snarkos_models::{
     curves::{Field, PrimeField},
@@ -36,6 +39,11 @@ use snarkos_models::{
 };
 
 impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
+    /// Enforces an iteration statement by unrolling it from `start` to `stop`.
+    /// If the loop has a `while` condition, the condition is evaluated before each iteration and
+    /// folded into a running indicator. Once the condition fails, every remaining iteration is
+    /// evaluated with a false indicator so its side effects are discarded.
+    /// If the loop body may return early, the running indicator is also cleared once it returns.
     #[allow(clippy::too_many_arguments)]
     pub fn enforce_iteration_statement<CS: ConstraintSystem<F>>(
         &mut self,
@@ -54,6 +62,11 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
         let to = self.enforce_index(cs, file_scope, function_scope, statement.stop, &statement.span)?;
 
         let span = statement.span.clone();
+
+        // The loop body is selected while the `while` condition has held for every previous iteration
+        let mut loop_indicator = *indicator;
+        let termination = Termination::of_block(&statement.block);
+
         for i in from..to {
             // Store index in current function scope.
             // For loop scope is not implemented.
@@ -65,18 +78,47 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 ConstrainedValue::Integer(Integer::U32(UInt32::constant(i as u32))),
             );
 
+            let cs = &mut cs.ns(|| format!("for loop iteration {} {}:{}", i, &span.line, &span.start));
+
+            if let Some(condition) = statement.condition.clone() {
+                let condition = match self.enforce_expression(
+                    cs,
+                    file_scope,
+                    function_scope,
+                    Some(Type::Boolean),
+                    *condition,
+                )? {
+                    ConstrainedValue::Boolean(resolved) => resolved,
+                    value => return Err(StatementError::conditional_boolean(value.to_string(), span)),
+                };
+
+                loop_indicator = Boolean::and(cs.ns(|| "loop indicator"), &loop_indicator, &condition).map_err(
+                    |_| StatementError::indicator_calculation(format!("for loop iteration {}", i), span.clone()),
+                )?;
+
+                // The condition is known to be false, so no later iteration can be selected
+                if let Boolean::Constant(false) = loop_indicator {
+                    break;
+                }
+            }
+
             // Evaluate statements and possibly return early
             let result = self.evaluate_block(
-                &mut cs.ns(|| format!("for loop iteration {} {}:{}", i, &span.line, &span.start)),
+                cs,
                 file_scope,
                 function_scope,
-                indicator,
+                &loop_indicator,
                 statement.block.clone(),
                 return_type.clone(),
                 declared_circuit_reference,
                 mut_self,
             )?;
 
+            // Later iterations are only selected if this iteration did not return
+            if termination.may_return() {
+                loop_indicator = indicator_after_returns(cs.ns(|| "early return"), &loop_indicator, &result, &span)?;
+            }
+
             results.extend(result);
         }
 
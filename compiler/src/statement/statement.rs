@@ -17,7 +17,7 @@
 //! Enforces a statement in a compiled Leo program.
 
 use crate::{errors::StatementError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
-use leo_ast::{Statement, Type};
+use leo_ast::{Span, Statement, Type};
 
 use snarkos_models::{
     curves::{Field, PrimeField},
@@ -108,11 +108,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
                 let value = self.enforce_expression(cs, file_scope, function_scope, None, statement.expression)?;
                 // handle empty return value cases
                 match &value {
-                    ConstrainedValue::Tuple(values) => {
-                        if !values.is_empty() {
-                            results.push((*indicator, value));
-                        }
-                    }
+                    ConstrainedValue::Tuple(values) if values.is_empty() => {}
                     _ => return Err(StatementError::unassigned(expression_string, statement.span)),
                 }
             }
@@ -143,3 +139,22 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedProgram<F, G> {
 pub fn get_indicator_value(indicator: &Boolean) -> bool {
     indicator.eq(&Boolean::constant(true))
 }
+
+/// Returns the indicator for the statements that follow a statement that may return early.
+/// The statements are selected if the `indicator` is true and none of the given results were returned.
+pub fn indicator_after_returns<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    indicator: &Boolean,
+    results: &[IndicatorAndConstrainedValue<F, G>],
+    span: &Span,
+) -> StatementResult<Boolean> {
+    let mut returned = Boolean::constant(false);
+
+    for (i, (result_indicator, _result)) in results.iter().enumerate() {
+        returned = Boolean::or(cs.ns(|| format!("returned {}", i)), &returned, result_indicator)
+            .map_err(|_| StatementError::indicator_calculation(format!("returned {}", i), span.to_owned()))?;
+    }
+
+    Boolean::and(cs.ns(|| "not returned"), indicator, &returned.not())
+        .map_err(|_| StatementError::indicator_calculation("not returned".to_string(), span.to_owned()))
+}
@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Conversion of char declarations to constraints in Leo.
+
+use crate::{errors::CharError, ConstrainedValue, GroupType, Integer};
+use leo_ast::{InputValue, Span};
+use leo_gadgets::bits::comparator::{ComparatorGadget, EvaluateLtGadget};
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{
+            alloc::AllocGadget,
+            boolean::Boolean,
+            eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
+            select::CondSelectGadget,
+            uint::UInt32,
+        },
+    },
+};
+use std::fmt;
+
+/// A unicode scalar value stored as its `u32` code point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Char {
+    pub integer: Integer,
+}
+
+impl Char {
+    ///
+    /// Returns a new char value from a character literal.
+    ///
+    /// The returned char value is "constant" and is not allocated in the constraint system.
+    ///
+    pub fn constant(character: char) -> Self {
+        Char {
+            integer: Integer::U32(UInt32::constant(character as u32)),
+        }
+    }
+
+    ///
+    /// Returns a new constant char value from a decimal code point.
+    ///
+    pub fn from_code_point(string: String, span: &Span) -> Result<Self, CharError> {
+        match string.parse::<u32>().ok().and_then(std::char::from_u32) {
+            Some(character) => Ok(Self::constant(character)),
+            None => Err(CharError::invalid_scalar_value(string, span.to_owned())),
+        }
+    }
+
+    ///
+    /// Returns the char value of a `u32` integer.
+    ///
+    /// Enforces that the integer is a valid unicode scalar value.
+    ///
+    pub fn from_integer<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        cs: &mut CS,
+        integer: Integer,
+        span: &Span,
+    ) -> Result<Self, CharError> {
+        let character = Char { integer };
+
+        // Check that the value is a unicode scalar value.
+        if let Some(value) = character.integer.get_value() {
+            if character.get_value().is_none() {
+                return Err(CharError::invalid_scalar_value(value, span.to_owned()));
+            }
+        }
+
+        if !character.is_constant() {
+            let bits = character.integer.get_bits();
+            let namespace = format!("enforce {} as char {}:{}", character.integer, span.line, span.start);
+
+            enforce_scalar_value(cs.ns(|| namespace), &bits)
+                .map_err(|error| CharError::cannot_enforce("as char".to_string(), error, span.to_owned()))?;
+        }
+
+        Ok(character)
+    }
+
+    pub fn from_input<F: Field + PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+        cs: &mut CS,
+        name: &str,
+        input_value: Option<InputValue>,
+        span: &Span,
+    ) -> Result<ConstrainedValue<F, G>, CharError> {
+        // Check that the input value is the correct type
+        let char_value = match input_value {
+            Some(input) => {
+                if let InputValue::Char(character) = input {
+                    Some(character)
+                } else {
+                    return Err(CharError::invalid_char(input.to_string(), span.to_owned()));
+                }
+            }
+            None => None,
+        };
+
+        let character = Self::allocate(
+            cs.ns(|| format!("`{}: char` {}:{}", name, span.line, span.start)),
+            char_value,
+            span,
+        )?;
+
+        Ok(ConstrainedValue::Char(character))
+    }
+
+    ///
+    /// Returns a new char value allocated in the constraint system.
+    ///
+    /// Enforces that the allocated value is a valid unicode scalar value.
+    ///
+    pub fn allocate<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        character: Option<char>,
+        span: &Span,
+    ) -> Result<Self, CharError> {
+        let integer = UInt32::alloc(cs.ns(|| "code point"), || {
+            character
+                .map(|character| character as u32)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })
+        .map_err(|_| CharError::missing_char(span.to_owned()))?;
+        let integer = Integer::U32(integer);
+
+        enforce_scalar_value(cs.ns(|| "scalar value"), &integer.get_bits())
+            .map_err(|error| CharError::cannot_enforce("allocate".to_string(), error, span.to_owned()))?;
+
+        Ok(Char { integer })
+    }
+
+    ///
+    /// Returns `true` if the char is a constant that is not allocated in the constraint system.
+    ///
+    pub fn is_constant(&self) -> bool {
+        self.integer.is_constant()
+    }
+
+    pub fn get_value(&self) -> Option<char> {
+        self.integer
+            .get_value()
+            .and_then(|value| value.parse::<u32>().ok())
+            .and_then(std::char::from_u32)
+    }
+}
+
+///
+/// Enforces that the little-endian bits of a `u32` are a unicode scalar value.
+///
+/// Scalar values are at most `0x10FFFF` and exclude the surrogate code points `0xD800..=0xDFFF`.
+///
+fn enforce_scalar_value<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    bits: &[Boolean],
+) -> Result<(), SynthesisError> {
+    // The code point fits in 21 bits.
+    for (i, bit) in bits.iter().enumerate().skip(21) {
+        bit.enforce_equal(cs.ns(|| format!("bit {}", i)), &Boolean::constant(false))?;
+    }
+
+    // The code point is not in `0x110000..=0x1FFFFF`.
+    let mut plane = Boolean::constant(false);
+    for (i, bit) in bits.iter().enumerate().take(20).skip(16) {
+        plane = Boolean::or(cs.ns(|| format!("plane bit {}", i)), &plane, bit)?;
+    }
+    Boolean::enforce_nand(cs.ns(|| "at most 0x10FFFF"), &[bits[20], plane])?;
+
+    // The code point is not a surrogate with the bit pattern `0000011011xxxxxxxxxxx`.
+    let surrogate = [
+        bits[11],
+        bits[12],
+        bits[13].not(),
+        bits[14],
+        bits[15],
+        bits[16].not(),
+        bits[17].not(),
+        bits[18].not(),
+        bits[19].not(),
+        bits[20].not(),
+    ];
+    Boolean::enforce_nand(cs.ns(|| "not a surrogate"), &surrogate)
+}
+
+impl<F: Field + PrimeField> EvaluateEqGadget<F> for Char {
+    fn evaluate_equal<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        self.integer.evaluate_equal(cs, &other.integer)
+    }
+}
+
+impl<F: Field + PrimeField> EvaluateLtGadget<F> for Char {
+    fn less_than<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        self.integer.less_than(cs, &other.integer)
+    }
+}
+
+impl<F: Field + PrimeField> ComparatorGadget<F> for Char {}
+
+impl<F: Field + PrimeField> ConditionalEqGadget<F> for Char {
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.integer.conditional_enforce_equal(cs, &other.integer, condition)
+    }
+
+    fn cost() -> usize {
+        <UInt32 as ConditionalEqGadget<F>>::cost()
+    }
+}
+
+impl<F: Field + PrimeField> CondSelectGadget<F> for Char {
+    fn conditionally_select<CS: ConstraintSystem<F>>(
+        cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Char {
+            integer: Integer::conditionally_select(cs, cond, &first.integer, &second.integer)?,
+        })
+    }
+
+    fn cost() -> usize {
+        <UInt32 as CondSelectGadget<F>>::cost()
+    }
+}
+
+impl fmt::Display for Char {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.get_value() {
+            Some(character) => write!(f, "'{}'", character.escape_debug()),
+            None => write!(f, "[input char]"),
+        }
+    }
+}
@@ -15,10 +15,11 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 //! Conversion of integer declarations to constraints in Leo.
-use crate::{errors::IntegerError, IntegerTrait};
+use crate::{errors::IntegerError, FieldType, IntegerTrait};
 use leo_ast::{InputValue, IntegerType, Span, Type};
 use leo_gadgets::{
     arithmetic::*,
+    bitwise::*,
     bits::comparator::{ComparatorGadget, EvaluateLtGadget},
     signed_integer::*,
 };
@@ -27,7 +28,8 @@ use snarkos_errors::gadgets::SynthesisError;
 use snarkos_models::{
     curves::{Field, PrimeField},
     gadgets::{
-        r1cs::ConstraintSystem,
+        curves::FpGadget,
+        r1cs::{ConstraintSystem, ConstraintVar, LinearCombination},
         utilities::{
             alloc::AllocGadget,
             boolean::Boolean,
@@ -37,7 +39,7 @@ use snarkos_models::{
         },
     },
 };
-use std::fmt;
+use std::{convert::TryFrom, fmt};
 
 /// An integer type enum wrapping the integer value.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
@@ -191,6 +193,50 @@ impl Integer {
         match_integer!(integer => integer.get_value())
     }
 
+    ///
+    /// Returns `true` if the integer is a constant that is not allocated in the constraint system.
+    ///
+    pub fn is_constant(&self) -> bool {
+        self.get_bits().iter().all(|bit| matches!(bit, Boolean::Constant(_)))
+    }
+
+    ///
+    /// Returns the result of a binary operation on two constant integers without enforcing constraints.
+    ///
+    /// Returns `None` if either integer is allocated. Returns an error if the operation does not have a
+    /// result of the same integer type.
+    ///
+    fn constant_binary_operation(
+        &self,
+        other: &Self,
+        operation: &str,
+        unsigned: impl Fn(u128, u128) -> Option<u128>,
+        signed: impl Fn(i128, i128) -> Option<i128>,
+        span: &Span,
+    ) -> Result<Option<Self>, IntegerError> {
+        if !self.is_constant() || !other.is_constant() || self.get_type() != other.get_type() {
+            return Ok(None);
+        }
+
+        let (a, b) = match (self.get_value(), other.get_value()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Ok(None),
+        };
+        let result = match self.get_type() {
+            IntegerType::U8 | IntegerType::U16 | IntegerType::U32 | IntegerType::U64 | IntegerType::U128 => {
+                a.parse().ok().zip(b.parse().ok()).and_then(|(a, b)| unsigned(a, b)).map(|c| c.to_string())
+            }
+            IntegerType::I8 | IntegerType::I16 | IntegerType::I32 | IntegerType::I64 | IntegerType::I128 => {
+                a.parse().ok().zip(b.parse().ok()).and_then(|(a, b)| signed(a, b)).map(|c| c.to_string())
+            }
+        };
+
+        let error = || IntegerError::constant_operation(format!("{} {} {}", a, operation, b), span.to_owned());
+        let result = result.ok_or_else(error)?;
+
+        Self::new_constant(&self.get_type(), result, span).map(Some).map_err(|_| error())
+    }
+
     pub fn to_usize(&self, span: &Span) -> Result<usize, IntegerError> {
         let unsigned_integer = self;
         let value_option: Option<String> = match_unsigned_integer!(unsigned_integer => unsigned_integer.get_value());
@@ -443,6 +489,11 @@ impl Integer {
         other: Self,
         span: &Span,
     ) -> Result<Self, IntegerError> {
+        // Multiply constants without enforcing constraints.
+        if let Some(result) = self.constant_binary_operation(&other, "*", u128::checked_mul, i128::checked_mul, span)? {
+            return Ok(result);
+        }
+
         let unique_namespace = format!("enforce {} * {} {}:{}", self, other, span.line, span.start);
 
         let a = self;
@@ -459,6 +510,11 @@ impl Integer {
         other: Self,
         span: &Span,
     ) -> Result<Self, IntegerError> {
+        // Divide constants without enforcing constraints.
+        if let Some(result) = self.constant_binary_operation(&other, "/", u128::checked_div, i128::checked_div, span)? {
+            return Ok(result);
+        }
+
         let unique_namespace = format!("enforce {} ÷ {} {}:{}", self, other, span.line, span.start);
 
         let a = self;
@@ -469,12 +525,42 @@ impl Integer {
         result.ok_or_else(|| IntegerError::binary_operation("÷".to_string(), span.to_owned()))
     }
 
+    pub fn rem<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} % {} {}:{}", self, other, span.line, span.start);
+
+        let a = self;
+        let b = other;
+
+        let result = match_integers_span!((a, b), span => a
+            .div_rem(cs.ns(|| unique_namespace), &b)
+            .map(|(_quotient, remainder)| remainder));
+
+        result.ok_or_else(|| IntegerError::binary_operation("%".to_string(), span.to_owned()))
+    }
+
     pub fn pow<F: Field + PrimeField, CS: ConstraintSystem<F>>(
         self,
         cs: &mut CS,
         other: Self,
         span: &Span,
     ) -> Result<Self, IntegerError> {
+        // Raise constants to a constant power without enforcing constraints.
+        let exponent = |exponent: u128| u32::try_from(exponent).ok();
+        if let Some(result) = self.constant_binary_operation(
+            &other,
+            "**",
+            |a, b| a.checked_pow(exponent(b)?),
+            |a, b| a.checked_pow(exponent(u128::try_from(b).ok()?)?),
+            span,
+        )? {
+            return Ok(result);
+        }
+
         let unique_namespace = format!("enforce {} ** {} {}:{}", self, other, span.line, span.start);
 
         let a = self;
@@ -484,6 +570,299 @@ impl Integer {
 
         result.ok_or_else(|| IntegerError::binary_operation("**".to_string(), span.to_owned()))
     }
+
+    pub fn bit_and<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} & {} {}:{}", self, other, span.line, span.start);
+
+        let a = self;
+        let b = other;
+
+        let result = match_integers_span!((a, b), span => a.bit_and(cs.ns(|| unique_namespace), &b));
+
+        result.ok_or_else(|| IntegerError::binary_operation("&".to_string(), span.to_owned()))
+    }
+
+    pub fn bit_or<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} | {} {}:{}", self, other, span.line, span.start);
+
+        let a = self;
+        let b = other;
+
+        let result = match_integers_span!((a, b), span => a.bit_or(cs.ns(|| unique_namespace), &b));
+
+        result.ok_or_else(|| IntegerError::binary_operation("|".to_string(), span.to_owned()))
+    }
+
+    pub fn bit_xor<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} ^ {} {}:{}", self, other, span.line, span.start);
+
+        let a = self;
+        let b = other;
+
+        let result = match_integers_span!((a, b), span => a.bit_xor(cs.ns(|| unique_namespace), &b));
+
+        result.ok_or_else(|| IntegerError::binary_operation("^".to_string(), span.to_owned()))
+    }
+
+    pub fn shl<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} << {} {}:{}", self, other, span.line, span.start);
+
+        // The shift amount may be an integer of any unsigned type.
+        if is_signed(&other.get_type()) {
+            return Err(IntegerError::binary_operation("<<".to_string(), span.to_owned()));
+        }
+
+        let a = self;
+        let amount = other.get_bits();
+
+        Ok(match_integer_span!(a, span => a.shl(cs.ns(|| unique_namespace), &amount)))
+    }
+
+    pub fn shr<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} >> {} {}:{}", self, other, span.line, span.start);
+
+        // The shift amount may be an integer of any unsigned type.
+        if is_signed(&other.get_type()) {
+            return Err(IntegerError::binary_operation(">>".to_string(), span.to_owned()));
+        }
+
+        let a = self;
+        let amount = other.get_bits();
+
+        Ok(match_integer_span!(a, span => a.shr(cs.ns(|| unique_namespace), &amount)))
+    }
+
+    pub fn bit_not(self) -> Self {
+        let a = self;
+
+        match_integer_map!(a => a.bit_not())
+    }
+    ///
+    /// Returns the integer cast to the given integer type.
+    ///
+    /// Enforces that the value of the integer fits in the target type.
+    ///
+    pub fn cast<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        target_type: &IntegerType,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let value = self.get_value();
+
+        // Check that the value fits in the target type.
+        if let Some(ref value) = value {
+            if Self::new_constant(target_type, value.clone(), span).is_err() {
+                return Err(IntegerError::cast_overflow(value.clone(), target_type, span.to_owned()));
+            }
+        }
+
+        let mut cs = cs.ns(|| format!("enforce {} as {} {}:{}", self, target_type, span.line, span.start));
+
+        let source_signed = is_signed(&self.get_type());
+        let target_signed = is_signed(target_type);
+        let target_size = bit_size(target_type);
+
+        let bits = self.get_bits();
+        let size = bits.len();
+        let sign = if source_signed { bits[size - 1] } else { Boolean::constant(false) };
+
+        // The value fits in the target type if the bits above it are copies of its sign bit.
+        let (reference, start, end) = match (source_signed, target_signed) {
+            (false, false) => (Boolean::constant(false), target_size, size),
+            (true, false) => (Boolean::constant(false), target_size.min(size - 1), size),
+            (false, true) => (Boolean::constant(false), target_size - 1, size),
+            (true, true) => (sign, target_size - 1, size - 1),
+        };
+
+        for (i, bit) in bits.iter().enumerate().take(end).skip(start) {
+            if let (Boolean::Constant(_), Boolean::Constant(_)) = (bit, reference) {
+                continue;
+            }
+
+            bit.enforce_equal(cs.ns(|| format!("bit {}", i)), &reference)
+                .map_err(|e| IntegerError::synthesis(e, span.to_owned()))?;
+        }
+
+        // Truncate or extend the bits to the target size.
+        let mut result_bits = bits;
+        result_bits.resize(target_size, sign);
+
+        Ok(Self::from_bits(target_type, result_bits, value))
+    }
+
+    ///
+    /// Returns the integer as a field element.
+    ///
+    pub fn to_field<F: Field + PrimeField, CS: ConstraintSystem<F>>(&self) -> FieldType<F> {
+        let bits = self.get_bits();
+        let size = bits.len();
+        let signed = is_signed(&self.get_type());
+
+        let mut lc = LinearCombination::zero();
+        let mut value = Some(F::zero());
+        let mut coeff = F::one();
+
+        for (i, bit) in bits.iter().enumerate() {
+            // The sign bit of a signed integer has a negative weight.
+            let weight = if signed && i == size - 1 { -coeff } else { coeff };
+
+            lc = lc + &bit.lc(CS::one(), weight);
+            value = match (value, bit.get_value()) {
+                (Some(value), Some(bit)) => Some(if bit { value + &weight } else { value }),
+                _ => None,
+            };
+
+            coeff.double_in_place();
+        }
+
+        match value {
+            Some(value) if bits.iter().all(|bit| matches!(bit, Boolean::Constant(_))) => FieldType::Constant(value),
+            _ => FieldType::Allocated(FpGadget {
+                value,
+                variable: ConstraintVar::LC(lc),
+            }),
+        }
+    }
+
+    ///
+    /// Returns a new integer of the given type from a field element.
+    ///
+    /// Enforces that the field element is equal to the value of the integer.
+    ///
+    pub fn from_field<F: Field + PrimeField, CS: ConstraintSystem<F>>(
+        cs: &mut CS,
+        field: FieldType<F>,
+        target_type: &IntegerType,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let value = match field.get_value() {
+            Some(field_value) => {
+                let value = field_to_integer_string(field_value)
+                    .filter(|value| Self::new_constant(target_type, value.clone(), span).is_ok());
+
+                Some(value.ok_or_else(|| {
+                    IntegerError::cast_overflow(field_value.to_string(), target_type, span.to_owned())
+                })?)
+            }
+            None => None,
+        };
+
+        let gadget = match field {
+            FieldType::Constant(_) => return Self::new_constant(target_type, value.unwrap(), span),
+            FieldType::Allocated(gadget) => gadget,
+        };
+
+        let mut cs = cs.ns(|| format!("enforce field as {} {}:{}", target_type, span.line, span.start));
+
+        let integer = Self::allocate_type(&mut cs, target_type.clone(), "cast", value, span)?;
+        let integer_field = integer.to_field::<F, CS>();
+
+        let integer_lc = match integer_field {
+            FieldType::Allocated(integer_gadget) => integer_gadget.variable,
+            FieldType::Constant(_) => unreachable!("allocated integers have allocated bits"),
+        };
+
+        cs.enforce(
+            || "enforce field equals integer",
+            |lc| &gadget.variable + lc,
+            |lc| lc + CS::one(),
+            |lc| &integer_lc + lc,
+        );
+
+        Ok(integer)
+    }
+
+    ///
+    /// Returns a new integer of the given type with the value `0` or `1` of a boolean.
+    ///
+    pub fn from_boolean(boolean: Boolean, target_type: &IntegerType) -> Self {
+        let value = boolean.get_value().map(|value| (value as u8).to_string());
+
+        let mut bits = vec![boolean];
+        bits.resize(bit_size(target_type), Boolean::constant(false));
+
+        Self::from_bits(target_type, bits, value)
+    }
+
+    ///
+    /// Returns a new integer of the given type from its little-endian bits and value.
+    ///
+    fn from_bits(integer_type: &IntegerType, bits: Vec<Boolean>, value: Option<String>) -> Self {
+        match integer_type {
+            IntegerType::U8 => Integer::U8(UInt8 {
+                bits,
+                negated: false,
+                value: value.and_then(|value| value.parse().ok()),
+            }),
+            IntegerType::U16 => Integer::U16(UInt16 {
+                bits,
+                negated: false,
+                value: value.and_then(|value| value.parse().ok()),
+            }),
+            IntegerType::U32 => Integer::U32(UInt32 {
+                bits,
+                negated: false,
+                value: value.and_then(|value| value.parse().ok()),
+            }),
+            IntegerType::U64 => Integer::U64(UInt64 {
+                bits,
+                negated: false,
+                value: value.and_then(|value| value.parse().ok()),
+            }),
+            IntegerType::U128 => Integer::U128(UInt128 {
+                bits,
+                negated: false,
+                value: value.and_then(|value| value.parse().ok()),
+            }),
+
+            IntegerType::I8 => Integer::I8(Int8 {
+                bits,
+                value: value.and_then(|value| value.parse().ok()),
+            }),
+            IntegerType::I16 => Integer::I16(Int16 {
+                bits,
+                value: value.and_then(|value| value.parse().ok()),
+            }),
+            IntegerType::I32 => Integer::I32(Int32 {
+                bits,
+                value: value.and_then(|value| value.parse().ok()),
+            }),
+            IntegerType::I64 => Integer::I64(Int64 {
+                bits,
+                value: value.and_then(|value| value.parse().ok()),
+            }),
+            IntegerType::I128 => Integer::I128(Int128 {
+                bits,
+                value: value.and_then(|value| value.parse().ok()),
+            }),
+        }
+    }
 }
 
 impl<F: Field + PrimeField> EvaluateEqGadget<F> for Integer {
@@ -558,3 +937,44 @@ impl<F: Field + PrimeField> CondSelectGadget<F> for Integer {
         unimplemented!() // cannot determine which integer we are enforcing
     }
 }
+
+/// Returns `true` if the integer type is signed.
+fn is_signed(integer_type: &IntegerType) -> bool {
+    matches!(
+        integer_type,
+        IntegerType::I8 | IntegerType::I16 | IntegerType::I32 | IntegerType::I64 | IntegerType::I128
+    )
+}
+
+/// Returns the number of bits in the integer type.
+fn bit_size(integer_type: &IntegerType) -> usize {
+    match integer_type {
+        IntegerType::U8 | IntegerType::I8 => 8,
+        IntegerType::U16 | IntegerType::I16 => 16,
+        IntegerType::U32 | IntegerType::I32 => 32,
+        IntegerType::U64 | IntegerType::I64 => 64,
+        IntegerType::U128 | IntegerType::I128 => 128,
+    }
+}
+
+/// Returns the value of a field element as a decimal integer string.
+///
+/// Field elements greater than `(p - 1) / 2` are read as negative numbers.
+/// Returns `None` if the magnitude does not fit in 128 bits.
+fn field_to_integer_string<F: PrimeField>(value: F) -> Option<String> {
+    let magnitude = |value: F| {
+        let repr = value.into_repr();
+        let limbs = repr.as_ref();
+
+        if limbs[2..].iter().all(|limb| *limb == 0) {
+            Some(limbs[0] as u128 | (limbs[1] as u128) << 64)
+        } else {
+            None
+        }
+    };
+
+    match magnitude(value) {
+        Some(magnitude) => Some(magnitude.to_string()),
+        None => magnitude(-value).map(|magnitude| format!("-{}", magnitude)),
+    }
+}
@@ -65,6 +65,64 @@ macro_rules! match_integer {
     };
 }
 
+#[macro_export]
+macro_rules! match_integer_map {
+    ($integer: ident => $expression: expr) => {
+        match $integer {
+            Integer::U8($integer) => Integer::U8($expression),
+            Integer::U16($integer) => Integer::U16($expression),
+            Integer::U32($integer) => Integer::U32($expression),
+            Integer::U64($integer) => Integer::U64($expression),
+            Integer::U128($integer) => Integer::U128($expression),
+
+            Integer::I8($integer) => Integer::I8($expression),
+            Integer::I16($integer) => Integer::I16($expression),
+            Integer::I32($integer) => Integer::I32($expression),
+            Integer::I64($integer) => Integer::I64($expression),
+            Integer::I128($integer) => Integer::I128($expression),
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! match_integer_span {
+    ($integer: ident, $span: ident => $expression: expr) => {
+        match $integer {
+            Integer::U8($integer) => Integer::U8(
+                $expression.map_err(|e| IntegerError::synthesis(e, $span.to_owned()))?,
+            ),
+            Integer::U16($integer) => Integer::U16(
+                $expression.map_err(|e| IntegerError::synthesis(e, $span.to_owned()))?,
+            ),
+            Integer::U32($integer) => Integer::U32(
+                $expression.map_err(|e| IntegerError::synthesis(e, $span.to_owned()))?,
+            ),
+            Integer::U64($integer) => Integer::U64(
+                $expression.map_err(|e| IntegerError::synthesis(e, $span.to_owned()))?,
+            ),
+            Integer::U128($integer) => Integer::U128(
+                $expression.map_err(|e| IntegerError::synthesis(e, $span.to_owned()))?,
+            ),
+
+            Integer::I8($integer) => Integer::I8(
+                $expression.map_err(|e| IntegerError::signed(e, $span.to_owned()))?,
+            ),
+            Integer::I16($integer) => Integer::I16(
+                $expression.map_err(|e| IntegerError::signed(e, $span.to_owned()))?,
+            ),
+            Integer::I32($integer) => Integer::I32(
+                $expression.map_err(|e| IntegerError::signed(e, $span.to_owned()))?,
+            ),
+            Integer::I64($integer) => Integer::I64(
+                $expression.map_err(|e| IntegerError::signed(e, $span.to_owned()))?,
+            ),
+            Integer::I128($integer) => Integer::I128(
+                $expression.map_err(|e| IntegerError::signed(e, $span.to_owned()))?,
+            ),
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! match_unsigned_integer {
     ($integer: ident => $expression: expr) => {
@@ -21,6 +21,9 @@ pub use self::address::*;
 
 pub mod boolean;
 
+pub mod char;
+pub use self::char::*;
+
 pub mod field;
 pub use self::field::*;
 
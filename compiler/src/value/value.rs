@@ -18,23 +18,25 @@
 
 use crate::{
     boolean::input::{allocate_bool, new_bool_constant},
-    errors::{ExpressionError, FieldError, ValueError},
+    errors::{AddressError, ExpressionError, FieldError, ValueError},
     is_in_scope,
     new_scope,
     Address,
+    Char,
     FieldType,
     GroupType,
     Integer,
+    PublicConstraintSystem,
 };
-use leo_ast::{ArrayDimensions, Circuit, Function, GroupValue, Identifier, Span, Type};
+use leo_ast::{ArrayDimensions, Circuit, Enum, Function, GroupValue, Identifier, Span, Type};
 use leo_core::Value;
 
 use snarkos_errors::gadgets::SynthesisError;
 use snarkos_models::{
     curves::{Field, PrimeField},
     gadgets::{
-        r1cs::ConstraintSystem,
-        utilities::{boolean::Boolean, eq::ConditionalEqGadget, select::CondSelectGadget},
+        r1cs::{ConstraintSystem, LinearCombination},
+        utilities::{alloc::AllocGadget, boolean::Boolean, eq::ConditionalEqGadget, select::CondSelectGadget},
     },
 };
 use std::fmt;
@@ -42,11 +44,21 @@ use std::fmt;
 #[derive(Clone, PartialEq, Eq)]
 pub struct ConstrainedCircuitMember<F: Field + PrimeField, G: GroupType<F>>(pub Identifier, pub ConstrainedValue<F, G>);
 
+/// An enum variant with a bit that is set if the variant is active and the variant payload.
+/// The payload is `None` if the variant has no payload or no value of this variant was ever constructed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ConstrainedEnumVariant<F: Field + PrimeField, G: GroupType<F>>(
+    pub Identifier,
+    pub Boolean,
+    pub Option<ConstrainedValue<F, G>>,
+);
+
 #[derive(Clone, PartialEq, Eq)]
 pub enum ConstrainedValue<F: Field + PrimeField, G: GroupType<F>> {
     // Data types
     Address(Address),
     Boolean(Boolean),
+    Char(Char),
     Field(FieldType<F>),
     Group(G),
     Integer(Integer),
@@ -61,6 +73,10 @@ pub enum ConstrainedValue<F: Field + PrimeField, G: GroupType<F>> {
     CircuitDefinition(Circuit),
     CircuitExpression(Identifier, Vec<ConstrainedCircuitMember<F, G>>),
 
+    // Enums
+    EnumDefinition(Enum),
+    EnumExpression(Identifier, Vec<ConstrainedEnumVariant<F, G>>),
+
     // Functions
     Function(Option<Identifier>, Box<Function>), // (optional circuit identifier, function definition)
 
@@ -85,6 +101,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
             // Data types
             Type::Address => Ok(ConstrainedValue::Address(Address::constant(value, span)?)),
             Type::Boolean => Ok(ConstrainedValue::Boolean(new_bool_constant(value, span)?)),
+            Type::Char => Ok(ConstrainedValue::Char(Char::from_code_point(value, span)?)),
             Type::Field => Ok(ConstrainedValue::Field(FieldType::constant(value, span)?)),
             Type::Group => Ok(ConstrainedValue::Group(G::constant(GroupValue::Single(
                 value,
@@ -107,6 +124,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
             // Data types
             ConstrainedValue::Address(_address) => Type::Address,
             ConstrainedValue::Boolean(_bool) => Type::Boolean,
+            ConstrainedValue::Char(_char) => Type::Char,
             ConstrainedValue::Field(_field) => Type::Field,
             ConstrainedValue::Group(_group) => Type::Group,
             ConstrainedValue::Integer(integer) => Type::IntegerType(integer.get_type()),
@@ -136,6 +154,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
                 Type::Tuple(types)
             }
             ConstrainedValue::CircuitExpression(id, _members) => Type::Circuit(id.clone()),
+            ConstrainedValue::EnumExpression(id, _variants) => Type::Enum(id.clone()),
             ConstrainedValue::Mutable(value) => return value.to_type(span),
             value => return Err(ValueError::implicit(value.to_string(), span.to_owned())),
         })
@@ -229,6 +248,14 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
         }
     }
 
+    pub(crate) fn enum_definition(&self) -> Option<&Enum> {
+        match self {
+            ConstrainedValue::EnumDefinition(enum_) => Some(enum_),
+            ConstrainedValue::Import(_import_scope, enum_) => enum_.enum_definition(),
+            _ => None,
+        }
+    }
+
     ///
     /// Modifies the `self` [ConstrainedValue] so there are no `mut` keywords wrapping the `self` value.
     ///
@@ -260,6 +287,11 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
 
                 *boolean = allocate_bool(&mut cs, &name, option, span)?;
             }
+            ConstrainedValue::Char(character) => {
+                let namespace = format!("allocate char {}:{}", span.line, span.start);
+
+                *character = Char::allocate(cs.ns(|| namespace), character.get_value(), span)?;
+            }
             ConstrainedValue::Field(field) => {
                 let gadget = field
                     .allocated(cs.ns(|| format!("allocate field {}:{}", span.line, span.start)))
@@ -300,6 +332,34 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
                     member.1.allocate_value(cs.ns(|| unique_name), span)
                 })?;
             }
+            ConstrainedValue::EnumExpression(_id, variants) => {
+                variants.iter_mut().enumerate().try_for_each(|(i, variant)| {
+                    let option = variant.1.get_value();
+                    let name = option
+                        .map(|b| b.to_string())
+                        .unwrap_or_else(|| "[allocated]".to_string());
+                    let unique_name = format!("allocate enum variant {} {}:{}", i, span.line, span.start);
+
+                    variant.1 = allocate_bool(&mut cs.ns(|| &unique_name), &name, option, span)?;
+
+                    match variant.2 {
+                        Some(ref mut payload) => payload.allocate_value(cs.ns(|| format!("{} payload", unique_name)), span),
+                        None => Ok(()),
+                    }
+                })?;
+
+                // Enforce that exactly one variant bit is set
+                let active_variants = variants
+                    .iter()
+                    .fold(LinearCombination::zero(), |lc, variant| lc + variant.1.lc(CS::one(), F::one()));
+
+                cs.enforce(
+                    || format!("one active enum variant {}:{}", span.line, span.start),
+                    |_| active_variants,
+                    |lc| lc + CS::one(),
+                    |lc| lc + CS::one(),
+                );
+            }
             ConstrainedValue::Mutable(value) => {
                 value.allocate_value(cs, span)?;
             }
@@ -309,6 +369,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
 
             // Empty wrappers that are unreachable
             ConstrainedValue::CircuitDefinition(_) => {}
+            ConstrainedValue::EnumDefinition(_) => {}
             ConstrainedValue::Function(_, _) => {}
             ConstrainedValue::Import(_, _) => {}
 
@@ -320,6 +381,47 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConstrainedValue<F, G> {
 
         Ok(())
     }
+
+    ///
+    /// Returns a copy of the value where every variable is allocated as a public input.
+    ///
+    pub(crate) fn allocate_public<CS: ConstraintSystem<F>>(&self, mut cs: CS, span: &Span) -> Result<Self, ValueError> {
+        self.allocate_public_copy(PublicConstraintSystem::new(&mut cs), span)
+    }
+
+    fn allocate_public_copy<CS: ConstraintSystem<F>>(&self, mut cs: CS, span: &Span) -> Result<Self, ValueError> {
+        match self {
+            // Addresses are not reallocated by `allocate_value`
+            ConstrainedValue::Address(address) => {
+                let option = address.address.as_ref().map(|address| address.to_string());
+                let address = Address::alloc(
+                    cs.ns(|| format!("allocate public address {}:{}", span.line, span.start)),
+                    || option.ok_or(SynthesisError::AssignmentMissing),
+                )
+                .map_err(|_| AddressError::missing_address(span.to_owned()))?;
+
+                Ok(ConstrainedValue::Address(address))
+            }
+            ConstrainedValue::Array(array) => array
+                .iter()
+                .enumerate()
+                .map(|(i, value)| value.allocate_public_copy(cs.ns(|| format!("public array member {}", i)), span))
+                .collect::<Result<Vec<_>, _>>()
+                .map(ConstrainedValue::Array),
+            ConstrainedValue::Tuple(tuple) => tuple
+                .iter()
+                .enumerate()
+                .map(|(i, value)| value.allocate_public_copy(cs.ns(|| format!("public tuple member {}", i)), span))
+                .collect::<Result<Vec<_>, _>>()
+                .map(ConstrainedValue::Tuple),
+            value => {
+                let mut public = value.clone();
+                public.allocate_value(&mut cs, span)?;
+
+                Ok(public)
+            }
+        }
+    }
 }
 
 impl<F: Field + PrimeField, G: GroupType<F>> fmt::Display for ConstrainedValue<F, G> {
@@ -335,6 +437,7 @@ impl<F: Field + PrimeField, G: GroupType<F>> fmt::Display for ConstrainedValue<F
                     .map(|v| v.to_string())
                     .unwrap_or_else(|| "[allocated]".to_string())
             ),
+            ConstrainedValue::Char(ref value) => write!(f, "{}", value),
             ConstrainedValue::Field(ref value) => write!(f, "{:?}", value),
             ConstrainedValue::Group(ref value) => write!(f, "{:?}", value),
             ConstrainedValue::Integer(ref value) => write!(f, "{}", value),
@@ -366,6 +469,19 @@ impl<F: Field + PrimeField, G: GroupType<F>> fmt::Display for ConstrainedValue<F
                 write!(f, "}}")
             }
             ConstrainedValue::CircuitDefinition(ref circuit) => write!(f, "circuit {{ {} }}", circuit.circuit_name),
+            ConstrainedValue::EnumExpression(ref identifier, ref variants) => {
+                let variant = variants
+                    .iter()
+                    .find(|variant| variant.1.get_value().unwrap_or(false))
+                    .map(|variant| match variant.2 {
+                        Some(ref payload) => format!("{}({})", variant.0, payload),
+                        None => variant.0.to_string(),
+                    })
+                    .unwrap_or_else(|| "[allocated]".to_string());
+
+                write!(f, "{}::{}", identifier, variant)
+            }
+            ConstrainedValue::EnumDefinition(ref enum_) => write!(f, "enum {{ {} }}", enum_.enum_name),
             ConstrainedValue::Function(ref _circuit_option, ref function) => {
                 write!(f, "function {{ {}() }}", function.identifier)
             }
@@ -397,6 +513,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConditionalEqGadget<F> for Constrai
             (ConstrainedValue::Boolean(bool_1), ConstrainedValue::Boolean(bool_2)) => {
                 bool_1.conditional_enforce_equal(cs, bool_2, condition)
             }
+            (ConstrainedValue::Char(char_1), ConstrainedValue::Char(char_2)) => {
+                char_1.conditional_enforce_equal(cs, char_2, condition)
+            }
             (ConstrainedValue::Field(field_1), ConstrainedValue::Field(field_2)) => {
                 field_1.conditional_enforce_equal(cs, field_2, condition)
             }
@@ -418,6 +537,27 @@ impl<F: Field + PrimeField, G: GroupType<F>> ConditionalEqGadget<F> for Constrai
                 }
                 Ok(())
             }
+            (
+                ConstrainedValue::EnumExpression(_identifier_1, variants_1),
+                ConstrainedValue::EnumExpression(_identifier_2, variants_2),
+            ) => {
+                for (i, (left, right)) in variants_1.iter().zip(variants_2.iter()).enumerate() {
+                    left.1
+                        .conditional_enforce_equal(cs.ns(|| format!("enum variant {}", i)), &right.1, condition)?;
+
+                    // The payloads are only equal if the variant is active in both values
+                    if let (Some(payload_1), Some(payload_2)) = (&left.2, &right.2) {
+                        let active = Boolean::and(cs.ns(|| format!("enum variant {} active", i)), &left.1, condition)?;
+
+                        payload_1.conditional_enforce_equal(
+                            cs.ns(|| format!("enum variant {} payload", i)),
+                            payload_2,
+                            &active,
+                        )?;
+                    }
+                }
+                Ok(())
+            }
             (_, _) => Err(SynthesisError::Unsatisfiable),
         }
     }
@@ -441,6 +581,9 @@ impl<F: Field + PrimeField, G: GroupType<F>> CondSelectGadget<F> for Constrained
             (ConstrainedValue::Boolean(bool_1), ConstrainedValue::Boolean(bool_2)) => {
                 ConstrainedValue::Boolean(Boolean::conditionally_select(cs, cond, bool_1, bool_2)?)
             }
+            (ConstrainedValue::Char(char_1), ConstrainedValue::Char(char_2)) => {
+                ConstrainedValue::Char(Char::conditionally_select(cs, cond, char_1, char_2)?)
+            }
             (ConstrainedValue::Field(field_1), ConstrainedValue::Field(field_2)) => {
                 ConstrainedValue::Field(FieldType::conditionally_select(cs, cond, field_1, field_2)?)
             }
@@ -500,6 +643,23 @@ impl<F: Field + PrimeField, G: GroupType<F>> CondSelectGadget<F> for Constrained
 
                 ConstrainedValue::CircuitExpression(identifier.clone(), members)
             }
+            (
+                ConstrainedValue::EnumExpression(identifier, variants_1),
+                ConstrainedValue::EnumExpression(_identifier, variants_2),
+            ) => {
+                let mut variants = Vec::with_capacity(variants_1.len());
+
+                for (i, (first, second)) in variants_1.iter().zip(variants_2.iter()).enumerate() {
+                    variants.push(ConstrainedEnumVariant::conditionally_select(
+                        cs.ns(|| format!("enum variant[{}]", i)),
+                        cond,
+                        first,
+                        second,
+                    )?);
+                }
+
+                ConstrainedValue::EnumExpression(identifier.clone(), variants)
+            }
             (ConstrainedValue::Static(first), ConstrainedValue::Static(second)) => {
                 let value = Self::conditionally_select(cs, cond, first, second)?;
 
@@ -534,6 +694,37 @@ impl<F: Field + PrimeField, G: GroupType<F>> CondSelectGadget<F> for Constrained
     }
 }
 
+impl<F: Field + PrimeField, G: GroupType<F>> CondSelectGadget<F> for ConstrainedEnumVariant<F, G> {
+    fn conditionally_select<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        // identifiers will be the same
+        let active = Boolean::conditionally_select(cs.ns(|| "active"), cond, &first.1, &second.1)?;
+
+        // A missing payload belongs to a variant that is not active, so the other payload can be kept
+        let payload = match (&first.2, &second.2) {
+            (Some(payload_1), Some(payload_2)) => Some(ConstrainedValue::conditionally_select(
+                cs.ns(|| "payload"),
+                cond,
+                payload_1,
+                payload_2,
+            )?),
+            (Some(payload), None) | (None, Some(payload)) => Some(payload.clone()),
+            (None, None) => None,
+        };
+
+        Ok(ConstrainedEnumVariant(first.0.clone(), active, payload))
+    }
+
+    // The cost of selecting a payload depends on its type, so only the variant bit is counted
+    fn cost() -> usize {
+        <Boolean as CondSelectGadget<F>>::cost()
+    }
+}
+
 impl<F: Field + PrimeField, G: GroupType<F>> From<Value> for ConstrainedValue<F, G> {
     fn from(v: Value) -> Self {
         match v {
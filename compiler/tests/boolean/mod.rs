@@ -106,7 +106,15 @@ fn test_not_u32() {
     let program_string = include_str!("not_u32.leo");
     let program = parse_program(program_string).unwrap();
 
-    expect_compiler_error(program);
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_not_field() {
+    let program_string = include_str!("not_field.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
 }
 
 // Boolean or ||
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    assert_satisfied,
+    expect_compiler_error,
+    expect_type_inference_error,
+    parse_program,
+    parse_program_with_input,
+};
+
+#[test]
+fn test_widen() {
+    let program_string = include_str!("widen.leo");
+    let input_string = include_str!("input/widen.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_narrow() {
+    let program_string = include_str!("narrow.leo");
+    let input_string = include_str!("input/narrow.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_narrow_overflow() {
+    let program_string = include_str!("narrow_overflow.leo");
+    let input_string = include_str!("input/narrow_overflow.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_signed_to_unsigned_overflow() {
+    let program_string = include_str!("signed_to_unsigned_overflow.leo");
+    let input_string = include_str!("input/signed_to_unsigned_overflow.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_unsigned_to_signed_overflow() {
+    let program_string = include_str!("unsigned_to_signed_overflow.leo");
+    let input_string = include_str!("input/unsigned_to_signed_overflow.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_integer_to_field() {
+    let program_string = include_str!("integer_to_field.leo");
+    let input_string = include_str!("input/integer_to_field.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_field_to_integer() {
+    let program_string = include_str!("field_to_integer.leo");
+    let input_string = include_str!("input/field_to_integer.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_field_to_integer_overflow() {
+    let program_string = include_str!("field_to_integer_overflow.leo");
+    let input_string = include_str!("input/field_to_integer_overflow.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_boolean_to_integer() {
+    let program_string = include_str!("boolean_to_integer.leo");
+    let input_string = include_str!("input/boolean_to_integer.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_constants() {
+    let program_string = include_str!("constants.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_constant_overflow() {
+    let program_string = include_str!("constant_overflow.leo");
+    let program = parse_program(program_string).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_precedence() {
+    let program_string = include_str!("precedence.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_boolean_to_field_fail() {
+    let program_string = include_str!("boolean_to_field.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_integer_to_boolean_fail() {
+    let program_string = include_str!("integer_to_boolean.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_to_group_fail() {
+    let program_string = include_str!("to_group.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
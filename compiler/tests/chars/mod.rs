@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    assert_satisfied,
+    expect_compiler_error,
+    expect_type_inference_error,
+    get_output,
+    parse_program,
+    parse_program_with_input,
+};
+
+#[test]
+fn test_char() {
+    let program_string = include_str!("char.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_ordering() {
+    let program_string = include_str!("ordering.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_cast() {
+    let program_string = include_str!("cast.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_cast_surrogate_fail() {
+    let program_string = include_str!("cast_surrogate_fail.leo");
+    let program = parse_program(program_string).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_cast_range_fail() {
+    let program_string = include_str!("cast_range_fail.leo");
+    let program = parse_program(program_string).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_cast_u8_fail() {
+    let program_string = include_str!("cast_u8_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_add_fail() {
+    let program_string = include_str!("add_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_compare_u32_fail() {
+    let program_string = include_str!("compare_u32_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_empty_fail() {
+    let program_string = include_str!("empty_fail.leo");
+    let syntax_error = parse_program(program_string).is_err();
+
+    assert!(syntax_error);
+}
+
+#[test]
+fn test_multiple_fail() {
+    let program_string = include_str!("multiple_fail.leo");
+    let syntax_error = parse_program(program_string).is_err();
+
+    assert!(syntax_error);
+}
+
+#[test]
+fn test_input() {
+    let program_string = include_str!("input.leo");
+    let input_string = include_str!("input/main.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_input_fail_type() {
+    let program_string = include_str!("input.leo");
+    let input_string = include_str!("input/main_fail_type.in");
+    let syntax_error = parse_program_with_input(program_string, input_string).is_err();
+
+    assert!(syntax_error);
+}
+
+#[test]
+fn test_input_fail_surrogate() {
+    let program_string = include_str!("input.leo");
+    let input_string = include_str!("input/main_fail_surrogate.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_output_register() {
+    let program_string = include_str!("output_register.leo");
+    let input_string = include_str!("input/registers.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let expected = include_bytes!("output/registers.out");
+    let actual = get_output(program);
+
+    assert_eq!(expected, actual.bytes().as_slice());
+}
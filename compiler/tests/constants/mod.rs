@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_satisfied, expect_symbol_table_error, expect_type_inference_error, parse_program};
+use leo_compiler::errors::CompilerError;
+
+fn expect_constant_error(error: CompilerError) {
+    assert!(matches!(error, CompilerError::ImportError(_)))
+}
+
+#[test]
+fn test_basic() {
+    let program_string = include_str!("basic.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_reference() {
+    let program_string = include_str!("reference.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_function() {
+    let program_string = include_str!("function.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_function_field() {
+    let program_string = include_str!("function_field.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_function_integer() {
+    let program_string = include_str!("function_integer.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_array() {
+    let program_string = include_str!("array.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_circuit() {
+    let program_string = include_str!("circuit.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_circuit_underscore() {
+    let program_string = include_str!("circuit_underscore.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_arithmetic() {
+    let program_string = include_str!("arithmetic.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_mismatch() {
+    let program_string = include_str!("mismatch.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_duplicate() {
+    let program_string = include_str!("duplicate.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_symbol_table_error(error);
+}
+
+#[test]
+fn test_overflow() {
+    let program_string = include_str!("overflow.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_constant_error(error);
+}
+
+#[test]
+fn test_division_by_zero() {
+    let program_string = include_str!("division_by_zero.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_constant_error(error);
+}
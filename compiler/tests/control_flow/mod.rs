@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    assert_satisfied,
+    expect_compiler_error,
+    expect_type_inference_error,
+    generate_main_input,
+    generate_test_input_u32,
+    parse_program,
+};
+
+#[test]
+fn test_early_return() {
+    let program_string = include_str!("early_return.leo");
+    let program = parse_program(program_string).unwrap();
+
+    // Check the inputs that take and skip the early return.
+    for &(a, expected) in [(20, 10), (3, 4)].iter() {
+        let mut program = program.clone();
+        let main_input = generate_main_input(vec![
+            ("a", generate_test_input_u32(a)),
+            ("expected", generate_test_input_u32(expected)),
+        ]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+
+    // Check that the statements after the early return are not selected.
+    let mut program = program.clone();
+    let main_input = generate_main_input(vec![
+        ("a", generate_test_input_u32(20)),
+        ("expected", generate_test_input_u32(21)),
+    ]);
+
+    program.set_main_input(main_input);
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_early_return_mut_self() {
+    let program_string = include_str!("early_return_mut_self.leo");
+    let program = parse_program(program_string).unwrap();
+
+    // Check that a member is not mutated after the function returns early.
+    for &(a, expected) in [(1, 1), (2, 2), (5, 3)].iter() {
+        let mut program = program.clone();
+        let main_input = generate_main_input(vec![
+            ("a", generate_test_input_u32(a)),
+            ("expected", generate_test_input_u32(expected)),
+        ]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_loop_return() {
+    let program_string = include_str!("loop_return.leo");
+    let program = parse_program(program_string).unwrap();
+
+    for &(a, expected) in [(0, 1), (2, 3), (10, 5)].iter() {
+        let mut program = program.clone();
+        let main_input = generate_main_input(vec![
+            ("a", generate_test_input_u32(a)),
+            ("expected", generate_test_input_u32(expected)),
+        ]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_else_return() {
+    let program_string = include_str!("else_return.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_conflicting_types_fail() {
+    let program_string = include_str!("conflicting_types_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
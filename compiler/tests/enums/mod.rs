@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    assert_satisfied,
+    expect_type_inference_error,
+    generate_main_input,
+    generate_test_input_u32,
+    parse_program,
+};
+
+use snarkos_curves::edwards_bls12::Fq;
+use snarkos_models::{curves::One, gadgets::r1cs::TestConstraintSystem};
+
+#[test]
+fn test_basic() {
+    let program_string = include_str!("basic.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_select() {
+    let program_string = include_str!("select.leo");
+
+    for (n, expected) in &[(0u32, 0u32), (1, 12), (2, 9)] {
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("n", generate_test_input_u32(*n)),
+            ("expected", generate_test_input_u32(*expected)),
+        ]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_arm_indicator() {
+    let program_string = include_str!("arm_indicator.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    // The assertion in the function called from the inactive arm is not enforced
+    let main_input = generate_main_input(vec![
+        ("n", generate_test_input_u32(0)),
+        ("expected", generate_test_input_u32(0)),
+    ]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_wildcard() {
+    let program_string = include_str!("wildcard.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_non_exhaustive() {
+    let program_string = include_str!("non_exhaustive.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_unreachable_arm() {
+    let program_string = include_str!("unreachable_arm.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_payload_fail() {
+    let program_string = include_str!("payload_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_mismatched_enum() {
+    let program_string = include_str!("mismatched_enum.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_shadow() {
+    let program_string = include_str!("shadow.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_allocate() {
+    let program_string = include_str!("allocate.leo");
+    let program = parse_program(program_string).unwrap();
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    program.generate_constraints_helper(&mut cs).unwrap();
+
+    assert!(cs.is_satisfied());
+
+    // Setting the bit of an inactive variant violates the constraints of the allocated enum value
+    cs.set("allocate enum variant 0 7:5/`false: bool` 7:5/boolean", Fq::one());
+
+    assert!(!cs.is_satisfied());
+}
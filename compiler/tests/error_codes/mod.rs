@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{expect_compiler_error, parse_program};
+use leo_compiler::errors::CompilerError;
+
+use std::collections::HashSet;
+
+const ERROR_CODE_TABLES: &[&[(&str, &str)]] = &[
+    leo_grammar::ERROR_CODES,
+    leo_symbol_table::ERROR_CODES,
+    leo_type_inference::ERROR_CODES,
+    leo_compiler::errors::ERROR_CODES,
+    leo_package::ERROR_CODES,
+];
+
+fn is_documented(code: &str) -> bool {
+    ERROR_CODE_TABLES
+        .iter()
+        .flat_map(|table| table.iter())
+        .any(|(table_code, _)| *table_code == code)
+}
+
+#[test]
+fn test_error_codes_unique() {
+    let mut codes = HashSet::new();
+
+    for (code, explanation) in ERROR_CODE_TABLES.iter().flat_map(|table| table.iter()) {
+        assert_eq!(code.len(), 5, "malformed error code `{}`", code);
+        assert!(code.starts_with('E') && code[1..].chars().all(|c| c.is_ascii_digit()));
+        assert!(!explanation.is_empty(), "missing explanation for `{}`", code);
+        assert!(codes.insert(code), "duplicate error code `{}`", code);
+    }
+}
+
+#[test]
+fn test_syntax_error_code() {
+    let program_string = "function main() {\n    let a = ;\n}";
+    let error = parse_program(program_string).err().unwrap();
+
+    assert_eq!(error.error_code(), "E0104");
+}
+
+#[test]
+fn test_type_inference_error_code() {
+    let program_string = include_str!("../syntax/compare_mismatched_types.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    assert_eq!(error.error_code(), "E0327");
+    assert!(is_documented(error.error_code()));
+}
+
+#[test]
+fn test_expression_error_code() {
+    let program_string = include_str!("../syntax/undefined.leo");
+    let program = parse_program(program_string).unwrap();
+    let error = expect_compiler_error(program);
+
+    assert_eq!(error.error_code(), "E0445");
+    assert!(is_documented(error.error_code()));
+}
+
+#[test]
+fn test_duplicate_definition_error_codes() {
+    let duplicate_constant = parse_program(include_str!("../constants/duplicate.leo")).err().unwrap();
+    let duplicate_function = parse_program("function main() {}\nfunction main() {}\n").err().unwrap();
+
+    assert_eq!(duplicate_constant.error_code(), "E0204");
+    assert_eq!(duplicate_function.error_code(), "E0205");
+    assert!(is_documented(duplicate_constant.error_code()));
+    assert!(is_documented(duplicate_function.error_code()));
+}
+
+#[test]
+fn test_mismatched_enum_error_code() {
+    let program_string = include_str!("../enums/mismatched_enum.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    assert_eq!(error.error_code(), "E0313");
+    assert!(is_documented(error.error_code()));
+}
+
+#[test]
+fn test_no_main_error_code() {
+    let error = CompilerError::NoMain;
+
+    assert_eq!(error.error_code(), "E0406");
+    assert!(is_documented(error.error_code()));
+}
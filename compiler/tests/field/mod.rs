@@ -14,7 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_compiler_error, generate_main_input, parse_program};
+use crate::{
+    assert_satisfied,
+    expect_compiler_error,
+    expect_type_inference_error,
+    generate_main_input,
+    parse_program,
+};
 use leo_ast::InputValue;
 
 use snarkos_curves::edwards_bls12::Fq;
@@ -154,6 +160,14 @@ fn test_div() {
     }
 }
 
+#[test]
+fn test_rem_fail() {
+    let program_string = include_str!("rem.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
 #[test]
 fn test_mul() {
     use std::ops::Mul;
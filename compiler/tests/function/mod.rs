@@ -17,10 +17,16 @@
 use crate::{
     assert_satisfied,
     expect_compiler_error,
+    expect_control_flow_error,
+    expect_symbol_table_error,
     expect_type_inference_error,
+    generate_main_input,
+    generate_test_input_u32,
     get_output,
     parse_program,
     parse_program_with_input,
+    parse_program_with_public_inputs,
+    PublicInputsRecorder,
 };
 use leo_compiler::errors::{CompilerError, ExpressionError, FunctionError, StatementError};
 
@@ -72,17 +78,17 @@ fn test_multiple_returns() {
 #[test]
 fn test_multiple_returns_fail() {
     let program_string = include_str!("multiple_returns_fail.leo");
-    let program = parse_program(program_string).unwrap();
+    let error = parse_program(program_string).err().unwrap();
 
-    expect_compiler_error(program);
+    expect_control_flow_error(error);
 }
 
 #[test]
 fn test_multiple_returns_fail_conditional() {
     let program_string = include_str!("multiple_returns_fail_conditional.leo");
-    let program = parse_program(program_string).unwrap();
+    let error = parse_program(program_string).err().unwrap();
 
-    expect_compiler_error(program);
+    expect_control_flow_error(error);
 }
 
 #[test]
@@ -206,3 +212,49 @@ fn test_return_tuple_conditional() {
 
     assert_satisfied(program);
 }
+
+#[test]
+fn test_public_input() {
+    let program_string = include_str!("public_input.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("a", generate_test_input_u32(1)),
+        ("b", generate_test_input_u32(2)),
+    ]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_public_input_fail() {
+    let program_string = include_str!("public_input_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_symbol_table_error(error);
+}
+
+#[test]
+fn test_public_output() {
+    let program_string = include_str!("public_output.leo");
+    let input_string = include_str!("input/public_output.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let mut cs = PublicInputsRecorder::default();
+    let output = program.clone().compile_constraints(&mut cs).unwrap();
+
+    let expected_string = include_str!("output/public_output.public");
+    let public_inputs = program.public_inputs(&output);
+
+    assert_eq!(expected_string.as_bytes(), public_inputs.bytes().as_slice());
+
+    // The public inputs file allocates the same public inputs without the private input
+    let program = parse_program_with_public_inputs(program_string, expected_string).unwrap();
+
+    let mut public_cs = PublicInputsRecorder::default();
+    program.compile_public_inputs(&mut public_cs).unwrap();
+
+    assert!(!cs.inputs.is_empty());
+    assert_eq!(cs.inputs, public_cs.inputs);
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    assert_satisfied,
+    expect_compiler_error,
+    expect_symbol_table_error,
+    parse_program,
+    EdwardsTestCompiler,
+    TEST_OUTPUT_DIRECTORY,
+};
+
+use std::path::PathBuf;
+
+#[test]
+fn test_function() {
+    let program_string = include_str!("function.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_length() {
+    let program_string = include_str!("length.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_array_initializer() {
+    let program_string = include_str!("array_initializer.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_multiple() {
+    let program_string = include_str!("multiple.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_circuit() {
+    let program_string = include_str!("circuit.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_circuit_package_underscore() {
+    let program_string = include_str!("circuit.leo");
+    let mut program = EdwardsTestCompiler::new(
+        "generic_circuits".to_string(),
+        PathBuf::from("/generic_circuits/src/main.leo"),
+        PathBuf::from(TEST_OUTPUT_DIRECTORY),
+    );
+
+    program.parse_program_from_string(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_mismatch() {
+    let program_string = include_str!("mismatch.leo");
+    let program = parse_program(program_string).unwrap();
+
+    expect_compiler_error(program);
+}
+
+#[test]
+fn test_uninferable() {
+    let program_string = include_str!("uninferable.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_symbol_table_error(error);
+}
+
+#[test]
+fn test_undefined() {
+    let program_string = include_str!("undefined.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_symbol_table_error(error);
+}
+
+#[test]
+fn test_main_fail() {
+    let program_string = include_str!("main_fail.leo");
+    let program = parse_program(program_string).unwrap();
+
+    expect_compiler_error(program);
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, parse_program};
+use crate::{assert_satisfied, expect_control_flow_error, parse_program};
 
 use std::env::{current_dir, set_current_dir};
 
@@ -152,3 +152,36 @@ fn test_many_import_star() {
 
     assert_satisfied(program);
 }
+
+#[test]
+#[ignore]
+fn test_constant() {
+    set_local_dir();
+
+    let program_string = include_str!("constant.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+#[ignore]
+fn test_constant_star() {
+    set_local_dir();
+
+    let program_string = include_str!("constant_star.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+#[ignore]
+fn test_missing_return() {
+    set_local_dir();
+
+    let program_string = include_str!("missing_return.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_control_flow_error(error);
+}
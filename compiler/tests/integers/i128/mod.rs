@@ -87,11 +87,46 @@ fn test_i128_div() {
     TestI128::test_div();
 }
 
+#[test]
+fn test_i128_rem() {
+    TestI128::test_rem();
+}
+
 #[test]
 fn test_i128_pow() {
     TestI128::test_pow();
 }
 
+#[test]
+fn test_i128_bit_and() {
+    TestI128::test_bit_and();
+}
+
+#[test]
+fn test_i128_bit_or() {
+    TestI128::test_bit_or();
+}
+
+#[test]
+fn test_i128_bit_xor() {
+    TestI128::test_bit_xor();
+}
+
+#[test]
+fn test_i128_bit_not() {
+    TestI128::test_bit_not();
+}
+
+#[test]
+fn test_i128_shl() {
+    TestI128::test_shl();
+}
+
+#[test]
+fn test_i128_shr() {
+    TestI128::test_shr();
+}
+
 #[test]
 fn test_i128_eq() {
     TestI128::test_eq();
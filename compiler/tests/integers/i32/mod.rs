@@ -86,11 +86,46 @@ fn test_i32_div() {
     TestI32::test_div();
 }
 
+#[test]
+fn test_i32_rem() {
+    TestI32::test_rem();
+}
+
 #[test]
 fn test_i32_pow() {
     TestI32::test_pow();
 }
 
+#[test]
+fn test_i32_bit_and() {
+    TestI32::test_bit_and();
+}
+
+#[test]
+fn test_i32_bit_or() {
+    TestI32::test_bit_or();
+}
+
+#[test]
+fn test_i32_bit_xor() {
+    TestI32::test_bit_xor();
+}
+
+#[test]
+fn test_i32_bit_not() {
+    TestI32::test_bit_not();
+}
+
+#[test]
+fn test_i32_shl() {
+    TestI32::test_shl();
+}
+
+#[test]
+fn test_i32_shr() {
+    TestI32::test_shr();
+}
+
 #[test]
 fn test_i32_eq() {
     TestI32::test_eq();
@@ -87,11 +87,46 @@ fn test_i64_div() {
     TestI64::test_div();
 }
 
+#[test]
+fn test_i64_rem() {
+    TestI64::test_rem();
+}
+
 #[test]
 fn test_i64_pow() {
     TestI64::test_pow();
 }
 
+#[test]
+fn test_i64_bit_and() {
+    TestI64::test_bit_and();
+}
+
+#[test]
+fn test_i64_bit_or() {
+    TestI64::test_bit_or();
+}
+
+#[test]
+fn test_i64_bit_xor() {
+    TestI64::test_bit_xor();
+}
+
+#[test]
+fn test_i64_bit_not() {
+    TestI64::test_bit_not();
+}
+
+#[test]
+fn test_i64_shl() {
+    TestI64::test_shl();
+}
+
+#[test]
+fn test_i64_shr() {
+    TestI64::test_shr();
+}
+
 #[test]
 fn test_i64_eq() {
     TestI64::test_eq();
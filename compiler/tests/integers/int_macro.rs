@@ -209,6 +209,44 @@ macro_rules! test_int {
                 }
             }
 
+            fn test_rem() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+                    let b: $type_ = rand::random();
+
+                    let program_string = include_str!("rem.leo");
+                    let mut program = parse_program(program_string).unwrap();
+
+                    // expect an error when taking the remainder of a division by zero
+                    if b == 0 {
+                        let main_input = generate_main_input(vec![
+                            ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                            ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                            ("c", Some(InputValue::Integer($integer_type, b.to_string()))),
+                        ]);
+
+                        program.set_main_input(main_input);
+
+                        expect_compiler_error(program);
+                    } else {
+                        let c = match a.checked_rem(b) {
+                            Some(valid) => valid,
+                            None => continue,
+                        };
+
+                        let main_input = generate_main_input(vec![
+                            ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                            ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                            ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                        ]);
+
+                        program.set_main_input(main_input);
+
+                        assert_satisfied(program);
+                    }
+                }
+            }
+
             fn test_pow() {
                 for _ in 0..10 {
                     let a: $type_ = rand::random();
@@ -235,6 +273,148 @@ macro_rules! test_int {
                 }
             }
 
+            fn test_bit_and() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+                    let b: $type_ = rand::random();
+
+                    let c = a & b;
+
+                    let program_string = include_str!("bit_and.leo");
+                    let mut program = parse_program(program_string).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                        ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
+            fn test_bit_or() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+                    let b: $type_ = rand::random();
+
+                    let c = a | b;
+
+                    let program_string = include_str!("bit_or.leo");
+                    let mut program = parse_program(program_string).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                        ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
+            fn test_bit_xor() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+                    let b: $type_ = rand::random();
+
+                    let c = a ^ b;
+
+                    let program_string = include_str!("bit_xor.leo");
+                    let mut program = parse_program(program_string).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                        ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
+            fn test_bit_not() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+
+                    let program_string = include_str!("bit_not.leo");
+                    let mut program = parse_program(program_string).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer($integer_type, (!a).to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
+            fn test_shl() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+
+                    // only shift by amounts smaller than the bit width
+                    let b = rand::random::<u32>() % (std::mem::size_of::<$type_>() * 8) as u32;
+
+                    let c = a << b;
+
+                    // the shift amount is unsigned regardless of the shifted type
+                    let amount_type = leo_input::types::IntegerType::Unsigned(
+                        leo_input::types::UnsignedIntegerType::U32Type(leo_input::types::U32Type {}),
+                    );
+
+                    let program_string = include_str!("shl.leo");
+                    let mut program = parse_program(program_string).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer(amount_type, b.to_string()))),
+                        ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
+            fn test_shr() {
+                for _ in 0..10 {
+                    let a: $type_ = rand::random();
+
+                    // only shift by amounts smaller than the bit width
+                    let b = rand::random::<u32>() % (std::mem::size_of::<$type_>() * 8) as u32;
+
+                    let c = a >> b;
+
+                    // the shift amount is unsigned regardless of the shifted type
+                    let amount_type = leo_input::types::IntegerType::Unsigned(
+                        leo_input::types::UnsignedIntegerType::U32Type(leo_input::types::U32Type {}),
+                    );
+
+                    let program_string = include_str!("shr.leo");
+                    let mut program = parse_program(program_string).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, a.to_string()))),
+                        ("b", Some(InputValue::Integer(amount_type, b.to_string()))),
+                        ("c", Some(InputValue::Integer($integer_type, c.to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    assert_satisfied(program);
+                }
+            }
+
             fn test_eq() {
                 for _ in 0..10 {
                     let a: $type_ = rand::random();
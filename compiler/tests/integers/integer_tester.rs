@@ -42,9 +42,30 @@ pub trait IntegerTester {
     /// Tests a non-wrapping division
     fn test_div();
 
+    /// Tests a non-wrapping remainder
+    fn test_rem();
+
     /// Tests a non-wrapping exponentiation
     fn test_pow();
 
+    /// Tests bitwise & evaluation
+    fn test_bit_and();
+
+    /// Tests bitwise | evaluation
+    fn test_bit_or();
+
+    /// Tests bitwise ^ evaluation
+    fn test_bit_xor();
+
+    /// Tests bitwise ! evaluation
+    fn test_bit_not();
+
+    /// Tests a << shift by less than the bit width
+    fn test_shl();
+
+    /// Tests a >> shift by less than the bit width
+    fn test_shr();
+
     /// Tests == evaluation
     fn test_eq();
 
@@ -71,11 +71,46 @@ fn test_u16_div() {
     TestU16::test_div();
 }
 
+#[test]
+fn test_u16_rem() {
+    TestU16::test_rem();
+}
+
 #[test]
 fn test_u16_pow() {
     TestU16::test_pow();
 }
 
+#[test]
+fn test_u16_bit_and() {
+    TestU16::test_bit_and();
+}
+
+#[test]
+fn test_u16_bit_or() {
+    TestU16::test_bit_or();
+}
+
+#[test]
+fn test_u16_bit_xor() {
+    TestU16::test_bit_xor();
+}
+
+#[test]
+fn test_u16_bit_not() {
+    TestU16::test_bit_not();
+}
+
+#[test]
+fn test_u16_shl() {
+    TestU16::test_shl();
+}
+
+#[test]
+fn test_u16_shr() {
+    TestU16::test_shr();
+}
+
 #[test]
 fn test_u16_eq() {
     TestU16::test_eq();
@@ -71,11 +71,46 @@ fn test_u32_div() {
     TestU32::test_div();
 }
 
+#[test]
+fn test_u32_rem() {
+    TestU32::test_rem();
+}
+
 #[test]
 fn test_u32_pow() {
     TestU32::test_pow();
 }
 
+#[test]
+fn test_u32_bit_and() {
+    TestU32::test_bit_and();
+}
+
+#[test]
+fn test_u32_bit_or() {
+    TestU32::test_bit_or();
+}
+
+#[test]
+fn test_u32_bit_xor() {
+    TestU32::test_bit_xor();
+}
+
+#[test]
+fn test_u32_bit_not() {
+    TestU32::test_bit_not();
+}
+
+#[test]
+fn test_u32_shl() {
+    TestU32::test_shl();
+}
+
+#[test]
+fn test_u32_shr() {
+    TestU32::test_shr();
+}
+
 #[test]
 fn test_u32_eq() {
     TestU32::test_eq();
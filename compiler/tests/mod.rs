@@ -20,19 +20,27 @@
 pub mod address;
 pub mod array;
 pub mod boolean;
+pub mod casts;
+pub mod chars;
 pub mod circuits;
 pub mod compiler;
 pub mod console;
+pub mod constants;
+pub mod control_flow;
 pub mod core;
 pub mod definition;
+pub mod enums;
+pub mod error_codes;
 // pub mod field;
 pub mod function;
+pub mod generics;
 // pub mod group;
 pub mod import;
 pub mod input_files;
 pub mod integers;
 pub mod mutability;
 pub mod statements;
+pub mod strings;
 pub mod syntax;
 pub mod tuples;
 
@@ -47,7 +55,8 @@ use leo_compiler::{
 use leo_input::types::{IntegerType, U32Type, UnsignedIntegerType};
 
 use snarkos_curves::edwards_bls12::Fq;
-use snarkos_models::gadgets::r1cs::TestConstraintSystem;
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::gadgets::r1cs::{ConstraintSystem, Index, LinearCombination, TestConstraintSystem, Variable};
 
 use std::path::PathBuf;
 
@@ -143,6 +152,80 @@ pub fn parse_program_with_input_and_state(
     Ok(compiler)
 }
 
+pub fn parse_program_with_public_inputs(
+    program_string: &str,
+    public_inputs_string: &str,
+) -> Result<EdwardsTestCompiler, CompilerError> {
+    let mut compiler = new_compiler();
+    let path = PathBuf::new();
+
+    compiler.parse_public_inputs(public_inputs_string, &path)?;
+    compiler.parse_program_from_string(program_string)?;
+
+    Ok(compiler)
+}
+
+/// Records the values of the public inputs allocated on a constraint system in order.
+#[derive(Default)]
+pub(crate) struct PublicInputsRecorder {
+    pub(crate) inputs: Vec<Fq>,
+    num_aux: usize,
+}
+
+impl ConstraintSystem<Fq> for PublicInputsRecorder {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, _annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<Fq, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        f()?;
+        self.num_aux += 1;
+
+        Ok(Variable::new_unchecked(Index::Aux(self.num_aux - 1)))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, _annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<Fq, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.inputs.push(f()?);
+
+        Ok(Variable::new_unchecked(Index::Input(self.inputs.len())))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _annotation: A, _a: LA, _b: LB, _c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<Fq>) -> LinearCombination<Fq>,
+        LB: FnOnce(LinearCombination<Fq>) -> LinearCombination<Fq>,
+        LC: FnOnce(LinearCombination<Fq>) -> LinearCombination<Fq>,
+    {
+    }
+
+    fn push_namespace<NR, N>(&mut self, _name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self) {}
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        0
+    }
+}
+
 pub(crate) fn get_output(program: EdwardsTestCompiler) -> OutputBytes {
     // synthesize the circuit on the test constraint system
     let mut cs = TestConstraintSystem::<Fq>::new();
@@ -167,6 +250,10 @@ pub(crate) fn expect_compiler_error(program: EdwardsTestCompiler) -> CompilerErr
     program.generate_constraints_helper(&mut cs).unwrap_err()
 }
 
+pub(crate) fn expect_control_flow_error(error: CompilerError) {
+    assert!(matches!(error, CompilerError::ControlFlowError(_)))
+}
+
 pub(crate) fn expect_type_inference_error(error: CompilerError) {
     assert!(matches!(error, CompilerError::TypeInferenceError(_)))
 }
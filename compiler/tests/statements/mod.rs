@@ -14,7 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_type_inference_error, generate_main_input, parse_program};
+use crate::{
+    assert_satisfied,
+    expect_type_inference_error,
+    generate_main_input,
+    generate_test_input_u32,
+    parse_program,
+};
 use leo_ast::InputValue;
 
 pub mod conditional;
@@ -57,6 +63,79 @@ fn test_iteration_basic() {
     assert_satisfied(program);
 }
 
+#[test]
+fn test_iteration_while() {
+    let program_string = include_str!("iteration_while.leo");
+
+    for (n, expected) in &[(0u32, 0u32), (4, 6), (20, 45)] {
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("n", generate_test_input_u32(*n)),
+            ("expected", generate_test_input_u32(*expected)),
+        ]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_iteration_while_constant() {
+    let program_string = include_str!("iteration_while_constant.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_iteration_while_fail() {
+    let program_string = include_str!("iteration_while_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_assign_bitwise() {
+    let program_string = include_str!("assign_bitwise.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_shift_mixed_width() {
+    let program_string = include_str!("shift_mixed_width.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("a", generate_test_input_u32(3)),
+        ("b", generate_test_input_u32(99)),
+    ]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_shift_signed_amount_fail() {
+    let program_string = include_str!("shift_signed_amount_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_type_inference_error(error);
+}
+
+#[test]
+fn test_assign_remainder() {
+    let program_string = include_str!("assign_remainder.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
 #[test]
 fn test_num_returns_fail() {
     let program_string = include_str!("num_returns_fail.leo");
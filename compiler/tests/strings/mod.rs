@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assert_satisfied, parse_program, parse_program_with_input};
+
+#[test]
+fn test_string() {
+    let program_string = include_str!("string.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_byte_string() {
+    let program_string = include_str!("byte_string.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_escapes() {
+    let program_string = include_str!("escapes.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_unicode() {
+    let program_string = include_str!("unicode.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_constant() {
+    let program_string = include_str!("constant.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_byte_string_non_ascii_fail() {
+    let program_string = include_str!("byte_string_non_ascii_fail.leo");
+    let syntax_error = parse_program(program_string).is_err();
+
+    assert!(syntax_error);
+}
+
+#[test]
+fn test_unicode_surrogate_fail() {
+    let program_string = include_str!("unicode_surrogate_fail.leo");
+    let syntax_error = parse_program(program_string).is_err();
+
+    assert!(syntax_error);
+}
+
+#[test]
+fn test_unicode_range_fail() {
+    let program_string = include_str!("unicode_range_fail.leo");
+    let syntax_error = parse_program(program_string).is_err();
+
+    assert!(syntax_error);
+}
+
+#[test]
+fn test_escape_fail() {
+    let program_string = include_str!("escape_fail.leo");
+    let syntax_error = parse_program(program_string).is_err();
+
+    assert!(syntax_error);
+}
+
+#[test]
+fn test_input() {
+    let program_string = include_str!("input.leo");
+    let input_string = include_str!("input/main.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_input_fail_length() {
+    let program_string = include_str!("input.leo");
+    let input_string = include_str!("input/main_fail_length.in");
+    let syntax_error = parse_program_with_input(program_string, input_string).is_err();
+
+    assert!(syntax_error);
+}
+
+#[test]
+fn test_input_fail_type() {
+    let program_string = include_str!("input.leo");
+    let input_string = include_str!("input/main_fail_type.in");
+    let syntax_error = parse_program_with_input(program_string, input_string).is_err();
+
+    assert!(syntax_error);
+}
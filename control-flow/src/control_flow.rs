@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ControlFlowError, Termination};
+use leo_ast::{Block, CircuitMember, Function, Node, Program, Statement};
+
+/// A control flow check for a Leo program.
+///
+/// A [`ControlFlow`] check walks the statements of each function in order. It reports a statement
+/// that follows a statement that always returns and a function with a return type that may reach
+/// the end of its body. The [`Termination`] of each statement decides whether it always returns.
+///
+/// The types of return statements are checked against the function output type by type inference.
+pub struct ControlFlow;
+
+impl ControlFlow {
+    ///
+    /// Creates and runs a new `ControlFlow` check on a given program.
+    ///
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(program: &Program) -> Result<(), ControlFlowError> {
+        for circuit in program.circuits.values() {
+            for member in circuit.members.iter() {
+                if let CircuitMember::CircuitFunction(function) = member {
+                    Self::check_function(function)?;
+                }
+            }
+        }
+
+        for function in program.functions.values() {
+            Self::check_function(function)?;
+        }
+
+        for test in program.tests.values() {
+            Self::check_function(&test.function)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks the control flow of a single function.
+    ///
+    pub fn check_function(function: &Function) -> Result<(), ControlFlowError> {
+        Self::check_block(&function.block)?;
+
+        // A function with a return type must return on every path.
+        match &function.output {
+            Some(output) if Termination::of_block(&function.block) != Termination::Always => {
+                Err(ControlFlowError::missing_return(&function.identifier, output))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Checks the statements of a block.
+    ///
+    /// Returns an error if a statement follows a statement that always returns.
+    ///
+    fn check_block(block: &Block) -> Result<(), ControlFlowError> {
+        let mut termination = Termination::Never;
+
+        for statement in block.statements.iter() {
+            if termination == Termination::Always {
+                return Err(ControlFlowError::unreachable_statement(statement.span()));
+            }
+
+            Self::check_statement(statement)?;
+
+            termination = termination.then(Termination::of_statement(statement));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks the blocks nested in a statement.
+    ///
+    fn check_statement(statement: &Statement) -> Result<(), ControlFlowError> {
+        match statement {
+            Statement::Conditional(statement) => {
+                Self::check_block(&statement.block)?;
+
+                match &statement.next {
+                    Some(next) => Self::check_statement(next),
+                    None => Ok(()),
+                }
+            }
+            Statement::Iteration(statement) => Self::check_block(&statement.block),
+            Statement::Block(block) => Self::check_block(block),
+            Statement::Return(_)
+            | Statement::Definition(_)
+            | Statement::Assign(_)
+            | Statement::Console(_)
+            | Statement::Expression(_) => Ok(()),
+        }
+    }
+}
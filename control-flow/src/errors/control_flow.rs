@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Error as FormattedError, Identifier, Span, Type};
+
+use std::path::Path;
+
+/// Errors encountered when checking the control flow of a program.
+#[derive(Debug, Error)]
+pub enum ControlFlowError {
+    #[error("{}", _0)]
+    Error(#[from] FormattedError),
+}
+
+impl ControlFlowError {
+    ///
+    /// Set the filepath for the error stacktrace.
+    ///
+    pub fn set_path(&mut self, path: &Path) {
+        match self {
+            ControlFlowError::Error(error) => error.set_path(path),
+        }
+    }
+
+    ///
+    /// Return a new formatted error with a given message and span information.
+    ///
+    fn new_from_span(message: String, span: &Span) -> Self {
+        ControlFlowError::Error(FormattedError::new_from_span(message, span.to_owned()))
+    }
+
+    ///
+    /// A function with a return type has a path that does not return a value.
+    ///
+    pub fn missing_return(identifier: &Identifier, expected: &Type) -> Self {
+        let message = format!(
+            "Function `{}` must return `{}` on every path but may reach the end of its body.",
+            identifier.name, expected
+        );
+
+        Self::new_from_span(message, &identifier.span)
+    }
+
+    ///
+    /// A statement follows a statement that always returns.
+    ///
+    pub fn unreachable_statement(span: &Span) -> Self {
+        let message = "Unreachable statement. Every path before it has already returned.".to_string();
+
+        Self::new_from_span(message, span)
+    }
+}
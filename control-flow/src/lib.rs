@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A control flow check for a Leo program.
+//!
+//! This module contains the [`ControlFlow`] type, which walks the statements of every function
+//! in a program and reports missing returns and unreachable statements.
+//!
+//! The [`Termination`] of a statement is also used by the compiler to decide when the indicator
+//! of the statements that follow it must be gated.
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod control_flow;
+pub use self::control_flow::*;
+
+pub mod errors;
+pub use self::errors::*;
+
+pub mod termination;
+pub use self::termination::*;
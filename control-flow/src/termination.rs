@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Block, Statement};
+
+/// Describes whether evaluating a statement returns from the enclosing function.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Termination {
+    /// The statement never returns.
+    Never,
+    /// The statement returns on some paths.
+    Sometimes,
+    /// The statement returns on every path.
+    Always,
+}
+
+impl Termination {
+    ///
+    /// Returns the `Termination` of a statement.
+    ///
+    /// A conditional statement always returns only if every branch always returns and it has a
+    /// final `else` branch. A loop never guarantees a return since its body may not be evaluated.
+    ///
+    pub fn of_statement(statement: &Statement) -> Self {
+        match statement {
+            Statement::Return(_) => Termination::Always,
+            Statement::Conditional(conditional) => {
+                let first = Self::of_block(&conditional.block);
+                let next = conditional
+                    .next
+                    .as_ref()
+                    .map(|next| Self::of_statement(next))
+                    .unwrap_or(Termination::Never);
+
+                first.either(next)
+            }
+            Statement::Iteration(iteration) => Self::of_block(&iteration.block).repeated(),
+            Statement::Block(block) => Self::of_block(block),
+            Statement::Definition(_) | Statement::Assign(_) | Statement::Console(_) | Statement::Expression(_) => {
+                Termination::Never
+            }
+        }
+    }
+
+    ///
+    /// Returns the `Termination` of a block of statements evaluated in order.
+    ///
+    pub fn of_block(block: &Block) -> Self {
+        block
+            .statements
+            .iter()
+            .map(Self::of_statement)
+            .fold(Termination::Never, Termination::then)
+    }
+
+    ///
+    /// Returns `true` if the statement may return.
+    ///
+    pub fn may_return(self) -> bool {
+        self != Termination::Never
+    }
+
+    ///
+    /// Returns the `Termination` of evaluating `self` followed by `next`.
+    ///
+    pub fn then(self, next: Self) -> Self {
+        match (self, next) {
+            (Termination::Always, _) | (_, Termination::Always) => Termination::Always,
+            (Termination::Never, Termination::Never) => Termination::Never,
+            _ => Termination::Sometimes,
+        }
+    }
+
+    ///
+    /// Returns the `Termination` of evaluating either `self` or `other`.
+    ///
+    pub fn either(self, other: Self) -> Self {
+        match (self, other) {
+            (Termination::Always, Termination::Always) => Termination::Always,
+            (Termination::Never, Termination::Never) => Termination::Never,
+            _ => Termination::Sometimes,
+        }
+    }
+
+    ///
+    /// Returns the `Termination` of a loop body that may be evaluated zero or more times.
+    ///
+    pub fn repeated(self) -> Self {
+        match self {
+            Termination::Never => Termination::Never,
+            _ => Termination::Sometimes,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Ast, Program};
+use leo_control_flow::ControlFlow;
+use leo_grammar::Grammar;
+
+use std::path::PathBuf;
+
+const TEST_PROGRAM_PATH: &str = "";
+const TEST_PROGRAM_NAME: &str = "test";
+
+/// A helper struct to test a `ControlFlow` check.
+pub struct TestControlFlow {
+    program: Program,
+}
+
+impl TestControlFlow {
+    pub fn new(program_string: &str) -> Self {
+        // Get test file path.
+        let file_path = PathBuf::from(TEST_PROGRAM_PATH);
+
+        // Get parser syntax tree.
+        let ast = Grammar::new(&file_path, program_string).unwrap();
+
+        // Get typed syntax tree.
+        let typed = Ast::new(TEST_PROGRAM_NAME, &ast);
+        let program = typed.into_repr();
+
+        Self { program }
+    }
+
+    pub fn check(self) {
+        ControlFlow::new(&self.program).unwrap();
+    }
+
+    pub fn expect_error(self) {
+        assert!(ControlFlow::new(&self.program).is_err());
+    }
+}
+
+#[test]
+fn test_returns() {
+    let program_string = include_str!("returns.leo");
+
+    TestControlFlow::new(program_string).check();
+}
+
+#[test]
+fn test_missing_return() {
+    let program_string = include_str!("missing_return.leo");
+
+    TestControlFlow::new(program_string).expect_error();
+}
+
+#[test]
+fn test_missing_return_loop() {
+    let program_string = include_str!("missing_return_loop.leo");
+
+    TestControlFlow::new(program_string).expect_error();
+}
+
+#[test]
+fn test_missing_return_circuit() {
+    let program_string = include_str!("missing_return_circuit.leo");
+
+    TestControlFlow::new(program_string).expect_error();
+}
+
+#[test]
+fn test_unreachable() {
+    let program_string = include_str!("unreachable.leo");
+
+    TestControlFlow::new(program_string).expect_error();
+}
+
+#[test]
+fn test_unreachable_branches() {
+    let program_string = include_str!("unreachable_branches.leo");
+
+    TestControlFlow::new(program_string).expect_error();
+}
+
+#[test]
+fn test_unreachable_nested() {
+    let program_string = include_str!("unreachable_nested.leo");
+
+    TestControlFlow::new(program_string).expect_error();
+}
@@ -17,6 +17,7 @@
 use crate::{CoreCircuit, CoreCircuitError, Value};
 
 use leo_ast::{
+    ArrayDimension,
     ArrayDimensions,
     Block,
     CallExpression,
@@ -66,24 +67,27 @@ impl CoreCircuit for Blake2sCircuit {
     fn ast(circuit_name: Identifier, span: Span) -> Circuit {
         Circuit {
             circuit_name,
+            const_parameters: vec![],
             members: vec![CircuitMember::CircuitFunction(Function {
                 identifier: Identifier {
                     name: "hash".to_owned(),
                     span: span.clone(),
                 },
+                const_parameters: vec![],
                 input: vec![
                     FunctionInput::Variable(FunctionInputVariable {
                         identifier: Identifier {
                             name: "seed".to_owned(),
                             span: span.clone(),
                         },
+                        public: false,
                         mutable: false,
                         type_: Type::Array(
                             Box::new(Type::IntegerType(IntegerType::U8)),
-                            ArrayDimensions(vec![PositiveNumber {
+                            ArrayDimensions(vec![ArrayDimension::Number(PositiveNumber {
                                 value: 32usize.to_string(),
                                 span: span.clone(),
-                            }]),
+                            })]),
                         ),
                         span: span.clone(),
                     }),
@@ -92,23 +96,24 @@ impl CoreCircuit for Blake2sCircuit {
                             name: "message".to_owned(),
                             span: span.clone(),
                         },
+                        public: false,
                         mutable: false,
                         type_: Type::Array(
                             Box::new(Type::IntegerType(IntegerType::U8)),
-                            ArrayDimensions(vec![PositiveNumber {
+                            ArrayDimensions(vec![ArrayDimension::Number(PositiveNumber {
                                 value: 32usize.to_string(),
                                 span: span.clone(),
-                            }]),
+                            })]),
                         ),
                         span: span.clone(),
                     }),
                 ],
                 output: Some(Type::Array(
                     Box::new(Type::IntegerType(IntegerType::U8)),
-                    ArrayDimensions(vec![PositiveNumber {
+                    ArrayDimensions(vec![ArrayDimension::Number(PositiveNumber {
                         value: 32usize.to_string(),
                         span: span.clone(),
-                    }]),
+                    })]),
                 )),
                 block: Block {
                     statements: vec![Statement::Return(ReturnStatement {
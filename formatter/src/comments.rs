@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// A comment in a Leo file.
+///
+/// The `COMMENT` rule of the grammar is silent, so comments are collected from the source text
+/// in a separate pass and placed back into the formatted output by their byte offsets.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comment {
+    /// The text of the comment, including its delimiters.
+    pub text: String,
+    /// The byte offset of the first character of the comment.
+    pub start: usize,
+    /// The byte offset after the last character of the comment.
+    pub end: usize,
+}
+
+impl Comment {
+    /// Returns `true` if the comment runs to the end of its line.
+    pub fn is_line_comment(&self) -> bool {
+        self.text.starts_with("//")
+    }
+}
+
+///
+/// Returns the comments of the given source in the order they appear.
+///
+/// String, byte string and character literals are skipped, so comment delimiters inside of them
+/// are not mistaken for comments.
+///
+pub fn collect_comments(source: &str) -> Vec<Comment> {
+    let bytes = source.as_bytes();
+    let mut comments = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            quote @ b'"' | quote @ b'\'' => {
+                index += 1;
+                while index < bytes.len() && bytes[index] != quote {
+                    if bytes[index] == b'\\' {
+                        index += 1;
+                    }
+                    index += 1;
+                }
+                index += 1;
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'/') => {
+                let start = index;
+                while index < bytes.len() && bytes[index] != b'\n' && bytes[index] != b'\r' {
+                    index += 1;
+                }
+                comments.push(Comment {
+                    text: source[start..index].trim_end().to_string(),
+                    start,
+                    end: index,
+                });
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'*') => {
+                let start = index;
+                index = match source[index + 2..].find("*/") {
+                    Some(offset) => index + 2 + offset + 2,
+                    None => bytes.len(),
+                };
+                comments.push(Comment {
+                    text: source[start..index].to_string(),
+                    start,
+                    end: index,
+                });
+            }
+            _ => index += 1,
+        }
+    }
+
+    comments
+}
@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A source formatter for Leo programs.
+//!
+//! This module contains the [`format`] function, which prints a Leo file in the canonical style
+//! from its grammar tree. The grammar does not keep comments, so they are collected from the
+//! source text by [`collect_comments`] and placed back by the [`Printer`].
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod comments;
+pub use self::comments::*;
+
+pub mod errors;
+pub use self::errors::*;
+
+pub mod printer;
+pub use self::printer::*;
+
+use leo_grammar::Grammar;
+
+use std::path::Path;
+
+/// Returns the given Leo file in the canonical style.
+pub fn format(file_path: &Path, source: &str) -> Result<String, FormatterError> {
+    let grammar = Grammar::new(file_path, source).map_err(|mut error| {
+        // Log the syntax error with the path of the file
+        error.set_path(file_path);
+        error
+    })?;
+    let printer = Printer::new(source, collect_comments(source));
+
+    Ok(printer.print_file(grammar.as_repr()))
+}
@@ -0,0 +1,906 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Comment;
+use leo_grammar::{
+    access::{Access, AssigneeAccess},
+    annotations::{Annotation, AnnotationName},
+    circuits::{Circuit, CircuitMember},
+    common::{
+        Assignee,
+        Declare,
+        KeywordOrIdentifier,
+        Range,
+        RangeOrExpression,
+        SelfKeywordOrIdentifier,
+        SpreadOrExpression,
+        Variables,
+    },
+    console::{ConsoleFunction, ConsoleFunctionCall, FormattedString},
+    definitions::Definition,
+    enums::EnumDefinition,
+    expressions::{CircuitName, Expression, MatchExpression, MatchPattern},
+    files::File,
+    functions::{ConstParameter, Function, Input},
+    imports::{Package, PackageAccess},
+    operations::{AssignOperation, BinaryOperation, UnaryOperation},
+    statements::{Block, ConditionalNestedOrEndStatement, ConditionalStatement, Statement},
+    types::{ArrayDimension, ArrayDimensions, DataType, IntegerType, Type},
+    values::{GroupRepresentation, Value},
+};
+
+use pest::Span;
+
+/// The indentation of one level of nesting.
+const INDENT: &str = "    ";
+
+///
+/// Prints a grammar tree in the canonical Leo style.
+///
+/// Every construct is printed on a single line, except for blocks, circuits, enums and match
+/// expressions, which put each of their items on a line of its own. At most one blank line is
+/// kept between items.
+///
+/// Comments are placed back at the boundaries between items. A comment that starts on the same
+/// line as the code before it stays at the end of that line, and any other comment is put on
+/// a line of its own before the next item. Comments inside of a single line construct are moved
+/// to the end of its line.
+///
+pub struct Printer<'a> {
+    source: &'a str,
+    comments: Vec<Comment>,
+    next_comment: usize,
+    output: String,
+    line: String,
+    indent: usize,
+    /// The last line of the output ends with a comment.
+    commented: bool,
+    /// The last line of the output opens an item list, or nothing was printed yet.
+    opened: bool,
+}
+
+impl<'a> Printer<'a> {
+    pub fn new(source: &'a str, comments: Vec<Comment>) -> Self {
+        Self {
+            source,
+            comments,
+            next_comment: 0,
+            output: String::new(),
+            line: String::new(),
+            indent: 0,
+            commented: false,
+            opened: true,
+        }
+    }
+
+    /// Prints the given file and returns the formatted source.
+    pub fn print_file(mut self, file: &File) -> String {
+        for definition in file.definitions.iter() {
+            self.definition(definition);
+        }
+
+        self.comments_before(usize::MAX);
+
+        self.output
+    }
+
+    fn write(&mut self, text: &str) {
+        self.line.push_str(text);
+    }
+
+    fn end_line(&mut self) {
+        for _ in 0..self.indent {
+            self.output.push_str(INDENT);
+        }
+        self.output.push_str(self.line.trim_end());
+        self.output.push('\n');
+
+        self.line.clear();
+        self.commented = false;
+        self.opened = false;
+    }
+
+    /// Ends the current line with an opening brace and indents the items that follow it.
+    fn open(&mut self) {
+        self.write("{");
+        self.end_line();
+        self.opened = true;
+        self.indent += 1;
+    }
+
+    /// Prints the comments before the given closing brace and unindents it.
+    fn close(&mut self, position: usize) {
+        self.comments_before(position);
+        self.indent -= 1;
+        self.write("}");
+    }
+
+    /// Returns `true` if there is a comment before the given position that was not printed yet.
+    fn has_comment_before(&self, position: usize) -> bool {
+        match self.comments.get(self.next_comment) {
+            Some(comment) => comment.start < position,
+            None => false,
+        }
+    }
+
+    /// Prints the comments and the blank line before an item that starts at the given position.
+    fn item(&mut self, position: usize) {
+        self.comments_before(position);
+
+        if !self.opened && self.blank_line_before(position) {
+            self.output.push('\n');
+        }
+    }
+
+    /// Prints the comments that start before the given position.
+    fn comments_before(&mut self, position: usize) {
+        while self.has_comment_before(position) {
+            let comment = self.comments[self.next_comment].clone();
+            self.next_comment += 1;
+
+            if !self.commented && !self.output.is_empty() && self.follows_code(comment.start) {
+                // Keep the comment at the end of the line it started on
+                self.output.pop();
+                self.output.push(' ');
+                self.output.push_str(&comment.text);
+                self.output.push('\n');
+            } else {
+                if !self.opened && self.blank_line_before(comment.start) {
+                    self.output.push('\n');
+                }
+                self.write(&comment.text);
+                self.end_line();
+            }
+
+            self.commented = true;
+        }
+    }
+
+    /// Returns `true` if there is code or a comment before the given position on its line.
+    fn follows_code(&self, position: usize) -> bool {
+        let before = self.source[..position].trim_end_matches(&[' ', '\t'][..]);
+
+        !before.is_empty() && !before.ends_with('\n') && !before.ends_with('\r')
+    }
+
+    /// Returns `true` if the given position is preceded by an empty line.
+    fn blank_line_before(&self, position: usize) -> bool {
+        let position = position.min(self.source.len());
+        let before = self.source[..position].trim_end_matches(|c: char| c.is_ascii_whitespace());
+
+        self.source[before.len()..position].matches('\n').count() > 1
+    }
+
+    ///
+    /// Returns the position after the last token of the given span.
+    ///
+    /// Rules that end with line breaks include the comments between them in their span,
+    /// so trailing whitespace and comments are skipped.
+    ///
+    fn content_end(&self, span: &Span) -> usize {
+        let mut end = span.end();
+
+        loop {
+            end = self.source[..end].trim_end_matches(|c: char| c.is_ascii_whitespace()).len();
+
+            match self
+                .comments
+                .iter()
+                .find(|comment| comment.end == end && comment.start >= span.start())
+            {
+                Some(comment) => end = comment.start,
+                None => return end,
+            }
+        }
+    }
+
+    // Definitions
+
+    fn definition(&mut self, definition: &Definition) {
+        self.item(definition_span(definition).start());
+        self.definition_body(definition);
+    }
+
+    fn definition_body(&mut self, definition: &Definition) {
+        match definition {
+            Definition::Annotated(annotated) => {
+                self.annotation(&annotated.annotation);
+                self.end_line();
+                self.comments_before(definition_span(&annotated.definition).start());
+                self.definition_body(&annotated.definition);
+            }
+            Definition::Import(import) => {
+                self.write("import ");
+                self.package(&import.package);
+                self.write(";");
+                self.end_line();
+            }
+            Definition::Circuit(circuit) => self.circuit(circuit),
+            Definition::Enum(enum_) => self.enum_definition(enum_),
+            Definition::Const(const_) => {
+                self.write(&format!("const {}: ", const_.identifier.value));
+                self.type_(&const_.type_);
+                self.write(" = ");
+                self.expression(&const_.expression);
+                self.write(";");
+                self.end_line();
+            }
+            Definition::Function(function) => self.function(function),
+            Definition::TestFunction(test_function) => {
+                self.write("test ");
+                self.function(&test_function.function);
+            }
+        }
+    }
+
+    fn annotation(&mut self, annotation: &Annotation) {
+        let name = match annotation.name {
+            AnnotationName::Context(_) => "context",
+        };
+        let arguments = annotation
+            .arguments
+            .arguments
+            .iter()
+            .map(|argument| argument.value.as_str())
+            .collect::<Vec<_>>();
+
+        self.write(&format!("@{}({})", name, arguments.join(", ")));
+    }
+
+    fn package(&mut self, package: &Package) {
+        self.write(&package.name.value);
+        self.write(".");
+        self.package_access(&package.access);
+    }
+
+    fn package_access(&mut self, access: &PackageAccess) {
+        match access {
+            PackageAccess::Star(_) => self.write("*"),
+            PackageAccess::SubPackage(package) => self.package(package),
+            PackageAccess::Symbol(symbol) => {
+                self.write(&symbol.value.value);
+                if let Some(alias) = &symbol.alias {
+                    self.write(&format!(" as {}", alias.value));
+                }
+            }
+            PackageAccess::Multiple(accesses) => {
+                self.write("(");
+                for (i, access) in accesses.iter().enumerate() {
+                    if i > 0 {
+                        self.write(", ");
+                    }
+                    self.package_access(access);
+                }
+                self.write(")");
+            }
+        }
+    }
+
+    fn circuit(&mut self, circuit: &Circuit) {
+        self.write(&format!("circuit {}", circuit.identifier.value));
+        self.const_parameters(&circuit.const_parameters);
+        self.write(" ");
+
+        let close = self.content_end(&circuit.span) - 1;
+        if circuit.members.is_empty() && !self.has_comment_before(close) {
+            self.write("{}");
+            self.end_line();
+            return;
+        }
+
+        self.open();
+        for member in circuit.members.iter() {
+            match member {
+                CircuitMember::CircuitVariableDefinition(variable) => {
+                    self.item(variable.span.start());
+                    self.write(&format!("{}: ", variable.identifier.value));
+                    self.type_(&variable.type_);
+                    self.write(",");
+                    self.end_line();
+                }
+                CircuitMember::CircuitFunction(function) => {
+                    self.item(function.span.start());
+                    self.function(function);
+                }
+            }
+        }
+        self.close(close);
+        self.end_line();
+    }
+
+    fn enum_definition(&mut self, enum_: &EnumDefinition) {
+        self.write(&format!("enum {} ", enum_.identifier.value));
+
+        let close = self.content_end(&enum_.span) - 1;
+        if enum_.variants.is_empty() && !self.has_comment_before(close) {
+            self.write("{}");
+            self.end_line();
+            return;
+        }
+
+        self.open();
+        for variant in enum_.variants.iter() {
+            self.item(variant.span.start());
+            self.write(&variant.identifier.value);
+            if let Some(payload) = &variant.payload {
+                self.write("(");
+                self.type_(payload);
+                self.write(")");
+            }
+            self.write(",");
+            self.end_line();
+        }
+        self.close(close);
+        self.end_line();
+    }
+
+    fn function(&mut self, function: &Function) {
+        self.write(&format!("function {}", function.identifier.value));
+        self.const_parameters(&function.const_parameters);
+
+        self.write("(");
+        for (i, parameter) in function.parameters.iter().enumerate() {
+            if i > 0 {
+                self.write(", ");
+            }
+            match parameter {
+                Input::InputKeyword(keyword) => self.write(&keyword.keyword),
+                Input::SelfKeyword(keyword) => self.write(&keyword.keyword),
+                Input::MutSelfKeyword(keyword) => self.write(&format!("mut {}", keyword.self_keyword.keyword)),
+                Input::FunctionInput(input) => {
+                    if input.public.is_some() {
+                        self.write("public ");
+                    }
+                    if input.mutable.is_some() {
+                        self.write("mut ");
+                    }
+                    self.write(&format!("{}: ", input.identifier.value));
+                    self.type_(&input.type_);
+                }
+            }
+        }
+        self.write(")");
+
+        if let Some(returns) = &function.returns {
+            self.write(" -> ");
+            self.type_(returns);
+        }
+
+        self.write(" ");
+        self.block(&function.block);
+        self.end_line();
+    }
+
+    fn const_parameters(&mut self, parameters: &[ConstParameter]) {
+        if parameters.is_empty() {
+            return;
+        }
+
+        let parameters = parameters
+            .iter()
+            .map(|parameter| format!("const {}: u32", parameter.identifier.value))
+            .collect::<Vec<_>>();
+
+        self.write(&format!("<{}>", parameters.join(", ")));
+    }
+
+    // Statements
+
+    fn block(&mut self, block: &Block) {
+        let close = block.span.end() - 1;
+        if block.statements.is_empty() && !self.has_comment_before(close) {
+            self.write("{}");
+            return;
+        }
+
+        self.open();
+        for statement in block.statements.iter() {
+            self.statement(statement);
+        }
+        self.close(close);
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        self.item(statement_span(statement).start());
+
+        match statement {
+            Statement::Return(return_) => {
+                self.write("return ");
+                self.expression(&return_.expression);
+            }
+            Statement::Definition(definition) => {
+                match definition.declare {
+                    Declare::Const(_) => self.write("const "),
+                    Declare::Let(_) => self.write("let "),
+                }
+                self.variables(&definition.variables);
+                self.write(" = ");
+                self.expression(&definition.expression);
+                self.write(";");
+            }
+            Statement::Assign(assign) => {
+                self.assignee(&assign.assignee);
+                self.write(&format!(" {} ", assign_operator(&assign.assign)));
+                self.expression(&assign.expression);
+                self.write(";");
+            }
+            Statement::Conditional(conditional) => self.conditional(conditional),
+            Statement::Iteration(for_) => {
+                self.write(&format!("for {} in ", for_.index.value));
+                self.expression(&for_.start);
+                self.write("..");
+                self.expression(&for_.stop);
+                if let Some(condition) = &for_.condition {
+                    self.write(" while ");
+                    self.expression(condition);
+                }
+                self.write(" ");
+                self.block(&for_.block);
+            }
+            Statement::Console(console) => self.console(console),
+            Statement::Expression(expression) => {
+                self.expression(&expression.expression);
+                self.write(";");
+            }
+            Statement::Block(block) => self.block(block),
+        }
+
+        self.end_line();
+    }
+
+    fn conditional(&mut self, conditional: &ConditionalStatement) {
+        self.write("if ");
+        self.expression(&conditional.condition);
+        self.write(" ");
+        self.block(&conditional.block);
+
+        match &conditional.next {
+            Some(ConditionalNestedOrEndStatement::Nested(nested)) => {
+                self.write(" else ");
+                self.conditional(nested);
+            }
+            Some(ConditionalNestedOrEndStatement::End(block)) => {
+                self.write(" else ");
+                self.block(block);
+            }
+            None => {}
+        }
+    }
+
+    fn variables(&mut self, variables: &Variables) {
+        let names = variables
+            .names
+            .iter()
+            .map(|name| match name.mutable {
+                Some(_) => format!("mut {}", name.identifier.value),
+                None => name.identifier.value.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        if names.len() == 1 {
+            self.write(&names[0]);
+        } else {
+            self.write(&format!("({})", names.join(", ")));
+        }
+
+        if let Some(type_) = &variables.type_ {
+            self.write(": ");
+            self.type_(type_);
+        }
+    }
+
+    fn assignee(&mut self, assignee: &Assignee) {
+        self.self_keyword_or_identifier(&assignee.name);
+
+        for access in assignee.accesses.iter() {
+            match access {
+                AssigneeAccess::Array(array) => {
+                    self.write("[");
+                    self.range_or_expression(&array.expression);
+                    self.write("]");
+                }
+                AssigneeAccess::Tuple(tuple) => self.write(&format!(".{}", tuple.number.value)),
+                AssigneeAccess::Member(member) => self.write(&format!(".{}", member.identifier.value)),
+            }
+        }
+    }
+
+    fn console(&mut self, console: &ConsoleFunctionCall) {
+        self.write("console.");
+
+        match &console.function {
+            ConsoleFunction::Assert(assert) => {
+                self.write("assert(");
+                self.expression(&assert.expression);
+                self.write(")");
+            }
+            ConsoleFunction::Debug(debug) => self.formatted_string("debug", &debug.string),
+            ConsoleFunction::Error(error) => self.formatted_string("error", &error.string),
+            ConsoleFunction::Log(log) => self.formatted_string("log", &log.string),
+        }
+
+        self.write(";");
+    }
+
+    fn formatted_string(&mut self, function: &str, string: &FormattedString) {
+        // The string literal runs up to the first quote after the opening one
+        let literal = &string.string[..string.string[1..].find('"').unwrap() + 2];
+
+        self.write(&format!("{}({}", function, literal));
+        for parameter in string.parameters.iter() {
+            self.write(", ");
+            self.expression(parameter);
+        }
+        self.write(")");
+    }
+
+    // Expressions
+
+    fn expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Value(value) => self.value(value),
+            Expression::Identifier(identifier) => self.write(&identifier.value),
+            Expression::Unary(unary) => {
+                let (operator, parenthesize) = match unary.operation {
+                    UnaryOperation::Negate(_) => ("-", matches!(unary.expression, Expression::Value(_))),
+                    UnaryOperation::Not(_) => ("!", false),
+                };
+
+                self.write(operator);
+                self.operand(&unary.expression, parenthesize || !is_term(&unary.expression));
+            }
+            Expression::Binary(binary) => {
+                let precedence = precedence(&binary.operation);
+
+                self.operand(&binary.left, binary_operand(&binary.left, precedence));
+                self.write(&format!(" {} ", binary_operator(&binary.operation)));
+                self.operand(&binary.right, binary_operand(&binary.right, precedence + 1));
+            }
+            Expression::Cast(cast) => {
+                let parenthesize = matches!(cast.expression, Expression::Binary(_) | Expression::Ternary(_));
+
+                self.operand(&cast.expression, parenthesize);
+                self.write(" as ");
+                self.data_type(&cast.type_);
+            }
+            Expression::Ternary(ternary) => {
+                self.write("if ");
+                self.operand(&ternary.first, matches!(ternary.first, Expression::Ternary(_)));
+                self.write(" ? ");
+                self.operand(&ternary.second, matches!(ternary.second, Expression::Ternary(_)));
+                self.write(" : ");
+                self.expression(&ternary.third);
+            }
+            Expression::Match(match_) => self.match_expression(match_),
+            Expression::ArrayInline(array) => {
+                self.write("[");
+                for (i, element) in array.expressions.iter().enumerate() {
+                    if i > 0 {
+                        self.write(", ");
+                    }
+                    match element {
+                        SpreadOrExpression::Spread(spread) => {
+                            self.write("...");
+                            self.expression(&spread.expression);
+                        }
+                        SpreadOrExpression::Expression(expression) => self.expression(expression),
+                    }
+                }
+                self.write("]");
+            }
+            Expression::ArrayInitializer(array) => {
+                self.write("[");
+                self.expression(&array.expression);
+                self.write("; ");
+                self.array_dimensions(&array.dimensions);
+                self.write("]");
+            }
+            Expression::CircuitInline(circuit) => {
+                match &circuit.name {
+                    CircuitName::SelfType(self_type) => self.write(&self_type.keyword),
+                    CircuitName::Identifier(identifier) => self.write(&identifier.value),
+                }
+
+                if circuit.members.is_empty() {
+                    self.write(" {}");
+                    return;
+                }
+
+                self.write(" { ");
+                for (i, member) in circuit.members.iter().enumerate() {
+                    if i > 0 {
+                        self.write(", ");
+                    }
+                    self.write(&format!("{}: ", member.identifier.value));
+                    self.expression(&member.expression);
+                }
+                self.write(" }");
+            }
+            Expression::Postfix(postfix) => {
+                match &postfix.name {
+                    KeywordOrIdentifier::SelfType(self_type) => self.write(&self_type.keyword),
+                    KeywordOrIdentifier::Input(keyword) => self.write(&keyword.keyword),
+                    KeywordOrIdentifier::SelfKeywordOrIdentifier(name) => self.self_keyword_or_identifier(name),
+                }
+
+                for access in postfix.accesses.iter() {
+                    match access {
+                        Access::Array(array) => {
+                            self.write("[");
+                            self.range_or_expression(&array.expression);
+                            self.write("]");
+                        }
+                        Access::Tuple(tuple) => self.write(&format!(".{}", tuple.number.value)),
+                        Access::Call(call) => {
+                            self.write("(");
+                            self.expressions(&call.expressions);
+                            self.write(")");
+                        }
+                        Access::Object(member) => self.write(&format!(".{}", member.identifier.value)),
+                        Access::StaticObject(member) => self.write(&format!("::{}", member.identifier.value)),
+                    }
+                }
+            }
+            Expression::Tuple(tuple) => {
+                self.write("(");
+                self.expressions(&tuple.expressions);
+                self.write(")");
+            }
+        }
+    }
+
+    /// Prints an operand of an operator, in parentheses if it would not parse back on its own.
+    fn operand(&mut self, expression: &Expression, parenthesize: bool) {
+        if parenthesize {
+            self.write("(");
+            self.expression(expression);
+            self.write(")");
+        } else {
+            self.expression(expression);
+        }
+    }
+
+    fn expressions(&mut self, expressions: &[Expression]) {
+        for (i, expression) in expressions.iter().enumerate() {
+            if i > 0 {
+                self.write(", ");
+            }
+            self.expression(expression);
+        }
+    }
+
+    fn match_expression(&mut self, match_: &MatchExpression) {
+        self.write("match ");
+        self.expression(&match_.expression);
+        self.write(" ");
+        self.open();
+
+        for arm in match_.arms.iter() {
+            self.item(arm.span.start());
+
+            match &arm.pattern {
+                MatchPattern::Variant(variant) => {
+                    self.write(&format!("{}::{}", variant.enum_name.value, variant.variant.value));
+                    if let Some(binding) = &variant.binding {
+                        self.write(&format!("({})", binding.value));
+                    }
+                }
+                MatchPattern::Wildcard(_) => self.write("_"),
+            }
+
+            self.write(" => ");
+            self.expression(&arm.expression);
+            self.write(",");
+            self.end_line();
+        }
+
+        self.close(match_.span.end() - 1);
+    }
+
+    fn range_or_expression(&mut self, range_or_expression: &RangeOrExpression) {
+        match range_or_expression {
+            RangeOrExpression::Range(Range { from, to, .. }) => {
+                if let Some(from) = from {
+                    self.expression(from);
+                }
+                self.write("..");
+                if let Some(to) = to {
+                    self.expression(to);
+                }
+            }
+            RangeOrExpression::Expression(expression) => self.expression(expression),
+        }
+    }
+
+    fn self_keyword_or_identifier(&mut self, name: &SelfKeywordOrIdentifier) {
+        match name {
+            SelfKeywordOrIdentifier::SelfKeyword(keyword) => self.write(&keyword.keyword),
+            SelfKeywordOrIdentifier::Identifier(identifier) => self.write(&identifier.value),
+        }
+    }
+
+    fn value(&mut self, value: &Value) {
+        match value {
+            Value::Group(group) => match &group.value {
+                GroupRepresentation::Single(number) => self.write(&format!("{}group", number.span().as_str())),
+                GroupRepresentation::Tuple(tuple) => self.write(&format!(
+                    "({}, {})group",
+                    tuple.x.span().as_str(),
+                    tuple.y.span().as_str()
+                )),
+            },
+            value => self.write(value.span().as_str()),
+        }
+    }
+
+    // Types
+
+    fn type_(&mut self, type_: &Type) {
+        match type_ {
+            Type::Basic(data_type) => self.data_type(data_type),
+            Type::Array(array) => {
+                self.write("[");
+                self.type_(&array.type_);
+                self.write("; ");
+                self.array_dimensions(&array.dimensions);
+                self.write("]");
+            }
+            Type::Tuple(tuple) => {
+                self.write("(");
+                for (i, type_) in tuple.types.iter().enumerate() {
+                    if i > 0 {
+                        self.write(", ");
+                    }
+                    self.type_(type_);
+                }
+                self.write(")");
+            }
+            Type::Circuit(circuit) => self.write(&circuit.identifier.value),
+            Type::SelfType(self_type) => self.write(&self_type.keyword),
+        }
+    }
+
+    fn data_type(&mut self, data_type: &DataType) {
+        match data_type {
+            DataType::Address(_) => self.write("address"),
+            DataType::Boolean(_) => self.write("bool"),
+            DataType::Char(_) => self.write("char"),
+            DataType::Field(_) => self.write("field"),
+            DataType::Group(_) => self.write("group"),
+            DataType::Integer(IntegerType::Signed(signed)) => self.write(&signed.to_string()),
+            DataType::Integer(IntegerType::Unsigned(unsigned)) => self.write(&unsigned.to_string()),
+        }
+    }
+
+    fn array_dimensions(&mut self, dimensions: &ArrayDimensions) {
+        let dimension = |dimension: &ArrayDimension| match dimension {
+            ArrayDimension::Number(number) => number.value.clone(),
+            ArrayDimension::Const(identifier) => identifier.value.clone(),
+        };
+
+        match dimensions {
+            ArrayDimensions::Single(single) => self.write(&dimension(&single.dimension)),
+            ArrayDimensions::Multiple(multiple) => {
+                let dimensions = multiple.dimensions.iter().map(dimension).collect::<Vec<_>>();
+                self.write(&format!("({})", dimensions.join(", ")));
+            }
+        }
+    }
+}
+
+fn definition_span<'a, 'ast>(definition: &'a Definition<'ast>) -> &'a Span<'ast> {
+    match definition {
+        Definition::Annotated(annotated) => &annotated.span,
+        Definition::Import(import) => &import.span,
+        Definition::Circuit(circuit) => &circuit.span,
+        Definition::Enum(enum_) => &enum_.span,
+        Definition::Const(const_) => &const_.span,
+        Definition::Function(function) => &function.span,
+        Definition::TestFunction(test_function) => &test_function.span,
+    }
+}
+
+fn statement_span<'a, 'ast>(statement: &'a Statement<'ast>) -> &'a Span<'ast> {
+    match statement {
+        Statement::Return(return_) => &return_.span,
+        Statement::Definition(definition) => &definition.span,
+        Statement::Assign(assign) => &assign.span,
+        Statement::Conditional(conditional) => &conditional.span,
+        Statement::Iteration(for_) => &for_.span,
+        Statement::Console(console) => &console.span,
+        Statement::Expression(expression) => &expression.span,
+        Statement::Block(block) => &block.span,
+    }
+}
+
+/// Returns `true` if the expression parses as the operand of a unary operator.
+fn is_term(expression: &Expression) -> bool {
+    !matches!(
+        expression,
+        Expression::Binary(_) | Expression::Cast(_) | Expression::Ternary(_)
+    )
+}
+
+/// Returns `true` if the operand of a binary operator needs parentheses.
+fn binary_operand(expression: &Expression, precedence: u8) -> bool {
+    match expression {
+        Expression::Binary(binary) => self::precedence(&binary.operation) < precedence,
+        Expression::Ternary(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns the binding strength of a binary operator, see the precedence climber of the grammar.
+fn precedence(operation: &BinaryOperation) -> u8 {
+    match operation {
+        BinaryOperation::Or => 1,
+        BinaryOperation::And => 2,
+        BinaryOperation::Eq
+        | BinaryOperation::Ne
+        | BinaryOperation::Ge
+        | BinaryOperation::Gt
+        | BinaryOperation::Le
+        | BinaryOperation::Lt => 3,
+        BinaryOperation::BitOr => 4,
+        BinaryOperation::BitXor => 5,
+        BinaryOperation::BitAnd => 6,
+        BinaryOperation::Shl | BinaryOperation::Shr => 7,
+        BinaryOperation::Add | BinaryOperation::Sub => 8,
+        BinaryOperation::Mul | BinaryOperation::Div | BinaryOperation::Rem => 9,
+        BinaryOperation::Pow => 10,
+    }
+}
+
+fn binary_operator(operation: &BinaryOperation) -> &'static str {
+    match operation {
+        BinaryOperation::Or => "||",
+        BinaryOperation::And => "&&",
+        BinaryOperation::Eq => "==",
+        BinaryOperation::Ne => "!=",
+        BinaryOperation::Ge => ">=",
+        BinaryOperation::Gt => ">",
+        BinaryOperation::Le => "<=",
+        BinaryOperation::Lt => "<",
+        BinaryOperation::Add => "+",
+        BinaryOperation::Sub => "-",
+        BinaryOperation::Mul => "*",
+        BinaryOperation::Div => "/",
+        BinaryOperation::Rem => "%",
+        BinaryOperation::Pow => "**",
+        BinaryOperation::BitOr => "|",
+        BinaryOperation::BitXor => "^",
+        BinaryOperation::BitAnd => "&",
+        BinaryOperation::Shl => "<<",
+        BinaryOperation::Shr => ">>",
+    }
+}
+
+fn assign_operator(operation: &AssignOperation) -> &'static str {
+    match operation {
+        AssignOperation::Assign(_) => "=",
+        AssignOperation::AddAssign(_) => "+=",
+        AssignOperation::SubAssign(_) => "-=",
+        AssignOperation::MulAssign(_) => "*=",
+        AssignOperation::DivAssign(_) => "/=",
+        AssignOperation::RemAssign(_) => "%=",
+        AssignOperation::PowAssign(_) => "**=",
+        AssignOperation::BitAndAssign(_) => "&=",
+        AssignOperation::BitOrAssign(_) => "|=",
+        AssignOperation::BitXorAssign(_) => "^=",
+        AssignOperation::ShlAssign(_) => "<<=",
+        AssignOperation::ShrAssign(_) => ">>=",
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::Ast;
+use leo_formatter::{collect_comments, format};
+use leo_grammar::Grammar;
+
+use serde_json::Value;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const TEST_PROGRAM_NAME: &str = "test";
+
+/// Returns the typed syntax tree of the given program without its spans.
+fn typed_tree(file_path: &Path, program_string: &str) -> Value {
+    let grammar = Grammar::new(file_path, program_string).unwrap();
+    let json = Ast::new(TEST_PROGRAM_NAME, &grammar).to_json_string().unwrap();
+
+    strip_spans(serde_json::from_str(&json).unwrap())
+}
+
+/// Returns the name of an identifier, which is serialized with its span.
+fn strip_identifier(string: String) -> String {
+    match serde_json::from_str::<Value>(&string) {
+        Ok(Value::Object(identifier)) if identifier.contains_key("span") => identifier["name"].to_string(),
+        _ => string,
+    }
+}
+
+fn strip_spans(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let is_span = ["text", "line", "start", "end"].iter().all(|key| object.contains_key(*key));
+            if is_span && object.len() == 4 {
+                return Value::Null;
+            }
+
+            // The string of a console function includes the whitespace between its parameters
+            let is_formatted_string = object.contains_key("containers");
+
+            Value::Object(
+                object
+                    .into_iter()
+                    .filter(|(key, _)| !(is_formatted_string && key == "string"))
+                    .map(|(key, value)| (strip_identifier(key), strip_spans(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(array) => Value::Array(array.into_iter().map(strip_spans).collect()),
+        Value::String(string) => Value::String(strip_identifier(string)),
+        value => value,
+    }
+}
+
+fn comment_texts(program_string: &str) -> Vec<String> {
+    collect_comments(program_string)
+        .into_iter()
+        .map(|comment| comment.text)
+        .collect()
+}
+
+fn leo_files(directory: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            leo_files(&path, files);
+        } else if path.extension().and_then(|extension| extension.to_str()) == Some("leo") {
+            files.push(path);
+        }
+    }
+}
+
+#[test]
+fn test_format() {
+    let file_path = PathBuf::from("unformatted.leo");
+    let program_string = include_str!("unformatted.leo");
+    let expected = include_str!("formatted.leo");
+
+    assert_eq!(format(&file_path, program_string).unwrap(), expected);
+}
+
+#[test]
+fn test_format_formatted() {
+    let file_path = PathBuf::from("formatted.leo");
+    let program_string = include_str!("formatted.leo");
+
+    assert_eq!(format(&file_path, program_string).unwrap(), program_string);
+}
+
+#[test]
+fn test_syntax_error() {
+    let file_path = PathBuf::from("syntax_error.leo");
+
+    assert!(format(&file_path, "function main() {\n    let a = ;\n}\n").is_err());
+}
+
+#[test]
+fn test_compiler_tests() {
+    let mut files = Vec::new();
+    leo_files(&Path::new(env!("CARGO_MANIFEST_DIR")).join("../compiler/tests"), &mut files);
+    files.sort();
+
+    let mut formatted_files = 0;
+
+    for file_path in files.iter() {
+        let program_string = fs::read_to_string(file_path).unwrap();
+
+        // Files of the syntax tests do not parse on purpose
+        if Grammar::new(file_path, &program_string).is_err() {
+            continue;
+        }
+
+        let formatted = format(file_path, &program_string)
+            .unwrap_or_else(|error| panic!("failed to format {:?}: {}", file_path, error));
+
+        assert_eq!(
+            typed_tree(file_path, &program_string),
+            typed_tree(file_path, &formatted),
+            "formatting changed the program {:?}:\n{}",
+            file_path,
+            formatted
+        );
+        assert_eq!(
+            comment_texts(&program_string),
+            comment_texts(&formatted),
+            "formatting lost a comment of {:?}",
+            file_path
+        );
+        assert_eq!(
+            format(file_path, &formatted).unwrap(),
+            formatted,
+            "formatting is not idempotent on {:?}",
+            file_path
+        );
+
+        formatted_files += 1;
+    }
+
+    assert!(formatted_files > 500);
+}
@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        r1cs::{ConstraintSystem, LinearCombination},
+        utilities::{
+            alloc::AllocGadget,
+            boolean::Boolean,
+            uint::{UInt, UInt128, UInt16, UInt32, UInt64, UInt8},
+        },
+    },
+};
+
+/// Computes the quotient and the remainder of a division at once.
+///
+/// Instead of long division, the quotient `q` and remainder `r` of `a / b` are witnessed
+/// and constrained by `a == q * b + r` and `r < b`.
+pub trait DivRem<F: Field, Rhs = Self>
+where
+    Self: std::marker::Sized,
+{
+    type ErrorType;
+
+    fn div_rem<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<(Self, Self), Self::ErrorType>;
+}
+
+/// Returns the unsigned value of little-endian bits if every bit has a known value.
+fn bits_value(bits: &[Boolean]) -> Option<u128> {
+    bits.iter()
+        .enumerate()
+        .try_fold(0u128, |value, (i, bit)| Some(value | ((bit.get_value()? as u128) << i)))
+}
+
+/// Returns the linear combination of little-endian bits.
+fn bits_lc<F: Field, CS: ConstraintSystem<F>>(bits: &[Boolean]) -> LinearCombination<F> {
+    let mut coeff = F::one();
+    let mut lc = LinearCombination::zero();
+
+    for bit in bits {
+        lc = lc + &bit.lc(CS::one(), coeff);
+        coeff.double_in_place();
+    }
+
+    lc
+}
+
+/// Allocates `size` little-endian bits holding the given value.
+fn alloc_bits<F: Field, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    value: Option<u128>,
+    size: usize,
+) -> Result<Vec<Boolean>, SynthesisError> {
+    (0..size)
+        .map(|i| {
+            Boolean::alloc(cs.ns(|| format!("bit {}", i)), || {
+                value
+                    .map(|value| (value >> i) & 1 == 1)
+                    .ok_or(SynthesisError::AssignmentMissing)
+            })
+        })
+        .collect()
+}
+
+/// Allocates the product of two unsigned values and returns it as a linear combination.
+fn enforce_product<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    a: &[Boolean],
+    b: &[Boolean],
+) -> Result<LinearCombination<F>, SynthesisError> {
+    let product = cs.alloc(
+        || "product",
+        || match (bits_value(a), bits_value(b)) {
+            (Some(a), Some(b)) => Ok(F::from(a) * &F::from(b)),
+            _ => Err(SynthesisError::AssignmentMissing),
+        },
+    )?;
+
+    cs.enforce(
+        || "enforce product",
+        |lc| lc + &bits_lc::<F, CS>(a),
+        |lc| lc + &bits_lc::<F, CS>(b),
+        |lc| lc + product,
+    );
+
+    Ok(LinearCombination::zero() + product)
+}
+
+impl<F: PrimeField> DivRem<F> for Vec<Boolean> {
+    type ErrorType = SynthesisError;
+
+    fn div_rem<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<(Self, Self), Self::ErrorType> {
+        let size = self.len();
+
+        let (quotient_value, remainder_value) = match (bits_value(self), bits_value(other)) {
+            (Some(_), Some(0)) => return Err(SynthesisError::DivisionByZero),
+            (Some(a), Some(b)) => (Some(a / b), Some(a % b)),
+            _ => (None, None),
+        };
+
+        // Constant operands produce constant results.
+        let is_constant = self
+            .iter()
+            .chain(other.iter())
+            .all(|bit| matches!(bit, Boolean::Constant(_)));
+
+        if let (true, Some(quotient), Some(remainder)) = (is_constant, quotient_value, remainder_value) {
+            let constant = |value: u128| (0..size).map(|i| Boolean::constant((value >> i) & 1 == 1)).collect();
+
+            return Ok((constant(quotient), constant(remainder)));
+        }
+
+        // Witness the quotient and the remainder.
+        let quotient = alloc_bits(cs.ns(|| "quotient"), quotient_value, size)?;
+        let remainder = alloc_bits(cs.ns(|| "remainder"), remainder_value, size)?;
+
+        // Enforce r < b by showing that b - r - 1 fits into `size` bits.
+        let gap_value = match (bits_value(other), remainder_value) {
+            (Some(divisor), Some(remainder)) => divisor.checked_sub(remainder + 1),
+            _ => None,
+        };
+        let gap = alloc_bits(cs.ns(|| "gap"), gap_value, size)?;
+
+        cs.enforce(
+            || "remainder less than divisor",
+            |lc| lc + &bits_lc::<F, CS>(other) - &bits_lc::<F, CS>(&remainder) - &bits_lc::<F, CS>(&gap) - CS::one(),
+            |lc| lc + CS::one(),
+            |lc| lc,
+        );
+
+        // Compute q * b from half-width limbs so that the product cannot wrap around the field.
+        // q * b <= a < 2^size holds for an honest prover, so the product of the high limbs is zero.
+        let half = size / 2;
+        let (quotient_low, quotient_high) = quotient.split_at(half);
+        let (divisor_low, divisor_high) = other.split_at(half);
+
+        cs.enforce(
+            || "high product is zero",
+            |lc| lc + &bits_lc::<F, CS>(quotient_high),
+            |lc| lc + &bits_lc::<F, CS>(divisor_high),
+            |lc| lc,
+        );
+
+        let low = enforce_product(cs.ns(|| "low product"), quotient_low, divisor_low)?;
+        let cross_first = enforce_product(cs.ns(|| "first cross product"), quotient_low, divisor_high)?;
+        let cross_second = enforce_product(cs.ns(|| "second cross product"), quotient_high, divisor_low)?;
+
+        let shift = F::from(1u128 << half);
+
+        // Enforce a == q * b + r.
+        cs.enforce(
+            || "dividend",
+            |lc| {
+                lc + &low + (shift, &cross_first) + (shift, &cross_second) + &bits_lc::<F, CS>(&remainder)
+                    - &bits_lc::<F, CS>(self)
+            },
+            |lc| lc + CS::one(),
+            |lc| lc,
+        );
+
+        Ok((quotient, remainder))
+    }
+}
+
+macro_rules! div_rem_uint_impl {
+    ($($gadget: ident),*) => ($(
+        impl<F: Field + PrimeField> DivRem<F> for $gadget {
+            type ErrorType = SynthesisError;
+
+            fn div_rem<CS: ConstraintSystem<F>>(
+                &self,
+                cs: CS,
+                other: &Self
+            ) -> Result<(Self, Self), Self::ErrorType> {
+                let (quotient, remainder) = self.bits.div_rem(cs, &other.bits)?;
+
+                Ok((
+                    <$gadget as UInt>::from_bits_le(&quotient),
+                    <$gadget as UInt>::from_bits_le(&remainder),
+                ))
+            }
+        }
+    )*)
+}
+
+div_rem_uint_impl!(UInt8, UInt16, UInt32, UInt64, UInt128);
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::PrimeField,
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{boolean::Boolean, eq::EqGadget, select::CondSelectGadget},
+    },
+};
+
+/// Shifts an array of bits by a variable amount using a logarithmic barrel shifter.
+/// Expects least significant bit first for both the bits and the shift amount.
+pub trait ShiftBits<F: PrimeField>
+where
+    Self: std::marker::Sized,
+{
+    /// Shifts towards the most significant bit, filling with `false`.
+    fn shift_left<CS: ConstraintSystem<F>>(&self, cs: CS, amount: &[Boolean]) -> Result<Self, SynthesisError>;
+
+    /// Shifts towards the least significant bit, filling with the given `fill` bit.
+    fn shift_right<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        amount: &[Boolean],
+        fill: &Boolean,
+    ) -> Result<Self, SynthesisError>;
+}
+
+/// Returns the value of a little-endian shift amount if every bit has a known value.
+pub fn shift_amount_value(amount: &[Boolean]) -> Option<u32> {
+    amount.iter().enumerate().try_fold(0u32, |value, (i, bit)| match bit.get_value()? {
+        true if i >= 32 => None,
+        true => Some(value | (1 << i)),
+        false => Some(value),
+    })
+}
+
+/// Returns the number of shift amount bits needed to address every bit position.
+fn shift_stages(length: usize) -> usize {
+    (0usize.leading_zeros() - (length - 1).leading_zeros()) as usize
+}
+
+/// Enforces that the shift amount is smaller than the number of bits being shifted.
+fn enforce_shift_in_range<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    amount: &[Boolean],
+    stages: usize,
+) -> Result<(), SynthesisError> {
+    for (i, bit) in amount.iter().enumerate().skip(stages) {
+        bit.enforce_equal(cs.ns(|| format!("shift amount bit {} is zero", i)), &Boolean::constant(false))?;
+    }
+
+    Ok(())
+}
+
+impl<F: PrimeField> ShiftBits<F> for Vec<Boolean> {
+    fn shift_left<CS: ConstraintSystem<F>>(&self, mut cs: CS, amount: &[Boolean]) -> Result<Self, SynthesisError> {
+        let length = self.len();
+        let stages = shift_stages(length);
+
+        enforce_shift_in_range(cs.ns(|| "shift_left_range"), amount, stages)?;
+
+        let mut result = self.clone();
+
+        for (stage, condition) in amount.iter().take(stages).enumerate() {
+            let offset = 1 << stage;
+
+            let mut shifted = vec![Boolean::constant(false); offset.min(length)];
+            shifted.extend_from_slice(&result[..length.saturating_sub(offset)]);
+
+            result = shifted
+                .iter()
+                .zip(result.iter())
+                .enumerate()
+                .map(|(i, (shifted_bit, bit))| {
+                    Boolean::conditionally_select(
+                        cs.ns(|| format!("shift_left_stage_{}_bit_{}", stage, i)),
+                        condition,
+                        shifted_bit,
+                        bit,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        Ok(result)
+    }
+
+    fn shift_right<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        amount: &[Boolean],
+        fill: &Boolean,
+    ) -> Result<Self, SynthesisError> {
+        let length = self.len();
+        let stages = shift_stages(length);
+
+        enforce_shift_in_range(cs.ns(|| "shift_right_range"), amount, stages)?;
+
+        let mut result = self.clone();
+
+        for (stage, condition) in amount.iter().take(stages).enumerate() {
+            let offset = 1 << stage;
+
+            let mut shifted = result.iter().skip(offset).cloned().collect::<Vec<_>>();
+            shifted.resize(length, *fill);
+
+            result = shifted
+                .iter()
+                .zip(result.iter())
+                .enumerate()
+                .map(|(i, (shifted_bit, bit))| {
+                    Boolean::conditionally_select(
+                        cs.ns(|| format!("shift_right_stage_{}_bit_{}", stage, i)),
+                        condition,
+                        shifted_bit,
+                        bit,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        Ok(result)
+    }
+}
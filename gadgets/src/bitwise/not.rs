@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkos_models::gadgets::utilities::{
+    boolean::Boolean,
+    uint::{UInt128, UInt16, UInt32, UInt64, UInt8},
+};
+
+/// Flips every bit of a value.
+pub trait BitNot {
+    fn bit_not(&self) -> Self;
+}
+
+impl BitNot for Vec<Boolean> {
+    fn bit_not(&self) -> Self {
+        self.iter().map(|bit| bit.not()).collect()
+    }
+}
+
+macro_rules! bit_not_uint_impl {
+    ($($gadget: ident),*) => ($(
+        impl BitNot for $gadget {
+            fn bit_not(&self) -> Self {
+                Self {
+                    bits: self.bits.bit_not(),
+                    negated: false,
+                    value: self.value.map(|value| !value),
+                }
+            }
+        }
+    )*)
+}
+
+bit_not_uint_impl!(UInt8, UInt16, UInt32, UInt64, UInt128);
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::bits::{shift_amount_value, ShiftBits};
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::{Field, PrimeField},
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{
+            boolean::Boolean,
+            uint::{UInt128, UInt16, UInt32, UInt64, UInt8},
+        },
+    },
+};
+
+/// Shifts a value towards its most significant bit, discarding the bits shifted out.
+/// The shift amount is given as little-endian bits and must be smaller than the bit width.
+pub trait Shl<F: Field>
+where
+    Self: std::marker::Sized,
+{
+    type ErrorType;
+
+    fn shl<CS: ConstraintSystem<F>>(&self, cs: CS, amount: &[Boolean]) -> Result<Self, Self::ErrorType>;
+}
+
+macro_rules! shl_uint_impl {
+    ($($gadget: ident),*) => ($(
+        impl<F: Field + PrimeField> Shl<F> for $gadget {
+            type ErrorType = SynthesisError;
+
+            fn shl<CS: ConstraintSystem<F>>(
+                &self,
+                cs: CS,
+                amount: &[Boolean]
+            ) -> Result<Self, Self::ErrorType> {
+                let value = match (self.value, shift_amount_value(amount)) {
+                    (Some(a), Some(b)) => a.checked_shl(b),
+                    _ => None,
+                };
+
+                let bits = self.bits.shift_left(cs, amount)?;
+
+                Ok(Self {
+                    bits,
+                    negated: false,
+                    value,
+                })
+            }
+        }
+    )*)
+}
+
+shl_uint_impl!(UInt8, UInt16, UInt32, UInt64, UInt128);
@@ -21,6 +21,8 @@ pub mod arithmetic;
 
 pub mod bits;
 
+pub mod bitwise;
+
 pub mod errors;
 
 pub mod signed_integer;
@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    arithmetic::{DivRem, Neg},
+    errors::SignedIntegerError,
+    Int,
+    Int128,
+    Int16,
+    Int32,
+    Int64,
+    Int8,
+};
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::PrimeField,
+    gadgets::{
+        r1cs::ConstraintSystem,
+        utilities::{boolean::Boolean, eq::EqGadget, select::CondSelectGadget},
+    },
+};
+
+/// Selects `first` if the condition is true, `second` otherwise, bit by bit.
+fn select_bits<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    condition: &Boolean,
+    first: &[Boolean],
+    second: &[Boolean],
+) -> Result<Vec<Boolean>, SynthesisError> {
+    first
+        .iter()
+        .zip(second.iter())
+        .enumerate()
+        .map(|(i, (a, b))| Boolean::conditionally_select(cs.ns(|| format!("select bit {}", i)), condition, a, b))
+        .collect()
+}
+
+/// Returns the two's complement magnitude of the given bits as an unsigned value.
+fn magnitude<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    bits: &[Boolean],
+    is_negative: &Boolean,
+) -> Result<Vec<Boolean>, SynthesisError> {
+    let negated = bits.to_vec().neg(cs.ns(|| "negate"))?;
+
+    select_bits(cs.ns(|| "select magnitude"), is_negative, &negated, bits)
+}
+
+macro_rules! div_rem_int_impl {
+    ($($gadget:ident),*) => ($(
+        impl<F: PrimeField> DivRem<F> for $gadget {
+            type ErrorType = SignedIntegerError;
+
+            fn div_rem<CS: ConstraintSystem<F>>(
+                &self,
+                mut cs: CS,
+                other: &Self
+            ) -> Result<(Self, Self), Self::ErrorType> {
+                // Division truncates towards zero and the remainder takes the sign of the dividend.
+                let (quotient_value, remainder_value) = match (self.value, other.value) {
+                    (Some(_), Some(0)) => return Err(SignedIntegerError::DivisionByZero),
+                    (Some(a), Some(b)) => match (a.checked_div(b), a.checked_rem(b)) {
+                        (Some(quotient), Some(remainder)) => (Some(quotient), Some(remainder)),
+                        _ => return Err(SignedIntegerError::Overflow),
+                    },
+                    _ => (None, None),
+                };
+
+                if let (true, Some(quotient), Some(remainder)) =
+                    (Self::result_is_constant(&self, &other), quotient_value, remainder_value)
+                {
+                    return Ok((Self::constant(quotient), Self::constant(remainder)));
+                }
+
+                let dividend_negative = *self.bits.last().unwrap();
+                let divisor_negative = *other.bits.last().unwrap();
+
+                // Divide the magnitudes as unsigned values.
+                let dividend = magnitude(cs.ns(|| "dividend magnitude"), &self.bits, &dividend_negative)?;
+                let divisor = magnitude(cs.ns(|| "divisor magnitude"), &other.bits, &divisor_negative)?;
+
+                let (quotient, remainder) = dividend.div_rem(cs.ns(|| "unsigned div_rem"), &divisor)?;
+
+                // Restore the signs of the results.
+                let quotient_negative = Boolean::xor(
+                    cs.ns(|| "quotient sign"),
+                    &dividend_negative,
+                    &divisor_negative,
+                )?;
+
+                let negated_quotient = quotient.neg(cs.ns(|| "negate quotient"))?;
+                let quotient_bits = select_bits(
+                    cs.ns(|| "select quotient"),
+                    &quotient_negative,
+                    &negated_quotient,
+                    &quotient,
+                )?;
+
+                let negated_remainder = remainder.neg(cs.ns(|| "negate remainder"))?;
+                let remainder_bits = select_bits(
+                    cs.ns(|| "select remainder"),
+                    &dividend_negative,
+                    &negated_remainder,
+                    &remainder,
+                )?;
+
+                // A positive quotient must fit into the signed range.
+                let overflow = Boolean::and(
+                    cs.ns(|| "positive quotient overflow"),
+                    &quotient_negative.not(),
+                    quotient.last().unwrap(),
+                )?;
+                overflow.enforce_equal(cs.ns(|| "enforce no overflow"), &Boolean::constant(false))?;
+
+                Ok((
+                    Self {
+                        bits: quotient_bits,
+                        value: quotient_value,
+                    },
+                    Self {
+                        bits: remainder_bits,
+                        value: remainder_value,
+                    },
+                ))
+            }
+        }
+    )*)
+}
+
+div_rem_int_impl!(Int8, Int16, Int32, Int64, Int128);
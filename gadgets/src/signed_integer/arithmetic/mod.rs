@@ -21,6 +21,9 @@ pub use self::add::*;
 pub mod div;
 pub use self::div::*;
 
+pub mod div_rem;
+pub use self::div_rem::*;
+
 pub mod mul;
 pub use self::mul::*;
 
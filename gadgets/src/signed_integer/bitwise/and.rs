@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{bitwise::BitAnd, errors::SignedIntegerError, signed_integer::*};
+
+use snarkos_models::{curves::PrimeField, gadgets::r1cs::ConstraintSystem};
+
+macro_rules! bit_and_int_impl {
+    ($($gadget: ident)*) => ($(
+        impl<F: PrimeField> BitAnd<F> for $gadget {
+            type ErrorType = SignedIntegerError;
+
+            fn bit_and<CS: ConstraintSystem<F>>(
+                &self,
+                cs: CS,
+                other: &Self
+            ) -> Result<Self, Self::ErrorType> {
+                let value = match (self.value, other.value) {
+                    (Some(a), Some(b)) => Some(a & b),
+                    _ => None,
+                };
+
+                let bits = self.bits.bit_and(cs, &other.bits)?;
+
+                Ok(Self {
+                    bits,
+                    value,
+                })
+            }
+        }
+    )*)
+}
+
+bit_and_int_impl!(Int8 Int16 Int32 Int64 Int128);
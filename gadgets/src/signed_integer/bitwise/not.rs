@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{bitwise::BitNot, signed_integer::*};
+
+macro_rules! bit_not_int_impl {
+    ($($gadget: ident)*) => ($(
+        impl BitNot for $gadget {
+            fn bit_not(&self) -> Self {
+                Self {
+                    bits: self.bits.bit_not(),
+                    value: self.value.map(|value| !value),
+                }
+            }
+        }
+    )*)
+}
+
+bit_not_int_impl!(Int8 Int16 Int32 Int64 Int128);
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bits::{shift_amount_value, ShiftBits},
+    bitwise::Shr,
+    errors::SignedIntegerError,
+    signed_integer::*,
+};
+
+use snarkos_models::{
+    curves::PrimeField,
+    gadgets::{r1cs::ConstraintSystem, utilities::boolean::Boolean},
+};
+
+macro_rules! shr_int_impl {
+    ($($gadget: ident)*) => ($(
+        impl<F: PrimeField> Shr<F> for $gadget {
+            type ErrorType = SignedIntegerError;
+
+            fn shr<CS: ConstraintSystem<F>>(
+                &self,
+                cs: CS,
+                amount: &[Boolean]
+            ) -> Result<Self, Self::ErrorType> {
+                let value = match (self.value, shift_amount_value(amount)) {
+                    (Some(a), Some(b)) => match a.checked_shr(b) {
+                        Some(result) => Some(result),
+                        None => return Err(SignedIntegerError::Overflow),
+                    },
+                    _ => None,
+                };
+
+                // arithmetic shift: fill with the sign bit
+                let sign = *self.bits.last().unwrap();
+                let bits = self.bits.shift_right(cs, amount, &sign)?;
+
+                Ok(Self {
+                    bits,
+                    value,
+                })
+            }
+        }
+    )*)
+}
+
+shr_int_impl!(Int8 Int16 Int32 Int64 Int128);
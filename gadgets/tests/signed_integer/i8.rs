@@ -14,13 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_gadgets::{arithmetic::*, Int8};
+use leo_gadgets::{arithmetic::*, bitwise::*, Int8};
 
 use snarkos_models::{
     curves::{One, Zero},
     gadgets::{
         r1cs::{ConstraintSystem, Fr, TestConstraintSystem},
-        utilities::{alloc::AllocGadget, boolean::Boolean},
+        utilities::{alloc::AllocGadget, boolean::Boolean, uint::UInt8},
     },
 };
 
@@ -353,6 +353,71 @@ fn test_int8_div() {
     }
 }
 
+#[test]
+fn test_int8_div_rem_constants() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: i8 = rng.gen();
+        let b: i8 = rng.gen();
+
+        let (expected_quotient, expected_remainder) = match (a.checked_div(b), a.checked_rem(b)) {
+            (Some(quotient), Some(remainder)) => (quotient, remainder),
+            _ => continue,
+        };
+
+        let a_bit = Int8::constant(a);
+        let b_bit = Int8::constant(b);
+
+        let (quotient, remainder) = a_bit.div_rem(cs.ns(|| "div_rem"), &b_bit).unwrap();
+
+        assert!(quotient.value == Some(expected_quotient));
+        assert!(remainder.value == Some(expected_remainder));
+
+        check_all_constant_bits(expected_quotient, quotient);
+        check_all_constant_bits(expected_remainder, remainder);
+    }
+}
+
+#[test]
+fn test_int8_div_rem() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: i8 = rng.gen();
+        let b: i8 = rng.gen();
+
+        let (expected_quotient, expected_remainder) = match (a.checked_div(b), a.checked_rem(b)) {
+            (Some(quotient), Some(remainder)) => (quotient, remainder),
+            _ => continue,
+        };
+
+        let a_bit = Int8::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int8::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let (quotient, remainder) = a_bit.div_rem(cs.ns(|| "div_rem"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(quotient.value == Some(expected_quotient));
+        assert!(remainder.value == Some(expected_remainder));
+
+        check_all_allocated_bits(expected_quotient, quotient);
+        check_all_allocated_bits(expected_remainder, remainder);
+
+        // Flip a bit of the witnessed remainder and see if the constraints still hold
+        let path = "div_rem/unsigned div_rem/remainder/bit 0/boolean";
+        let value = cs.get(path);
+        cs.set(path, if value.is_zero() { Fr::one() } else { Fr::zero() });
+
+        assert!(!cs.is_satisfied());
+    }
+}
+
 #[test]
 fn test_int8_pow_constants() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -424,3 +489,65 @@ fn test_int8_pow() {
         assert!(!cs.is_satisfied());
     }
 }
+
+#[test]
+fn test_int8_bit_xor() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: i8 = rng.gen();
+        let b: i8 = rng.gen();
+
+        let expected = a ^ b;
+
+        let a_bit = Int8::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = Int8::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.bit_xor(cs.ns(|| "xor"), &b_bit).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected));
+
+        check_all_allocated_bits(expected, r);
+    }
+}
+
+#[test]
+fn test_int8_shr() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: i8 = rng.gen();
+        let b: u8 = rng.gen_range(0, 8);
+
+        let expected = a >> b;
+
+        let a_bit = Int8::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+        let b_bit = UInt8::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+        let r = a_bit.shr(cs.ns(|| "shift right"), &b_bit.bits).unwrap();
+
+        assert!(cs.is_satisfied());
+
+        assert!(r.value == Some(expected));
+
+        check_all_allocated_bits(expected, r);
+    }
+}
+
+#[test]
+fn test_int8_shl_out_of_range() {
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let a_bit = Int8::alloc(cs.ns(|| "a_bit"), || Ok(1i8)).unwrap();
+    let b_bit = UInt8::alloc(cs.ns(|| "b_bit"), || Ok(8u8)).unwrap();
+
+    let r = a_bit.shl(cs.ns(|| "shift left"), &b_bit.bits);
+
+    assert!(r.is_err());
+}
@@ -20,8 +20,10 @@ use crate::{
     expressions::{
         ArrayInitializerExpression,
         ArrayInlineExpression,
+        CastType,
         CircuitInlineExpression,
         Expression,
+        MatchExpression,
         PostfixExpression,
         TernaryExpression,
         UnaryExpression,
@@ -39,6 +41,7 @@ use pest::{
     Parser,
     Span,
 };
+use std::collections::VecDeque;
 
 #[derive(Parser)]
 #[grammar = "leo.pest"]
@@ -68,8 +71,14 @@ fn precedence_climber() -> PrecClimber<Rule> {
             | Operator::new(Rule::operation_gt, Assoc::Left)
             | Operator::new(Rule::operation_le, Assoc::Left)
             | Operator::new(Rule::operation_lt, Assoc::Left),
+        Operator::new(Rule::operation_bit_or, Assoc::Left),
+        Operator::new(Rule::operation_bit_xor, Assoc::Left),
+        Operator::new(Rule::operation_bit_and, Assoc::Left),
+        Operator::new(Rule::operation_shl, Assoc::Left) | Operator::new(Rule::operation_shr, Assoc::Left),
         Operator::new(Rule::operation_add, Assoc::Left) | Operator::new(Rule::operation_sub, Assoc::Left),
-        Operator::new(Rule::operation_mul, Assoc::Left) | Operator::new(Rule::operation_div, Assoc::Left),
+        Operator::new(Rule::operation_mul, Assoc::Left)
+            | Operator::new(Rule::operation_div, Assoc::Left)
+            | Operator::new(Rule::operation_rem, Assoc::Left),
         Operator::new(Rule::operation_pow, Assoc::Left),
     ])
 }
@@ -96,6 +105,9 @@ fn parse_term(pair: Pair<Rule>) -> Expression {
                 Rule::expression_conditional => {
                     Expression::Ternary(Box::new(TernaryExpression::from_pest(&mut pair.into_inner()).unwrap()))
                 }
+                Rule::expression_match => {
+                    Expression::Match(Box::new(MatchExpression::from_pest(&mut pair.into_inner()).unwrap()))
+                }
                 Rule::expression_unary => {
                     // The following is necessary to match with the unary operator and its unary expression
                     let span = next.as_span();
@@ -131,6 +143,17 @@ fn parse_term(pair: Pair<Rule>) -> Expression {
     }
 }
 
+fn cast_expression<'ast>(expression: Expression<'ast>, casts: Vec<Pair<'ast, Rule>>) -> Expression<'ast> {
+    casts.into_iter().fold(expression, |expression, pair| {
+        let cast = CastType::from_pest(&mut Pairs::single(pair)).unwrap();
+
+        let (start, _) = expression.span().clone().split();
+        let (_, end) = cast.span.split();
+
+        Expression::cast(expression, cast.type_, start.span(&end))
+    })
+}
+
 fn binary_expression<'ast>(lhs: Expression<'ast>, pair: Pair<'ast, Rule>, rhs: Expression<'ast>) -> Expression<'ast> {
     let (start, _) = lhs.span().clone().split();
     let (_, end) = rhs.span().clone().split();
@@ -149,7 +172,13 @@ fn binary_expression<'ast>(lhs: Expression<'ast>, pair: Pair<'ast, Rule>, rhs: E
         Rule::operation_sub => Expression::binary(BinaryOperation::Sub, lhs, rhs, span),
         Rule::operation_mul => Expression::binary(BinaryOperation::Mul, lhs, rhs, span),
         Rule::operation_div => Expression::binary(BinaryOperation::Div, lhs, rhs, span),
+        Rule::operation_rem => Expression::binary(BinaryOperation::Rem, lhs, rhs, span),
         Rule::operation_pow => Expression::binary(BinaryOperation::Pow, lhs, rhs, span),
+        Rule::operation_bit_or => Expression::binary(BinaryOperation::BitOr, lhs, rhs, span),
+        Rule::operation_bit_xor => Expression::binary(BinaryOperation::BitXor, lhs, rhs, span),
+        Rule::operation_bit_and => Expression::binary(BinaryOperation::BitAnd, lhs, rhs, span),
+        Rule::operation_shl => Expression::binary(BinaryOperation::Shl, lhs, rhs, span),
+        Rule::operation_shr => Expression::binary(BinaryOperation::Shr, lhs, rhs, span),
         _ => unreachable!(),
     }
 }
@@ -164,7 +193,27 @@ impl<'ast> FromPest<'ast> for Expression<'ast> {
             Rule::expression => {
                 // advance the iterator
                 pest.next();
-                Ok(PRECEDENCE_CLIMBER.climb(pair.into_inner(), parse_term, binary_expression))
+
+                // Casts bind tighter than any binary operation, so they are collected with their term
+                let mut pairs = Vec::new();
+                let mut casts: VecDeque<Vec<_>> = VecDeque::new();
+
+                for pair in pair.into_inner() {
+                    match pair.as_rule() {
+                        Rule::cast_type => casts.back_mut().unwrap().push(pair),
+                        Rule::expression_term => {
+                            casts.push_back(Vec::new());
+                            pairs.push(pair);
+                        }
+                        _ => pairs.push(pair),
+                    }
+                }
+
+                Ok(PRECEDENCE_CLIMBER.climb(
+                    pairs.into_iter(),
+                    |pair| cast_expression(parse_term(pair), casts.pop_front().unwrap()),
+                    binary_expression,
+                ))
             }
             _ => Err(ConversionError::NoMatch),
         }
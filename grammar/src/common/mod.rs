@@ -38,6 +38,9 @@ pub use mutable::*;
 pub mod mut_self_keyword;
 pub use mut_self_keyword::*;
 
+pub mod public;
+pub use public::*;
+
 pub mod range;
 pub use range::*;
 
@@ -17,7 +17,8 @@
 use crate::{
     ast::Rule,
     circuits::Circuit,
-    definitions::AnnotatedDefinition,
+    definitions::{AnnotatedDefinition, ConstDefinition},
+    enums::EnumDefinition,
     functions::{Function, TestFunction},
     imports::Import,
 };
@@ -31,6 +32,8 @@ pub enum Definition<'ast> {
     Annotated(AnnotatedDefinition<'ast>),
     Import(Import<'ast>),
     Circuit(Circuit<'ast>),
+    Enum(EnumDefinition<'ast>),
+    Const(ConstDefinition<'ast>),
     Function(Function<'ast>),
     TestFunction(TestFunction<'ast>),
 }
@@ -17,5 +17,8 @@
 pub mod annotated_definition;
 pub use annotated_definition::*;
 
+pub mod const_definition;
+pub use const_definition::*;
+
 pub mod definition;
 pub use definition::*;
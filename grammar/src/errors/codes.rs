@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The stable error codes of `leo-grammar` and their descriptions, as shown by `leo explain`.
+pub const ERROR_CODES: &[(&str, &str)] = &[
+    (
+        "E0100",
+        "An external crate failed while reading or parsing a Leo file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system. Check that the file exists and can be read.",
+    ),
+    (
+        "E0101",
+        "A Leo file could not be read.
+
+The compiler could not read the file at the given path. Check that the path
+points to a readable `.leo` file inside the package.",
+    ),
+    (
+        "E0102",
+        "The syntax tree of a program could not be serialized to or from JSON.
+
+This error is raised when a parsed grammar is converted to JSON, for example
+when syntax tree snapshots are generated for tests.",
+    ),
+    (
+        "E0103",
+        "The abstract syntax tree of a program could not be constructed.
+
+The file was tokenized, but the parsed rules could not be converted into a
+syntax tree. This usually indicates a bug in the grammar rather than in the
+program.",
+    ),
+    (
+        "E0104",
+        "A Leo file contains a syntax error.
+
+The parser expected a different token at the highlighted location. The
+message lists the tokens that were expected, for example:
+
+    function main() {
+        let a = 1u8      // expected `;`
+        return a
+    }",
+    ),
+];
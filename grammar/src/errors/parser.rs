@@ -38,6 +38,19 @@ pub enum ParserError {
 }
 
 impl ParserError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ParserError::Crate(..) => "E0100",
+            ParserError::FileReadError(_) => "E0101",
+            ParserError::JsonError(_) => "E0102",
+            ParserError::SyntaxError(error) => error.error_code(),
+            ParserError::SyntaxTreeError => "E0103",
+        }
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         if let ParserError::SyntaxError(error) = self {
             let new_error: Error<Rule> = match error {
@@ -24,6 +24,17 @@ pub enum SyntaxError {
     Error(Error<Rule>),
 }
 
+impl SyntaxError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            SyntaxError::Error(_) => "E0104",
+        }
+    }
+}
+
 impl From<Error<Rule>> for SyntaxError {
     fn from(mut error: Error<Rule>) -> Self {
         error = error.renamed_rules(|rule| match *rule {
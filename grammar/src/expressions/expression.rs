@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{common::Identifier, expressions::*, operations::BinaryOperation, values::Value};
+use crate::{common::Identifier, expressions::*, operations::BinaryOperation, types::DataType, values::Value};
 
 use pest::Span;
 use serde::Serialize;
@@ -26,7 +26,9 @@ pub enum Expression<'ast> {
     Identifier(Identifier<'ast>),
     Unary(Box<UnaryExpression<'ast>>),
     Binary(Box<BinaryExpression<'ast>>),
+    Cast(Box<CastExpression<'ast>>),
     Ternary(Box<TernaryExpression<'ast>>),
+    Match(Box<MatchExpression<'ast>>),
     ArrayInline(ArrayInlineExpression<'ast>),
     ArrayInitializer(Box<ArrayInitializerExpression<'ast>>),
     CircuitInline(CircuitInlineExpression<'ast>),
@@ -49,6 +51,14 @@ impl<'ast> Expression<'ast> {
         }))
     }
 
+    pub fn cast(expression: Expression<'ast>, type_: DataType, span: Span<'ast>) -> Self {
+        Expression::Cast(Box::new(CastExpression {
+            expression,
+            type_,
+            span,
+        }))
+    }
+
     pub fn ternary(
         first: Expression<'ast>,
         second: Expression<'ast>,
@@ -69,7 +79,9 @@ impl<'ast> Expression<'ast> {
             Expression::Identifier(expression) => &expression.span,
             Expression::Unary(expression) => &expression.span,
             Expression::Binary(expression) => &expression.span,
+            Expression::Cast(expression) => &expression.span,
             Expression::Ternary(expression) => &expression.span,
+            Expression::Match(expression) => &expression.span,
             Expression::ArrayInline(expression) => &expression.span,
             Expression::ArrayInitializer(expression) => &expression.span,
             Expression::CircuitInline(expression) => &expression.span,
@@ -86,11 +98,19 @@ impl<'ast> fmt::Display for Expression<'ast> {
             Expression::Identifier(ref expression) => write!(f, "{}", expression),
             Expression::Unary(ref expression) => write!(f, "{}", expression),
             Expression::Binary(ref expression) => write!(f, "{} == {}", expression.left, expression.right),
+            Expression::Cast(ref expression) => write!(f, "{}", expression.span.as_str()),
             Expression::Ternary(ref expression) => write!(
                 f,
                 "if {} ? {} : {}",
                 expression.first, expression.second, expression.third
             ),
+            Expression::Match(ref expression) => {
+                write!(f, "match {} {{ ", expression.expression)?;
+                for arm in expression.arms.iter() {
+                    write!(f, "{}, ", arm)?;
+                }
+                write!(f, "}}")
+            }
             Expression::ArrayInline(ref expression) => {
                 for (i, spread_or_expression) in expression.expressions.iter().enumerate() {
                     write!(f, "{}", spread_or_expression)?;
@@ -23,12 +23,21 @@ pub use array_inline_expression::*;
 pub mod binary_expression;
 pub use binary_expression::*;
 
+pub mod cast_expression;
+pub use cast_expression::*;
+
 pub mod circuit_inline_expression;
 pub use circuit_inline_expression::*;
 
 pub mod expression;
 pub use expression::*;
 
+pub mod match_expression;
+pub use match_expression::*;
+
+pub mod match_pattern;
+pub use match_pattern::*;
+
 pub mod unary_expression;
 pub use unary_expression::*;
 
@@ -36,6 +36,7 @@ pub mod circuits;
 pub mod common;
 pub mod console;
 pub mod definitions;
+pub mod enums;
 pub mod expressions;
 pub mod files;
 pub mod functions;
@@ -27,7 +27,13 @@ pub enum AssignOperation {
     SubAssign(SubAssign),
     MulAssign(MulAssign),
     DivAssign(DivAssign),
+    RemAssign(RemAssign),
     PowAssign(PowAssign),
+    BitAndAssign(BitAndAssign),
+    BitOrAssign(BitOrAssign),
+    BitXorAssign(BitXorAssign),
+    ShlAssign(ShlAssign),
+    ShrAssign(ShrAssign),
 }
 
 #[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
@@ -50,6 +56,30 @@ pub struct MulAssign {}
 #[pest_ast(rule(Rule::operation_div_assign))]
 pub struct DivAssign {}
 
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_rem_assign))]
+pub struct RemAssign {}
+
 #[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
 #[pest_ast(rule(Rule::operation_pow_assign))]
 pub struct PowAssign {}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_bit_and_assign))]
+pub struct BitAndAssign {}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_bit_or_assign))]
+pub struct BitOrAssign {}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_bit_xor_assign))]
+pub struct BitXorAssign {}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_shl_assign))]
+pub struct ShlAssign {}
+
+#[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
+#[pest_ast(rule(Rule::operation_shr_assign))]
+pub struct ShrAssign {}
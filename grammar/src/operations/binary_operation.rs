@@ -34,5 +34,11 @@ pub enum BinaryOperation {
     Sub,
     Mul,
     Div,
+    Rem,
     Pow,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shl,
+    Shr,
 }
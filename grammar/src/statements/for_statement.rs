@@ -27,6 +27,7 @@ pub struct ForStatement<'ast> {
     pub index: Identifier<'ast>,
     pub start: Expression<'ast>,
     pub stop: Expression<'ast>,
+    pub condition: Option<Box<Expression<'ast>>>,
     pub block: Block<'ast>,
     #[pest_ast(outer())]
     #[serde(with = "SpanDef")]
@@ -35,6 +36,10 @@ pub struct ForStatement<'ast> {
 
 impl<'ast> fmt::Display for ForStatement<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "for {} in {}..{} {}", self.index, self.start, self.stop, self.block)
+        write!(f, "for {} in {}..{} ", self.index, self.start, self.stop)?;
+        if let Some(ref condition) = self.condition {
+            write!(f, "while {} ", condition)?;
+        }
+        write!(f, "{}", self.block)
     }
 }
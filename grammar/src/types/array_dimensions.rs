@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ast::Rule, values::PositiveNumber, SpanDef};
+use crate::{ast::Rule, types::ArrayDimension, SpanDef};
 
 use pest::Span;
 use pest_ast::FromPest;
@@ -30,7 +30,7 @@ pub enum ArrayDimensions<'ast> {
 #[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
 #[pest_ast(rule(Rule::dimension_single))]
 pub struct Single<'ast> {
-    pub number: PositiveNumber<'ast>,
+    pub dimension: ArrayDimension<'ast>,
     #[pest_ast(outer())]
     #[serde(with = "SpanDef")]
     pub span: Span<'ast>,
@@ -39,7 +39,7 @@ pub struct Single<'ast> {
 #[derive(Clone, Debug, FromPest, PartialEq, Serialize)]
 #[pest_ast(rule(Rule::dimension_multiple))]
 pub struct Multiple<'ast> {
-    pub numbers: Vec<PositiveNumber<'ast>>,
+    pub dimensions: Vec<ArrayDimension<'ast>>,
     #[pest_ast(outer())]
     #[serde(with = "SpanDef")]
     pub span: Span<'ast>,
@@ -48,8 +48,8 @@ pub struct Multiple<'ast> {
 impl<'ast> std::fmt::Display for ArrayDimensions<'ast> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self {
-            ArrayDimensions::Single(ref single) => write!(f, "{}", single.number),
-            ArrayDimensions::Multiple(ref multiple) => write!(f, "{:?}", multiple.numbers),
+            ArrayDimensions::Single(ref single) => write!(f, "{}", single.dimension),
+            ArrayDimensions::Multiple(ref multiple) => write!(f, "{:?}", multiple.dimensions),
         }
     }
 }
@@ -16,7 +16,7 @@
 
 use crate::{
     ast::Rule,
-    types::{AddressType, BooleanType, FieldType, GroupType, IntegerType},
+    types::{AddressType, BooleanType, CharType, FieldType, GroupType, IntegerType},
 };
 
 use pest_ast::FromPest;
@@ -27,6 +27,7 @@ use serde::Serialize;
 pub enum DataType {
     Address(AddressType),
     Boolean(BooleanType),
+    Char(CharType),
     Field(FieldType),
     Group(GroupType),
     Integer(IntegerType),
@@ -17,6 +17,9 @@
 pub mod address_type;
 pub use address_type::*;
 
+pub mod array_dimension;
+pub use array_dimension::*;
+
 pub mod array_dimensions;
 pub use array_dimensions::*;
 
@@ -26,6 +29,9 @@ pub use array_type::*;
 pub mod boolean_type;
 pub use boolean_type::*;
 
+pub mod char_type;
+pub use char_type::*;
+
 pub mod circuit_type;
 pub use circuit_type::*;
 
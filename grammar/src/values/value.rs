@@ -16,7 +16,17 @@
 
 use crate::{
     ast::Rule,
-    values::{AddressValue, BooleanValue, FieldValue, GroupValue, IntegerValue, NumberValue},
+    values::{
+        AddressValue,
+        BooleanValue,
+        ByteStringValue,
+        CharValue,
+        FieldValue,
+        GroupValue,
+        IntegerValue,
+        NumberValue,
+        StringValue,
+    },
 };
 
 use pest::Span;
@@ -29,10 +39,13 @@ use std::fmt;
 pub enum Value<'ast> {
     Address(AddressValue<'ast>),
     Boolean(BooleanValue<'ast>),
+    ByteString(ByteStringValue<'ast>),
+    Char(CharValue<'ast>),
     Field(FieldValue<'ast>),
     Group(GroupValue<'ast>),
     Implicit(NumberValue<'ast>),
     Integer(IntegerValue<'ast>),
+    String(StringValue<'ast>),
 }
 
 impl<'ast> Value<'ast> {
@@ -40,10 +53,13 @@ impl<'ast> Value<'ast> {
         match self {
             Value::Address(value) => &value.span,
             Value::Boolean(value) => &value.span,
+            Value::ByteString(value) => &value.span,
+            Value::Char(value) => &value.span,
             Value::Field(value) => &value.span,
             Value::Group(value) => &value.span,
             Value::Implicit(value) => &value.span(),
             Value::Integer(value) => &value.span(),
+            Value::String(value) => &value.span,
         }
     }
 }
@@ -53,10 +69,13 @@ impl<'ast> fmt::Display for Value<'ast> {
         match *self {
             Value::Address(ref value) => write!(f, "{}", value),
             Value::Boolean(ref value) => write!(f, "{}", value),
+            Value::ByteString(ref value) => write!(f, "{}", value),
+            Value::Char(ref value) => write!(f, "{}", value),
             Value::Field(ref value) => write!(f, "{}", value),
             Value::Group(ref value) => write!(f, "{}", value),
             Value::Implicit(ref value) => write!(f, "{}", value),
             Value::Integer(ref value) => write!(f, "{}", value),
+            Value::String(ref value) => write!(f, "{}", value),
         }
     }
 }
@@ -53,3 +53,83 @@ fn multiple_redundant_parens() {
         ]
     }
 }
+
+#[test]
+fn shift_left() {
+    parses_to! {
+        parser: LanguageParser,
+        input:  "a << b",
+        rule:   Rule::expression,
+        tokens: [
+            expression(0, 6, [
+                expression_term(0, 1, [identifier(0, 1, [])]),
+                operation_shl(2, 4, []),
+                expression_term(5, 6, [identifier(5, 6, [])])
+            ])
+        ]
+    }
+}
+
+#[test]
+fn bitwise_and_not_logical_and() {
+    parses_to! {
+        parser: LanguageParser,
+        input:  "a & b",
+        rule:   Rule::expression,
+        tokens: [
+            expression(0, 5, [
+                expression_term(0, 1, [identifier(0, 1, [])]),
+                operation_bit_and(2, 3, []),
+                expression_term(4, 5, [identifier(4, 5, [])])
+            ])
+        ]
+    }
+}
+
+#[test]
+fn cast() {
+    parses_to! {
+        parser: LanguageParser,
+        input:  "a as u8",
+        rule:   Rule::expression,
+        tokens: [
+            expression(0, 7, [
+                expression_term(0, 1, [identifier(0, 1, [])]),
+                cast_type(2, 7, [type_data(5, 7, [type_integer(5, 7, [type_integer_unsigned(5, 7, [type_u8(5, 7, [])])])])])
+            ])
+        ]
+    }
+}
+
+#[test]
+fn byte_string_escape() {
+    parses_to! {
+        parser: LanguageParser,
+        input:  r#"b"a\n""#,
+        rule:   Rule::expression,
+        tokens: [
+            expression(0, 6, [
+                expression_term(0, 6, [value(0, 6, [value_byte_string(0, 6, [
+                    byte_string_character(2, 3, []),
+                    byte_string_character(3, 5, [])
+                ])])])
+            ])
+        ]
+    }
+}
+
+#[test]
+fn char_escape() {
+    parses_to! {
+        parser: LanguageParser,
+        input:  r#"'\''"#,
+        rule:   Rule::expression,
+        tokens: [
+            expression(0, 4, [
+                expression_term(0, 4, [value(0, 4, [value_char(0, 4, [
+                    char_character(1, 3, [])
+                ])])])
+            ])
+        ]
+    }
+}
@@ -18,7 +18,7 @@ use crate::errors::ImportParserError;
 use leo_ast::{Package, Program};
 
 use indexmap::{IndexMap, IndexSet};
-use std::env::current_dir;
+use std::{env::current_dir, path::PathBuf};
 
 /// Stores imported packages.
 ///
@@ -27,7 +27,9 @@ use std::env::current_dir;
 #[derive(Clone, Default)]
 pub struct ImportParser {
     imports: IndexMap<String, Program>,
+    import_files: IndexMap<String, PathBuf>,
     core_packages: IndexSet<Package>,
+    source_files: IndexSet<PathBuf>,
 }
 
 impl ImportParser {
@@ -37,11 +39,21 @@ impl ImportParser {
     /// It is okay if the imported program is already present since importing multiple symbols from
     /// the same file is allowed.
     ///
-    pub(crate) fn insert_import(&mut self, file_name: String, program: Program) {
+    pub(crate) fn insert_import(&mut self, file_name: String, program: Program, file_path: PathBuf) {
+        // Record the path of the imported program.
+        let _file_path = self.import_files.insert(file_name.clone(), file_path);
+
         // Insert the imported program.
         let _program = self.imports.insert(file_name, program);
     }
 
+    ///
+    /// Records the path of a Leo file that was read while resolving imports.
+    ///
+    pub(crate) fn insert_source_file(&mut self, file_path: PathBuf) {
+        self.source_files.insert(file_path);
+    }
+
     ///
     /// Inserts a core package into the `ImportParser`.
     ///
@@ -68,6 +80,20 @@ impl ImportParser {
         self.imports.get(file_name)
     }
 
+    ///
+    /// Returns the file name and program of every imported program.
+    ///
+    pub fn imports(&self) -> impl Iterator<Item = (&String, &Program)> {
+        self.imports.iter()
+    }
+
+    ///
+    /// Returns the path of the Leo file that the program corresponding to the file name was read from.
+    ///
+    pub fn get_import_file(&self, file_name: &str) -> Option<&PathBuf> {
+        self.import_files.get(file_name)
+    }
+
     ///
     /// Returns a reference to the core package corresponding to the given package.
     ///
@@ -75,6 +101,13 @@ impl ImportParser {
         self.core_packages.iter().find(|core_package| core_package.eq(&package))
     }
 
+    ///
+    /// Returns the paths of every Leo file read while resolving imports, in resolution order.
+    ///
+    pub fn source_files(&self) -> impl Iterator<Item = &PathBuf> {
+        self.source_files.iter()
+    }
+
     ///
     /// Returns a new `ImportParser` from a given `Program`.
     ///
@@ -84,11 +117,20 @@ impl ImportParser {
     ///     3. Insert the Leo syntax tree into the `ImportParser`
     ///
     pub fn parse(program: &Program) -> Result<Self, ImportParserError> {
-        let mut imports = Self::default();
-
         // Find all imports relative to current directory.
         let path = current_dir().map_err(ImportParserError::current_directory_error)?;
 
+        Self::parse_from_directory(program, path)
+    }
+
+    ///
+    /// Returns a new `ImportParser` from a given `Program` and package directory.
+    ///
+    /// Imported packages are found in the `src` and `imports` directories of the given package directory.
+    ///
+    pub fn parse_from_directory(program: &Program, path: PathBuf) -> Result<Self, ImportParserError> {
+        let mut imports = Self::default();
+
         // Parse each import statement.
         for import in &program.imports {
             imports.parse_package(path.clone(), &import.package)?;
@@ -27,8 +27,9 @@ static FILE_EXTENSION: &str = "leo";
 /// Returns a Leo syntax tree from a given package.
 ///
 /// Builds an abstract syntax tree from the given file and then builds the Leo syntax tree.
+/// Also returns the path of the file that was read.
 ///
-fn parse_import_file(package: &DirEntry, span: &Span) -> Result<Program, ImportParserError> {
+fn parse_import_file(package: &DirEntry, span: &Span) -> Result<(Program, PathBuf), ImportParserError> {
     // Get the package file type.
     let file_type = package
         .file_type()
@@ -55,7 +56,9 @@ fn parse_import_file(package: &DirEntry, span: &Span) -> Result<Program, ImportP
     let ast = &Grammar::new(&file_path, &program_string)?;
 
     // Build the package Leo syntax tree from the package abstract syntax tree.
-    Ok(Program::from(&file_name, ast.as_repr()))
+    let program = Program::from(&file_name, ast.as_repr());
+
+    Ok((program, file_path))
 }
 
 impl ImportParser {
@@ -102,7 +105,10 @@ impl ImportParser {
     ///
     pub fn parse_import_package(&mut self, package: &DirEntry, span: &Span) -> Result<(), ImportParserError> {
         // Get the package Leo syntax tree.
-        let program = parse_import_file(package, span)?;
+        let (program, file_path) = parse_import_file(package, span)?;
+
+        // Record the file so that build fingerprints cover the whole import graph.
+        self.insert_source_file(file_path.clone());
 
         // Insert the package's imports into the import parser.
         for import in &program.imports {
@@ -119,7 +125,7 @@ impl ImportParser {
             .unwrap(); // the file exists so these will not fail
 
         // Attempt to insert the Leo syntax tree for the imported package.
-        self.insert_import(file_name, program);
+        self.insert_import(file_name, program, file_path);
 
         Ok(())
     }
@@ -20,7 +20,7 @@ use crate::{
     expressions::{ArrayInlineExpression, Expression},
     sections::Header,
     tables::Table,
-    types::{DataType, Type},
+    types::{ArrayType, DataType, Type},
     values::{NumberValue, Value},
 };
 
@@ -120,6 +120,15 @@ impl InputParserError {
         Self::new_from_span(message, span)
     }
 
+    pub fn string_type(array_type: ArrayType, length: usize, span: Span) -> Self {
+        let message = format!(
+            "expected type `{}`, found a string literal of type `[u8; {}]`",
+            array_type, length
+        );
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn array_init_length(expected: Vec<usize>, actual: Vec<usize>, span: Span) -> Self {
         let message = format!(
             "expected an array with a fixed size of {:?} elements, found one with {:?} elements",
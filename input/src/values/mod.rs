@@ -26,6 +26,12 @@ pub use address_value::*;
 pub mod boolean_value;
 pub use boolean_value::*;
 
+pub mod byte_string_value;
+pub use byte_string_value::*;
+
+pub mod char_value;
+pub use char_value::*;
+
 pub mod field_value;
 pub use field_value::*;
 
@@ -50,6 +56,12 @@ pub use positive_number::*;
 pub mod signed_integer_value;
 pub use signed_integer_value::*;
 
+pub mod string_character;
+pub use string_character::*;
+
+pub mod string_value;
+pub use string_value::*;
+
 pub mod value;
 pub use value::*;
 
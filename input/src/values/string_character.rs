@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ast::{span_into_string, Rule};
+
+use pest::Span;
+use pest_ast::FromPest;
+use std::fmt;
+
+/// A single character in a string literal, which may be an escape sequence.
+#[derive(Clone, Debug, FromPest, PartialEq, Eq)]
+#[pest_ast(rule(Rule::string_character))]
+pub struct StringCharacter<'ast> {
+    #[pest_ast(outer(with(span_into_string)))]
+    pub value: String,
+    #[pest_ast(outer())]
+    pub span: Span<'ast>,
+}
+
+impl<'ast> StringCharacter<'ast> {
+    ///
+    /// Returns the utf-8 encoding of the character.
+    ///
+    pub fn bytes(&self) -> Vec<u8> {
+        // The grammar only accepts escape sequences of valid unicode scalar values.
+        let character = std::char::from_u32(unescape(&self.value)).unwrap_or(std::char::REPLACEMENT_CHARACTER);
+
+        character.to_string().into_bytes()
+    }
+}
+
+impl<'ast> fmt::Display for StringCharacter<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// A single character in a byte string literal, which may be an escape sequence.
+#[derive(Clone, Debug, FromPest, PartialEq, Eq)]
+#[pest_ast(rule(Rule::byte_string_character))]
+pub struct ByteStringCharacter<'ast> {
+    #[pest_ast(outer(with(span_into_string)))]
+    pub value: String,
+    #[pest_ast(outer())]
+    pub span: Span<'ast>,
+}
+
+impl<'ast> ByteStringCharacter<'ast> {
+    ///
+    /// Returns the byte value of the character.
+    ///
+    pub fn byte(&self) -> u8 {
+        // The grammar only accepts ascii characters and two digit hex escape sequences.
+        unescape(&self.value) as u8
+    }
+}
+
+impl<'ast> fmt::Display for ByteStringCharacter<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// A character literal, which may be an escape sequence.
+#[derive(Clone, Debug, FromPest, PartialEq, Eq)]
+#[pest_ast(rule(Rule::char_character))]
+pub struct CharCharacter<'ast> {
+    #[pest_ast(outer(with(span_into_string)))]
+    pub value: String,
+    #[pest_ast(outer())]
+    pub span: Span<'ast>,
+}
+
+impl<'ast> CharCharacter<'ast> {
+    ///
+    /// Returns the unicode scalar value of the character.
+    ///
+    pub fn character(&self) -> char {
+        // The grammar only accepts escape sequences of valid unicode scalar values.
+        std::char::from_u32(unescape(&self.value)).unwrap_or(std::char::REPLACEMENT_CHARACTER)
+    }
+}
+
+impl<'ast> fmt::Display for CharCharacter<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+///
+/// Returns the code point of a character or escape sequence.
+///
+fn unescape(value: &str) -> u32 {
+    let mut characters = value.chars();
+
+    match (characters.next(), characters.as_str()) {
+        (Some('\\'), "n") => '\n' as u32,
+        (Some('\\'), "r") => '\r' as u32,
+        (Some('\\'), "t") => '\t' as u32,
+        (Some('\\'), "0") => 0,
+        (Some('\\'), escape) if escape.starts_with('x') => u32::from_str_radix(&escape[1..], 16).unwrap_or(0),
+        (Some('\\'), escape) if escape.starts_with("u{") => {
+            u32::from_str_radix(&escape[2..escape.len() - 1], 16).unwrap_or(0)
+        }
+        (Some('\\'), escape) => escape.chars().next().map(|character| character as u32).unwrap_or(0),
+        (Some(character), _) => character as u32,
+        (None, _) => 0,
+    }
+}
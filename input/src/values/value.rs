@@ -16,7 +16,16 @@
 
 use crate::{
     ast::Rule,
-    values::{BooleanValue, FieldValue, GroupValue, IntegerValue, NumberValue},
+    values::{
+        BooleanValue,
+        ByteStringValue,
+        CharValue,
+        FieldValue,
+        GroupValue,
+        IntegerValue,
+        NumberValue,
+        StringValue,
+    },
 };
 
 use crate::values::AddressValue;
@@ -29,10 +38,13 @@ use std::fmt;
 pub enum Value<'ast> {
     Address(AddressValue<'ast>),
     Boolean(BooleanValue<'ast>),
+    ByteString(ByteStringValue<'ast>),
+    Char(CharValue<'ast>),
     Field(FieldValue<'ast>),
     Group(GroupValue<'ast>),
     Implicit(NumberValue<'ast>),
     Integer(IntegerValue<'ast>),
+    String(StringValue<'ast>),
 }
 
 impl<'ast> Value<'ast> {
@@ -40,10 +52,13 @@ impl<'ast> Value<'ast> {
         match self {
             Value::Address(value) => &value.span(),
             Value::Boolean(value) => &value.span,
+            Value::ByteString(value) => &value.span,
+            Value::Char(value) => &value.span,
             Value::Field(value) => &value.span,
             Value::Group(value) => &value.span,
             Value::Implicit(value) => &value.span(),
             Value::Integer(value) => &value.span(),
+            Value::String(value) => &value.span,
         }
     }
 }
@@ -53,10 +68,13 @@ impl<'ast> fmt::Display for Value<'ast> {
         match *self {
             Value::Address(ref value) => write!(f, "{}", value),
             Value::Boolean(ref value) => write!(f, "{}", value),
+            Value::ByteString(ref value) => write!(f, "{}", value),
+            Value::Char(ref value) => write!(f, "{}", value),
             Value::Field(ref value) => write!(f, "{}", value),
             Value::Group(ref value) => write!(f, "{}", value),
             Value::Implicit(ref value) => write!(f, "{}", value),
             Value::Integer(ref value) => write!(f, "{}", value),
+            Value::String(ref value) => write!(f, "{}", value),
         }
     }
 }
@@ -0,0 +1,290 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{error_diagnostic, file_diagnostic, import_diagnostic, parser_diagnostic, range_contains, span_range};
+use leo_ast::{Circuit, CircuitMember, Function, Identifier, Input, Program};
+use leo_grammar::Grammar;
+use leo_imports::ImportParser;
+use leo_package::root::MANIFEST_FILENAME;
+use leo_symbol_table::{ImportedSymbols, SymbolTable};
+use leo_type_inference::{IdentifierUse, TypeInference, TypedIdentifier};
+
+use lsp_types::{Diagnostic, Position};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+/// A top-level definition in a program or one of its imported programs.
+pub struct Definition<'a> {
+    /// The path of the file that contains the definition.
+    pub path: &'a Path,
+
+    /// The program that contains the definition.
+    pub program: &'a Program,
+
+    /// The name of the circuit, enum, constant, or function.
+    pub identifier: &'a Identifier,
+}
+
+/// The result of parsing, resolving, and type checking a Leo file.
+///
+/// Each stage runs only if the previous stage succeeded. Every error reported by the failing
+/// stage is recorded as a diagnostic.
+#[derive(Clone)]
+pub struct Analysis {
+    /// The path of the analyzed file.
+    pub path: PathBuf,
+
+    /// The program, if the file could be parsed.
+    pub program: Option<Program>,
+
+    /// The programs imported by the file.
+    pub imports: ImportParser,
+
+    /// The symbol table, if the program and its imports could be resolved.
+    pub symbol_table: Option<SymbolTable>,
+
+    /// The types of the variables and circuit members in the program.
+    pub typed_identifiers: Vec<TypedIdentifier>,
+
+    /// The errors found in the file.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Analysis {
+    ///
+    /// Returns a new `Analysis` of the given Leo source read from the given path.
+    ///
+    pub fn new(path: PathBuf, source: &str) -> Self {
+        let mut analysis = Self {
+            path,
+            program: None,
+            imports: ImportParser::default(),
+            symbol_table: None,
+            typed_identifiers: Vec::new(),
+            diagnostics: Vec::new(),
+        };
+
+        // A bug in a compiler pass should not take down the editor session.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| analysis.analyze(source)));
+
+        match result {
+            Ok(Ok(())) => {}
+            Ok(Err(diagnostics)) => analysis.diagnostics.extend(diagnostics),
+            Err(_) => analysis.diagnostics.push(file_diagnostic(
+                "The compiler failed unexpectedly while checking this file",
+            )),
+        }
+
+        analysis
+    }
+
+    fn analyze(&mut self, source: &str) -> Result<(), Vec<Diagnostic>> {
+        // Parse the program.
+        let grammar = Grammar::new(&self.path, source).map_err(|error| vec![parser_diagnostic(&error)])?;
+        self.program = Some(Program::from(&program_name(&self.path), grammar.as_repr()));
+        let program = self.program.as_ref().unwrap();
+
+        // Parse the imported packages relative to the package directory.
+        self.imports = ImportParser::parse_from_directory(program, package_directory(&self.path))
+            .map_err(|error| vec![import_diagnostic(&error, program)])?;
+
+        // Resolve the names and types defined by the program and its imports.
+        let symbol_table = SymbolTable::new(program, &self.imports, &Input::new())
+            .map_err(|error| error.into_errors().iter().map(|error| error_diagnostic(error)).collect::<Vec<_>>())?;
+        self.symbol_table = Some(symbol_table.clone());
+
+        // Infer the types of variables and circuit members.
+        self.typed_identifiers = TypeInference::typed_identifiers(program, symbol_table.clone());
+
+        TypeInference::new(program, symbol_table)
+            .map_err(|error| error.into_errors().iter().map(|error| error_diagnostic(error)).collect())
+    }
+
+    ///
+    /// Returns the typed identifier at the given position.
+    ///
+    pub fn typed_identifier_at(&self, position: Position) -> Option<&TypedIdentifier> {
+        self.typed_identifiers
+            .iter()
+            .find(|typed_identifier| range_contains(&span_range(&typed_identifier.identifier.span), position))
+    }
+
+    ///
+    /// Returns the definition of the variable that the given typed identifier refers to.
+    ///
+    /// Variables cannot be shadowed, so this is the closest preceding definition with the same name
+    /// in the enclosing function.
+    ///
+    pub fn variable_definition(&self, reference: &TypedIdentifier) -> Option<&Identifier> {
+        let position = span_range(&reference.identifier.span).start;
+        let function_start = self
+            .enclosing_function(position)
+            .map(|function| span_range(&function.span).start)
+            .unwrap_or_default();
+
+        self.typed_identifiers
+            .iter()
+            .filter(|typed_identifier| {
+                typed_identifier.use_ == IdentifierUse::Definition
+                    && typed_identifier.identifier.name == reference.identifier.name
+            })
+            .map(|typed_identifier| &typed_identifier.identifier)
+            .filter(|identifier| {
+                let start = span_range(&identifier.span).start;
+
+                function_start <= start && start <= position
+            })
+            .max_by_key(|identifier| (identifier.span.line, identifier.span.start))
+    }
+
+    ///
+    /// Returns the function or circuit function that encloses the given position.
+    ///
+    /// Functions do not nest, so this is the function that starts closest before the position.
+    ///
+    pub fn enclosing_function(&self, position: Position) -> Option<&Function> {
+        let program = self.program.as_ref()?;
+
+        let circuit_functions = program.circuits.values().flat_map(circuit_functions);
+
+        program
+            .functions
+            .values()
+            .chain(circuit_functions)
+            .filter(|function| span_range(&function.span).start <= position)
+            .max_by_key(|function| (function.span.line, function.span.start))
+    }
+
+    ///
+    /// Returns the circuit whose member function encloses the given position.
+    ///
+    pub fn enclosing_circuit(&self, position: Position) -> Option<&Circuit> {
+        let program = self.program.as_ref()?;
+        let function = self.enclosing_function(position)?;
+
+        program
+            .circuits
+            .values()
+            .find(|circuit| circuit_functions(circuit).any(|member| member.span == function.span))
+    }
+
+    ///
+    /// Returns the top-level definition with the given name in the program or its imports.
+    ///
+    pub fn resolve(&self, name: &str) -> Option<Definition<'_>> {
+        let program = self.program.as_ref()?;
+
+        if let Some(identifier) = top_level_identifier(program, name) {
+            return Some(Definition {
+                path: &self.path,
+                program,
+                identifier,
+            });
+        }
+
+        program
+            .imports
+            .iter()
+            .flat_map(|import| ImportedSymbols::new(import).symbols)
+            .find_map(|(package, symbol)| {
+                // Find the name of the symbol in the imported program.
+                let imported_name = if symbol.is_star() {
+                    name.to_string()
+                } else if symbol.alias.as_ref().unwrap_or(&symbol.symbol).name == name {
+                    symbol.symbol.name
+                } else {
+                    return None;
+                };
+
+                let imported = self.imports.get_import(&package)?;
+                let path = self.imports.get_import_file(&package)?;
+                let identifier = top_level_identifier(imported, &imported_name)?;
+
+                Some(Definition {
+                    path,
+                    program: imported,
+                    identifier,
+                })
+            })
+    }
+
+    ///
+    /// Returns the path and name of the given member of the circuit with the given name.
+    ///
+    pub fn resolve_circuit_member(&self, circuit: &str, member: &str) -> Option<(&Path, &Identifier)> {
+        let definition = self.resolve(circuit)?;
+        let circuit = definition.program.circuits.get(definition.identifier)?;
+
+        circuit
+            .members
+            .iter()
+            .map(|circuit_member| match circuit_member {
+                CircuitMember::CircuitVariable(identifier, _) => identifier,
+                CircuitMember::CircuitFunction(function) => &function.identifier,
+            })
+            .find(|identifier| identifier.name == member)
+            .map(|identifier| (definition.path, identifier))
+    }
+}
+
+///
+/// Returns the member functions of a circuit.
+///
+fn circuit_functions(circuit: &Circuit) -> impl Iterator<Item = &Function> {
+    circuit.members.iter().filter_map(|member| match member {
+        CircuitMember::CircuitFunction(function) => Some(function),
+        CircuitMember::CircuitVariable(_, _) => None,
+    })
+}
+
+///
+/// Returns the name of the circuit, enum, constant, or function with the given name in a program.
+///
+fn top_level_identifier<'a>(program: &'a Program, name: &str) -> Option<&'a Identifier> {
+    program
+        .circuits
+        .keys()
+        .chain(program.enums.keys())
+        .chain(program.constants.iter().map(|constant| &constant.identifier))
+        .chain(program.functions.keys())
+        .find(|identifier| identifier.name == name)
+}
+
+///
+/// Returns the program name of a Leo file.
+///
+fn program_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+///
+/// Returns the package directory of a Leo file.
+///
+/// This is the closest ancestor with a Leo manifest, or the parent directory of the file.
+///
+fn package_directory(path: &Path) -> PathBuf {
+    let parent = path.parent().unwrap_or(path);
+
+    parent
+        .ancestors()
+        .find(|directory| directory.join(MANIFEST_FILENAME).exists())
+        .unwrap_or(parent)
+        .to_path_buf()
+}
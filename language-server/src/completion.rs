@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{function_signature, is_identifier_character, span_range, Analysis};
+use leo_symbol_table::{SymbolTable, Type};
+
+use lsp_types::{CompletionItem, CompletionItemKind, Position};
+
+///
+/// Returns the completions for a circuit member access or static function access before the given position.
+///
+/// Circuit variables and member functions complete after `.`, and static functions and enum
+/// variants complete after `::`. Core package circuits are in the symbol table, so their
+/// functions complete like those of any other circuit.
+///
+pub fn completion(analysis: &Analysis, source: &str, position: Position) -> Vec<CompletionItem> {
+    let table = match analysis.symbol_table.as_ref() {
+        Some(table) => table,
+        None => return Vec::new(),
+    };
+
+    let line = source.lines().nth(position.line as usize).unwrap_or_default();
+    let before = line.chars().take(position.character as usize).collect::<Vec<_>>();
+
+    // Skip the partially typed member name.
+    let mut end = before.len();
+    while end > 0 && is_identifier_character(before[end - 1]) {
+        end -= 1;
+    }
+
+    let (is_static, receiver_end) = if before[..end].ends_with(&['.']) {
+        (false, end - 1)
+    } else if before[..end].ends_with(&[':', ':']) {
+        (true, end - 2)
+    } else {
+        return Vec::new();
+    };
+
+    let mut receiver_start = receiver_end;
+    while receiver_start > 0 && is_identifier_character(before[receiver_start - 1]) {
+        receiver_start -= 1;
+    }
+
+    let receiver = before[receiver_start..receiver_end].iter().collect::<String>();
+
+    if is_static {
+        static_members(analysis, table, &receiver, position)
+    } else {
+        instance_members(analysis, table, &receiver, position)
+    }
+}
+
+///
+/// Returns the static functions of a circuit or the variants of an enum.
+///
+fn static_members(analysis: &Analysis, table: &SymbolTable, receiver: &str, position: Position) -> Vec<CompletionItem> {
+    let name = match receiver {
+        "Self" => match analysis.enclosing_circuit(position) {
+            Some(circuit) => circuit.circuit_name.name.clone(),
+            None => return Vec::new(),
+        },
+        name => name.to_string(),
+    };
+
+    if let Some(enum_type) = table.get_enum_type(&name) {
+        return enum_type
+            .variants
+            .iter()
+            .map(|variant| {
+                let detail = variant
+                    .payload
+                    .as_ref()
+                    .map(|payload| format!("{}({})", variant.identifier, payload));
+
+                new_item(variant.identifier.to_string(), CompletionItemKind::ENUM_MEMBER, detail)
+            })
+            .collect();
+    }
+
+    match table.get_circuit_type(&name) {
+        Some(circuit_type) => circuit_type
+            .functions
+            .iter()
+            .filter(|function| !function.contains_self())
+            .map(|function| {
+                let detail = Some(function_signature(function));
+
+                new_item(function.identifier.to_string(), CompletionItemKind::FUNCTION, detail)
+            })
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+///
+/// Returns the variables and member functions of the circuit type of a variable.
+///
+fn instance_members(
+    analysis: &Analysis,
+    table: &SymbolTable,
+    receiver: &str,
+    position: Position,
+) -> Vec<CompletionItem> {
+    let name = match receiver {
+        "self" => match analysis.enclosing_circuit(position) {
+            Some(circuit) => circuit.circuit_name.name.clone(),
+            None => return Vec::new(),
+        },
+        receiver => {
+            // Find the type of the closest preceding use of the variable.
+            let type_ = analysis
+                .typed_identifiers
+                .iter()
+                .filter(|typed_identifier| {
+                    typed_identifier.identifier.name == receiver
+                        && span_range(&typed_identifier.identifier.span).start <= position
+                })
+                .max_by_key(|typed_identifier| span_range(&typed_identifier.identifier.span).start)
+                .map(|typed_identifier| &typed_identifier.type_);
+
+            match type_ {
+                Some(Type::Circuit(identifier)) => identifier.name.clone(),
+                _ => return Vec::new(),
+            }
+        }
+    };
+
+    let circuit_type = match table.get_circuit_type(&name) {
+        Some(circuit_type) => circuit_type,
+        None => return Vec::new(),
+    };
+
+    let variables = circuit_type.variables.iter().map(|variable| {
+        let detail = Some(variable.type_.to_string());
+
+        new_item(variable.identifier.to_string(), CompletionItemKind::FIELD, detail)
+    });
+
+    let functions = circuit_type
+        .functions
+        .iter()
+        .filter(|function| function.contains_self())
+        .map(|function| {
+            let detail = Some(function_signature(function));
+
+            new_item(function.identifier.to_string(), CompletionItemKind::METHOD, detail)
+        });
+
+    variables.chain(functions).collect()
+}
+
+fn new_item(label: String, kind: CompletionItemKind, detail: Option<String>) -> CompletionItem {
+    CompletionItem {
+        label,
+        kind: Some(kind),
+        detail,
+        ..CompletionItem::default()
+    }
+}
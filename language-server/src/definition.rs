@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{identifier_at, span_range, Analysis};
+use leo_ast::Identifier;
+use leo_type_inference::IdentifierUse;
+
+use lsp_types::{Location, Position, Url};
+use std::path::Path;
+
+///
+/// Returns the location of the definition of the identifier at the given position.
+///
+/// Variables resolve to their definition in the enclosing function, circuit members to their
+/// circuit, and circuits, enums, constants, and functions to the program or imported package
+/// that defines them.
+///
+pub fn definition(analysis: &Analysis, source: &str, position: Position) -> Option<Location> {
+    if let Some(typed_identifier) = analysis.typed_identifier_at(position) {
+        match &typed_identifier.use_ {
+            IdentifierUse::Definition => return location(&analysis.path, &typed_identifier.identifier),
+            IdentifierUse::Reference => {
+                // A reference without a variable definition refers to a constant.
+                if let Some(identifier) = analysis.variable_definition(typed_identifier) {
+                    return location(&analysis.path, identifier);
+                }
+            }
+            IdentifierUse::CircuitMember(circuit) => {
+                let (path, identifier) =
+                    analysis.resolve_circuit_member(&circuit.name, &typed_identifier.identifier.name)?;
+
+                return location(path, identifier);
+            }
+        }
+    }
+
+    let (name, _range) = identifier_at(source, position)?;
+    let definition = analysis.resolve(&name)?;
+
+    location(definition.path, definition.identifier)
+}
+
+fn location(path: &Path, identifier: &Identifier) -> Option<Location> {
+    let uri = Url::from_file_path(path).ok()?;
+
+    Some(Location::new(uri, span_range(&identifier.span)))
+}
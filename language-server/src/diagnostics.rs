@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{error_range, span_range};
+use leo_ast::{formatted_source, Program};
+use leo_grammar::{ParserError, SyntaxError};
+use leo_imports::ImportParserError;
+
+use lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+use pest::error::LineColLocation;
+use std::error::Error;
+
+static DIAGNOSTIC_SOURCE: &str = "leo";
+
+///
+/// Returns a diagnostic from a given Leo parser error.
+///
+pub fn parser_diagnostic(error: &ParserError) -> Diagnostic {
+    match error {
+        ParserError::SyntaxError(SyntaxError::Error(error)) => {
+            let (start, end) = match error.line_col {
+                LineColLocation::Pos((line, column)) => ((line, column), (line, column + 1)),
+                LineColLocation::Span(start, end) => (start, end),
+            };
+
+            let range = Range::new(
+                Position::new(start.0 as u32 - 1, start.1 as u32 - 1),
+                Position::new(end.0 as u32 - 1, end.1 as u32 - 1),
+            );
+
+            // The message is the last line of the formatted pest error.
+            let formatted = error.to_string();
+            let message = formatted.lines().last().unwrap_or_default().trim_start();
+
+            new_diagnostic(range, message.trim_start_matches("= ").to_string())
+        }
+        error => new_diagnostic(Range::default(), error.to_string()),
+    }
+}
+
+///
+/// Returns a diagnostic from a given import parser error.
+///
+/// A syntax error in an imported file is reported on the import statements of the program.
+///
+pub fn import_diagnostic(error: &ImportParserError, program: &Program) -> Diagnostic {
+    match error {
+        ImportParserError::ParserError(error) => {
+            let mut diagnostic = parser_diagnostic(error);
+
+            diagnostic.range = program
+                .imports
+                .first()
+                .map(|import| span_range(&import.span))
+                .unwrap_or_default();
+            diagnostic.message = format!("Failed to parse an imported file: {}", diagnostic.message);
+
+            diagnostic
+        }
+        error => error_diagnostic(error),
+    }
+}
+
+///
+/// Returns a diagnostic from a given import, symbol table, or type inference error.
+///
+/// The location and message are taken from the formatted Leo error that caused the given error.
+///
+pub fn error_diagnostic(error: &(dyn Error + 'static)) -> Diagnostic {
+    match formatted_source(error) {
+        Some(formatted) => new_diagnostic(error_range(formatted), formatted.message.clone()),
+        None => new_diagnostic(Range::default(), error.to_string()),
+    }
+}
+
+///
+/// Returns a diagnostic at the start of the file for an error without a location.
+///
+pub fn file_diagnostic(message: &str) -> Diagnostic {
+    new_diagnostic(Range::default(), message.to_string())
+}
+
+fn new_diagnostic(range: Range, message: String) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some(DIAGNOSTIC_SOURCE.to_string()),
+        message,
+        ..Diagnostic::default()
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{identifier_at, range_contains, span_range, Analysis};
+use leo_ast::CircuitMember;
+use leo_symbol_table::{FunctionInputType, FunctionType, SymbolTable};
+use leo_type_inference::IdentifierUse;
+
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position, Range};
+
+///
+/// Returns the type of the variable, circuit member, or top-level definition at the given position.
+///
+pub fn hover(analysis: &Analysis, source: &str, position: Position) -> Option<Hover> {
+    let table = analysis.symbol_table.as_ref()?;
+    let (signature, range) = typed_identifier_signature(analysis, table, position)
+        .or_else(|| circuit_member_signature(analysis, table, position))
+        .or_else(|| {
+            let (name, range) = identifier_at(source, position)?;
+
+            Some((definition_signature(table, &name)?, range))
+        })?;
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!("```leo\n{}\n```", signature),
+        }),
+        range: Some(range),
+    })
+}
+
+///
+/// Returns the signature of a function type.
+///
+pub fn function_signature(function_type: &FunctionType) -> String {
+    named_function_signature(&function_type.identifier.name, function_type)
+}
+
+fn named_function_signature(name: &str, function_type: &FunctionType) -> String {
+    let inputs = function_type
+        .inputs
+        .iter()
+        .map(|input| match input {
+            FunctionInputType::InputKeyword(_) => "input".to_string(),
+            FunctionInputType::SelfKeyword(_) => "self".to_string(),
+            FunctionInputType::MutSelfKeyword(_) => "mut self".to_string(),
+            FunctionInputType::Variable(variable) => format!("{}: {}", variable.identifier, variable.type_),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("function {}({}) -> {}", name, inputs, function_type.output.type_)
+}
+
+///
+/// Returns the signature of a variable or circuit member access at the given position.
+///
+fn typed_identifier_signature(analysis: &Analysis, table: &SymbolTable, position: Position) -> Option<(String, Range)> {
+    let typed_identifier = analysis.typed_identifier_at(position)?;
+    let identifier = &typed_identifier.identifier;
+
+    let signature = match &typed_identifier.use_ {
+        IdentifierUse::CircuitMember(circuit) => member_signature(table, &circuit.name, &identifier.name),
+        IdentifierUse::Definition | IdentifierUse::Reference => None,
+    };
+
+    let signature = signature.unwrap_or_else(|| format!("{}: {}", identifier, typed_identifier.type_));
+
+    Some((signature, span_range(&identifier.span)))
+}
+
+///
+/// Returns the signature of a circuit member definition at the given position.
+///
+fn circuit_member_signature(analysis: &Analysis, table: &SymbolTable, position: Position) -> Option<(String, Range)> {
+    let program = analysis.program.as_ref()?;
+
+    program.circuits.values().find_map(|circuit| {
+        circuit.members.iter().find_map(|member| {
+            let identifier = match member {
+                CircuitMember::CircuitVariable(identifier, _) => identifier,
+                CircuitMember::CircuitFunction(function) => &function.identifier,
+            };
+            let range = span_range(&identifier.span);
+
+            if !range_contains(&range, position) {
+                return None;
+            }
+
+            Some((
+                member_signature(table, &circuit.circuit_name.name, &identifier.name)?,
+                range,
+            ))
+        })
+    })
+}
+
+///
+/// Returns the signature of the given member of the circuit with the given name.
+///
+fn member_signature(table: &SymbolTable, circuit: &str, member: &str) -> Option<String> {
+    let circuit_type = table.get_circuit_type(circuit)?;
+
+    if let Some(variable) = circuit_type
+        .variables
+        .iter()
+        .find(|variable| variable.identifier.name == member)
+    {
+        return Some(format!("{}.{}: {}", circuit, variable.identifier, variable.type_));
+    }
+
+    circuit_type
+        .functions
+        .iter()
+        .find(|function| function.identifier.name == member)
+        .map(|function| named_function_signature(&format!("{}::{}", circuit, function.identifier), function))
+}
+
+///
+/// Returns the signature of the circuit, enum, constant, or function with the given name.
+///
+fn definition_signature(table: &SymbolTable, name: &str) -> Option<String> {
+    if let Some(function_type) = table.get_function_type(name) {
+        return Some(function_signature(function_type));
+    }
+
+    if let Some(circuit_type) = table.get_circuit_type(name) {
+        return Some(format!("circuit {}", circuit_type.identifier));
+    }
+
+    if let Some(enum_type) = table.get_enum_type(name) {
+        return Some(format!("enum {}", enum_type.identifier));
+    }
+
+    table
+        .get_constant_type(name)
+        .map(|type_| format!("const {}: {}", name, type_))
+}
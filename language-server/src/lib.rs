@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Leo language server.
+//!
+//! This module contains the [`LanguageServer`] type, which speaks the language server protocol
+//! over stdio. Every open Leo file is parsed, resolved, and type checked on change to publish
+//! diagnostics and answer hover, go-to-definition, and completion requests.
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod analysis;
+pub use self::analysis::*;
+
+pub mod completion;
+pub use self::completion::*;
+
+pub mod definition;
+pub use self::definition::*;
+
+pub mod diagnostics;
+pub use self::diagnostics::*;
+
+pub mod errors;
+pub use self::errors::*;
+
+pub mod hover;
+pub use self::hover::*;
+
+pub mod position;
+pub use self::position::*;
+
+pub mod server;
+pub use self::server::*;
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Error as FormattedError, Span};
+
+use lsp_types::{Position, Range};
+
+///
+/// Returns the zero-based range of a Leo span.
+///
+/// Leo spans store one-based line and column numbers. A span that ends on a later line is
+/// clipped to the end of its first line.
+///
+pub fn span_range(span: &Span) -> Range {
+    line_range(span.line, span.start, span.end, &span.text)
+}
+
+///
+/// Returns the zero-based range of a formatted Leo error.
+///
+pub fn error_range(error: &FormattedError) -> Range {
+    line_range(error.line, error.start, error.end, &error.text)
+}
+
+///
+/// Returns `true` if the given position lies within the given range, including its end.
+///
+pub fn range_contains(range: &Range, position: Position) -> bool {
+    range.start <= position && position <= range.end
+}
+
+///
+/// Returns the identifier at the given position of a source string and its range.
+///
+pub fn identifier_at(source: &str, position: Position) -> Option<(String, Range)> {
+    let line = source.lines().nth(position.line as usize)?;
+    let characters = line.chars().collect::<Vec<_>>();
+    let column = (position.character as usize).min(characters.len());
+
+    let mut start = column;
+    while start > 0 && is_identifier_character(characters[start - 1]) {
+        start -= 1;
+    }
+
+    let mut end = column;
+    while end < characters.len() && is_identifier_character(characters[end]) {
+        end += 1;
+    }
+
+    if start == end {
+        return None;
+    }
+
+    let identifier = characters[start..end].iter().collect();
+    let range = Range::new(
+        Position::new(position.line, start as u32),
+        Position::new(position.line, end as u32),
+    );
+
+    Some((identifier, range))
+}
+
+///
+/// Returns `true` if the given character can be part of a Leo identifier.
+///
+pub fn is_identifier_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '_'
+}
+
+fn line_range(line: usize, start: usize, end: usize, text: &str) -> Range {
+    let line = line.saturating_sub(1) as u32;
+    let start = start.saturating_sub(1);
+
+    // The span text is the first line of the span with a leading space.
+    let end = if end > start + 1 {
+        end - 1
+    } else {
+        text.chars().count().saturating_sub(1).max(start)
+    };
+
+    Range::new(Position::new(line, start as u32), Position::new(line, end as u32))
+}
@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{completion, definition, hover, Analysis, LanguageServerError};
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument,
+        DidCloseTextDocument,
+        DidOpenTextDocument,
+        Notification as _,
+        PublishDiagnostics,
+    },
+    request::{Completion, GotoDefinition, HoverRequest, Request as _},
+    CompletionOptions,
+    CompletionParams,
+    CompletionResponse,
+    DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams,
+    GotoDefinitionParams,
+    GotoDefinitionResponse,
+    Hover,
+    HoverParams,
+    HoverProviderCapability,
+    OneOf,
+    PublishDiagnosticsParams,
+    ServerCapabilities,
+    TextDocumentSyncCapability,
+    TextDocumentSyncKind,
+    Url,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{collections::HashMap, path::PathBuf};
+
+/// An open Leo file.
+struct Document {
+    /// The current text of the file.
+    text: String,
+
+    /// The most recent analysis that produced a symbol table.
+    ///
+    /// Hover, go-to-definition, and completion fall back to this analysis while the current
+    /// text does not parse, for example in the middle of typing `point.`.
+    analysis: Option<Analysis>,
+}
+
+/// A language server for Leo files.
+///
+/// Every change to an open file is analyzed and the resulting diagnostics are published to the client.
+pub struct LanguageServer {
+    connection: Connection,
+    documents: HashMap<Url, Document>,
+}
+
+impl LanguageServer {
+    ///
+    /// Runs a new `LanguageServer` over stdio until the client shuts it down.
+    ///
+    pub fn run() -> Result<(), LanguageServerError> {
+        let (connection, io_threads) = Connection::stdio();
+
+        let capabilities = serde_json::to_value(Self::capabilities())?;
+        connection.initialize(capabilities)?;
+
+        let server = Self {
+            connection,
+            documents: HashMap::new(),
+        };
+        server.main_loop()?;
+
+        io_threads.join()?;
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the language features supported by the server.
+    ///
+    pub fn capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec![".".to_string(), ":".to_string()]),
+                ..CompletionOptions::default()
+            }),
+            ..ServerCapabilities::default()
+        }
+    }
+
+    fn main_loop(mut self) -> Result<(), LanguageServerError> {
+        let receiver = self.connection.receiver.clone();
+
+        for message in receiver {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Result<(), LanguageServerError> {
+        let response = match request.method.as_str() {
+            HoverRequest::METHOD => self.respond(request, |params: HoverParams| -> Option<Hover> {
+                let position = params.text_document_position_params;
+                let (document, analysis) = self.analysis(&position.text_document.uri)?;
+
+                hover(analysis, &document.text, position.position)
+            }),
+            GotoDefinition::METHOD => self.respond(
+                request,
+                |params: GotoDefinitionParams| -> Option<GotoDefinitionResponse> {
+                    let position = params.text_document_position_params;
+                    let (document, analysis) = self.analysis(&position.text_document.uri)?;
+
+                    definition(analysis, &document.text, position.position).map(GotoDefinitionResponse::Scalar)
+                },
+            ),
+            Completion::METHOD => self.respond(request, |params: CompletionParams| -> Option<CompletionResponse> {
+                let position = params.text_document_position;
+                let (document, analysis) = self.analysis(&position.text_document.uri)?;
+
+                Some(CompletionResponse::Array(completion(
+                    analysis,
+                    &document.text,
+                    position.position,
+                )))
+            }),
+            method => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unsupported request `{}`", method),
+            ),
+        };
+
+        self.send(Message::Response(response))
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<(), LanguageServerError> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = serde_json::from_value(notification.params)?;
+                let document = params.text_document;
+
+                self.update(document.uri, document.text, document.version)
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams = serde_json::from_value(notification.params)?;
+                let document = params.text_document;
+
+                // The server asks for full document sync, so the last change holds the whole text.
+                match params.content_changes.into_iter().last() {
+                    Some(change) => self.update(document.uri, change.text, document.version),
+                    None => Ok(()),
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = serde_json::from_value(notification.params)?;
+                let uri = params.text_document.uri;
+
+                self.documents.remove(&uri);
+
+                self.publish_diagnostics(uri, Vec::new(), None)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Analyzes the new text of a file and publishes its diagnostics.
+    ///
+    fn update(&mut self, uri: Url, text: String, version: i32) -> Result<(), LanguageServerError> {
+        let path = uri.to_file_path().unwrap_or_else(|_| PathBuf::from(uri.path()));
+        let analysis = Analysis::new(path, &text);
+        let diagnostics = analysis.diagnostics.clone();
+
+        let document = self.documents.entry(uri.clone()).or_insert(Document {
+            text: String::new(),
+            analysis: None,
+        });
+        document.text = text;
+
+        if analysis.symbol_table.is_some() {
+            document.analysis = Some(analysis);
+        }
+
+        self.publish_diagnostics(uri, diagnostics, Some(version))
+    }
+
+    ///
+    /// Returns an open file and its most recent analysis with a symbol table.
+    ///
+    fn analysis(&self, uri: &Url) -> Option<(&Document, &Analysis)> {
+        let document = self.documents.get(uri)?;
+
+        Some((document, document.analysis.as_ref()?))
+    }
+
+    fn respond<P: DeserializeOwned, R: Serialize>(&self, request: Request, handler: impl FnOnce(P) -> R) -> Response {
+        match serde_json::from_value(request.params) {
+            Ok(params) => Response::new_ok(request.id, handler(params)),
+            Err(error) => invalid_params(request.id, error),
+        }
+    }
+
+    fn publish_diagnostics(
+        &self,
+        uri: Url,
+        diagnostics: Vec<lsp_types::Diagnostic>,
+        version: Option<i32>,
+    ) -> Result<(), LanguageServerError> {
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, version);
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+
+        self.send(Message::Notification(notification))
+    }
+
+    fn send(&self, message: Message) -> Result<(), LanguageServerError> {
+        self.connection
+            .sender
+            .send(message)
+            .map_err(|_| LanguageServerError::Disconnected)
+    }
+}
+
+fn invalid_params(id: RequestId, error: serde_json::Error) -> Response {
+    Response::new_err(id, ErrorCode::InvalidParams as i32, error.to_string())
+}
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_language_server::{completion, definition, hover, Analysis};
+
+use lsp_types::{CompletionItem, HoverContents, Position, Range, Url};
+use std::{fs, path::PathBuf};
+
+const MAIN_FILE: &str = "tests/src/main.leo";
+const LIBRARY_FILE: &str = "tests/src/lib.leo";
+
+fn file_path(file: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(file)
+}
+
+/// Returns the analysis of the main file with the given text replaced.
+fn analyze_main(from: &str, to: &str) -> (Analysis, String) {
+    let source = fs::read_to_string(file_path(MAIN_FILE)).unwrap().replace(from, to);
+
+    (Analysis::new(file_path(MAIN_FILE), &source), source)
+}
+
+fn hover_value(analysis: &Analysis, source: &str, position: Position) -> String {
+    match hover(analysis, source, position).unwrap().contents {
+        HoverContents::Markup(markup) => markup.value,
+        contents => panic!("unexpected hover contents {:?}", contents),
+    }
+}
+
+fn labels(items: Vec<CompletionItem>) -> Vec<String> {
+    items.into_iter().map(|item| item.label).collect()
+}
+
+fn range(line: u32, start: u32, end: u32) -> Range {
+    Range::new(Position::new(line, start), Position::new(line, end))
+}
+
+#[test]
+fn test_no_diagnostics() {
+    let (analysis, _source) = analyze_main("", "");
+
+    assert!(analysis.diagnostics.is_empty());
+    assert!(analysis.symbol_table.is_some());
+}
+
+#[test]
+fn test_syntax_diagnostic() {
+    let (analysis, _source) = analyze_main("let total = p.sum();", "let total = ;");
+
+    assert_eq!(analysis.diagnostics.len(), 1);
+    assert_eq!(analysis.diagnostics[0].range.start, Position::new(5, 16));
+    assert!(analysis.symbol_table.is_none());
+}
+
+#[test]
+fn test_type_diagnostic() {
+    let (analysis, _source) = analyze_main("double(total)", "double(true)");
+
+    assert_eq!(analysis.diagnostics.len(), 1);
+    assert_eq!(analysis.diagnostics[0].range, range(6, 24, 30));
+    assert_eq!(
+        analysis.diagnostics[0].message,
+        "Mismatched types. Expected type `u32`, found type `bool`."
+    );
+}
+
+#[test]
+fn test_multiple_diagnostics() {
+    let (analysis, _source) = analyze_main(
+        "return b\n}",
+        "return true\n}\n\nfunction other() -> bool {\n    return 1u8\n}",
+    );
+
+    assert_eq!(analysis.diagnostics.len(), 2);
+    assert_eq!(analysis.diagnostics[0].range.start.line, 7);
+    assert_eq!(analysis.diagnostics[1].range.start.line, 11);
+}
+
+#[test]
+fn test_hover() {
+    let (analysis, source) = analyze_main("", "");
+
+    // A variable definition and a variable reference.
+    assert_eq!(
+        hover_value(&analysis, &source, Position::new(4, 8)),
+        "```leo\np: circuit Point\n```"
+    );
+    assert_eq!(
+        hover_value(&analysis, &source, Position::new(7, 11)),
+        "```leo\nb: u32\n```"
+    );
+
+    // A circuit variable and a circuit function.
+    assert_eq!(
+        hover_value(&analysis, &source, Position::new(6, 14)),
+        "```leo\nPoint.x: u32\n```"
+    );
+    assert_eq!(
+        hover_value(&analysis, &source, Position::new(4, 20)),
+        "```leo\nfunction Point::new(x: u32, y: u32) -> circuit Point\n```"
+    );
+
+    // An imported function.
+    assert_eq!(
+        hover_value(&analysis, &source, Position::new(6, 20)),
+        "```leo\nfunction double(a: u32) -> u32\n```"
+    );
+}
+
+#[test]
+fn test_definition() {
+    let (analysis, source) = analyze_main("", "");
+    let main_uri = Url::from_file_path(file_path(MAIN_FILE)).unwrap();
+    let library_uri = Url::from_file_path(file_path(LIBRARY_FILE)).unwrap();
+
+    // A variable resolves to its definition.
+    let location = definition(&analysis, &source, Position::new(7, 11)).unwrap();
+    assert_eq!(location.uri, main_uri);
+    assert_eq!(location.range, range(6, 8, 9));
+
+    // A circuit member resolves to the imported circuit.
+    let location = definition(&analysis, &source, Position::new(6, 14)).unwrap();
+    assert_eq!(location.uri, library_uri);
+    assert_eq!(location.range, range(1, 4, 5));
+
+    // An imported function resolves to the imported package.
+    let location = definition(&analysis, &source, Position::new(6, 20)).unwrap();
+    assert_eq!(location.uri, library_uri);
+    assert_eq!(location.range, range(13, 9, 15));
+}
+
+#[test]
+fn test_completion() {
+    let (analysis, source) = analyze_main("", "");
+
+    // Complete against the last analysis while the edited text does not parse.
+    let edited = source.replace("    return b\n", "    p.\n    Blake2s::\n    Point::\n");
+
+    let members = completion(&analysis, &edited, Position::new(7, 6));
+    assert_eq!(labels(members), vec!["x", "y", "sum"]);
+
+    let core_functions = completion(&analysis, &edited, Position::new(8, 13));
+    assert_eq!(labels(core_functions), vec!["hash"]);
+
+    let static_functions = completion(&analysis, &edited, Position::new(9, 11));
+    assert_eq!(labels(static_functions), vec!["new"]);
+}
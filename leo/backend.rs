@@ -0,0 +1,404 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The proof systems used to setup, prove, and verify a program.
+//!
+//! Key and proof files start with the byte of the backend that made them. Reading a file made by
+//! another backend than the selected one returns an error.
+
+use crate::errors::BackendError;
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+pub use leo_package::root::Backend;
+
+use snarkos_algorithms::snark::{
+    gm17::{self, GM17},
+    groth16::{self, Groth16},
+};
+use snarkos_curves::bls12_377::{Bls12_377, Fr};
+use snarkos_models::algorithms::SNARK;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use std::io::{self, Write};
+
+type Program = Compiler<Fr, EdwardsGroupType>;
+
+type Groth16Backend = Groth16<Bls12_377, Program, Vec<Fr>>;
+
+type GM17Backend = GM17<Bls12_377, Program, Vec<Fr>>;
+
+/// The stream of a seeded generator that samples the setup parameters.
+pub const SETUP_STREAM: u64 = 0;
+
+/// The stream of a seeded generator that samples the randomness of proofs.
+pub const PROVE_STREAM: u64 = 1;
+
+/// Returns a reproducible random number generator for the given seed.
+///
+/// Anyone who knows the seed can recompute the toxic waste of a setup and forge proofs,
+/// so seeded generators must only be used for testing.
+pub fn seeded_rng(seed: u64, stream: u64) -> ChaChaRng {
+    let mut rng = ChaChaRng::seed_from_u64(seed);
+    rng.set_stream(stream);
+    rng
+}
+
+/// Writes the byte of the given backend to the writer.
+fn write_backend<W: Write>(backend: Backend, mut writer: W) -> io::Result<()> {
+    writer.write_all(&[backend.id()])
+}
+
+/// Returns the remaining bytes of a file if it was made by the given backend.
+fn read_backend<'a>(name: &'static str, backend: Backend, bytes: &'a [u8]) -> Result<&'a [u8], BackendError> {
+    let (id, bytes) = bytes.split_first().ok_or(BackendError::Unknown(name))?;
+    let recorded = Backend::from_id(*id).ok_or(BackendError::Unknown(name))?;
+
+    if recorded != backend {
+        return Err(BackendError::Mismatch(name, recorded, backend));
+    }
+
+    Ok(bytes)
+}
+
+/// Returns the backend recorded in the bytes of a key or proof file.
+pub fn recorded_backend(bytes: &[u8]) -> Option<Backend> {
+    bytes.first().and_then(|id| Backend::from_id(*id))
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+pub enum ProvingKey {
+    Groth16(groth16::Parameters<Bls12_377>),
+    GM17(gm17::Parameters<Bls12_377>),
+}
+
+impl ProvingKey {
+    /// Runs the setup of the given backend for the program.
+    pub fn setup<R: Rng>(
+        backend: Backend,
+        program: Program,
+        rng: &mut R,
+    ) -> Result<(Self, PreparedVerifyingKey), BackendError> {
+        Ok(match backend {
+            Backend::Groth16 => {
+                let (proving_key, prepared_verifying_key) = Groth16Backend::setup(program, rng)?;
+
+                (
+                    ProvingKey::Groth16(proving_key),
+                    PreparedVerifyingKey::Groth16(prepared_verifying_key),
+                )
+            }
+            Backend::GM17 => {
+                let (proving_key, prepared_verifying_key) = GM17Backend::setup(program, rng)?;
+
+                (
+                    ProvingKey::GM17(proving_key),
+                    PreparedVerifyingKey::GM17(prepared_verifying_key),
+                )
+            }
+        })
+    }
+
+    pub fn backend(&self) -> Backend {
+        match self {
+            ProvingKey::Groth16(_) => Backend::Groth16,
+            ProvingKey::GM17(_) => Backend::GM17,
+        }
+    }
+
+    /// Produces a proof for the program with its input.
+    pub fn prove<R: Rng>(&self, program: Program, rng: &mut R) -> Result<Proof, BackendError> {
+        Ok(match self {
+            ProvingKey::Groth16(parameters) => Proof::Groth16(Groth16Backend::prove(parameters, program, rng)?),
+            ProvingKey::GM17(parameters) => Proof::GM17(GM17Backend::prove(parameters, program, rng)?),
+        })
+    }
+
+    /// Reads parameters generated outside of Leo, with or without the byte of their backend.
+    pub fn import(backend: Backend, bytes: &[u8]) -> Result<Self, BackendError> {
+        if recorded_backend(bytes) == Some(backend) {
+            if let Ok(proving_key) = Self::read(backend, bytes, true) {
+                return Ok(proving_key);
+            }
+        }
+
+        Ok(match backend {
+            Backend::Groth16 => ProvingKey::Groth16(groth16::Parameters::read(bytes, true)?),
+            Backend::GM17 => ProvingKey::GM17(gm17::Parameters::read(bytes, true)?),
+        })
+    }
+
+    pub fn verifying_key(&self) -> VerifyingKey {
+        match self {
+            ProvingKey::Groth16(parameters) => VerifyingKey::Groth16(parameters.vk.clone()),
+            ProvingKey::GM17(parameters) => VerifyingKey::GM17(parameters.vk.clone()),
+        }
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write_backend(self.backend(), &mut writer)?;
+
+        match self {
+            ProvingKey::Groth16(parameters) => parameters.write(writer),
+            ProvingKey::GM17(parameters) => parameters.write(writer),
+        }
+    }
+
+    pub fn read(backend: Backend, bytes: &[u8], checked: bool) -> Result<Self, BackendError> {
+        let bytes = read_backend("proving key", backend, bytes)?;
+
+        Ok(match backend {
+            Backend::Groth16 => ProvingKey::Groth16(groth16::Parameters::read(bytes, checked)?),
+            Backend::GM17 => ProvingKey::GM17(gm17::Parameters::read(bytes, checked)?),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub enum VerifyingKey {
+    Groth16(groth16::VerifyingKey<Bls12_377>),
+    GM17(gm17::VerifyingKey<Bls12_377>),
+}
+
+impl VerifyingKey {
+    pub fn backend(&self) -> Backend {
+        match self {
+            VerifyingKey::Groth16(_) => Backend::Groth16,
+            VerifyingKey::GM17(_) => Backend::GM17,
+        }
+    }
+
+    /// Returns the number of public inputs the key verifies.
+    pub fn num_public_inputs(&self) -> usize {
+        // Both keys hold one more element than the number of public inputs for the constant one
+        match self {
+            VerifyingKey::Groth16(verifying_key) => verifying_key.gamma_abc_g1.len() - 1,
+            VerifyingKey::GM17(verifying_key) => verifying_key.query.len() - 1,
+        }
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write_backend(self.backend(), &mut writer)?;
+
+        match self {
+            VerifyingKey::Groth16(verifying_key) => verifying_key.write(writer),
+            VerifyingKey::GM17(verifying_key) => verifying_key.write(writer),
+        }
+    }
+
+    pub fn read(backend: Backend, bytes: &[u8]) -> Result<Self, BackendError> {
+        let bytes = read_backend("verification key", backend, bytes)?;
+
+        Ok(match backend {
+            Backend::Groth16 => VerifyingKey::Groth16(groth16::VerifyingKey::read(bytes)?),
+            Backend::GM17 => VerifyingKey::GM17(gm17::VerifyingKey::read(bytes)?),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub enum PreparedVerifyingKey {
+    Groth16(groth16::PreparedVerifyingKey<Bls12_377>),
+    GM17(gm17::PreparedVerifyingKey<Bls12_377>),
+}
+
+impl PreparedVerifyingKey {
+    pub fn backend(&self) -> Backend {
+        match self {
+            PreparedVerifyingKey::Groth16(_) => Backend::Groth16,
+            PreparedVerifyingKey::GM17(_) => Backend::GM17,
+        }
+    }
+
+    /// Verifies the proof against the public inputs in the order of the verifying key.
+    pub fn verify(&self, public_inputs: &[Fr], proof: &Proof) -> Result<bool, BackendError> {
+        let public_inputs = public_inputs.to_vec();
+
+        match (self, proof) {
+            (PreparedVerifyingKey::Groth16(verifying_key), Proof::Groth16(proof)) => {
+                Ok(Groth16Backend::verify(verifying_key, &public_inputs, proof)?)
+            }
+            (PreparedVerifyingKey::GM17(verifying_key), Proof::GM17(proof)) => {
+                Ok(GM17Backend::verify(verifying_key, &public_inputs, proof)?)
+            }
+            (verifying_key, proof) => Err(BackendError::Mismatch("proof", proof.backend(), verifying_key.backend())),
+        }
+    }
+}
+
+impl From<VerifyingKey> for PreparedVerifyingKey {
+    fn from(verifying_key: VerifyingKey) -> Self {
+        match verifying_key {
+            VerifyingKey::Groth16(verifying_key) => PreparedVerifyingKey::Groth16(verifying_key.into()),
+            VerifyingKey::GM17(verifying_key) => PreparedVerifyingKey::GM17(verifying_key.into()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Proof {
+    Groth16(groth16::Proof<Bls12_377>),
+    GM17(gm17::Proof<Bls12_377>),
+}
+
+impl Proof {
+    pub fn backend(&self) -> Backend {
+        match self {
+            Proof::Groth16(_) => Backend::Groth16,
+            Proof::GM17(_) => Backend::GM17,
+        }
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write_backend(self.backend(), &mut writer)?;
+
+        match self {
+            Proof::Groth16(proof) => proof.write(writer),
+            Proof::GM17(proof) => proof.write(writer),
+        }
+    }
+
+    pub fn read(backend: Backend, bytes: &[u8]) -> Result<Self, BackendError> {
+        let bytes = read_backend("proof", backend, bytes)?;
+
+        Ok(match backend {
+            Backend::Groth16 => Proof::Groth16(groth16::Proof::read(bytes)?),
+            Backend::GM17 => Proof::GM17(gm17::Proof::read(bytes)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synthesizer::CircuitSynthesizer;
+
+    use std::{env::temp_dir, path::PathBuf};
+
+    static PROGRAM: &str = "function main(a: bool) -> bool {\n    return !a\n}\n";
+    static INPUT: &str = "[main]\na: bool = true;\n\n[registers]\nr0: bool = false;\n";
+
+    static OTHER_PROGRAM: &str = "function main(a: bool, b: bool) -> bool {\n    return a && b\n}\n";
+    static OTHER_INPUT: &str = "[main]\na: bool = true;\nb: bool = false;\n\n[registers]\nr0: bool = false;\n";
+
+    fn parse_program(source: &str, input: &str) -> Program {
+        let output_file = temp_dir().join("leo-backend-test.out");
+        let mut program = Program::new("test".to_string(), PathBuf::from("main.leo"), output_file);
+
+        program.parse_input(input, &PathBuf::new(), "", &PathBuf::new()).unwrap();
+        program.parse_program_from_string(source).unwrap();
+
+        program
+    }
+
+    fn program() -> Program {
+        parse_program(PROGRAM, INPUT)
+    }
+
+    /// Returns the public inputs of the program in the order of the verifying key.
+    fn public_inputs() -> Vec<Fr> {
+        let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+        program().compile_constraints(&mut cs).unwrap();
+
+        cs.public_inputs().to_vec()
+    }
+
+    fn setup_and_prove(backend: Backend) -> (ProvingKey, PreparedVerifyingKey, Proof) {
+        let (proving_key, prepared_verifying_key) =
+            ProvingKey::setup(backend, program(), &mut seeded_rng(0, SETUP_STREAM)).unwrap();
+        let proof = proving_key.prove(program(), &mut seeded_rng(0, PROVE_STREAM)).unwrap();
+
+        (proving_key, prepared_verifying_key, proof)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let public_inputs = public_inputs();
+
+        for backend in &[Backend::Groth16, Backend::GM17] {
+            let (proving_key, _, proof) = setup_and_prove(*backend);
+
+            let mut verifying_key_bytes = vec![];
+            proving_key.verifying_key().write(&mut verifying_key_bytes).unwrap();
+            let mut proof_bytes = vec![];
+            proof.write(&mut proof_bytes).unwrap();
+
+            assert_eq!(recorded_backend(&verifying_key_bytes), Some(*backend));
+            assert_eq!(recorded_backend(&proof_bytes), Some(*backend));
+
+            let verifying_key = VerifyingKey::read(*backend, &verifying_key_bytes).unwrap();
+            let proof = Proof::read(*backend, &proof_bytes).unwrap();
+
+            assert_eq!(verifying_key.num_public_inputs(), public_inputs.len());
+            assert!(PreparedVerifyingKey::from(verifying_key).verify(&public_inputs, &proof).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_foreign_parameters() {
+        let public_inputs = public_inputs();
+
+        for backend in &[Backend::Groth16, Backend::GM17] {
+            // Parameters of another program with the same number of public inputs
+            let other_program = parse_program(OTHER_PROGRAM, OTHER_INPUT);
+            let (proving_key, prepared_verifying_key) =
+                ProvingKey::setup(*backend, other_program, &mut seeded_rng(0, SETUP_STREAM)).unwrap();
+            assert_eq!(proving_key.verifying_key().num_public_inputs(), public_inputs.len());
+
+            let proof = proving_key.prove(program(), &mut seeded_rng(0, PROVE_STREAM)).unwrap();
+            assert!(!prepared_verifying_key.verify(&public_inputs, &proof).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_backend_mismatch() {
+        let (proving_key, _, proof) = setup_and_prove(Backend::Groth16);
+        let (_, gm17_verifying_key, _) = setup_and_prove(Backend::GM17);
+
+        let mut proving_key_bytes = vec![];
+        proving_key.write(&mut proving_key_bytes).unwrap();
+        let mut proof_bytes = vec![];
+        proof.write(&mut proof_bytes).unwrap();
+
+        assert!(matches!(
+            ProvingKey::read(Backend::GM17, &proving_key_bytes, false),
+            Err(BackendError::Mismatch("proving key", Backend::Groth16, Backend::GM17))
+        ));
+        assert!(matches!(
+            Proof::read(Backend::GM17, &proof_bytes),
+            Err(BackendError::Mismatch("proof", Backend::Groth16, Backend::GM17))
+        ));
+        assert!(matches!(
+            gm17_verifying_key.verify(&public_inputs(), &proof),
+            Err(BackendError::Mismatch("proof", Backend::Groth16, Backend::GM17))
+        ));
+    }
+
+    #[test]
+    fn test_unknown_backend() {
+        assert_eq!(recorded_backend(&[]), None);
+        assert_eq!(recorded_backend(&[u8::MAX]), None);
+
+        assert!(matches!(
+            Proof::read(Backend::Groth16, &[]),
+            Err(BackendError::Unknown("proof"))
+        ));
+        assert!(matches!(
+            Proof::read(Backend::Groth16, &[u8::MAX]),
+            Err(BackendError::Unknown("proof"))
+        ));
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{cli_types::*, errors::CLIError, logger, updater::Updater};
+use crate::{cli_types::*, diagnostics::MessageFormat, errors::CLIError, logger, updater::Updater};
 
 use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
 
@@ -104,6 +104,11 @@ pub trait CLI {
 
     #[cfg_attr(tarpaulin, skip)]
     fn process(arguments: &ArgMatches) -> Result<(), CLIError> {
+        // Set the error message format before the logger picks its output stream.
+        if let Some(format) = arguments.value_of("message-format").and_then(|format| format.parse().ok()) {
+            MessageFormat::set(format);
+        }
+
         // Set logging environment
         match arguments.is_present("debug") {
             true => logger::init_logger("leo", 2),
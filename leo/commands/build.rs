@@ -18,12 +18,28 @@ use crate::{
     cli::*,
     cli_types::*,
     errors::CLIError,
-    synthesizer::{CircuitSynthesizer, SerializedCircuit},
+    synthesizer::{
+        underconstrained_warnings,
+        CircuitSynthesizer,
+        Compression,
+        ProfilingSynthesizer,
+        SerializationFormat,
+        SerializedCircuit,
+    },
 };
 use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
 use leo_package::{
     inputs::*,
-    outputs::{ChecksumFile, CircuitFile, OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
+    outputs::{
+        BuildManifestFile,
+        ChecksumFile,
+        CircuitFile,
+        ExportFile,
+        ExportFormat,
+        OutputsDirectory,
+        ProfileFile,
+        OUTPUTS_DIRECTORY_NAME,
+    },
     root::Manifest,
     source::{LibraryFile, MainFile, LIBRARY_FILENAME, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
@@ -32,29 +48,99 @@ use snarkos_curves::{bls12_377::Bls12_377, edwards_bls12::Fq};
 use snarkos_models::gadgets::r1cs::ConstraintSystem;
 
 use clap::ArgMatches;
-use std::{convert::TryFrom, env::current_dir, time::Instant};
+use std::{
+    convert::TryFrom,
+    env::current_dir,
+    io::{BufReader, BufWriter},
+    time::Instant,
+};
+
+/// Options for how the compiled circuit is written to the output directory.
+#[derive(Clone, Debug, Default)]
+pub struct BuildOptions {
+    pub format: SerializationFormat,
+    pub compression: Compression,
+    pub check: bool,
+    pub profile: bool,
+    pub folded: bool,
+    pub underconstrained: bool,
+    pub emit: Vec<ExportFormat>,
+}
 
 #[derive(Debug)]
 pub struct BuildCommand;
 
 impl CLI for BuildCommand {
-    type Options = ();
+    type Options = BuildOptions;
     type Output = Option<(Compiler<Fq, EdwardsGroupType>, bool)>;
 
     const ABOUT: AboutType = "Compile the current package as a program";
     const ARGUMENTS: &'static [ArgumentType] = &[];
-    const FLAGS: &'static [FlagType] = &[];
+    const FLAGS: &'static [FlagType] = &[
+        "[compress] --compress 'Compress the binary circuit file'",
+        "[check] --check 'Read back and deserialize the circuit file after writing it'",
+        "[profile] --profile 'Print the number of constraints and variables added by each function and statement'",
+        "[folded] --folded 'Write the constraint profile as folded stacks for flamegraphs'",
+        "[underconstrained] --underconstrained 'Warn about witnesses that the constraints do not pin down'",
+    ];
     const NAME: NameType = "build";
-    const OPTIONS: &'static [OptionType] = &[];
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[format] --format=<format> 'Specify the serialization format of the circuit file'",
+            &[],
+            SerializationFormat::NAMES,
+            &[],
+        ),
+        (
+            "[emit] --emit=<format>... 'Export the circuit to the given comma separated formats'",
+            &[],
+            ExportFormat::NAMES,
+            &[],
+        ),
+    ];
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        let format = arguments
+            .value_of("format")
+            .and_then(|format| format.parse().ok())
+            .unwrap_or_default();
+        let compression = if arguments.is_present("compress") {
+            Compression::Deflate
+        } else {
+            Compression::None
+        };
+
+        let emit = match arguments.values_of("emit") {
+            Some(formats) => formats.map(|format| format.parse()).collect::<Result<_, _>>()?,
+            None => vec![],
+        };
+
+        Ok(BuildOptions {
+            format,
+            compression,
+            check: arguments.is_present("check"),
+            profile: arguments.is_present("profile"),
+            folded: arguments.is_present("folded"),
+            underconstrained: arguments.is_present("underconstrained"),
+            emit,
+        })
     }
 
     #[cfg_attr(tarpaulin, skip)]
-    fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
+    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+        let BuildOptions {
+            format,
+            compression,
+            check,
+            profile,
+            folded,
+            underconstrained,
+            emit,
+        } = options;
+
         // Begin "Compiling" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Compiling");
         let enter = span.enter();
@@ -129,56 +215,135 @@ impl CLI for BuildCommand {
                 &state_path,
             )?;
 
-            // Compute the current program checksum
-            let program_checksum = program.checksum()?;
+            // Compute the checksums of the program, its imports and its input files
+            let build_manifest = program.fingerprint()?;
 
-            // Generate the program on the constraint system and verify correctness
-            {
-                let mut cs = CircuitSynthesizer::<Bls12_377> {
-                    at: vec![],
-                    bt: vec![],
-                    ct: vec![],
-                    input_assignment: vec![],
-                    aux_assignment: vec![],
-                };
-                let temporary_program = program.clone();
-                let output = temporary_program.compile_constraints(&mut cs)?;
+            // Compare against the manifest of the previous build, if there is a readable one
+            let build_manifest_file = BuildManifestFile::new(&package_name);
+            let previous_manifest = match build_manifest_file.exists_at(&package_path) {
+                true => build_manifest_file.read_from(&package_path).ok(),
+                false => None,
+            };
+            let checksum_differs = match &previous_manifest {
+                Some(previous_manifest) => {
+                    for change in build_manifest.changes_since(previous_manifest) {
+                        tracing::info!("Detected change - {}", change);
+                    }
+
+                    build_manifest.checksum != previous_manifest.checksum
+                }
+                // By default, the checksum differs if there is no manifest to compare against
+                None => true,
+            };
 
-                tracing::debug!("Compiled constraints - {:#?}", output);
-                tracing::debug!("Number of constraints - {:#?}", cs.num_constraints());
+            let circuit_file = CircuitFile::new(&package_name);
+            let circuit_exists = match format {
+                SerializationFormat::Json => circuit_file.exists_at(&package_path),
+                SerializationFormat::Binary => circuit_file.exists_at_binary(&package_path),
+            };
 
-                // Serialize the circuit
-                let circuit_object = SerializedCircuit::from(cs);
-                let json = circuit_object.to_json_string().unwrap();
-                // println!("json: {}", json);
+            // Only the options below need the constraints, so an unchanged build can reuse the saved circuit
+            let synthesize = checksum_differs
+                || !circuit_exists
+                || check
+                || profile
+                || folded
+                || underconstrained
+                || !emit.is_empty();
 
-                // Write serialized circuit to circuit `.json` file.
-                let circuit_file = CircuitFile::new(&package_name);
-                circuit_file.write_to(&path, json)?;
+            // Generate the program on the constraint system and verify correctness
+            if synthesize {
+                let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+                let temporary_program = program.clone();
 
-                // Check that we can read the serialized circuit file
-                let serialized = circuit_file.read_from(&package_path)?;
+                let output = if profile || folded || underconstrained {
+                    // Record the constraints and variables added under each namespace, which also
+                    // locates the statements that allocated underconstrained witnesses
+                    let mut profiler = ProfilingSynthesizer::new(&mut cs);
+                    let output = temporary_program.compile_constraints(&mut profiler)?;
+                    let (_, constraint_profile) = profiler.into_parts();
+
+                    if profile {
+                        tracing::info!("Constraint profile\n\n{}\n", constraint_profile);
+                    }
+
+                    if folded {
+                        ProfileFile::new(&package_name).write_to(&path, constraint_profile.to_folded_stacks())?;
+
+                        tracing::info!("Folded constraint profile saved ({:?})", path);
+                    }
+
+                    // Warn about witnesses that the constraints do not pin down
+                    if underconstrained {
+                        let variables = cs.underconstrained_variables();
+                        for warning in underconstrained_warnings(&variables, &constraint_profile) {
+                            tracing::warn!("{}", warning);
+                        }
+                    }
+
+                    output
+                } else {
+                    temporary_program.compile_constraints(&mut cs)?
+                };
 
-                // Deserialize the circuit
-                let deserialized = SerializedCircuit::from_json_string(&serialized).unwrap();
-                let _circuit_synthesizer = CircuitSynthesizer::<Bls12_377>::try_from(deserialized).unwrap();
-                // println!("deserialized {:?}", circuit_synthesizer.num_constraints());
-            }
+                tracing::debug!("Compiled constraints - {:#?}", output);
+                tracing::debug!("Number of constraints - {:#?}", cs.num_constraints());
 
-            // If a checksum file exists, check if it differs from the new checksum
-            let checksum_file = ChecksumFile::new(&package_name);
-            let checksum_differs = if checksum_file.exists_at(&package_path) {
-                let previous_checksum = checksum_file.read_from(&package_path)?;
-                program_checksum != previous_checksum
+                // Export the circuit to the requested interchange formats
+                for export_format in &emit {
+                    let file = BufWriter::new(ExportFile::new(&package_name, *export_format).create(&path)?);
+                    match export_format {
+                        ExportFormat::R1cs => cs.write_r1cs(file)?,
+                        ExportFormat::Wtns => cs.write_wtns(file)?,
+                        ExportFormat::Zkif => cs.write_zkif(file)?,
+                    }
+
+                    tracing::info!("Exported {} circuit ({:?})", export_format, path);
+                }
+
+                match format {
+                    SerializationFormat::Json => {
+                        // Write serialized circuit to circuit `.json` file.
+                        let circuit_object = SerializedCircuit::from(cs);
+                        let json = circuit_object.to_json_string()?;
+                        circuit_file.write_to(&path, json)?;
+                    }
+                    SerializationFormat::Binary => {
+                        // Stream the circuit to the binary circuit file.
+                        let file = circuit_file.create_binary(&path)?;
+                        cs.write_binary(BufWriter::new(file), compression)?;
+                    }
+                }
+
+                tracing::debug!("Circuit saved ({} format)", format);
+
+                // Check that we can read and deserialize the circuit file
+                if check {
+                    let circuit_synthesizer = match format {
+                        SerializationFormat::Json => {
+                            let serialized = circuit_file.read_from(&package_path)?;
+                            let deserialized = SerializedCircuit::from_json_string(&serialized)?;
+                            CircuitSynthesizer::<Bls12_377>::try_from(deserialized)?
+                        }
+                        SerializationFormat::Binary => {
+                            let file = circuit_file.open_binary(&package_path)?;
+                            CircuitSynthesizer::<Bls12_377>::read_binary(BufReader::new(file))?
+                        }
+                    };
+
+                    tracing::debug!("Circuit checked - {} constraints", circuit_synthesizer.num_constraints());
+                }
             } else {
-                // By default, the checksum differs if there is no checksum to compare against
-                true
-            };
+                tracing::info!("No changes since the last build, reusing the saved circuit");
+            }
 
-            // If checksum differs, compile the program
+            // If checksum differs, save the new checksum and manifest
             if checksum_differs {
                 // Write the new checksum to the output directory
-                checksum_file.write_to(&path, program_checksum)?;
+                ChecksumFile::new(&package_name).write_to(&path, build_manifest.checksum.clone())?;
+
+                // Write the checksum of every file to the output directory
+                build_manifest_file.write_to(&path, &build_manifest)?;
 
                 tracing::debug!("Checksum saved ({:?})", path);
             }
@@ -16,7 +16,20 @@
 
 use crate::{cli::*, cli_types::*, errors::CLIError};
 use leo_package::{
-    outputs::{ChecksumFile, ProofFile, ProvingKeyFile, VerificationKeyFile},
+    outputs::{
+        BuildManifestFile,
+        ChecksumFile,
+        ExportFile,
+        ExportFormat,
+        ProfileFile,
+        ProofFile,
+        ProvingKeyFile,
+        PublicInputsFile,
+        SetupFile,
+        TranscriptFile,
+        VerificationKeyFile,
+        WitnessFile,
+    },
     root::Manifest,
 };
 
@@ -57,21 +70,42 @@ impl CLI for CleanCommand {
         // Remove the checksum from the output directory
         ChecksumFile::new(&package_name).remove(&path)?;
 
+        // Remove the build manifest from the output directory
+        BuildManifestFile::new(&package_name).remove(&path)?;
+
         // Remove the serialized circuit from the output directory
         CircuitFile::new(&package_name).remove(&path)?;
 
         // Remove the program output file from the output directory
         OutputFile::new(&package_name).remove(&path)?;
 
+        // Remove the serialized witness from the output directory
+        WitnessFile::new(&package_name).remove(&path)?;
+
+        // Remove the exported circuit files from the output directory
+        for format in ExportFormat::ALL {
+            ExportFile::new(&package_name, *format).remove(&path)?;
+        }
+
+        // Remove the folded constraint profile from the output directory
+        ProfileFile::new(&package_name).remove(&path)?;
+
         // Remove the proving key from the output directory
         ProvingKeyFile::new(&package_name).remove(&path)?;
 
         // Remove the verification key from the output directory
         VerificationKeyFile::new(&package_name).remove(&path)?;
 
+        // Remove the setup record and ceremony transcript from the output directory
+        SetupFile::new(&package_name).remove(&path)?;
+        TranscriptFile::new(&package_name).remove(&path)?;
+
         // Remove the proof from the output directory
         ProofFile::new(&package_name).remove(&path)?;
 
+        // Remove the public inputs from the output directory
+        PublicInputsFile::new(&package_name).remove(&path)?;
+
         // Drop "Compiling" context for console logging
         drop(enter);
 
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    cli::*,
+    cli_types::*,
+    errors::{BuildError, CLIError},
+    synthesizer::{Compression, SerializationFormat, SerializedWitness, WitnessSynthesizer},
+};
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType, OutputFile};
+use leo_package::{
+    inputs::*,
+    outputs::{OutputsDirectory, WitnessFile, OUTPUTS_DIRECTORY_NAME},
+    root::Manifest,
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+
+use snarkos_curves::{bls12_377::Bls12_377, edwards_bls12::Fq};
+use snarkos_models::gadgets::r1cs::ConstraintSystem;
+
+use clap::ArgMatches;
+use std::{convert::TryFrom, env::current_dir, io::BufWriter, time::Instant};
+
+#[derive(Debug)]
+pub struct ExecuteCommand;
+
+impl CLI for ExecuteCommand {
+    type Options = (SerializationFormat, Compression);
+    type Output = ();
+
+    const ABOUT: AboutType = "Solve the program witness without building constraints";
+    const ARGUMENTS: &'static [ArgumentType] = &[];
+    const FLAGS: &'static [FlagType] = &["[compress] --compress 'Compress the binary witness file'"];
+    const NAME: NameType = "execute";
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[format] --format=<format> 'Specify the serialization format of the witness file'",
+            &[],
+            SerializationFormat::NAMES,
+            &[],
+        ),
+    ];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        let format = arguments
+            .value_of("format")
+            .and_then(|format| format.parse().ok())
+            .unwrap_or_default();
+        let compression = if arguments.is_present("compress") {
+            Compression::Deflate
+        } else {
+            Compression::None
+        };
+
+        Ok((format, compression))
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+        let (format, compression) = options;
+
+        // Begin "Executing" context for console logging
+        let span = tracing::span!(tracing::Level::INFO, "Executing");
+        let enter = span.enter();
+
+        let path = current_dir()?;
+
+        // Get the package name
+        let manifest = Manifest::try_from(path.as_path())?;
+        let package_name = manifest.get_package_name();
+
+        // Sanitize the package path to the root directory
+        let mut package_path = path.clone();
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        // Construct the path to the main file in the source directory
+        let mut main_file_path = package_path.clone();
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        if !MainFile::exists_at(&package_path) {
+            return Err(BuildError::MainFileDoesNotExist(main_file_path.into_os_string()).into());
+        }
+
+        // Construct the path to the output directory
+        let mut output_directory = package_path.clone();
+        output_directory.push(OUTPUTS_DIRECTORY_NAME);
+
+        tracing::info!("Starting...");
+
+        // Start the timer
+        let start = Instant::now();
+
+        // Create the output directory
+        OutputsDirectory::create(&package_path)?;
+
+        // Load the input file at `package_name.in`
+        let (input_string, input_path) = InputFile::new(&package_name).read_from(&path)?;
+
+        // Load the state file at `package_name.in`
+        let (state_string, state_path) = StateFile::new(&package_name).read_from(&path)?;
+
+        // Log compilation of files to console
+        tracing::info!("Compiling main program... ({:?})", main_file_path);
+
+        // Load the program at `main_file_path`
+        let program = Compiler::<Fq, EdwardsGroupType>::parse_program_with_input(
+            package_name.clone(),
+            main_file_path,
+            output_directory.clone(),
+            &input_string,
+            &input_path,
+            &state_string,
+            &state_path,
+        )?;
+
+        // Solve the witness of the program without storing its constraints
+        let mut cs = WitnessSynthesizer::<Bls12_377>::default();
+        let output = program.compile_constraints(&mut cs)?;
+
+        tracing::debug!("Number of constraints - {:#?}", cs.num_constraints());
+
+        // Write the program output to the output directory
+        OutputFile::new(&package_name).write(&output_directory, output.bytes())?;
+
+        // Serialize the witness to the witness file
+        let witness_file = WitnessFile::new(&package_name);
+        match format {
+            SerializationFormat::Json => {
+                let witness_object = SerializedWitness::from(cs);
+                let json = witness_object.to_json_string()?;
+                witness_file.write_to(&path, json)?;
+            }
+            SerializationFormat::Binary => {
+                let file = witness_file.create_binary(&path)?;
+                cs.write_binary(BufWriter::new(file), compression)?;
+            }
+        }
+
+        tracing::info!("Complete");
+
+        // Drop "Executing" context for console logging
+        drop(enter);
+
+        // Begin "Done" context for console logging
+        tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
+            tracing::info!("Finished in {} milliseconds\n", start.elapsed().as_millis());
+        });
+
+        Ok(())
+    }
+}
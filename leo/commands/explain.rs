@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    cli::*,
+    cli_types::*,
+    errors::{CLIError, ExplainError},
+};
+
+use clap::ArgMatches;
+
+/// The error code tables of every crate that reports coded errors.
+const ERROR_CODE_TABLES: &[&[(&str, &str)]] = &[
+    leo_grammar::ERROR_CODES,
+    leo_symbol_table::ERROR_CODES,
+    leo_type_inference::ERROR_CODES,
+    leo_compiler::errors::ERROR_CODES,
+    leo_package::ERROR_CODES,
+];
+
+#[derive(Debug)]
+pub struct ExplainCommand;
+
+impl CLI for ExplainCommand {
+    type Options = String;
+    type Output = ();
+
+    const ABOUT: AboutType = "Print a detailed description of an error code";
+    const ARGUMENTS: &'static [ArgumentType] = &[
+        // (name, description, possible_values, required, index)
+        ("CODE", "The error code to explain, such as E0301", &[], true, 1u64),
+    ];
+    const FLAGS: &'static [FlagType] = &[];
+    const NAME: NameType = "explain";
+    const OPTIONS: &'static [OptionType] = &[];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(arguments.value_of("CODE").unwrap_or_default().to_uppercase())
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(code: Self::Options) -> Result<Self::Output, CLIError> {
+        let explanation = ERROR_CODE_TABLES
+            .iter()
+            .flat_map(|table| table.iter())
+            .find(|(table_code, _)| *table_code == code)
+            .map(|(_, explanation)| explanation)
+            .ok_or_else(|| ExplainError::UnknownCode(code.clone()))?;
+
+        println!("{}: {}", code, explanation);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    cli::*,
+    cli_types::*,
+    errors::{CLIError, FmtError},
+};
+use leo_formatter::format;
+use leo_package::{root::Manifest, source::SourceDirectory};
+
+use clap::ArgMatches;
+use std::{convert::TryFrom, env::current_dir, fs};
+
+#[derive(Debug)]
+pub struct FmtCommand;
+
+impl CLI for FmtCommand {
+    type Options = bool;
+    type Output = ();
+
+    const ABOUT: AboutType = "Formats the Leo files in the package";
+    const ARGUMENTS: &'static [ArgumentType] = &[];
+    const FLAGS: &'static [FlagType] = &["[check] --check 'List the unformatted files without changing them'"];
+    const NAME: NameType = "fmt";
+    const OPTIONS: &'static [OptionType] = &[];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(arguments.is_present("check"))
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(check: Self::Options) -> Result<Self::Output, CLIError> {
+        let path = current_dir()?;
+
+        // Verify that the current directory is a package
+        Manifest::try_from(path.as_path())?;
+
+        // Begin "Formatting" context for console logging
+        let span = tracing::span!(tracing::Level::INFO, "Formatting");
+        let _enter = span.enter();
+
+        let mut unformatted = 0;
+
+        for file_path in SourceDirectory::files(&path)?.into_iter() {
+            let program_string = fs::read_to_string(&file_path)?;
+            let formatted = format(&file_path, &program_string)?;
+
+            if formatted == program_string {
+                continue;
+            }
+
+            let file_name = file_path.strip_prefix(&path).unwrap_or(&file_path).display();
+
+            if check {
+                unformatted += 1;
+                tracing::warn!("{} is not formatted", file_name);
+            } else {
+                fs::write(&file_path, formatted)?;
+                tracing::info!("Formatted {}", file_name);
+            }
+        }
+
+        if unformatted > 0 {
+            return Err(FmtError::Unformatted(unformatted).into());
+        }
+
+        tracing::info!("Complete");
+
+        Ok(())
+    }
+}
@@ -17,13 +17,20 @@
 use crate::{
     cli::*,
     cli_types::*,
-    commands::BuildCommand,
-    errors::{CLIError, RunError},
+    errors::{CLIError, LintError, RunError},
 };
+use leo_ast::Input;
+use leo_compiler::{compiler::Compiler, errors::CompilerError, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_linter::{LintContext, Linter};
 use leo_package::{
-    root::Manifest,
-    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+    inputs::*,
+    outputs::OUTPUTS_DIRECTORY_NAME,
+    root::{LintLevel, Manifest},
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
+use leo_symbol_table::SymbolTable;
+
+use snarkos_curves::edwards_bls12::Fq;
 
 use clap::ArgMatches;
 use std::{convert::TryFrom, env::current_dir};
@@ -32,47 +39,116 @@ use std::{convert::TryFrom, env::current_dir};
 pub struct LintCommand;
 
 impl CLI for LintCommand {
-    type Options = ();
+    type Options = bool;
     type Output = ();
 
-    const ABOUT: AboutType = "Lints the Leo files in the package (*)";
+    const ABOUT: AboutType = "Lints the Leo files in the package";
     const ARGUMENTS: &'static [ArgumentType] = &[];
-    const FLAGS: &'static [FlagType] = &[];
+    const FLAGS: &'static [FlagType] = &["[list] --list 'List the lint rules and their levels'"];
     const NAME: NameType = "lint";
     const OPTIONS: &'static [OptionType] = &[];
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(arguments.is_present("list"))
     }
 
     #[cfg_attr(tarpaulin, skip)]
-    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+    fn output(list: Self::Options) -> Result<Self::Output, CLIError> {
+        let path = current_dir()?;
+
+        // Set the level of each rule from the `[lint]` section of the manifest
+        let manifest = Manifest::try_from(path.as_path())?;
+        let mut linter = Linter::default();
+        linter.configure(&manifest.get_lint_levels())?;
+
+        if list {
+            tracing::span!(tracing::Level::INFO, "Linting").in_scope(|| {
+                for (rule, level) in linter.rules() {
+                    tracing::info!("{:<22} {:<5} {}", rule.name(), level.to_string(), rule.description());
+                }
+            });
+
+            return Ok(());
+        }
+
         // Begin "Linting" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Linting");
         let _enter = span.enter();
 
-        let path = current_dir()?;
+        let package_name = manifest.get_package_name();
 
-        match BuildCommand::output(options)? {
-            Some((_program, _checksum_differs)) => {
-                // Get the package name
-                let _package_name = Manifest::try_from(path.as_path())?.get_package_name();
+        let mut main_file_path = path.clone();
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
 
-                tracing::error!("Unimplemented - `leo lint`");
+        if !MainFile::exists_at(&path) {
+            return Err(CLIError::RunError(RunError::MainFileDoesNotExist(
+                main_file_path.into_os_string(),
+            )));
+        }
 
-                Ok(())
-            }
-            None => {
-                let mut main_file_path = path;
-                main_file_path.push(SOURCE_DIRECTORY_NAME);
-                main_file_path.push(MAIN_FILENAME);
-
-                Err(CLIError::RunError(RunError::MainFileDoesNotExist(
-                    main_file_path.into_os_string(),
-                )))
+        let mut output_directory = path.clone();
+        output_directory.push(OUTPUTS_DIRECTORY_NAME);
+
+        // Load the input file at `package_name.in`
+        let (input_string, input_path) = InputFile::new(&package_name).read_from(&path)?;
+
+        // Load the state file at `package_name.in`
+        let (state_string, state_path) = StateFile::new(&package_name).read_from(&path)?;
+
+        // Parse and type check the program and its imports without synthesizing the circuit
+        let program = Compiler::<Fq, EdwardsGroupType>::type_check_program_with_input(
+            package_name,
+            main_file_path.clone(),
+            output_directory,
+            &input_string,
+            &input_path,
+            &state_string,
+            &state_path,
+        )?;
+
+        // Lint the main program and every program that it imports
+        let imported_programs = program.imported_programs();
+        let mut programs = vec![(main_file_path.clone(), program.program(), program.symbol_table()?)];
+
+        for (file_name, imported_program) in imported_programs.imports() {
+            let file_path = imported_programs.get_import_file(file_name).unwrap_or(&main_file_path);
+            let symbol_table =
+                SymbolTable::new(imported_program, imported_programs, &Input::new()).map_err(|mut e| {
+                    e.set_path(file_path);
+
+                    CompilerError::from(e)
+                })?;
+
+            programs.push((file_path.clone(), imported_program, symbol_table));
+        }
+
+        let mut denied = 0;
+
+        for (file_path, program, symbol_table) in programs.iter() {
+            let context = LintContext::new(program, symbol_table);
+
+            for mut lint in linter.run(&context).into_iter() {
+                lint.set_path(file_path);
+
+                match lint.level {
+                    LintLevel::Deny => {
+                        denied += 1;
+                        tracing::error!("{}", lint);
+                    }
+                    LintLevel::Allow | LintLevel::Warn => tracing::warn!("{}", lint),
+                }
             }
         }
+
+        if denied > 0 {
+            return Err(LintError::Denied(denied).into());
+        }
+
+        tracing::info!("Complete");
+
+        Ok(())
     }
 }
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{cli::*, cli_types::*, errors::CLIError};
+use leo_language_server::LanguageServer;
+
+use clap::ArgMatches;
+
+#[derive(Debug)]
+pub struct LspCommand;
+
+impl CLI for LspCommand {
+    type Options = ();
+    type Output = ();
+
+    const ABOUT: AboutType = "Runs the Leo language server over stdio";
+    const ARGUMENTS: &'static [ArgumentType] = &[];
+    const FLAGS: &'static [FlagType] = &[];
+    const NAME: NameType = "lsp";
+    const OPTIONS: &'static [OptionType] = &[];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn process(arguments: &ArgMatches) -> Result<(), CLIError> {
+        // Stdout carries the protocol messages, so there is no console logger or update check.
+        let options = Self::parse(arguments)?;
+        Self::output(options)?;
+        Ok(())
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok(())
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
+        Ok(LanguageServer::run()?)
+    }
+}
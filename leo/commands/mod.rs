@@ -26,6 +26,15 @@ pub use self::clean::*;
 pub mod deploy;
 pub use self::deploy::*;
 
+pub mod execute;
+pub use self::execute::*;
+
+pub mod explain;
+pub use self::explain::*;
+
+pub mod fmt;
+pub use self::fmt::*;
+
 pub mod init;
 pub use self::init::*;
 
@@ -35,6 +44,9 @@ pub use self::lint::*;
 pub mod login;
 pub use self::login::*;
 
+pub mod lsp;
+pub use self::lsp::*;
+
 pub mod new;
 pub use self::new::*;
 
@@ -61,3 +73,6 @@ pub use self::update::*;
 
 pub mod watch;
 pub use self::watch::*;
+
+pub mod verify;
+pub use self::verify::*;
@@ -14,12 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{cli::*, cli_types::*, commands::SetupCommand, errors::CLIError};
-use leo_package::{outputs::ProofFile, root::Manifest};
+use crate::{
+    backend::{seeded_rng, PreparedVerifyingKey, Proof, PROVE_STREAM},
+    cli::*,
+    cli_types::*,
+    commands::{SetupCommand, SetupOptions},
+    errors::CLIError,
+    synthesizer::CircuitSynthesizer,
+};
+use leo_package::{
+    outputs::{ProofFile, PublicInputsFile},
+    root::Manifest,
+};
 
-use snarkos_algorithms::snark::groth16::{Groth16, PreparedVerifyingKey, Proof};
 use snarkos_curves::bls12_377::{Bls12_377, Fr};
-use snarkos_models::algorithms::SNARK;
 
 use clap::ArgMatches;
 use rand::thread_rng;
@@ -29,24 +37,25 @@ use std::{convert::TryFrom, env::current_dir, time::Instant};
 pub struct ProveCommand;
 
 impl CLI for ProveCommand {
-    type Options = ();
-    type Output = (Proof<Bls12_377>, PreparedVerifyingKey<Bls12_377>);
+    type Options = SetupOptions;
+    type Output = (Proof, PreparedVerifyingKey, Vec<Fr>);
 
     const ABOUT: AboutType = "Run the program and produce a proof";
     const ARGUMENTS: &'static [ArgumentType] = &[];
     const FLAGS: &'static [FlagType] = &[];
     const NAME: NameType = "prove";
-    const OPTIONS: &'static [OptionType] = &[];
+    const OPTIONS: &'static [OptionType] = SetupCommand::OPTIONS;
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        SetupCommand::parse(arguments)
     }
 
     #[cfg_attr(tarpaulin, skip)]
     fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
-        let (program, parameters, prepared_verifying_key) = SetupCommand::output(options)?;
+        let seed = options.seed;
+        let (program, proving_key, prepared_verifying_key) = SetupCommand::output(options)?;
 
         // Begin "Proving" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Proving");
@@ -61,8 +70,17 @@ impl CLI for ProveCommand {
         // Start the timer
         let start = Instant::now();
 
-        let rng = &mut thread_rng();
-        let program_proof = Groth16::<Bls12_377, _, Vec<Fr>>::prove(&parameters, program, rng)?;
+        // Synthesize the program to record its public inputs in the order of the verifying key
+        let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+        let output = program.clone().compile_constraints(&mut cs)?;
+        let public_inputs_bytes = program.public_inputs(&output);
+        let public_inputs = cs.public_inputs().to_vec();
+
+        // A seeded setup also makes the proof reproducible
+        let program_proof = match seed {
+            Some(seed) => proving_key.prove(program, &mut seeded_rng(seed, PROVE_STREAM))?,
+            None => proving_key.prove(program, &mut thread_rng())?,
+        };
 
         // Finish the timer
         let end = start.elapsed().as_millis();
@@ -72,6 +90,11 @@ impl CLI for ProveCommand {
         program_proof.write(&mut proof)?;
         ProofFile::new(&package_name).write_to(&path, &proof)?;
 
+        // Write the public inputs file next to the proof
+        let public_inputs_file = PublicInputsFile::new(&package_name);
+        tracing::info!("Saving public inputs... ({:?})", public_inputs_file.full_path(&path));
+        public_inputs_file.write_to(&path, public_inputs_bytes.bytes())?;
+
         // Drop "Proving" context for console logging
         drop(enter);
 
@@ -80,6 +103,6 @@ impl CLI for ProveCommand {
             tracing::info!("Finished in {:?} milliseconds\n", end);
         });
 
-        Ok((program_proof, prepared_verifying_key))
+        Ok((program_proof, prepared_verifying_key, public_inputs))
     }
 }
@@ -67,7 +67,7 @@ impl CLI for PublishCommand {
     #[cfg_attr(tarpaulin, skip)]
     fn output(_options: Self::Options) -> Result<Self::Output, CLIError> {
         // Build all program files.
-        let _output = BuildCommand::output(())?;
+        let _output = BuildCommand::output(Default::default())?;
 
         // Begin "Publishing" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Publishing");
@@ -14,12 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{cli::*, cli_types::*, commands::ProveCommand, errors::CLIError};
-use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
-
-use snarkos_algorithms::snark::groth16::Groth16;
-use snarkos_curves::bls12_377::{Bls12_377, Fr};
-use snarkos_models::algorithms::SNARK;
+use crate::{
+    cli::*,
+    cli_types::*,
+    commands::{ProveCommand, SetupCommand, SetupOptions},
+    errors::CLIError,
+};
 
 use clap::ArgMatches;
 use std::time::Instant;
@@ -28,24 +28,24 @@ use std::time::Instant;
 pub struct RunCommand;
 
 impl CLI for RunCommand {
-    type Options = ();
+    type Options = SetupOptions;
     type Output = ();
 
     const ABOUT: AboutType = "Run a program with input variables";
     const ARGUMENTS: &'static [ArgumentType] = &[];
     const FLAGS: &'static [FlagType] = &[];
     const NAME: NameType = "run";
-    const OPTIONS: &'static [OptionType] = &[];
+    const OPTIONS: &'static [OptionType] = SetupCommand::OPTIONS;
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        SetupCommand::parse(arguments)
     }
 
     #[cfg_attr(tarpaulin, skip)]
     fn output(options: Self::Options) -> Result<(), CLIError> {
-        let (proof, prepared_verifying_key) = ProveCommand::output(options)?;
+        let (proof, prepared_verifying_key, public_inputs) = ProveCommand::output(options)?;
 
         // Begin "Verifying" context for console logging
         let span = tracing::span!(tracing::Level::INFO, "Verifying");
@@ -56,13 +56,8 @@ impl CLI for RunCommand {
         // Start the timer
         let start = Instant::now();
 
-        // Run the verifier
-        let is_success = Groth16::<Bls12_377, Compiler<Fr, EdwardsGroupType>, Vec<Fr>>::verify(
-            &prepared_verifying_key,
-            &vec![],
-            &proof,
-        )
-        .unwrap();
+        // Run the verifier against the public inputs of the proof
+        let is_success = prepared_verifying_key.verify(&public_inputs, &proof)?;
 
         // End the timer
         let end = start.elapsed().as_millis();
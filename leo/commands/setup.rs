@@ -15,124 +15,281 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    backend::{seeded_rng, Backend, PreparedVerifyingKey, ProvingKey, VerifyingKey, SETUP_STREAM},
     cli::*,
     cli_types::*,
     commands::BuildCommand,
-    errors::{CLIError, RunError},
+    errors::{CLIError, RunError, SetupError},
+    synthesizer::WitnessSynthesizer,
 };
 use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
 use leo_package::{
-    outputs::{ProvingKeyFile, VerificationKeyFile},
+    outputs::{ProvingKeyFile, SetupFile, SetupRecord, SetupSource, TranscriptFile, VerificationKeyFile},
     root::Manifest,
     source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 
-use snarkos_algorithms::snark::groth16::{Groth16, Parameters, PreparedVerifyingKey, VerifyingKey};
 use snarkos_curves::bls12_377::{Bls12_377, Fr};
-use snarkos_models::algorithms::snark::SNARK;
 
 use clap::ArgMatches;
 use rand::thread_rng;
-use std::{convert::TryFrom, env::current_dir, time::Instant};
+use sha2::{Digest, Sha256};
+use std::{convert::TryFrom, env::current_dir, fs, path::PathBuf, time::Instant};
+
+/// Options for where the proving and verification keys come from.
+#[derive(Clone, Debug, Default)]
+pub struct SetupOptions {
+    pub backend: Option<Backend>,
+    pub seed: Option<u64>,
+    pub parameters: Option<PathBuf>,
+    pub transcript: Option<PathBuf>,
+}
+
+/// Returns the hex encoded SHA-256 digest of the bytes.
+fn checksum(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hex::encode(hasher.finalize())
+}
+
+/// Returns true if the keys of the saved setup can be reused for the circuit with the given checksum.
+///
+/// Saved keys are only reused if they were made for this circuit by the selected backend,
+/// and from the seed if one is given. Imported parameters always replace the saved keys.
+fn reuses_setup(record: &SetupRecord, circuit_checksum: &str, backend: Backend, options: &SetupOptions) -> bool {
+    if record.circuit_checksum != circuit_checksum {
+        tracing::info!("The program changed since the saved setup, generating new keys");
+
+        return false;
+    }
+
+    record.backend == backend
+        && match (options.seed, &options.parameters) {
+            (Some(seed), _) => record.source == SetupSource::Seeded { seed },
+            (None, Some(_)) => false,
+            (None, None) => true,
+        }
+}
 
 #[derive(Debug)]
 pub struct SetupCommand;
 
 impl CLI for SetupCommand {
-    type Options = ();
-    type Output = (
-        Compiler<Fr, EdwardsGroupType>,
-        Parameters<Bls12_377>,
-        PreparedVerifyingKey<Bls12_377>,
-    );
+    type Options = SetupOptions;
+    type Output = (Compiler<Fr, EdwardsGroupType>, ProvingKey, PreparedVerifyingKey);
 
     const ABOUT: AboutType = "Run a program setup";
     const ARGUMENTS: &'static [ArgumentType] = &[];
     const FLAGS: &'static [FlagType] = &[];
     const NAME: NameType = "setup";
-    const OPTIONS: &'static [OptionType] = &[];
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        (
+            "[backend] --backend=<backend> 'Specify the proving backend'",
+            &[],
+            Backend::NAMES,
+            &[],
+        ),
+        (
+            "[seed] --seed=<seed> 'Derive a reproducible setup from a seed. INSECURE: for testing only'",
+            &["parameters", "transcript"],
+            &[],
+            &[],
+        ),
+        (
+            "[parameters] --parameters=<parameters> 'Import parameters generated outside of Leo, such as by an MPC'",
+            &["seed"],
+            &[],
+            &["transcript"],
+        ),
+        (
+            "[transcript] --transcript=<transcript> 'The transcript of the ceremony that generated the parameters'",
+            &["seed"],
+            &[],
+            &["parameters"],
+        ),
+    ];
     const SUBCOMMANDS: &'static [SubCommandType] = &[];
 
     #[cfg_attr(tarpaulin, skip)]
-    fn parse(_arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
-        Ok(())
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        let seed = match arguments.value_of("seed") {
+            Some(seed) => Some(
+                seed.parse::<u64>()
+                    .map_err(|_| SetupError::InvalidSeed(seed.to_string()))?,
+            ),
+            None => None,
+        };
+
+        Ok(SetupOptions {
+            backend: arguments.value_of("backend").and_then(|backend| backend.parse().ok()),
+            seed,
+            parameters: arguments.value_of("parameters").map(PathBuf::from),
+            transcript: arguments.value_of("transcript").map(PathBuf::from),
+        })
     }
 
     #[cfg_attr(tarpaulin, skip)]
     fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
         // Get the package name
         let path = current_dir()?;
-        let package_name = Manifest::try_from(path.as_path())?.get_package_name();
+        let manifest = Manifest::try_from(path.as_path())?;
+        let package_name = manifest.get_package_name();
+
+        // The `--backend` flag overrides the backend of the `[prover]` section in the manifest
+        let backend = options.backend.unwrap_or_else(|| manifest.get_backend());
 
-        match BuildCommand::output(options)? {
-            Some((program, checksum_differs)) => {
+        match BuildCommand::output(Default::default())? {
+            Some((program, _checksum_differs)) => {
                 // Begin "Setup" context for console logging
                 let span = tracing::span!(tracing::Level::INFO, "Setup");
                 let enter = span.enter();
 
+                // Input values do not change the circuit, so they are left out of the checksum
+                let circuit_checksum = program.circuit_checksum()?;
+
                 // Check if a proving key and verification key already exists
+                let setup_file = SetupFile::new(&package_name);
                 let keys_exist = ProvingKeyFile::new(&package_name).exists_at(&path)
-                    && VerificationKeyFile::new(&package_name).exists_at(&path);
-
-                // If keys do not exist or the checksum differs, run the program setup
-                // If keys do not exist or the checksum differs, run the program setup
-                let (end, proving_key, prepared_verifying_key) = if !keys_exist || checksum_differs {
-                    tracing::info!("Starting...");
-
-                    // Start the timer for setup
-                    let setup_start = Instant::now();
-
-                    // Run the program setup operation
-                    let rng = &mut thread_rng();
-                    let (proving_key, prepared_verifying_key) =
-                        Groth16::<Bls12_377, Compiler<Fr, _>, Vec<Fr>>::setup(program.clone(), rng).unwrap();
-
-                    // End the timer
-                    let end = setup_start.elapsed().as_millis();
-
-                    // TODO (howardwu): Convert parameters to a 'proving key' struct for serialization.
-                    // Write the proving key file to the output directory
-                    let proving_key_file = ProvingKeyFile::new(&package_name);
-                    tracing::info!("Saving proving key ({:?})", proving_key_file.full_path(&path));
-                    let mut proving_key_bytes = vec![];
-                    proving_key.write(&mut proving_key_bytes)?;
-                    let _ = proving_key_file.write_to(&path, &proving_key_bytes)?;
-                    tracing::info!("Complete");
-
-                    // Write the verification key file to the output directory
-                    let verification_key_file = VerificationKeyFile::new(&package_name);
-                    tracing::info!("Saving verification key ({:?})", verification_key_file.full_path(&path));
-                    let mut verification_key = vec![];
-                    proving_key.vk.write(&mut verification_key)?;
-                    let _ = verification_key_file.write_to(&path, &verification_key)?;
-                    tracing::info!("Complete");
-
-                    (end, proving_key, prepared_verifying_key)
-                } else {
-                    tracing::info!("Detected saved setup");
-
-                    // Start the timer for setup
-                    let setup_start = Instant::now();
-
-                    // Read the proving key file from the output directory
-                    tracing::info!("Loading proving key...");
-                    let proving_key_bytes = ProvingKeyFile::new(&package_name).read_from(&path)?;
-                    let proving_key = Parameters::<Bls12_377>::read(proving_key_bytes.as_slice(), true)?;
-                    tracing::info!("Complete");
-
-                    // Read the verification key file from the output directory
-                    tracing::info!("Loading verification key...");
-                    let verifying_key_bytes = VerificationKeyFile::new(&package_name).read_from(&path)?;
-                    let verifying_key = VerifyingKey::<Bls12_377>::read(verifying_key_bytes.as_slice())?;
-
-                    // Derive the prepared verifying key file from the verifying key
-                    let prepared_verifying_key = PreparedVerifyingKey::<Bls12_377>::from(verifying_key);
-                    tracing::info!("Complete");
-
-                    // End the timer
-                    let end = setup_start.elapsed().as_millis();
-
-                    (end, proving_key, prepared_verifying_key)
+                    && VerificationKeyFile::new(&package_name).exists_at(&path)
+                    && setup_file.exists_at(&path);
+
+                let saved_record = match keys_exist {
+                    true => Some(setup_file.read_from(&path)?)
+                        .filter(|record| reuses_setup(record, &circuit_checksum, backend, &options)),
+                    false => None,
+                };
+
+                let (end, proving_key, prepared_verifying_key) = match saved_record {
+                    Some(record) => {
+                        tracing::info!("Detected saved setup");
+
+                        if record.source.is_insecure() {
+                            tracing::warn!("The saved keys come from a seeded setup and are INSECURE");
+                        }
+
+                        // Start the timer for setup
+                        let setup_start = Instant::now();
+
+                        // Read the proving key file from the output directory
+                        tracing::info!("Loading proving key...");
+                        let proving_key_bytes = ProvingKeyFile::new(&package_name).read_from(&path)?;
+                        let proving_key = ProvingKey::read(backend, proving_key_bytes.as_slice(), true)?;
+                        tracing::info!("Complete");
+
+                        // Read the verification key file from the output directory
+                        tracing::info!("Loading verification key...");
+                        let verifying_key_bytes = VerificationKeyFile::new(&package_name).read_from(&path)?;
+                        let verifying_key = VerifyingKey::read(backend, verifying_key_bytes.as_slice())?;
+
+                        // Derive the prepared verifying key file from the verifying key
+                        let prepared_verifying_key = PreparedVerifyingKey::from(verifying_key);
+                        tracing::info!("Complete");
+
+                        // End the timer
+                        let end = setup_start.elapsed().as_millis();
+
+                        (end, proving_key, prepared_verifying_key)
+                    }
+                    None => {
+                        // Start the timer for setup
+                        let setup_start = Instant::now();
+
+                        let (proving_key, prepared_verifying_key, source) =
+                            match (options.seed, &options.parameters, &options.transcript) {
+                                (_, Some(parameters_path), Some(transcript_path)) => {
+                                    tracing::info!("Importing {} parameters ({:?})", backend, parameters_path);
+
+                                    let parameters = fs::read(parameters_path).map_err(|_| {
+                                        SetupError::ParametersFileError(parameters_path.clone().into_os_string())
+                                    })?;
+                                    let transcript = fs::read(transcript_path).map_err(|_| {
+                                        SetupError::TranscriptFileError(transcript_path.clone().into_os_string())
+                                    })?;
+
+                                    let proving_key = ProvingKey::import(backend, &parameters)?;
+                                    let verifying_key = proving_key.verifying_key();
+
+                                    // Check that the parameters fit the public inputs of the program
+                                    let mut cs = WitnessSynthesizer::<Bls12_377>::default();
+                                    program.clone().compile_constraints(&mut cs)?;
+                                    if verifying_key.num_public_inputs() != cs.input_assignment.len() {
+                                        return Err(SetupError::ParametersMismatch(
+                                            verifying_key.num_public_inputs(),
+                                            cs.input_assignment.len(),
+                                        )
+                                        .into());
+                                    }
+
+                                    // Check that the parameters fit the constraints of the program with a trial proof
+                                    let prepared_verifying_key = PreparedVerifyingKey::from(verifying_key);
+                                    let proof = proving_key.prove(program.clone(), &mut thread_rng())?;
+                                    if !prepared_verifying_key.verify(&cs.input_assignment, &proof)? {
+                                        return Err(SetupError::ParametersUnsatisfied.into());
+                                    }
+
+                                    // Keep the transcript next to the keys it vouches for
+                                    TranscriptFile::new(&package_name).write_to(&path, &transcript)?;
+
+                                    let source = SetupSource::Imported {
+                                        parameters_checksum: checksum(&parameters),
+                                        transcript_checksum: checksum(&transcript),
+                                    };
+
+                                    (proving_key, prepared_verifying_key, source)
+                                }
+                                (Some(seed), ..) => {
+                                    tracing::warn!("Running a seeded setup. The keys are INSECURE, for testing only");
+                                    tracing::info!("Starting {} setup...", backend);
+
+                                    let rng = &mut seeded_rng(seed, SETUP_STREAM);
+                                    let (proving_key, prepared_verifying_key) =
+                                        ProvingKey::setup(backend, program.clone(), rng)?;
+
+                                    (proving_key, prepared_verifying_key, SetupSource::Seeded { seed })
+                                }
+                                _ => {
+                                    tracing::info!("Starting {} setup...", backend);
+
+                                    let rng = &mut thread_rng();
+                                    let (proving_key, prepared_verifying_key) =
+                                        ProvingKey::setup(backend, program.clone(), rng)?;
+
+                                    (proving_key, prepared_verifying_key, SetupSource::Random)
+                                }
+                            };
+
+                        // End the timer
+                        let end = setup_start.elapsed().as_millis();
+
+                        // Write the proving key file to the output directory
+                        let proving_key_file = ProvingKeyFile::new(&package_name);
+                        tracing::info!("Saving proving key ({:?})", proving_key_file.full_path(&path));
+                        let mut proving_key_bytes = vec![];
+                        proving_key.write(&mut proving_key_bytes)?;
+                        let _ = proving_key_file.write_to(&path, &proving_key_bytes)?;
+                        tracing::info!("Complete");
+
+                        // Write the verification key file to the output directory
+                        let verification_key_file = VerificationKeyFile::new(&package_name);
+                        tracing::info!("Saving verification key ({:?})", verification_key_file.full_path(&path));
+                        let mut verification_key = vec![];
+                        proving_key.verifying_key().write(&mut verification_key)?;
+                        let _ = verification_key_file.write_to(&path, &verification_key)?;
+                        tracing::info!("Complete");
+
+                        // Record which circuit the keys were made for and where they came from
+                        let record = SetupRecord {
+                            circuit_checksum,
+                            backend,
+                            source,
+                            proving_key_checksum: checksum(&proving_key_bytes),
+                            verification_key_checksum: checksum(&verification_key),
+                        };
+                        setup_file.write_to(&path, &record)?;
+
+                        (end, proving_key, prepared_verifying_key)
+                    }
                 };
 
                 // Drop "Setup" context for console logging
@@ -157,3 +314,78 @@ impl CLI for SetupCommand {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_package::inputs::INPUTS_DIRECTORY_NAME;
+
+    use std::env::temp_dir;
+
+    static PROGRAM: &str = "function main(a: u32) -> u32 {\n    return a + 1\n}\n";
+    static OTHER_PROGRAM: &str = "function main(a: u32) -> u32 {\n    return a + 2\n}\n";
+
+    static INPUT: &str = "[main]\na: u32 = 1;\n\n[registers]\nr0: u32 = 0;\n";
+    static OTHER_INPUT: &str = "[main]\na: u32 = 2;\n\n[registers]\nr0: u32 = 0;\n";
+
+    /// Writes a package with the given main file, input file, and empty state file, and returns the compiled package.
+    fn compile_package(directory: &str, program: &str, input: &str) -> Compiler<Fr, EdwardsGroupType> {
+        let package_path = temp_dir().join(directory);
+
+        let main_file_path = package_path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+        fs::create_dir_all(main_file_path.parent().unwrap()).unwrap();
+        fs::write(&main_file_path, program).unwrap();
+
+        let input_path = package_path.join(INPUTS_DIRECTORY_NAME).join("test.in");
+        fs::create_dir_all(input_path.parent().unwrap()).unwrap();
+        fs::write(&input_path, input).unwrap();
+
+        let state_path = package_path.join(INPUTS_DIRECTORY_NAME).join("test.state");
+        fs::write(&state_path, "").unwrap();
+
+        Compiler::parse_program_with_input(
+            "test".to_string(),
+            main_file_path,
+            package_path.join("outputs"),
+            input,
+            &input_path,
+            "",
+            &state_path,
+        )
+        .unwrap()
+    }
+
+    /// Returns the record of a random setup for the given package.
+    fn setup_record(program: &Compiler<Fr, EdwardsGroupType>) -> SetupRecord {
+        SetupRecord {
+            circuit_checksum: program.circuit_checksum().unwrap(),
+            backend: Backend::default(),
+            source: SetupSource::Random,
+            proving_key_checksum: String::new(),
+            verification_key_checksum: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_input_change_reuses_setup() {
+        let directory = "leo-setup-test-input-change";
+        let record = setup_record(&compile_package(directory, PROGRAM, INPUT));
+
+        // Changing a value of `main.in` changes the build checksum but not the circuit
+        let before = compile_package(directory, PROGRAM, INPUT).checksum().unwrap();
+        let program = compile_package(directory, PROGRAM, OTHER_INPUT);
+        assert_ne!(before, program.checksum().unwrap());
+
+        let circuit_checksum = program.circuit_checksum().unwrap();
+        assert!(reuses_setup(&record, &circuit_checksum, Backend::default(), &SetupOptions::default()));
+    }
+
+    #[test]
+    fn test_program_change_replaces_setup() {
+        let directory = "leo-setup-test-program-change";
+        let record = setup_record(&compile_package(directory, PROGRAM, INPUT));
+
+        let circuit_checksum = compile_package(directory, OTHER_PROGRAM, INPUT).circuit_checksum().unwrap();
+        assert!(!reuses_setup(&record, &circuit_checksum, Backend::default(), &SetupOptions::default()));
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    backend::{Backend, PreparedVerifyingKey, Proof, VerifyingKey},
+    cli::*,
+    cli_types::*,
+    errors::{CLIError, VerifyError},
+    synthesizer::CircuitSynthesizer,
+};
+use leo_compiler::{compiler::Compiler, group::targets::edwards_bls12::EdwardsGroupType};
+use leo_package::{
+    outputs::{ProofFile, PublicInputsFile, VerificationKeyFile, OUTPUTS_DIRECTORY_NAME},
+    root::Manifest,
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+
+use snarkos_curves::bls12_377::{Bls12_377, Fr};
+
+use clap::ArgMatches;
+use std::{convert::TryFrom, env::current_dir, path::PathBuf, time::Instant};
+
+#[derive(Debug)]
+pub struct VerifyCommand;
+
+impl CLI for VerifyCommand {
+    // Format: proof, public_inputs, backend
+    type Options = (Option<String>, Option<String>, Option<Backend>);
+    type Output = ();
+
+    const ABOUT: AboutType = "Verify a proof against the verification key and public inputs";
+    const ARGUMENTS: &'static [ArgumentType] = &[];
+    const FLAGS: &'static [FlagType] = &[];
+    const NAME: NameType = "verify";
+    const OPTIONS: &'static [OptionType] = &[
+        // (argument, conflicts, possible_values, requires)
+        ("[proof] --proof=<proof> 'Specify the path to the proof file'", &[], &[], &[]),
+        (
+            "[public-inputs] --public-inputs=<public-inputs> 'Specify the path to the public inputs file'",
+            &[],
+            &[],
+            &[],
+        ),
+        (
+            "[backend] --backend=<backend> 'Specify the proving backend'",
+            &[],
+            Backend::NAMES,
+            &[],
+        ),
+    ];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[];
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        Ok((
+            arguments.value_of("proof").map(|s| s.to_string()),
+            arguments.value_of("public-inputs").map(|s| s.to_string()),
+            arguments.value_of("backend").and_then(|backend| backend.parse().ok()),
+        ))
+    }
+
+    #[cfg_attr(tarpaulin, skip)]
+    fn output(options: Self::Options) -> Result<Self::Output, CLIError> {
+        let (proof_path, public_inputs_path, backend) = options;
+
+        // Begin "Verifying" context for console logging
+        let span = tracing::span!(tracing::Level::INFO, "Verifying");
+        let enter = span.enter();
+
+        // Get the package name
+        let path = current_dir()?;
+        let manifest = Manifest::try_from(path.as_path())?;
+        let package_name = manifest.get_package_name();
+
+        // The `--backend` flag overrides the backend of the `[prover]` section in the manifest
+        let backend = backend.unwrap_or_else(|| manifest.get_backend());
+
+        // Sanitize the package path to the root directory
+        let mut package_path = path.clone();
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        if !MainFile::exists_at(&package_path) {
+            let mut main_file_path = package_path;
+            main_file_path.push(SOURCE_DIRECTORY_NAME);
+            main_file_path.push(MAIN_FILENAME);
+
+            return Err(VerifyError::MainFileDoesNotExist(main_file_path.into_os_string()).into());
+        }
+
+        tracing::info!("Starting...");
+
+        // Start the timer
+        let start = Instant::now();
+
+        // Construct the path to the output directory
+        let mut output_directory = package_path.clone();
+        output_directory.push(OUTPUTS_DIRECTORY_NAME);
+
+        // Construct the path to the main file in the source directory
+        let mut main_file_path = package_path;
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        // Read the verification key file from the output directory
+        tracing::info!("Loading verification key...");
+        let verifying_key_bytes = VerificationKeyFile::new(&package_name).read_from(&path)?;
+        let verifying_key = VerifyingKey::read(backend, verifying_key_bytes.as_slice())?;
+
+        // Derive the prepared verifying key file from the verifying key
+        let prepared_verifying_key = PreparedVerifyingKey::from(verifying_key);
+
+        // Read the proof file from the given path or the output directory
+        tracing::info!("Loading proof...");
+        let proof_file = ProofFile::new(&package_name);
+        let proof_bytes = match proof_path {
+            Some(proof_path) => proof_file.read_from(&PathBuf::from(proof_path))?,
+            None => proof_file.read_from(&path)?,
+        };
+        let proof = Proof::read(backend, proof_bytes.as_slice())?;
+
+        // Read the public inputs file from the given path or the output directory
+        tracing::info!("Loading public inputs...");
+        let public_inputs_file = PublicInputsFile::new(&package_name);
+        let public_inputs_path = public_inputs_path.map(PathBuf::from).unwrap_or_else(|| path.clone());
+        let (public_inputs_string, public_inputs_path) = public_inputs_file.read_from(&public_inputs_path)?;
+
+        // Load the program at `main_file_path` with the public inputs
+        let program = Compiler::<Fr, EdwardsGroupType>::parse_program_with_public_inputs(
+            package_name,
+            main_file_path,
+            output_directory,
+            &public_inputs_string,
+            &public_inputs_path,
+        )?;
+
+        // Allocate the public inputs in the order of the verifying key
+        let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+        program.compile_public_inputs(&mut cs)?;
+
+        // Run the verifier
+        let is_success = prepared_verifying_key.verify(cs.public_inputs(), &proof)?;
+
+        // End the timer
+        let end = start.elapsed().as_millis();
+
+        if !is_success {
+            return Err(VerifyError::ProofInvalid(public_inputs_path.into_owned().into_os_string()).into());
+        }
+
+        tracing::info!("Proof is valid");
+
+        // Drop "Verifying" context for console logging
+        drop(enter);
+
+        // Begin "Done" context for console logging
+        tracing::span!(tracing::Level::INFO, "Done").in_scope(|| {
+            tracing::info!("Finished in {:?} milliseconds\n", end);
+        });
+
+        Ok(())
+    }
+}
@@ -15,11 +15,14 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{cli::CLI, cli_types::*, commands::BuildCommand, errors::CLIError};
+use leo_package::{imports::IMPORTS_DIRECTORY_NAME, inputs::INPUTS_DIRECTORY_NAME, source::SOURCE_DIRECTORY_NAME};
+
 use clap::ArgMatches;
 use notify::{watcher, DebouncedEvent, RecursiveMode, Watcher};
-use std::{sync::mpsc::channel, time::Duration};
+use std::{path::Path, sync::mpsc::channel, time::Duration};
 
-const LEO_SOURCE_DIR: &str = "src/";
+// Directories holding files that the build depends on
+const WATCHED_DIRECTORIES: [&str; 3] = [SOURCE_DIRECTORY_NAME, IMPORTS_DIRECTORY_NAME, INPUTS_DIRECTORY_NAME];
 
 // Time interval for watching files, in seconds
 const INTERVAL: u64 = 3;
@@ -49,15 +52,21 @@ impl CLI for WatchCommand {
 
         let (tx, rx) = channel();
         let mut watcher = watcher(tx, Duration::from_secs(INTERVAL)).unwrap();
-        watcher.watch(LEO_SOURCE_DIR, RecursiveMode::Recursive).unwrap();
+        for directory in WATCHED_DIRECTORIES.iter().filter(|directory| Path::new(directory).exists()) {
+            watcher.watch(directory, RecursiveMode::Recursive).unwrap();
+        }
 
-        tracing::info!("Watching Leo source code");
+        tracing::info!("Watching Leo source code, imports and inputs");
 
         loop {
             match rx.recv() {
-                // See changes on the write event
-                Ok(DebouncedEvent::Write(_write)) => {
-                    match BuildCommand::output(()) {
+                // Rebuild when a file is written, created, removed or renamed.
+                // The build manifest decides whether the circuit needs to be synthesized again.
+                Ok(DebouncedEvent::Write(_))
+                | Ok(DebouncedEvent::Create(_))
+                | Ok(DebouncedEvent::Remove(_))
+                | Ok(DebouncedEvent::Rename(_, _)) => {
+                    match BuildCommand::output(Default::default()) {
                         Ok(_output) => {
                             tracing::info!("Built successfully");
                         }
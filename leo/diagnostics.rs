@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Machine-readable diagnostics for `--message-format=json`.
+
+use leo_ast::{formatted_source, Error as FormattedError};
+use leo_compiler::errors::CompilerError;
+use leo_grammar::{ParserError, SyntaxError};
+use leo_symbol_table::SymbolTableError;
+use leo_type_inference::TypeInferenceError;
+
+use pest::error::LineColLocation;
+use serde::Serialize;
+use std::{
+    error::Error,
+    str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static JSON_MESSAGES: AtomicBool = AtomicBool::new(false);
+
+/// The format of the errors printed by the CLI.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageFormat {
+    Human,
+    Json,
+}
+
+impl MessageFormat {
+    pub const NAMES: &'static [&'static str] = &["human", "json"];
+
+    ///
+    /// Returns the message format selected for this process.
+    ///
+    pub fn current() -> Self {
+        match JSON_MESSAGES.load(Ordering::Relaxed) {
+            true => MessageFormat::Json,
+            false => MessageFormat::Human,
+        }
+    }
+
+    ///
+    /// Selects the message format for this process.
+    ///
+    pub fn set(self) {
+        JSON_MESSAGES.store(self == MessageFormat::Json, Ordering::Relaxed);
+    }
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "human" => Ok(MessageFormat::Human),
+            "json" => Ok(MessageFormat::Json),
+            format => Err(format!("unknown message format `{}`", format)),
+        }
+    }
+}
+
+/// A one-based line and column in a Leo file.
+#[derive(Clone, Debug, Serialize)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// The range of a diagnostic. The end column is exclusive.
+#[derive(Clone, Debug, Serialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+/// A single error printed as one JSON line.
+#[derive(Clone, Debug, Serialize)]
+pub struct Diagnostic {
+    pub file: Option<String>,
+    pub range: Option<Range>,
+    pub severity: &'static str,
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl Diagnostic {
+    ///
+    /// Returns a diagnostic for an error with the given code.
+    ///
+    /// The file and range are taken from the formatted Leo error that caused the error, if any.
+    ///
+    pub fn new(code: &'static str, error: &(dyn Error + 'static)) -> Self {
+        match formatted_source(error) {
+            Some(formatted) => Self::from_formatted(code, formatted),
+            None => Self {
+                file: None,
+                range: None,
+                severity: "error",
+                code,
+                message: error.to_string(),
+            },
+        }
+    }
+
+    fn from_formatted(code: &'static str, error: &FormattedError) -> Self {
+        // Spans that continue on a later line end at the end of their first line.
+        let end = if error.end > error.start {
+            error.end
+        } else {
+            error.text.chars().count().max(error.start)
+        };
+
+        Self {
+            file: error.path.as_ref().map(|path| path.trim_matches('"').to_string()),
+            range: Some(Range {
+                start: Position {
+                    line: error.line,
+                    column: error.start,
+                },
+                end: Position {
+                    line: error.line,
+                    column: end,
+                },
+            }),
+            severity: "error",
+            code,
+            message: error.message.clone(),
+        }
+    }
+
+    fn from_parser_error(error: &ParserError) -> Self {
+        let SyntaxError::Error(syntax_error) = match error {
+            ParserError::SyntaxError(syntax_error) => syntax_error,
+            error => return Self::new(error.error_code(), error),
+        };
+
+        let (start, end) = match syntax_error.line_col {
+            LineColLocation::Pos((line, column)) => ((line, column), (line, column + 1)),
+            LineColLocation::Span(start, end) => (start, end),
+        };
+
+        // Pest only exposes the file path and message through the formatted error:
+        //   --> path:line:column
+        //    ...
+        //    = message
+        let formatted = syntax_error.to_string();
+        let file = formatted.lines().next().and_then(|location| {
+            let location = location.trim_start().strip_prefix("--> ")?;
+            let mut parts = location.rsplitn(3, ':');
+            parts.nth(2).map(|path| path.to_string())
+        });
+        let message = formatted.lines().last().unwrap_or_default().trim_start();
+
+        Self {
+            file,
+            range: Some(Range {
+                start: Position {
+                    line: start.0,
+                    column: start.1,
+                },
+                end: Position {
+                    line: end.0,
+                    column: end.1,
+                },
+            }),
+            severity: "error",
+            code: error.error_code(),
+            message: message.trim_start_matches("= ").to_string(),
+        }
+    }
+
+    ///
+    /// Returns a diagnostic for every independent error reported by the compiler.
+    ///
+    pub fn from_compiler_error(error: &CompilerError) -> Vec<Self> {
+        match error {
+            CompilerError::ParserError(error) => vec![Self::from_parser_error(error)],
+            CompilerError::SymbolTableError(SymbolTableError::Errors(errors)) => errors
+                .iter()
+                .map(|error| Self::new(error.error_code(), error))
+                .collect(),
+            CompilerError::TypeInferenceError(TypeInferenceError::Errors(errors)) => errors
+                .iter()
+                .map(|error| Self::new(error.error_code(), error))
+                .collect(),
+            error => vec![Self::new(error.error_code(), error)],
+        }
+    }
+
+    ///
+    /// Prints the diagnostic to stdout as a single line of JSON.
+    ///
+    pub fn emit(&self) {
+        match serde_json::to_string(self) {
+            Ok(json) => println!("{}", json),
+            Err(error) => tracing::error!("Failed to serialize diagnostic: {}", error),
+        }
+    }
+}
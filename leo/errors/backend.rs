@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_package::root::Backend;
+
+use std::io;
+
+#[derive(Debug, Error)]
+pub enum BackendError {
+    #[error("{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[error("the {} was made by the `{}` backend, but the `{}` backend is selected", _0, _1, _2)]
+    Mismatch(&'static str, Backend, Backend),
+
+    #[error("the {} does not record a known backend", _0)]
+    Unknown(&'static str),
+}
+
+impl From<io::Error> for BackendError {
+    fn from(error: io::Error) -> Self {
+        tracing::error!("{}\n", error);
+        BackendError::Crate("std::io", error.to_string())
+    }
+}
+
+impl From<snarkos_errors::algorithms::snark::SNARKError> for BackendError {
+    fn from(error: snarkos_errors::algorithms::snark::SNARKError) -> Self {
+        tracing::error!("{}\n", error);
+        BackendError::Crate("snarkos_errors", error.to_string())
+    }
+}
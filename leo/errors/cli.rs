@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::*;
+use crate::{
+    diagnostics::{Diagnostic, MessageFormat},
+    errors::*,
+};
 use leo_compiler::errors::OutputFileError;
 use leo_package::errors::*;
 
@@ -23,9 +26,15 @@ pub enum CLIError {
     #[error("{}", _0)]
     AddError(AddError),
 
+    #[error("{}", _0)]
+    BackendError(BackendError),
+
     #[error("{}", _0)]
     BuildError(BuildError),
 
+    #[error("{}", _0)]
+    BuildManifestFileError(BuildManifestFileError),
+
     #[error("{}", _0)]
     ZipFileError(ZipFileError),
 
@@ -38,6 +47,15 @@ pub enum CLIError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("{}", _0)]
+    ExportFileError(ExportFileError),
+
+    #[error("{}", _0)]
+    ExplainError(ExplainError),
+
+    #[error("{}", _0)]
+    FmtError(FmtError),
+
     #[error("{}", _0)]
     GitignoreError(GitignoreError),
 
@@ -56,6 +74,9 @@ pub enum CLIError {
     #[error("{}", _0)]
     LibraryFileError(LibraryFileError),
 
+    #[error("{}", _0)]
+    LintError(LintError),
+
     #[error("{}", _0)]
     LoginError(LoginError),
 
@@ -77,12 +98,18 @@ pub enum CLIError {
     #[error("{}", _0)]
     PackageError(PackageError),
 
+    #[error("{}", _0)]
+    ProfileFileError(ProfileFileError),
+
     #[error("{}", _0)]
     ProofFileError(ProofFileError),
 
     #[error("{}", _0)]
     ProvingKeyFileError(ProvingKeyFileError),
 
+    #[error("{}", _0)]
+    PublicInputsFileError(PublicInputsFileError),
+
     #[error("{}", _0)]
     PublishError(PublishError),
 
@@ -101,6 +128,12 @@ pub enum CLIError {
     #[error("{}", _0)]
     StateFileError(StateFileError),
 
+    #[error("{}", _0)]
+    SetupError(SetupError),
+
+    #[error("{}", _0)]
+    SetupFileError(SetupFileError),
+
     #[error("{}", _0)]
     TestError(TestError),
 
@@ -110,8 +143,32 @@ pub enum CLIError {
     #[error("TomlDeError: {0}")]
     TomlDeError(#[from] toml::de::Error),
 
+    #[error("{}", _0)]
+    TranscriptFileError(TranscriptFileError),
+
     #[error("{}", _0)]
     VerificationKeyFileError(VerificationKeyFileError),
+
+    #[error("{}", _0)]
+    VerifyError(VerifyError),
+
+    #[error("{}", _0)]
+    WitnessFileError(WitnessFileError),
+}
+
+macro_rules! impl_cli_coded_error {
+    ($($t:tt), +) => {
+        $(impl From<$t> for CLIError {
+            fn from(error: $t) -> Self {
+                match MessageFormat::current() {
+                    MessageFormat::Human => tracing::error!("{}\n", error),
+                    MessageFormat::Json => Diagnostic::new(error.error_code(), &error).emit(),
+                }
+
+                CLIError::$t(error)
+            }
+        })*
+    }
 }
 
 macro_rules! impl_cli_error {
@@ -128,31 +185,47 @@ macro_rules! impl_cli_error {
 
 impl_cli_error!(
     AddError,
+    BackendError,
     BuildError,
+    ExplainError,
+    FmtError,
+    InitError,
+    LintError,
+    LoginError,
+    NewError,
+    PublishError,
+    RunError,
+    SetupError,
+    TestError,
+    VerifyError
+);
+
+impl_cli_coded_error!(
+    BuildManifestFileError,
     CircuitFileError,
     ChecksumFileError,
+    ExportFileError,
     GitignoreError,
     ImportsDirectoryError,
-    InitError,
     InputsDirectoryError,
     InputFileError,
     LibraryFileError,
-    LoginError,
     MainFileError,
     ManifestError,
-    NewError,
     OutputFileError,
     OutputsDirectoryError,
     PackageError,
+    ProfileFileError,
     ProofFileError,
     ProvingKeyFileError,
-    PublishError,
+    PublicInputsFileError,
     READMEError,
-    RunError,
     SourceDirectoryError,
     StateFileError,
-    TestError,
+    SetupFileError,
+    TranscriptFileError,
     VerificationKeyFileError,
+    WitnessFileError,
     ZipFileError
 );
 
@@ -165,8 +238,26 @@ impl From<clap::Error> for CLIError {
 
 impl From<leo_compiler::errors::CompilerError> for CLIError {
     fn from(error: leo_compiler::errors::CompilerError) -> Self {
+        match MessageFormat::current() {
+            MessageFormat::Human => tracing::error!("{}\n", error),
+            MessageFormat::Json => Diagnostic::from_compiler_error(&error)
+                .iter()
+                .for_each(|diagnostic| diagnostic.emit()),
+        }
+
+        let message = match error.error_count() {
+            1 => "Program failed due to previous error".to_string(),
+            count => format!("Program failed due to {} previous errors", count),
+        };
+
+        CLIError::Crate("leo-compiler", message)
+    }
+}
+
+impl From<leo_formatter::FormatterError> for CLIError {
+    fn from(error: leo_formatter::FormatterError) -> Self {
         tracing::error!("{}\n", error);
-        CLIError::Crate("leo-compiler", "Program failed due to previous error".into())
+        CLIError::Crate("leo-formatter", "Program failed due to previous error".into())
     }
 }
 
@@ -177,6 +268,20 @@ impl From<leo_input::errors::InputParserError> for CLIError {
     }
 }
 
+impl From<leo_language_server::LanguageServerError> for CLIError {
+    fn from(error: leo_language_server::LanguageServerError) -> Self {
+        tracing::error!("{}\n", error);
+        CLIError::Crate("leo-language-server", error.to_string())
+    }
+}
+
+impl From<leo_linter::LinterError> for CLIError {
+    fn from(error: leo_linter::LinterError) -> Self {
+        tracing::error!("{}\n", error);
+        CLIError::Crate("leo-linter", error.to_string())
+    }
+}
+
 impl From<reqwest::Error> for CLIError {
     fn from(error: reqwest::Error) -> Self {
         tracing::error!("{}\n", error);
@@ -191,6 +296,13 @@ impl From<snarkos_errors::algorithms::snark::SNARKError> for CLIError {
     }
 }
 
+impl From<snarkos_errors::curves::FieldError> for CLIError {
+    fn from(error: snarkos_errors::curves::FieldError) -> Self {
+        tracing::error!("{}\n", error);
+        CLIError::Crate("snarkos_errors", error.to_string())
+    }
+}
+
 impl From<snarkos_errors::gadgets::SynthesisError> for CLIError {
     fn from(error: snarkos_errors::gadgets::SynthesisError) -> Self {
         tracing::error!("{}\n", error);
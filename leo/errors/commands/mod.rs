@@ -20,9 +20,18 @@ pub use self::add::*;
 pub mod build;
 pub use self::build::*;
 
+pub mod explain;
+pub use self::explain::*;
+
+pub mod fmt;
+pub use self::fmt::*;
+
 pub mod init;
 pub use self::init::*;
 
+pub mod lint;
+pub use self::lint::*;
+
 pub mod login;
 pub use self::login::*;
 
@@ -35,5 +44,11 @@ pub use self::publish::*;
 pub mod run;
 pub use self::run::*;
 
+pub mod setup;
+pub use self::setup::*;
+
 pub mod test;
 pub use self::test::*;
+
+pub mod verify;
+pub use self::verify::*;
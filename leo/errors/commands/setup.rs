@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::ffi::OsString;
+
+#[derive(Debug, Error)]
+pub enum SetupError {
+    #[error("the seed `{}` is not an unsigned 64-bit integer", _0)]
+    InvalidSeed(String),
+
+    #[error("cannot read the parameters file {:?}", _0)]
+    ParametersFileError(OsString),
+
+    #[error("the imported parameters have {} public inputs, but the program has {}", _0, _1)]
+    ParametersMismatch(usize, usize),
+
+    #[error("the imported parameters do not prove the constraints of the program")]
+    ParametersUnsatisfied,
+
+    #[error("cannot read the transcript file {:?}", _0)]
+    TranscriptFileError(OsString),
+}
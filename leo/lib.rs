@@ -17,11 +17,13 @@
 #[macro_use]
 extern crate thiserror;
 
+pub mod backend;
 pub mod cli;
 pub mod cli_types;
 pub mod commands;
 #[cfg_attr(tarpaulin, skip)]
 pub mod config;
+pub mod diagnostics;
 pub mod errors;
 pub mod logger;
 pub mod synthesizer;
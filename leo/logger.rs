@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::diagnostics::MessageFormat;
+
 use colored::Colorize;
 use std::fmt;
 use tracing::{event::Event, subscriber::Subscriber};
@@ -195,7 +197,11 @@ where
 }
 
 /// Initialize logger with custom format and verbosity.
+///
+/// The log is written to stderr when JSON diagnostics are selected.
 pub fn init_logger(_app_name: &'static str, verbosity: usize) {
+    let message_format = MessageFormat::current();
+
     let subscriber = FmtSubscriber::builder()
         // all spans/events with a level higher than TRACE (e.g, debug, info, warn, etc.)
         // will be written to stdout.
@@ -208,6 +214,13 @@ pub fn init_logger(_app_name: &'static str, verbosity: usize) {
         .without_time()
         .with_target(false)
         .event_format(Format::default())
+        // JSON diagnostics are printed to stdout, so the log moves to stderr.
+        .with_writer(move || -> Box<dyn std::io::Write> {
+            match message_format {
+                MessageFormat::Human => Box::new(std::io::stdout()),
+                MessageFormat::Json => Box::new(std::io::stderr()),
+            }
+        })
         .finish();
 
     tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
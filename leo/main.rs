@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_lang::{cli::*, commands::*, errors::CLIError, logger, updater::Updater};
+use leo_lang::{cli::*, commands::*, diagnostics::MessageFormat, errors::CLIError, logger, updater::Updater};
 
 use clap::{App, AppSettings, Arg};
 
@@ -29,11 +29,19 @@ fn main() -> Result<(), CLIError> {
             AppSettings::DisableHelpSubcommand,
             AppSettings::DisableVersion,
         ])
-        .args(&[Arg::with_name("debug")
-            .short("d")
-            .long("debug")
-            .help("Enables debugging mode")
-            .global(true)])
+        .args(&[
+            Arg::with_name("debug")
+                .short("d")
+                .long("debug")
+                .help("Enables debugging mode")
+                .global(true),
+            Arg::with_name("message-format")
+                .long("message-format")
+                .help("Selects how errors are printed")
+                .takes_value(true)
+                .possible_values(MessageFormat::NAMES)
+                .global(true),
+        ])
         .subcommands(vec![
             NewCommand::new().display_order(0),
             InitCommand::new().display_order(1),
@@ -43,14 +51,19 @@ fn main() -> Result<(), CLIError> {
             SetupCommand::new().display_order(5),
             ProveCommand::new().display_order(6),
             RunCommand::new().display_order(7),
-            LoginCommand::new().display_order(8),
-            AddCommand::new().display_order(9),
-            RemoveCommand::new().display_order(10),
-            PublishCommand::new().display_order(11),
-            DeployCommand::new().display_order(12),
-            CleanCommand::new().display_order(13),
-            LintCommand::new().display_order(14),
-            UpdateCommand::new().display_order(15),
+            ExecuteCommand::new().display_order(8),
+            VerifyCommand::new().display_order(9),
+            LoginCommand::new().display_order(10),
+            AddCommand::new().display_order(11),
+            RemoveCommand::new().display_order(12),
+            PublishCommand::new().display_order(13),
+            DeployCommand::new().display_order(14),
+            CleanCommand::new().display_order(15),
+            LintCommand::new().display_order(16),
+            FmtCommand::new().display_order(17),
+            LspCommand::new().display_order(18),
+            ExplainCommand::new().display_order(19),
+            UpdateCommand::new().display_order(20),
         ])
         .set_term_width(0);
 
@@ -66,6 +79,8 @@ fn main() -> Result<(), CLIError> {
         ("setup", Some(arguments)) => SetupCommand::process(arguments),
         ("prove", Some(arguments)) => ProveCommand::process(arguments),
         ("run", Some(arguments)) => RunCommand::process(arguments),
+        ("execute", Some(arguments)) => ExecuteCommand::process(arguments),
+        ("verify", Some(arguments)) => VerifyCommand::process(arguments),
         ("login", Some(arguments)) => LoginCommand::process(arguments),
         ("add", Some(arguments)) => AddCommand::process(arguments),
         ("remove", Some(arguments)) => RemoveCommand::process(arguments),
@@ -73,6 +88,9 @@ fn main() -> Result<(), CLIError> {
         ("deploy", Some(arguments)) => DeployCommand::process(arguments),
         ("clean", Some(arguments)) => CleanCommand::process(arguments),
         ("lint", Some(arguments)) => LintCommand::process(arguments),
+        ("fmt", Some(arguments)) => FmtCommand::process(arguments),
+        ("lsp", Some(arguments)) => LspCommand::process(arguments),
+        ("explain", Some(arguments)) => ExplainCommand::process(arguments),
         ("update", Some(arguments)) => UpdateCommand::process(arguments),
         _ => {
             // Set logging environment
@@ -0,0 +1,428 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A compact, versioned binary encoding of synthesized circuits and witnesses.
+//!
+//! Every file begins with a four byte magic, a little endian `u32` format version and a
+//! compression flag. The body that follows is optionally deflate compressed and is read and
+//! written as a stream, so the encoded circuit is never held in memory as a whole.
+
+use crate::synthesizer::{CircuitSynthesizer, WitnessSynthesizer};
+
+use snarkos_models::{curves::PairingEngine, gadgets::r1cs::Index};
+use snarkos_utilities::bytes::{FromBytes, ToBytes};
+
+use flate2::{read::DeflateDecoder, write::DeflateEncoder};
+use std::{
+    fmt,
+    io::{self, Read, Write},
+    str::FromStr,
+};
+
+pub static CIRCUIT_MAGIC: &[u8; 4] = b"LEOC";
+pub static WITNESS_MAGIC: &[u8; 4] = b"LEOW";
+pub const BINARY_FORMAT_VERSION: u32 = 1;
+
+/// The most elements preallocated for a count read from a file, so a corrupt count cannot
+/// exhaust memory before the end of the file is reached.
+const MAX_PREALLOCATION: usize = 1 << 16;
+
+/// The format a circuit or witness is serialized to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SerializationFormat {
+    #[default]
+    Json,
+    Binary,
+}
+
+impl SerializationFormat {
+    pub const NAMES: &'static [&'static str] = &["json", "binary"];
+}
+
+impl FromStr for SerializationFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "json" => Ok(SerializationFormat::Json),
+            "binary" => Ok(SerializationFormat::Binary),
+            _ => Err(format.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for SerializationFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SerializationFormat::Json => write!(f, "json"),
+            SerializationFormat::Binary => write!(f, "binary"),
+        }
+    }
+}
+
+/// The compression applied to the body of a binary file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Compression {
+    #[default]
+    None,
+    Deflate,
+}
+
+impl Compression {
+    fn flag(self) -> u8 {
+        match self {
+            Compression::None => 0,
+            Compression::Deflate => 1,
+        }
+    }
+
+    fn from_flag(flag: u8) -> io::Result<Self> {
+        match flag {
+            0 => Ok(Compression::None),
+            1 => Ok(Compression::Deflate),
+            _ => Err(invalid_data(format!("unknown compression flag {}", flag))),
+        }
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn write_header<W: Write>(writer: &mut W, magic: &[u8; 4], compression: Compression) -> io::Result<()> {
+    writer.write_all(magic)?;
+    writer.write_all(&BINARY_FORMAT_VERSION.to_le_bytes())?;
+    writer.write_all(&[compression.flag()])
+}
+
+fn read_header<R: Read>(reader: &mut R, magic: &[u8; 4]) -> io::Result<Compression> {
+    let mut found = [0u8; 4];
+    reader.read_exact(&mut found)?;
+    if &found != magic {
+        return Err(invalid_data(format!(
+            "expected magic {:?}, found {:?}",
+            String::from_utf8_lossy(magic),
+            String::from_utf8_lossy(&found)
+        )));
+    }
+
+    let mut version = [0u8; 4];
+    reader.read_exact(&mut version)?;
+    let version = u32::from_le_bytes(version);
+    if version != BINARY_FORMAT_VERSION {
+        return Err(invalid_data(format!(
+            "unsupported format version {}, expected {}",
+            version, BINARY_FORMAT_VERSION
+        )));
+    }
+
+    let mut flag = [0u8; 1];
+    reader.read_exact(&mut flag)?;
+    Compression::from_flag(flag[0])
+}
+
+/// Writes the header followed by a body that is compressed as requested.
+fn write_file<W, F>(mut writer: W, magic: &[u8; 4], compression: Compression, write_body: F) -> io::Result<()>
+where
+    W: Write,
+    F: FnOnce(&mut dyn Write) -> io::Result<()>,
+{
+    write_header(&mut writer, magic, compression)?;
+
+    match compression {
+        Compression::None => {
+            write_body(&mut writer)?;
+            writer.flush()
+        }
+        Compression::Deflate => {
+            let mut encoder = DeflateEncoder::new(writer, flate2::Compression::default());
+            write_body(&mut encoder)?;
+            encoder.finish()?.flush()
+        }
+    }
+}
+
+/// Reads the header and hands a decompressed body reader to `read_body`.
+///
+/// A file that ends before the counts in its body are read, or whose compressed body is corrupt,
+/// is reported as invalid data.
+fn read_file<R, F, T>(mut reader: R, magic: &[u8; 4], read_body: F) -> io::Result<T>
+where
+    R: Read,
+    F: FnOnce(&mut dyn Read) -> io::Result<T>,
+{
+    let result = match read_header(&mut reader, magic)? {
+        Compression::None => read_body(&mut reader),
+        Compression::Deflate => read_body(&mut DeflateDecoder::new(reader)),
+    };
+
+    result.map_err(|error| match error.kind() {
+        io::ErrorKind::UnexpectedEof => invalid_data("unexpected end of file".to_string()),
+        io::ErrorKind::InvalidInput => invalid_data(error.to_string()),
+        _ => error,
+    })
+}
+
+fn write_u64(writer: &mut dyn Write, value: usize) -> io::Result<()> {
+    writer.write_all(&(value as u64).to_le_bytes())
+}
+
+fn read_u64(reader: &mut dyn Read) -> io::Result<usize> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes) as usize)
+}
+
+fn write_assignments<E: PairingEngine>(writer: &mut dyn Write, assignments: &[E::Fr]) -> io::Result<()> {
+    for assignment in assignments {
+        assignment.write(&mut *writer)?;
+    }
+    Ok(())
+}
+
+fn read_assignments<E: PairingEngine>(reader: &mut dyn Read, count: usize) -> io::Result<Vec<E::Fr>> {
+    let mut assignments = Vec::with_capacity(count.min(MAX_PREALLOCATION));
+    for _ in 0..count {
+        assignments.push(E::Fr::read(&mut *reader)?);
+    }
+    Ok(assignments)
+}
+
+fn write_constraint<E: PairingEngine>(writer: &mut dyn Write, terms: &[(E::Fr, Index)]) -> io::Result<()> {
+    write_u64(writer, terms.len())?;
+    for (coefficient, index) in terms {
+        coefficient.write(&mut *writer)?;
+        match index {
+            Index::Input(index) => {
+                writer.write_all(&[0])?;
+                write_u64(writer, *index)?;
+            }
+            Index::Aux(index) => {
+                writer.write_all(&[1])?;
+                write_u64(writer, *index)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_constraint<E: PairingEngine>(reader: &mut dyn Read) -> io::Result<Vec<(E::Fr, Index)>> {
+    let len = read_u64(reader)?;
+    let mut terms = Vec::with_capacity(len.min(MAX_PREALLOCATION));
+    for _ in 0..len {
+        let coefficient = E::Fr::read(&mut *reader)?;
+
+        let mut tag = [0u8; 1];
+        reader.read_exact(&mut tag)?;
+        let index = match tag[0] {
+            0 => Index::Input(read_u64(reader)?),
+            1 => Index::Aux(read_u64(reader)?),
+            tag => return Err(invalid_data(format!("unknown variable index tag {}", tag))),
+        };
+
+        terms.push((coefficient, index));
+    }
+    Ok(terms)
+}
+
+impl<E: PairingEngine> CircuitSynthesizer<E> {
+    /// Streams the circuit to `writer` in the binary circuit format.
+    pub fn write_binary<W: Write>(&self, writer: W, compression: Compression) -> io::Result<()> {
+        write_file(writer, CIRCUIT_MAGIC, compression, |writer| {
+            write_u64(writer, self.input_assignment.len())?;
+            write_u64(writer, self.aux_assignment.len())?;
+            write_u64(writer, self.at.len())?;
+
+            write_assignments::<E>(writer, &self.input_assignment)?;
+            write_assignments::<E>(writer, &self.aux_assignment)?;
+
+            for i in 0..self.at.len() {
+                write_constraint::<E>(writer, &self.at[i])?;
+                write_constraint::<E>(writer, &self.bt[i])?;
+                write_constraint::<E>(writer, &self.ct[i])?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Streams a circuit in the binary circuit format from `reader`.
+    pub fn read_binary<R: Read>(reader: R) -> io::Result<Self> {
+        read_file(reader, CIRCUIT_MAGIC, |reader| {
+            let num_inputs = read_u64(reader)?;
+            let num_aux = read_u64(reader)?;
+            let num_constraints = read_u64(reader)?;
+
+            let input_assignment = read_assignments::<E>(reader, num_inputs)?;
+            let aux_assignment = read_assignments::<E>(reader, num_aux)?;
+
+            let capacity = num_constraints.min(MAX_PREALLOCATION);
+            let mut at = Vec::with_capacity(capacity);
+            let mut bt = Vec::with_capacity(capacity);
+            let mut ct = Vec::with_capacity(capacity);
+
+            for _ in 0..num_constraints {
+                at.push(read_constraint::<E>(reader)?);
+                bt.push(read_constraint::<E>(reader)?);
+                ct.push(read_constraint::<E>(reader)?);
+            }
+
+            Ok(Self {
+                at,
+                bt,
+                ct,
+                input_assignment,
+                aux_assignment,
+            })
+        })
+    }
+}
+
+impl<E: PairingEngine> WitnessSynthesizer<E> {
+    /// Streams the witness to `writer` in the binary witness format.
+    pub fn write_binary<W: Write>(&self, writer: W, compression: Compression) -> io::Result<()> {
+        write_file(writer, WITNESS_MAGIC, compression, |writer| {
+            write_u64(writer, self.input_assignment.len())?;
+            write_u64(writer, self.aux_assignment.len())?;
+            write_u64(writer, self.num_constraints)?;
+
+            write_assignments::<E>(writer, &self.input_assignment)?;
+            write_assignments::<E>(writer, &self.aux_assignment)
+        })
+    }
+
+    /// Streams a witness in the binary witness format from `reader`.
+    pub fn read_binary<R: Read>(reader: R) -> io::Result<Self> {
+        read_file(reader, WITNESS_MAGIC, |reader| {
+            let num_inputs = read_u64(reader)?;
+            let num_aux = read_u64(reader)?;
+            let num_constraints = read_u64(reader)?;
+
+            Ok(Self {
+                num_constraints,
+                input_assignment: read_assignments::<E>(reader, num_inputs)?,
+                aux_assignment: read_assignments::<E>(reader, num_aux)?,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkos_curves::bls12_377::{Bls12_377, Fr};
+    use snarkos_models::gadgets::r1cs::ConstraintSystem;
+
+    fn circuit() -> CircuitSynthesizer<Bls12_377> {
+        let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+
+        let x = cs.alloc_input(|| "x", || Ok(Fr::from(3u64))).unwrap();
+        let y = cs.alloc(|| "y", || Ok(Fr::from(9u64))).unwrap();
+        cs.enforce(|| "x * x = y", |lc| lc + x, |lc| lc + x, |lc| lc + y);
+
+        cs
+    }
+
+    fn witness() -> WitnessSynthesizer<Bls12_377> {
+        WitnessSynthesizer {
+            num_constraints: 1,
+            input_assignment: vec![Fr::from(3u64)],
+            aux_assignment: vec![Fr::from(9u64)],
+        }
+    }
+
+    #[test]
+    fn test_circuit_round_trip() {
+        let expected = circuit();
+
+        for compression in &[Compression::None, Compression::Deflate] {
+            let mut bytes = vec![];
+            expected.write_binary(&mut bytes, *compression).unwrap();
+
+            let circuit = CircuitSynthesizer::<Bls12_377>::read_binary(&bytes[..]).unwrap();
+
+            assert_eq!(circuit.input_assignment, expected.input_assignment);
+            assert_eq!(circuit.aux_assignment, expected.aux_assignment);
+            assert_eq!(circuit.at, expected.at);
+            assert_eq!(circuit.bt, expected.bt);
+            assert_eq!(circuit.ct, expected.ct);
+        }
+    }
+
+    #[test]
+    fn test_witness_round_trip() {
+        let expected = witness();
+
+        for compression in &[Compression::None, Compression::Deflate] {
+            let mut bytes = vec![];
+            expected.write_binary(&mut bytes, *compression).unwrap();
+
+            let witness = WitnessSynthesizer::<Bls12_377>::read_binary(&bytes[..]).unwrap();
+
+            assert_eq!(witness.num_constraints, expected.num_constraints);
+            assert_eq!(witness.input_assignment, expected.input_assignment);
+            assert_eq!(witness.aux_assignment, expected.aux_assignment);
+        }
+    }
+
+    #[test]
+    fn test_wrong_magic() {
+        let mut bytes = vec![];
+        witness().write_binary(&mut bytes, Compression::None).unwrap();
+
+        let error = CircuitSynthesizer::<Bls12_377>::read_binary(&bytes[..]).err().unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let mut bytes = vec![];
+        circuit().write_binary(&mut bytes, Compression::None).unwrap();
+        bytes[4..8].copy_from_slice(&(BINARY_FORMAT_VERSION + 1).to_le_bytes());
+
+        let error = CircuitSynthesizer::<Bls12_377>::read_binary(&bytes[..]).err().unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_corrupt_counts() {
+        let mut bytes = vec![];
+        circuit().write_binary(&mut bytes, Compression::None).unwrap();
+
+        // The number of inputs, auxiliary variables and constraints follow the nine byte header
+        for offset in &[9, 17, 25] {
+            let mut corrupt = bytes.clone();
+            corrupt[*offset..*offset + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+
+            let error = CircuitSynthesizer::<Bls12_377>::read_binary(&corrupt[..]).err().unwrap();
+
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[test]
+    fn test_truncated_body() {
+        let mut bytes = vec![];
+        circuit().write_binary(&mut bytes, Compression::Deflate).unwrap();
+        bytes.truncate(bytes.len() / 2);
+
+        let error = CircuitSynthesizer::<Bls12_377>::read_binary(&bytes[..]).err().unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
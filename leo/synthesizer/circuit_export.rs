@@ -0,0 +1,443 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Exports a synthesized circuit to formats read by other R1CS tooling.
+//!
+//! Variables are numbered as wires: wire `0` is the constant one that the synthesizer allocates as
+//! its first input, followed by the public inputs of the program and then every auxiliary variable.
+//!
+//! * `.r1cs` and `.wtns` follow the iden3 binary formats used by circom and snarkjs.
+//! * `.zkif` is a sequence of size-prefixed zkInterface flatbuffers messages.
+
+use crate::synthesizer::CircuitSynthesizer;
+
+use snarkos_models::{
+    curves::{FpParameters, One, PairingEngine, PrimeField, Zero},
+    gadgets::r1cs::Index,
+};
+use snarkos_utilities::bytes::ToBytes;
+
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+};
+
+static R1CS_MAGIC: &[u8; 4] = b"r1cs";
+const R1CS_VERSION: u32 = 1;
+const R1CS_HEADER_SECTION: u32 = 1;
+const R1CS_CONSTRAINTS_SECTION: u32 = 2;
+const R1CS_WIRE_TO_LABEL_SECTION: u32 = 3;
+
+static WTNS_MAGIC: &[u8; 4] = b"wtns";
+const WTNS_VERSION: u32 = 2;
+const WTNS_HEADER_SECTION: u32 = 1;
+const WTNS_VALUES_SECTION: u32 = 2;
+
+static ZKIF_IDENTIFIER: &str = "zkif";
+const ZKIF_CIRCUIT_HEADER: u8 = 1;
+const ZKIF_CONSTRAINT_SYSTEM: u8 = 2;
+const ZKIF_WITNESS: u8 = 3;
+
+/// Returns the vtable offset of the field at `index` in a flatbuffers table.
+const fn slot(index: u16) -> u16 {
+    4 + 2 * index
+}
+
+fn field_bytes<F: ToBytes>(field: &F) -> Vec<u8> {
+    let mut bytes = vec![];
+    field.write(&mut bytes).expect("writing to a vector cannot fail");
+    bytes
+}
+
+impl<E: PairingEngine> CircuitSynthesizer<E> {
+    fn num_wires(&self) -> usize {
+        self.input_assignment.len() + self.aux_assignment.len()
+    }
+
+    fn wire(&self, index: Index) -> usize {
+        match index {
+            Index::Input(index) => index,
+            Index::Aux(index) => self.input_assignment.len() + index,
+        }
+    }
+
+    /// Returns the terms of a linear combination with repeated wires merged and zero terms removed.
+    fn wire_terms(&self, terms: &[(E::Fr, Index)]) -> Vec<(usize, E::Fr)> {
+        let mut merged = BTreeMap::new();
+        for (coefficient, index) in terms {
+            *merged.entry(self.wire(*index)).or_insert_with(E::Fr::zero) += coefficient;
+        }
+
+        merged
+            .into_iter()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .collect()
+    }
+
+    fn wire_values(&self) -> impl Iterator<Item = &E::Fr> {
+        self.input_assignment.iter().chain(self.aux_assignment.iter())
+    }
+
+    /// Writes the constraints in the iden3 `.r1cs` binary format.
+    pub fn write_r1cs<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let modulus = field_bytes(&<E::Fr as PrimeField>::Parameters::MODULUS);
+        let n8 = modulus.len();
+
+        let constraints = (0..self.at.len())
+            .map(|i| {
+                (
+                    self.wire_terms(&self.at[i]),
+                    self.wire_terms(&self.bt[i]),
+                    self.wire_terms(&self.ct[i]),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        writer.write_all(R1CS_MAGIC)?;
+        writer.write_all(&R1CS_VERSION.to_le_bytes())?;
+        writer.write_all(&3u32.to_le_bytes())?;
+
+        // Header
+        writer.write_all(&R1CS_HEADER_SECTION.to_le_bytes())?;
+        writer.write_all(&((n8 + 32) as u64).to_le_bytes())?;
+        writer.write_all(&(n8 as u32).to_le_bytes())?;
+        writer.write_all(&modulus)?;
+        writer.write_all(&(self.num_wires() as u32).to_le_bytes())?;
+        // Public outputs are not separated from the public inputs of a Leo program
+        writer.write_all(&0u32.to_le_bytes())?;
+        writer.write_all(&(self.input_assignment.len().saturating_sub(1) as u32).to_le_bytes())?;
+        // Private inputs are not separated from the other auxiliary variables
+        writer.write_all(&0u32.to_le_bytes())?;
+        writer.write_all(&(self.num_wires() as u64).to_le_bytes())?;
+        writer.write_all(&(constraints.len() as u32).to_le_bytes())?;
+
+        // Constraints
+        let size: usize = constraints
+            .iter()
+            .map(|(a, b, c)| 12 + (a.len() + b.len() + c.len()) * (4 + n8))
+            .sum();
+        writer.write_all(&R1CS_CONSTRAINTS_SECTION.to_le_bytes())?;
+        writer.write_all(&(size as u64).to_le_bytes())?;
+        for (a, b, c) in &constraints {
+            for terms in &[a, b, c] {
+                writer.write_all(&(terms.len() as u32).to_le_bytes())?;
+                for (wire, coefficient) in terms.iter() {
+                    writer.write_all(&(*wire as u32).to_le_bytes())?;
+                    coefficient.write(&mut writer)?;
+                }
+            }
+        }
+
+        // Every wire is its own label
+        writer.write_all(&R1CS_WIRE_TO_LABEL_SECTION.to_le_bytes())?;
+        writer.write_all(&((self.num_wires() * 8) as u64).to_le_bytes())?;
+        for wire in 0..self.num_wires() {
+            writer.write_all(&(wire as u64).to_le_bytes())?;
+        }
+
+        writer.flush()
+    }
+
+    /// Writes the assignment of every wire in the iden3 `.wtns` binary format.
+    pub fn write_wtns<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let modulus = field_bytes(&<E::Fr as PrimeField>::Parameters::MODULUS);
+        let n8 = modulus.len();
+
+        writer.write_all(WTNS_MAGIC)?;
+        writer.write_all(&WTNS_VERSION.to_le_bytes())?;
+        writer.write_all(&2u32.to_le_bytes())?;
+
+        // Header
+        writer.write_all(&WTNS_HEADER_SECTION.to_le_bytes())?;
+        writer.write_all(&((n8 + 8) as u64).to_le_bytes())?;
+        writer.write_all(&(n8 as u32).to_le_bytes())?;
+        writer.write_all(&modulus)?;
+        writer.write_all(&(self.num_wires() as u32).to_le_bytes())?;
+
+        // Values
+        writer.write_all(&WTNS_VALUES_SECTION.to_le_bytes())?;
+        writer.write_all(&((self.num_wires() * n8) as u64).to_le_bytes())?;
+        for value in self.wire_values() {
+            value.write(&mut writer)?;
+        }
+
+        writer.flush()
+    }
+
+    /// Writes the circuit header, constraint system and witness as zkInterface messages.
+    pub fn write_zkif<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let num_inputs = self.input_assignment.len();
+
+        // Circuit header with the public inputs as instance variables
+        let mut builder = FlatBufferBuilder::new();
+        let instance_ids = (1..num_inputs).map(|wire| wire as u64).collect::<Vec<_>>();
+        let instance_values = self.input_assignment.get(1..).unwrap_or(&[]);
+        let instance_variables = zkif_variables(&mut builder, &instance_ids, instance_values);
+        let field_maximum = builder.create_vector(&field_bytes(&-E::Fr::one()));
+        let header = builder.start_table();
+        builder.push_slot_always(slot(0), instance_variables);
+        builder.push_slot(slot(1), self.num_wires() as u64, 0);
+        builder.push_slot_always(slot(2), field_maximum);
+        let header = builder.end_table(header);
+        write_zkif_message(&mut writer, builder, ZKIF_CIRCUIT_HEADER, header)?;
+
+        // Constraint system
+        let mut builder = FlatBufferBuilder::new();
+        let constraints = (0..self.at.len())
+            .map(|i| {
+                let terms = [&self.at[i], &self.bt[i], &self.ct[i]]
+                    .iter()
+                    .map(|terms| {
+                        let (ids, coefficients): (Vec<_>, Vec<_>) = self
+                            .wire_terms(terms)
+                            .into_iter()
+                            .map(|(wire, coefficient)| (wire as u64, coefficient))
+                            .unzip();
+                        zkif_variables(&mut builder, &ids, &coefficients)
+                    })
+                    .collect::<Vec<_>>();
+
+                let constraint = builder.start_table();
+                builder.push_slot_always(slot(0), terms[0]);
+                builder.push_slot_always(slot(1), terms[1]);
+                builder.push_slot_always(slot(2), terms[2]);
+                builder.end_table(constraint)
+            })
+            .collect::<Vec<_>>();
+        let constraints = builder.create_vector(&constraints);
+        let constraint_system = builder.start_table();
+        builder.push_slot_always(slot(0), constraints);
+        let constraint_system = builder.end_table(constraint_system);
+        write_zkif_message(&mut writer, builder, ZKIF_CONSTRAINT_SYSTEM, constraint_system)?;
+
+        // Witness with the assignments of the auxiliary variables
+        let mut builder = FlatBufferBuilder::new();
+        let witness_ids = (num_inputs..self.num_wires()).map(|wire| wire as u64).collect::<Vec<_>>();
+        let assigned_variables = zkif_variables(&mut builder, &witness_ids, &self.aux_assignment);
+        let witness = builder.start_table();
+        builder.push_slot_always(slot(0), assigned_variables);
+        let witness = builder.end_table(witness);
+        write_zkif_message(&mut writer, builder, ZKIF_WITNESS, witness)?;
+
+        writer.flush()
+    }
+}
+
+/// Builds a zkInterface `Variables` table of wire ids and their little endian values.
+fn zkif_variables<'a, F: ToBytes>(
+    builder: &mut FlatBufferBuilder<'a>,
+    ids: &[u64],
+    values: &[F],
+) -> WIPOffset<flatbuffers::TableFinishedWIPOffset> {
+    let values = values.iter().flat_map(field_bytes).collect::<Vec<_>>();
+
+    let ids = builder.create_vector(ids);
+    let values = builder.create_vector(&values);
+    let variables = builder.start_table();
+    builder.push_slot_always(slot(0), ids);
+    builder.push_slot_always(slot(1), values);
+    builder.end_table(variables)
+}
+
+/// Wraps a message in a zkInterface `Root` table and writes it with its size prefix.
+fn write_zkif_message<W: Write>(
+    writer: &mut W,
+    mut builder: FlatBufferBuilder,
+    message_type: u8,
+    message: WIPOffset<flatbuffers::TableFinishedWIPOffset>,
+) -> io::Result<()> {
+    let root = builder.start_table();
+    builder.push_slot_always(slot(1), message);
+    builder.push_slot(slot(0), message_type, 0);
+    let root = builder.end_table(root);
+    builder.finish_size_prefixed(root, Some(ZKIF_IDENTIFIER));
+
+    writer.write_all(builder.finished_data())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkos_curves::bls12_377::{Bls12_377, Fr};
+    use snarkos_models::gadgets::r1cs::ConstraintSystem;
+    use snarkos_utilities::bytes::FromBytes;
+
+    type Terms = Vec<(usize, Fr)>;
+
+    /// Reads little endian integers and field elements from an exported file.
+    struct Cursor<'a>(&'a [u8]);
+
+    impl<'a> Cursor<'a> {
+        fn take(&mut self, len: usize) -> &'a [u8] {
+            let (bytes, rest) = self.0.split_at(len);
+            self.0 = rest;
+            bytes
+        }
+
+        fn u32(&mut self) -> u32 {
+            let mut bytes = [0u8; 4];
+            bytes.copy_from_slice(self.take(4));
+            u32::from_le_bytes(bytes)
+        }
+
+        fn u64(&mut self) -> u64 {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(self.take(8));
+            u64::from_le_bytes(bytes)
+        }
+
+        fn field(&mut self, n8: usize) -> Fr {
+            Fr::read(self.take(n8)).unwrap()
+        }
+    }
+
+    /// Synthesizes `x * x = y` and `y * 1 = 9` for the public input `x = 3`.
+    fn square() -> CircuitSynthesizer<Bls12_377> {
+        let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+
+        let x = cs.alloc_input(|| "x", || Ok(Fr::from(3u64))).unwrap();
+        let y = cs.alloc(|| "y", || Ok(Fr::from(9u64))).unwrap();
+
+        cs.enforce(|| "x * x = y", |lc| lc + x, |lc| lc + x, |lc| lc + y);
+        cs.enforce(
+            || "y * 1 = 9",
+            |lc| lc + y,
+            |lc| lc + CircuitSynthesizer::<Bls12_377>::one(),
+            |lc| lc + (Fr::from(9u64), CircuitSynthesizer::<Bls12_377>::one()),
+        );
+
+        cs
+    }
+
+    /// Returns the number of public inputs and the constraints of an `.r1cs` file.
+    fn read_r1cs(bytes: &[u8]) -> (u32, Vec<(Terms, Terms, Terms)>) {
+        let mut cursor = Cursor(bytes);
+        assert_eq!(cursor.take(4), R1CS_MAGIC);
+        assert_eq!(cursor.u32(), R1CS_VERSION);
+        assert_eq!(cursor.u32(), 3);
+
+        assert_eq!(cursor.u32(), R1CS_HEADER_SECTION);
+        let _size = cursor.u64();
+        let n8 = cursor.u32() as usize;
+        let _modulus = cursor.take(n8);
+        let _num_wires = cursor.u32();
+        let _num_outputs = cursor.u32();
+        let num_public_inputs = cursor.u32();
+        let _num_private_inputs = cursor.u32();
+        let _num_labels = cursor.u64();
+        let num_constraints = cursor.u32();
+
+        assert_eq!(cursor.u32(), R1CS_CONSTRAINTS_SECTION);
+        let _size = cursor.u64();
+        let mut read_terms = |cursor: &mut Cursor| {
+            (0..cursor.u32())
+                .map(|_| (cursor.u32() as usize, cursor.field(n8)))
+                .collect::<Terms>()
+        };
+        let constraints = (0..num_constraints)
+            .map(|_| (read_terms(&mut cursor), read_terms(&mut cursor), read_terms(&mut cursor)))
+            .collect();
+
+        (num_public_inputs, constraints)
+    }
+
+    /// Returns the wire values of a `.wtns` file.
+    fn read_wtns(bytes: &[u8]) -> Vec<Fr> {
+        let mut cursor = Cursor(bytes);
+        assert_eq!(cursor.take(4), WTNS_MAGIC);
+        assert_eq!(cursor.u32(), WTNS_VERSION);
+        assert_eq!(cursor.u32(), 2);
+
+        assert_eq!(cursor.u32(), WTNS_HEADER_SECTION);
+        let _size = cursor.u64();
+        let n8 = cursor.u32() as usize;
+        let _modulus = cursor.take(n8);
+        let num_wires = cursor.u32();
+
+        assert_eq!(cursor.u32(), WTNS_VALUES_SECTION);
+        let _size = cursor.u64();
+        (0..num_wires).map(|_| cursor.field(n8)).collect()
+    }
+
+    fn evaluate(terms: &[(usize, Fr)], witness: &[Fr]) -> Fr {
+        terms
+            .iter()
+            .fold(Fr::zero(), |sum, (wire, coefficient)| sum + &(witness[*wire] * coefficient))
+    }
+
+    #[test]
+    fn test_r1cs_satisfied_by_wtns() {
+        let cs = square();
+
+        let mut r1cs = vec![];
+        cs.write_r1cs(&mut r1cs).unwrap();
+        let mut wtns = vec![];
+        cs.write_wtns(&mut wtns).unwrap();
+
+        let (num_public_inputs, constraints) = read_r1cs(&r1cs);
+        let witness = read_wtns(&wtns);
+
+        assert_eq!(num_public_inputs, 1);
+        assert_eq!(witness, vec![Fr::one(), Fr::from(3u64), Fr::from(9u64)]);
+        assert_eq!(constraints.len(), 2);
+
+        for (a, b, c) in &constraints {
+            assert_eq!(evaluate(a, &witness) * &evaluate(b, &witness), evaluate(c, &witness));
+        }
+    }
+
+    #[test]
+    fn test_r1cs_not_satisfied_by_wrong_wtns() {
+        let mut cs = square();
+        cs.aux_assignment[0] = Fr::from(10u64);
+
+        let mut r1cs = vec![];
+        cs.write_r1cs(&mut r1cs).unwrap();
+        let mut wtns = vec![];
+        cs.write_wtns(&mut wtns).unwrap();
+
+        let (_, constraints) = read_r1cs(&r1cs);
+        let witness = read_wtns(&wtns);
+
+        assert!(
+            constraints
+                .iter()
+                .any(|(a, b, c)| evaluate(a, &witness) * &evaluate(b, &witness) != evaluate(c, &witness))
+        );
+    }
+
+    #[test]
+    fn test_zkif_messages() {
+        let cs = square();
+
+        let mut zkif = vec![];
+        cs.write_zkif(&mut zkif).unwrap();
+
+        // Every message is a size prefixed flatbuffer with the zkInterface identifier
+        let mut cursor = Cursor(&zkif);
+        let mut messages = 0;
+        while !cursor.0.is_empty() {
+            let size = cursor.u32() as usize;
+            let message = cursor.take(size);
+
+            assert_eq!(&message[4..8], ZKIF_IDENTIFIER.as_bytes());
+            messages += 1;
+        }
+
+        assert_eq!(messages, 3);
+    }
+}
@@ -16,7 +16,7 @@
 
 use snarkos_errors::gadgets::SynthesisError;
 use snarkos_models::{
-    curves::{Field, PairingEngine},
+    curves::{Field, One, PairingEngine},
     gadgets::r1cs::{ConstraintSystem, Index, LinearCombination, Variable},
 };
 
@@ -31,6 +31,29 @@ pub struct CircuitSynthesizer<E: PairingEngine> {
     pub(crate) aux_assignment: Vec<E::Fr>,
 }
 
+impl<E: PairingEngine> Default for CircuitSynthesizer<E> {
+    /// Returns an empty synthesizer with the constant one allocated as the first input.
+    ///
+    /// The proving backends allocate the constant one before synthesizing a circuit, so
+    /// `CS::one()` and the public inputs of the program refer to different variables.
+    fn default() -> Self {
+        Self {
+            at: vec![],
+            bt: vec![],
+            ct: vec![],
+            input_assignment: vec![E::Fr::one()],
+            aux_assignment: vec![],
+        }
+    }
+}
+
+impl<E: PairingEngine> CircuitSynthesizer<E> {
+    /// Returns the assignments of the public inputs without the constant one.
+    pub fn public_inputs(&self) -> &[E::Fr] {
+        self.input_assignment.get(1..).unwrap_or(&[])
+    }
+}
+
 impl<E: PairingEngine> ConstraintSystem<E::Fr> for CircuitSynthesizer<E> {
     type Root = Self;
 
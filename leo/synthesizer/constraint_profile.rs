@@ -0,0 +1,313 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Attributes the cost of a synthesized circuit to the Leo source that produced it.
+//!
+//! The compiler names its namespaces after the statement or expression being enforced, usually
+//! ending in the `line:column` of its span, and names function calls `function call {name} {line}:{column}`.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    ops::AddAssign,
+    rc::Rc,
+};
+
+/// The root frame of every folded stack.
+pub static PROFILE_ROOT: &str = "main";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProfileCounts {
+    pub constraints: usize,
+    pub variables: usize,
+}
+
+impl AddAssign for ProfileCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.constraints += other.constraints;
+        self.variables += other.variables;
+    }
+}
+
+/// A single line of the profile table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileRow {
+    pub function: String,
+    pub location: Option<String>,
+    pub description: String,
+    pub counts: ProfileCounts,
+}
+
+/// The constraints and variables added directly under each namespace path.
+#[derive(Clone, Debug, Default)]
+pub struct ConstraintProfile {
+    namespaces: BTreeMap<Vec<String>, ProfileCounts>,
+    aux_namespaces: Vec<Rc<Vec<String>>>,
+}
+
+impl ConstraintProfile {
+    pub(crate) fn push_aux_namespace(&mut self, namespace: Rc<Vec<String>>) {
+        self.aux_namespaces.push(namespace);
+    }
+
+    /// Returns the namespace path the auxiliary variable at `index` was allocated under.
+    pub fn aux_namespace(&self, index: usize) -> Option<&[String]> {
+        self.aux_namespaces.get(index).map(|namespace| namespace.as_slice())
+    }
+
+    pub(crate) fn counts_mut(&mut self, namespace: &[String]) -> &mut ProfileCounts {
+        if !self.namespaces.contains_key(namespace) {
+            self.namespaces.insert(namespace.to_vec(), ProfileCounts::default());
+        }
+
+        self.namespaces.get_mut(namespace).unwrap()
+    }
+
+    /// Returns the counts of the whole circuit.
+    pub fn total(&self) -> ProfileCounts {
+        let mut total = ProfileCounts::default();
+        for counts in self.namespaces.values() {
+            total += *counts;
+        }
+        total
+    }
+
+    /// Returns the cost of each Leo function and span, most constraints first.
+    ///
+    /// Namespaces are attributed to the innermost enclosing span, so repeated
+    /// loop iterations and gadget internals are summed into the statement that created them.
+    pub fn rows(&self) -> Vec<ProfileRow> {
+        let mut rows: HashMap<(String, Option<String>, String), ProfileCounts> = HashMap::new();
+
+        for (namespace, counts) in &self.namespaces {
+            *rows.entry(attribute(namespace)).or_default() += *counts;
+        }
+
+        let mut rows = rows
+            .into_iter()
+            .map(|((function, location, description), counts)| ProfileRow {
+                function,
+                location,
+                description,
+                counts,
+            })
+            .collect::<Vec<_>>();
+
+        rows.sort_by(|a, b| {
+            b.counts
+                .constraints
+                .cmp(&a.counts.constraints)
+                .then(b.counts.variables.cmp(&a.counts.variables))
+                .then(a.function.cmp(&b.function))
+                .then(a.location.cmp(&b.location))
+                .then(a.description.cmp(&b.description))
+        });
+
+        rows
+    }
+
+    /// Returns the constraint counts as folded stacks, one `frame;frame;frame count` line per stack.
+    /// Numeric indices are removed from the frames so that loop iterations and gadget bits are merged.
+    /// The output can be rendered by `flamegraph.pl` or `inferno-flamegraph`.
+    pub fn to_folded_stacks(&self) -> String {
+        let mut stacks: BTreeMap<String, usize> = BTreeMap::new();
+
+        for (namespace, counts) in &self.namespaces {
+            if counts.constraints == 0 {
+                continue;
+            }
+
+            let mut stack = PROFILE_ROOT.to_string();
+            for segment in namespace {
+                stack.push(';');
+                stack.push_str(&strip_indices(segment).replace(';', ","));
+            }
+
+            *stacks.entry(stack).or_default() += counts.constraints;
+        }
+
+        stacks
+            .into_iter()
+            .map(|(stack, constraints)| format!("{} {}\n", stack, constraints))
+            .collect()
+    }
+}
+
+impl fmt::Display for ConstraintProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let total = self.total();
+
+        writeln!(f, "{:>12} {:>12}  {:<24} {:<10} statement", "constraints", "variables", "function", "span")?;
+        for row in self.rows() {
+            writeln!(
+                f,
+                "{:>12} {:>12}  {:<24} {:<10} {}",
+                row.counts.constraints,
+                row.counts.variables,
+                row.function,
+                row.location.as_deref().unwrap_or("-"),
+                row.description
+            )?;
+        }
+        write!(f, "{:>12} {:>12}  total", total.constraints, total.variables)
+    }
+}
+
+/// Returns the Leo function, span and statement description a namespace path was created by.
+pub(crate) fn attribute(namespace: &[String]) -> (String, Option<String>, String) {
+    let function = namespace
+        .iter()
+        .rev()
+        .find_map(|segment| function_name(segment))
+        .unwrap_or(PROFILE_ROOT)
+        .to_string();
+
+    let spanned = namespace.iter().rev().find_map(|segment| {
+        let (description, location) = split_location(segment);
+        location.map(|location| (description, location))
+    });
+    let (description, location) = match spanned {
+        Some((description, location)) => (description, Some(location.to_string())),
+        None => (namespace.first().map(|segment| segment.as_str()).unwrap_or(""), None),
+    };
+
+    (function, location, strip_indices(description))
+}
+
+/// Returns the name of the called function if the namespace is a function call.
+fn function_name(segment: &str) -> Option<&str> {
+    let call = segment.strip_prefix("function call ")?;
+    Some(split_location(call).0)
+}
+
+fn is_location(word: &str) -> bool {
+    let is_number = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+
+    match word.split_once(':') {
+        Some((line, column)) => is_number(line) && is_number(column),
+        None => false,
+    }
+}
+
+/// Splits a trailing `line:column` off a namespace.
+fn split_location(segment: &str) -> (&str, Option<&str>) {
+    match segment.rsplit_once(' ') {
+        Some((description, location)) if is_location(location) => (description, Some(location)),
+        _ => (segment, None),
+    }
+}
+
+/// Replaces loop counters, bit positions and other numeric indices with `#` so that repeated
+/// namespaces share a row. Spans and type names such as `u32` are kept.
+fn strip_indices(description: &str) -> String {
+    description
+        .split(' ')
+        .map(|word| {
+            if is_location(word) {
+                return word.to_string();
+            }
+
+            let mut stripped = String::with_capacity(word.len());
+            let mut keep_digits = false;
+            for c in word.chars() {
+                if !c.is_ascii_digit() {
+                    keep_digits = c.is_ascii_alphabetic();
+                    stripped.push(c);
+                } else if keep_digits {
+                    stripped.push(c);
+                } else if !stripped.ends_with('#') {
+                    stripped.push('#');
+                }
+            }
+            stripped
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(namespaces: &[(&[&str], usize, usize)]) -> ConstraintProfile {
+        let mut profile = ConstraintProfile::default();
+        for (namespace, constraints, variables) in namespaces {
+            let namespace = namespace.iter().map(|segment| segment.to_string()).collect::<Vec<_>>();
+            *profile.counts_mut(&namespace) += ProfileCounts {
+                constraints: *constraints,
+                variables: *variables,
+            };
+        }
+        profile
+    }
+
+    #[test]
+    fn test_attribute() {
+        let namespace = ["function call square 2:5", "let y 3:9", "multiplication 12"]
+            .iter()
+            .map(|segment| segment.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            attribute(&namespace),
+            ("square".to_string(), Some("3:9".to_string()), "let y".to_string())
+        );
+        assert_eq!(attribute(&[]), (PROFILE_ROOT.to_string(), None, "".to_string()));
+    }
+
+    #[test]
+    fn test_rows_merge_loop_iterations() {
+        let profile = profile(&[
+            (&["for 0 4:5", "bit 0"], 2, 1),
+            (&["for 1 4:5", "bit 1"], 2, 1),
+            (&["let a 2:5"], 5, 3),
+        ]);
+
+        let rows = profile.rows();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].description, "let a");
+        assert_eq!(rows[0].counts, ProfileCounts {
+            constraints: 5,
+            variables: 3
+        });
+        assert_eq!(rows[1].description, "for #");
+        assert_eq!(rows[1].location.as_deref(), Some("4:5"));
+        assert_eq!(rows[1].counts, ProfileCounts {
+            constraints: 4,
+            variables: 2
+        });
+        assert_eq!(profile.total(), ProfileCounts {
+            constraints: 9,
+            variables: 5
+        });
+    }
+
+    #[test]
+    fn test_folded_stacks() {
+        let profile = profile(&[
+            (&["function call square 2:5", "let y 3:9", "bit 0"], 1, 1),
+            (&["function call square 2:5", "let y 3:9", "bit 1"], 1, 1),
+            (&["let a; b 5:1"], 3, 0),
+            (&["allocate u32"], 0, 32),
+        ]);
+
+        assert_eq!(
+            profile.to_folded_stacks(),
+            "main;function call square 2:5;let y 3:9;bit # 2\nmain;let a, b 5:1 3\n"
+        );
+    }
+}
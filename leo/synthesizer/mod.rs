@@ -14,9 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod binary_format;
+pub use self::binary_format::*;
+
+pub mod circuit_export;
+
 pub mod circuit_synthesizer;
 pub use self::circuit_synthesizer::*;
 
+pub mod constraint_profile;
+pub use self::constraint_profile::*;
+
+pub mod profiling_synthesizer;
+pub use self::profiling_synthesizer::*;
+
 pub mod serialized_circuit;
 pub use self::serialized_circuit::*;
 
@@ -25,3 +36,12 @@ pub use self::serialized_field::*;
 
 pub mod serialized_index;
 pub use self::serialized_index::*;
+
+pub mod serialized_witness;
+pub use self::serialized_witness::*;
+
+pub mod underconstrained;
+pub use self::underconstrained::*;
+
+pub mod witness_synthesizer;
+pub use self::witness_synthesizer::*;
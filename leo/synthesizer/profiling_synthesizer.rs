@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::synthesizer::{ConstraintProfile, ProfileCounts};
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::Field,
+    gadgets::r1cs::{ConstraintSystem, LinearCombination, Variable},
+};
+
+use std::{marker::PhantomData, rc::Rc};
+
+/// Wraps a constraint system and records the constraints and variables added under each namespace path.
+pub struct ProfilingSynthesizer<F: Field, CS: ConstraintSystem<F>> {
+    inner: CS,
+    namespace: Vec<String>,
+    shared_namespace: Option<Rc<Vec<String>>>,
+    profile: ConstraintProfile,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ProfilingSynthesizer<F, CS> {
+    pub fn new(inner: CS) -> Self {
+        Self {
+            inner,
+            namespace: vec![],
+            shared_namespace: None,
+            profile: ConstraintProfile::default(),
+            _field: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped constraint system along with the recorded profile.
+    pub fn into_parts(self) -> (CS, ConstraintProfile) {
+        (self.inner, self.profile)
+    }
+
+    fn current(&mut self) -> &mut ProfileCounts {
+        self.profile.counts_mut(&self.namespace)
+    }
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for ProfilingSynthesizer<F, CS> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let variable = self.inner.alloc(annotation, f)?;
+        self.current().variables += 1;
+
+        // Remember where the variable was allocated, sharing the path between siblings
+        let namespace = &self.namespace;
+        let shared = self
+            .shared_namespace
+            .get_or_insert_with(|| Rc::new(namespace.clone()))
+            .clone();
+        self.profile.push_aux_namespace(shared);
+
+        Ok(variable)
+    }
+
+    #[inline]
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let variable = self.inner.alloc_input(annotation, f)?;
+        self.current().variables += 1;
+        Ok(variable)
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.inner.enforce(annotation, a, b, c);
+        self.current().constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        let name = name_fn().into();
+        self.inner.get_root().push_namespace(|| name.clone());
+        self.namespace.push(name);
+        self.shared_namespace = None;
+    }
+
+    fn pop_namespace(&mut self) {
+        self.inner.get_root().pop_namespace();
+        self.namespace.pop();
+        self.shared_namespace = None;
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.inner.num_constraints()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synthesizer::CircuitSynthesizer;
+    use snarkos_curves::bls12_377::{Bls12_377, Fr};
+
+    #[test]
+    fn test_records_namespaces() {
+        let mut profiler = ProfilingSynthesizer::new(CircuitSynthesizer::<Bls12_377>::default());
+
+        let x = profiler.alloc(|| "x", || Ok(Fr::from(3u64))).unwrap();
+        {
+            let mut cs = profiler.ns(|| "function call square 2:5");
+            let mut cs = cs.ns(|| "let y 3:9");
+            let y = cs.alloc(|| "y", || Ok(Fr::from(9u64))).unwrap();
+            cs.enforce(|| "x * x = y", |lc| lc + x, |lc| lc + x, |lc| lc + y);
+        }
+
+        let (cs, profile) = profiler.into_parts();
+
+        // The wrapped constraint system receives every variable and constraint
+        assert_eq!(cs.num_constraints(), 1);
+        assert_eq!(cs.aux_assignment.len(), 2);
+
+        assert_eq!(profile.total(), ProfileCounts {
+            constraints: 1,
+            variables: 2
+        });
+        assert_eq!(profile.aux_namespace(0), Some(&[][..]));
+        assert_eq!(
+            profile.aux_namespace(1),
+            Some(&["function call square 2:5".to_string(), "let y 3:9".to_string()][..])
+        );
+        assert_eq!(profile.aux_namespace(2), None);
+    }
+}
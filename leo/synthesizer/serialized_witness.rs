@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::synthesizer::{SerializedField, WitnessSynthesizer};
+
+use snarkos_models::curves::PairingEngine;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct SerializedWitness {
+    pub num_inputs: usize,
+    pub num_aux: usize,
+
+    pub input_assignment: Vec<SerializedField>,
+    pub aux_assignment: Vec<SerializedField>,
+}
+
+impl SerializedWitness {
+    pub fn to_json_string(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&self)
+    }
+
+    pub fn from_json_string(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+impl<E: PairingEngine> From<WitnessSynthesizer<E>> for SerializedWitness {
+    fn from(synthesizer: WitnessSynthesizer<E>) -> Self {
+        let num_inputs = synthesizer.input_assignment.len();
+        let num_aux = synthesizer.aux_assignment.len();
+
+        let input_assignment = synthesizer.input_assignment.iter().map(SerializedField::from).collect();
+        let aux_assignment = synthesizer.aux_assignment.iter().map(SerializedField::from).collect();
+
+        Self {
+            num_inputs,
+            num_aux,
+            input_assignment,
+            aux_assignment,
+        }
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Detects witnesses that the synthesized constraints do not pin down.
+
+use crate::synthesizer::{attribute, CircuitSynthesizer, ConstraintProfile};
+
+use snarkos_models::{curves::PairingEngine, gadgets::r1cs::Index};
+
+use std::{collections::BTreeMap, fmt};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UnderconstrainedKind {
+    /// The variable appears in no constraint, so any value satisfies the circuit.
+    Unconstrained,
+    /// The variable only appears in constraints where one side of the product is a constant.
+    LinearOnly,
+}
+
+impl fmt::Display for UnderconstrainedKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UnderconstrainedKind::Unconstrained => write!(f, "unconstrained"),
+            UnderconstrainedKind::LinearOnly => write!(f, "only linearly constrained"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnderconstrainedVariable {
+    pub index: usize,
+    pub kind: UnderconstrainedKind,
+}
+
+/// The underconstrained variables allocated by a single Leo statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnderconstrainedWarning {
+    pub kind: UnderconstrainedKind,
+    pub function: String,
+    pub location: Option<String>,
+    pub description: String,
+    pub variables: usize,
+}
+
+impl fmt::Display for UnderconstrainedWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} variable{} allocated by `{}` in function `{}`",
+            self.variables,
+            self.kind,
+            if self.variables == 1 { "" } else { "s" },
+            self.description,
+            self.function
+        )?;
+
+        match &self.location {
+            Some(location) => write!(f, " at {}", location),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns `true` if the linear combination only refers to the constant one variable.
+fn is_constant<F>(terms: &[(F, Index)]) -> bool {
+    terms.iter().all(|(_, index)| *index == Index::Input(0))
+}
+
+impl<E: PairingEngine> CircuitSynthesizer<E> {
+    /// Returns the auxiliary variables that appear in no constraint or only in linear constraints.
+    pub fn underconstrained_variables(&self) -> Vec<UnderconstrainedVariable> {
+        let mut constrained = vec![false; self.aux_assignment.len()];
+        let mut nonlinear = vec![false; self.aux_assignment.len()];
+
+        for i in 0..self.at.len() {
+            let linear = is_constant(&self.at[i]) || is_constant(&self.bt[i]);
+
+            for (_, index) in self.at[i].iter().chain(self.bt[i].iter()).chain(self.ct[i].iter()) {
+                if let Index::Aux(index) = index {
+                    constrained[*index] = true;
+                    nonlinear[*index] |= !linear;
+                }
+            }
+        }
+
+        (0..self.aux_assignment.len())
+            .filter_map(|index| {
+                let kind = if !constrained[index] {
+                    UnderconstrainedKind::Unconstrained
+                } else if !nonlinear[index] {
+                    UnderconstrainedKind::LinearOnly
+                } else {
+                    return None;
+                };
+
+                Some(UnderconstrainedVariable { index, kind })
+            })
+            .collect()
+    }
+}
+
+/// Groups underconstrained variables by the Leo function and statement that allocated them.
+pub fn underconstrained_warnings(
+    variables: &[UnderconstrainedVariable],
+    profile: &ConstraintProfile,
+) -> Vec<UnderconstrainedWarning> {
+    let mut warnings = BTreeMap::new();
+
+    for variable in variables {
+        let (function, location, description) = attribute(profile.aux_namespace(variable.index).unwrap_or(&[]));
+
+        *warnings
+            .entry((variable.kind, function, location, description))
+            .or_insert(0) += 1;
+    }
+
+    warnings
+        .into_iter()
+        .map(
+            |((kind, function, location, description), variables)| UnderconstrainedWarning {
+                kind,
+                function,
+                location,
+                description,
+                variables,
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synthesizer::ProfilingSynthesizer;
+    use snarkos_curves::bls12_377::{Bls12_377, Fr};
+    use snarkos_models::gadgets::r1cs::ConstraintSystem;
+
+    /// Allocates an unconstrained, a linearly constrained and a fully constrained variable.
+    fn synthesize() -> (CircuitSynthesizer<Bls12_377>, ConstraintProfile) {
+        let mut profiler = ProfilingSynthesizer::new(CircuitSynthesizer::<Bls12_377>::default());
+        let one = CircuitSynthesizer::<Bls12_377>::one();
+
+        let x = profiler.alloc_input(|| "x", || Ok(Fr::from(3u64))).unwrap();
+        {
+            let mut cs = profiler.ns(|| "function call square 2:5");
+
+            let _unused = cs.ns(|| "let unused 3:9").alloc(|| "unused", || Ok(Fr::from(1u64))).unwrap();
+
+            {
+                let mut cs = cs.ns(|| "let linear 4:9");
+                let linear = cs.alloc(|| "linear", || Ok(Fr::from(3u64))).unwrap();
+                cs.enforce(|| "linear = x", |lc| lc + linear, |lc| lc + one, |lc| lc + x);
+            }
+
+            let mut cs = cs.ns(|| "let square 5:9");
+            let square = cs.alloc(|| "square", || Ok(Fr::from(9u64))).unwrap();
+            cs.enforce(|| "x * x = square", |lc| lc + x, |lc| lc + x, |lc| lc + square);
+        }
+
+        profiler.into_parts()
+    }
+
+    #[test]
+    fn test_underconstrained_variables() {
+        let (cs, _) = synthesize();
+
+        assert_eq!(cs.underconstrained_variables(), vec![
+            UnderconstrainedVariable {
+                index: 0,
+                kind: UnderconstrainedKind::Unconstrained,
+            },
+            UnderconstrainedVariable {
+                index: 1,
+                kind: UnderconstrainedKind::LinearOnly,
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_public_input_is_not_constant() {
+        let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+
+        let x = cs.alloc_input(|| "x", || Ok(Fr::from(3u64))).unwrap();
+        let y = cs.alloc(|| "y", || Ok(Fr::from(9u64))).unwrap();
+        cs.enforce(|| "x * y = 27", |lc| lc + x, |lc| lc + y, |lc| {
+            lc + (Fr::from(27u64), CircuitSynthesizer::<Bls12_377>::one())
+        });
+
+        assert!(cs.underconstrained_variables().is_empty());
+    }
+
+    #[test]
+    fn test_underconstrained_warnings() {
+        let (cs, profile) = synthesize();
+
+        let warnings = underconstrained_warnings(&cs.underconstrained_variables(), &profile);
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[0].to_string(),
+            "1 unconstrained variable allocated by `let unused` in function `square` at 3:9"
+        );
+        assert_eq!(
+            warnings[1].to_string(),
+            "1 only linearly constrained variable allocated by `let linear` in function `square` at 4:9"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkos_errors::gadgets::SynthesisError;
+use snarkos_models::{
+    curves::PairingEngine,
+    gadgets::r1cs::{ConstraintSystem, Index, LinearCombination, Variable},
+};
+
+/// Records the assignments of variables without storing the constraints that use them.
+pub struct WitnessSynthesizer<E: PairingEngine> {
+    // Number of enforced constraints
+    pub(crate) num_constraints: usize,
+
+    // Assignments of variables
+    pub(crate) input_assignment: Vec<E::Fr>,
+    pub(crate) aux_assignment: Vec<E::Fr>,
+}
+
+impl<E: PairingEngine> Default for WitnessSynthesizer<E> {
+    fn default() -> Self {
+        Self {
+            num_constraints: 0,
+            input_assignment: vec![],
+            aux_assignment: vec![],
+        }
+    }
+}
+
+impl<E: PairingEngine> ConstraintSystem<E::Fr> for WitnessSynthesizer<E> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<F, A, AR>(&mut self, _: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let index = self.aux_assignment.len();
+        self.aux_assignment.push(f()?);
+        Ok(Variable::new_unchecked(Index::Aux(index)))
+    }
+
+    #[inline]
+    fn alloc_input<F, A, AR>(&mut self, _: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let index = self.input_assignment.len();
+        self.input_assignment.push(f()?);
+        Ok(Variable::new_unchecked(Index::Input(index)))
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, _: LA, _: LB, _: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+        LB: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+        LC: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+    {
+        // Do nothing; the linear combinations are not needed to solve the witness.
+        self.num_constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn pop_namespace(&mut self) {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synthesizer::CircuitSynthesizer;
+    use snarkos_curves::bls12_377::{Bls12_377, Fr};
+
+    /// Allocates `x = 3` as a public input and enforces `x * x = y`.
+    fn square<CS: ConstraintSystem<Fr>>(cs: &mut CS) {
+        let x = cs.alloc_input(|| "x", || Ok(Fr::from(3u64))).unwrap();
+        let y = cs.alloc(|| "y", || Ok(Fr::from(9u64))).unwrap();
+
+        cs.enforce(|| "x * x = y", |lc| lc + x, |lc| lc + x, |lc| lc + y);
+    }
+
+    #[test]
+    fn test_matches_circuit_synthesizer() {
+        let mut circuit = CircuitSynthesizer::<Bls12_377>::default();
+        square(&mut circuit);
+
+        let mut witness = WitnessSynthesizer::<Bls12_377>::default();
+        square(&mut witness);
+
+        assert_eq!(witness.num_constraints(), circuit.num_constraints());
+        assert_eq!(witness.input_assignment, circuit.public_inputs());
+        assert_eq!(witness.aux_assignment, circuit.aux_assignment);
+    }
+
+    #[test]
+    fn test_alloc_error() {
+        let mut witness = WitnessSynthesizer::<Bls12_377>::default();
+
+        let result = witness.alloc(|| "missing", || Err(SynthesisError::AssignmentMissing));
+
+        assert!(result.is_err());
+        assert!(witness.aux_assignment.is_empty());
+    }
+}
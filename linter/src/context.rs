@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::FunctionVariables;
+use leo_ast::{CircuitMember, Function, Identifier, Program};
+use leo_symbol_table::{SymbolTable, Type};
+use leo_type_inference::{TypeInference, TypedIdentifier};
+
+const MAIN_FUNCTION_NAME: &str = "main";
+
+/// The program that is linted and what the compiler checks learned about it.
+pub struct LintContext<'a> {
+    pub program: &'a Program,
+    /// The names and types defined by the program, its imports, and its input.
+    pub symbol_table: &'a SymbolTable,
+    /// The inferred types of the identifiers in every constant, circuit function, and function.
+    pub typed_identifiers: Vec<TypedIdentifier>,
+    /// The variables of every circuit function, function, and test in the program.
+    pub functions: Vec<FunctionVariables<'a>>,
+}
+
+impl<'a> LintContext<'a> {
+    pub fn new(program: &'a Program, symbol_table: &'a SymbolTable) -> Self {
+        let circuit_functions = program
+            .circuits
+            .values()
+            .flat_map(|circuit| circuit.members.iter())
+            .filter_map(|member| match member {
+                CircuitMember::CircuitFunction(function) => Some(function),
+                CircuitMember::CircuitVariable(_, _) => None,
+            });
+        let functions = program.functions.values();
+        let tests = program.tests.values().map(|test| &test.function);
+
+        Self {
+            program,
+            symbol_table,
+            typed_identifiers: TypeInference::typed_identifiers(program, symbol_table.clone()),
+            functions: circuit_functions.chain(functions).chain(tests).map(FunctionVariables::new).collect(),
+        }
+    }
+
+    ///
+    /// Returns the inferred type of the given identifier if type inference resolved it.
+    ///
+    pub fn type_of(&self, identifier: &Identifier) -> Option<&Type> {
+        self.typed_identifiers
+            .iter()
+            .find(|typed_identifier| {
+                typed_identifier.identifier == *identifier && typed_identifier.identifier.span == identifier.span
+            })
+            .map(|typed_identifier| &typed_identifier.type_)
+    }
+
+    ///
+    /// Returns the `main` function of the program if there is one.
+    ///
+    pub fn main_function(&self) -> Option<&'a Function> {
+        self.program
+            .functions
+            .values()
+            .find(|function| function.identifier.name == MAIN_FUNCTION_NAME)
+    }
+}
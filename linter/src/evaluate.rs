@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Evaluation of expressions whose value is known without running the program.
+
+use crate::FunctionVariables;
+use leo_ast::{BinaryOperation, Expression, Program, UnaryOperation, ValueExpression};
+
+/// How deep constant definitions are followed before giving up.
+const MAX_DEPTH: usize = 32;
+
+/// Evaluates boolean and integer expressions built from literals, operators, and program constants.
+pub struct ConstantEvaluator<'a> {
+    program: &'a Program,
+    /// The variables of the function the expressions appear in. They hide program constants.
+    variables: &'a FunctionVariables<'a>,
+}
+
+impl<'a> ConstantEvaluator<'a> {
+    pub fn new(program: &'a Program, variables: &'a FunctionVariables<'a>) -> Self {
+        Self { program, variables }
+    }
+
+    ///
+    /// Returns the value of a boolean expression if it does not depend on any variable.
+    ///
+    pub fn boolean(&self, expression: &Expression) -> Option<bool> {
+        self.evaluate_boolean(expression, 0)
+    }
+
+    ///
+    /// Returns the value of an integer expression if it does not depend on any variable.
+    ///
+    pub fn integer(&self, expression: &Expression) -> Option<i128> {
+        self.evaluate_integer(expression, 0)
+    }
+
+    ///
+    /// Returns the expression that defines the program constant with the given name.
+    ///
+    fn constant(&self, name: &str) -> Option<&'a Expression> {
+        if self.variables.declares(name) {
+            return None;
+        }
+
+        self.program
+            .constants
+            .iter()
+            .find(|constant| constant.identifier.name == name)
+            .map(|constant| &constant.expression)
+    }
+
+    fn evaluate_boolean(&self, expression: &Expression, depth: usize) -> Option<bool> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        let boolean = |expression: &Expression| self.evaluate_boolean(expression, depth + 1);
+        let integer = |expression: &Expression| self.evaluate_integer(expression, depth + 1);
+
+        match expression {
+            Expression::Value(ValueExpression::Boolean(value, _)) => value.parse().ok(),
+            Expression::Identifier(identifier) => boolean(self.constant(&identifier.name)?),
+            Expression::Unary(unary) if unary.op == UnaryOperation::Not => boolean(&unary.inner).map(|value| !value),
+            Expression::Binary(binary) => {
+                let (left, right) = (&binary.left, &binary.right);
+
+                match binary.op {
+                    // One known operand can decide the result on its own.
+                    BinaryOperation::And => match (boolean(left), boolean(right)) {
+                        (Some(false), _) | (_, Some(false)) => Some(false),
+                        (Some(true), Some(true)) => Some(true),
+                        _ => None,
+                    },
+                    BinaryOperation::Or => match (boolean(left), boolean(right)) {
+                        (Some(true), _) | (_, Some(true)) => Some(true),
+                        (Some(false), Some(false)) => Some(false),
+                        _ => None,
+                    },
+                    BinaryOperation::Eq | BinaryOperation::Ne => {
+                        let equal = match (boolean(left), boolean(right)) {
+                            (Some(left), Some(right)) => left == right,
+                            _ => integer(left)? == integer(right)?,
+                        };
+
+                        Some(equal == (binary.op == BinaryOperation::Eq))
+                    }
+                    BinaryOperation::Ge | BinaryOperation::Gt | BinaryOperation::Le | BinaryOperation::Lt => {
+                        let left = integer(left)?;
+                        let right = integer(right)?;
+
+                        Some(match binary.op {
+                            BinaryOperation::Ge => left >= right,
+                            BinaryOperation::Gt => left > right,
+                            BinaryOperation::Le => left <= right,
+                            _ => left < right,
+                        })
+                    }
+                    _ => None,
+                }
+            }
+            Expression::Conditional(conditional) => match boolean(&conditional.condition)? {
+                true => boolean(&conditional.if_true),
+                false => boolean(&conditional.if_false),
+            },
+            _ => None,
+        }
+    }
+
+    fn evaluate_integer(&self, expression: &Expression, depth: usize) -> Option<i128> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        let boolean = |expression: &Expression| self.evaluate_boolean(expression, depth + 1);
+        let integer = |expression: &Expression| self.evaluate_integer(expression, depth + 1);
+
+        match expression {
+            Expression::Value(ValueExpression::Integer(_, value, _)) => value.parse().ok(),
+            Expression::Value(ValueExpression::Implicit(value, _)) => value.parse().ok(),
+            Expression::Identifier(identifier) => integer(self.constant(&identifier.name)?),
+            Expression::Unary(unary) if unary.op == UnaryOperation::Negate => integer(&unary.inner)?.checked_neg(),
+            Expression::Cast(cast) => integer(&cast.inner),
+            Expression::Binary(binary) => {
+                let left = integer(&binary.left)?;
+                let right = integer(&binary.right)?;
+
+                match binary.op {
+                    BinaryOperation::Add => left.checked_add(right),
+                    BinaryOperation::Sub => left.checked_sub(right),
+                    BinaryOperation::Mul => left.checked_mul(right),
+                    BinaryOperation::Div => left.checked_div(right),
+                    _ => None,
+                }
+            }
+            Expression::Conditional(conditional) => match boolean(&conditional.condition)? {
+                true => integer(&conditional.if_true),
+                false => integer(&conditional.if_false),
+            },
+            _ => None,
+        }
+    }
+}
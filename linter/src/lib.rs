@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A linter for Leo programs.
+//!
+//! This module contains the [`Linter`] type, which runs a configurable set of [`LintRule`]s over
+//! a program that has passed the compiler checks and collects their findings as [`Lint`]s.
+//!
+//! Rules read the program through a [`LintContext`], which also holds the symbol table of the
+//! program, the types inferred for its identifiers, and the variables declared by every function.
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod context;
+pub use self::context::*;
+
+pub mod errors;
+pub use self::errors::*;
+
+pub mod evaluate;
+
+pub mod lint;
+pub use self::lint::*;
+
+pub mod linter;
+pub use self::linter::*;
+
+pub mod rule;
+pub use self::rule::*;
+
+pub mod rules;
+
+pub mod variables;
+pub use self::variables::*;
+
+pub mod visitor;
+pub use self::visitor::*;
@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Error as FormattedError, Span};
+use leo_package::root::LintLevel;
+
+use std::{fmt, path::Path};
+
+/// A finding of a lint rule.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Lint {
+    /// The name of the rule that reported the finding.
+    pub rule: &'static str,
+    pub level: LintLevel,
+    pub error: FormattedError,
+}
+
+impl Lint {
+    pub fn new(rule: &'static str, level: LintLevel, message: String, span: Span) -> Self {
+        Self {
+            rule,
+            level,
+            error: FormattedError::new_from_span(message, span),
+        }
+    }
+
+    ///
+    /// Sets the filepath for the finding.
+    ///
+    pub fn set_path(&mut self, path: &Path) {
+        self.error.set_path(path)
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let severity = match self.level {
+            LintLevel::Deny => "error",
+            LintLevel::Allow | LintLevel::Warn => "warning",
+        };
+
+        write!(f, "{} [{}]\n\n{}\n", severity, self.rule, self.error)
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{rules::*, Lint, LintContext, LintRule, LinterError, Reporter};
+use leo_package::root::LintLevel;
+
+use std::collections::BTreeMap;
+
+/// Runs lint rules over a program.
+///
+/// A default [`Linter`] holds every built-in rule at its default level. Other rules can be
+/// registered with [`Linter::add_rule`], and the level of any rule can be changed by name.
+pub struct Linter {
+    rules: Vec<(Box<dyn LintRule>, LintLevel)>,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        let mut linter = Self { rules: vec![] };
+
+        linter.add_rule(Box::new(UnusedVariables));
+        linter.add_rule(Box::new(UnusedImports));
+        linter.add_rule(Box::new(UnusedMut));
+        linter.add_rule(Box::new(ShadowedIdentifiers));
+        linter.add_rule(Box::new(ConstantConditions));
+        linter.add_rule(Box::new(ZeroIterationLoops));
+        linter.add_rule(Box::new(ConsoleLogInMain));
+
+        linter
+    }
+}
+
+impl Linter {
+    ///
+    /// Registers a rule at its default level.
+    ///
+    pub fn add_rule(&mut self, rule: Box<dyn LintRule>) {
+        let level = rule.default_level();
+
+        self.rules.push((rule, level));
+    }
+
+    ///
+    /// Sets the level of every rule named in the given map.
+    ///
+    /// Returns an error if a name does not match a registered rule.
+    ///
+    pub fn configure(&mut self, levels: &BTreeMap<String, LintLevel>) -> Result<(), LinterError> {
+        for (name, level) in levels {
+            match self.rules.iter_mut().find(|(rule, _)| rule.name() == name) {
+                Some((_, rule_level)) => *rule_level = *level,
+                None => {
+                    let names = self.rules.iter().map(|(rule, _)| rule.name()).collect::<Vec<_>>();
+
+                    return Err(LinterError::UnknownRule(name.clone(), names.join(", ")));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns every registered rule with its level.
+    ///
+    pub fn rules(&self) -> impl Iterator<Item = (&dyn LintRule, LintLevel)> {
+        self.rules.iter().map(|(rule, level)| (rule.as_ref(), *level))
+    }
+
+    ///
+    /// Runs every rule that is not allowed and returns the findings in source order.
+    ///
+    pub fn run(&self, context: &LintContext) -> Vec<Lint> {
+        let mut lints = vec![];
+
+        for (rule, level) in self.rules() {
+            if level == LintLevel::Allow {
+                continue;
+            }
+
+            let mut reporter = Reporter::default();
+            rule.check(context, &mut reporter);
+
+            for (message, span) in reporter.into_findings() {
+                lints.push(Lint::new(rule.name(), level, message, span));
+            }
+        }
+
+        lints.sort_by_key(|lint| (lint.error.line, lint.error.start));
+
+        lints
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::LintContext;
+use leo_ast::Span;
+use leo_package::root::LintLevel;
+
+/// A check that reports questionable code in a program.
+///
+/// Rules are registered with a [`Linter`](crate::Linter), which runs them at the level configured
+/// for their name under `[lint]` in `Leo.toml`.
+pub trait LintRule {
+    /// The name of the rule in the `[lint]` section of `Leo.toml`.
+    fn name(&self) -> &'static str;
+
+    /// A one line description of what the rule reports.
+    fn description(&self) -> &'static str;
+
+    /// The level of the rule when it is not configured.
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    /// Reports every finding of the rule in the given program.
+    fn check(&self, context: &LintContext, reporter: &mut Reporter);
+}
+
+/// Collects the findings of a single rule.
+#[derive(Default)]
+pub struct Reporter {
+    findings: Vec<(String, Span)>,
+}
+
+impl Reporter {
+    pub fn report(&mut self, message: String, span: &Span) {
+        self.findings.push((message, span.clone()));
+    }
+
+    pub fn into_findings(self) -> Vec<(String, Span)> {
+        self.findings
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{walk_block, LintContext, LintRule, Reporter, Visitor};
+use leo_ast::{ConsoleFunction, Statement};
+
+/// Reports `console.log` statements left in the `main` function.
+pub struct ConsoleLogInMain;
+
+impl LintRule for ConsoleLogInMain {
+    fn name(&self) -> &'static str {
+        "console_log_in_main"
+    }
+
+    fn description(&self) -> &'static str {
+        "`console.log` statements in the `main` function"
+    }
+
+    fn check(&self, context: &LintContext, reporter: &mut Reporter) {
+        if let Some(main) = context.main_function() {
+            walk_block(&mut ConsoleLogs { reporter }, &main.block);
+        }
+    }
+}
+
+struct ConsoleLogs<'a> {
+    reporter: &'a mut Reporter,
+}
+
+impl Visitor for ConsoleLogs<'_> {
+    fn visit_statement(&mut self, statement: &Statement) {
+        if let Statement::Console(console) = statement {
+            if let ConsoleFunction::Log(_) = console.function {
+                self.reporter.report("`console.log` left in the `main` function".to_string(), &console.span);
+            }
+        }
+    }
+}
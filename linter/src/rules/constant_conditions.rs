@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{evaluate::ConstantEvaluator, walk_block, LintContext, LintRule, Reporter, Visitor};
+use leo_ast::{Expression, Node, Statement};
+
+/// Reports `if`, `while`, and ternary conditions that are always `true` or always `false`.
+pub struct ConstantConditions;
+
+impl LintRule for ConstantConditions {
+    fn name(&self) -> &'static str {
+        "constant_conditions"
+    }
+
+    fn description(&self) -> &'static str {
+        "conditions whose value does not depend on any variable"
+    }
+
+    fn check(&self, context: &LintContext, reporter: &mut Reporter) {
+        for function in context.functions.iter() {
+            let mut conditions = Conditions {
+                evaluator: ConstantEvaluator::new(context.program, function),
+                reporter: &mut *reporter,
+            };
+
+            walk_block(&mut conditions, &function.function.block);
+        }
+    }
+}
+
+struct Conditions<'a, 'b> {
+    evaluator: ConstantEvaluator<'a>,
+    reporter: &'b mut Reporter,
+}
+
+impl Conditions<'_, '_> {
+    fn check_condition(&mut self, condition: &Expression) {
+        if let Some(value) = self.evaluator.boolean(condition) {
+            let message = format!("this condition is always `{}`", value);
+
+            self.reporter.report(message, condition.span());
+        }
+    }
+}
+
+impl Visitor for Conditions<'_, '_> {
+    fn visit_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Conditional(statement) => self.check_condition(&statement.condition),
+            Statement::Iteration(statement) => {
+                if let Some(condition) = &statement.condition {
+                    self.check_condition(condition);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        if let Expression::Conditional(expression) = expression {
+            self.check_condition(&expression.condition);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The built-in lint rules.
+
+pub mod console_log_in_main;
+pub use self::console_log_in_main::*;
+
+pub mod constant_conditions;
+pub use self::constant_conditions::*;
+
+pub mod shadowed_identifiers;
+pub use self::shadowed_identifiers::*;
+
+pub mod unused_imports;
+pub use self::unused_imports::*;
+
+pub mod unused_mut;
+pub use self::unused_mut::*;
+
+pub mod unused_variables;
+pub use self::unused_variables::*;
+
+pub mod zero_iteration_loops;
+pub use self::zero_iteration_loops::*;
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{LintContext, LintRule, Reporter};
+use leo_symbol_table::Type;
+
+/// Reports variables that hide another variable, or a function, circuit, enum, or constant of the program.
+pub struct ShadowedIdentifiers;
+
+impl LintRule for ShadowedIdentifiers {
+    fn name(&self) -> &'static str {
+        "shadowed_identifiers"
+    }
+
+    fn description(&self) -> &'static str {
+        "variables that hide another variable or a definition of the same name"
+    }
+
+    fn check(&self, context: &LintContext, reporter: &mut Reporter) {
+        for function in context.functions.iter() {
+            for variable in function.variables.iter() {
+                let name = &variable.identifier.name;
+
+                let message = match &variable.shadows {
+                    Some(shadowed) => format!(
+                        "`{}` shadows the variable of the same name declared on line {}",
+                        name, shadowed.span.line
+                    ),
+                    None => match context.symbol_table.get_user_defined_type(name) {
+                        Some(user_defined) => {
+                            let kind = match user_defined.type_ {
+                                Type::Circuit(_) => "circuit",
+                                Type::Enum(_) => "enum",
+                                Type::Function(_) => "function",
+                                _ => "constant",
+                            };
+
+                            format!("`{}` shadows the {} of the same name", name, kind)
+                        }
+                        None => continue,
+                    },
+                };
+
+                reporter.report(message, &variable.identifier.span);
+            }
+        }
+    }
+}
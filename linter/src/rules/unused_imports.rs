@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{walk_program, LintContext, LintRule, Reporter, Visitor};
+use leo_ast::{Expression, ImportSymbol, MatchPattern, PackageAccess, Type};
+
+use std::collections::HashSet;
+
+/// Reports imported symbols that are never referred to. Star imports are not checked.
+pub struct UnusedImports;
+
+impl LintRule for UnusedImports {
+    fn name(&self) -> &'static str {
+        "unused_imports"
+    }
+
+    fn description(&self) -> &'static str {
+        "imported symbols that are never used"
+    }
+
+    fn check(&self, context: &LintContext, reporter: &mut Reporter) {
+        let mut names = UsedNames::default();
+        walk_program(&mut names, context.program);
+
+        let mut symbols = vec![];
+        for import in context.program.imports.iter() {
+            imported_symbols(&import.package.access, &mut symbols);
+        }
+
+        for symbol in symbols {
+            let name = symbol.alias.as_ref().unwrap_or(&symbol.symbol);
+
+            if !names.0.contains(&name.name) {
+                reporter.report(format!("unused import `{}`", name.name), &symbol.span);
+            }
+        }
+    }
+}
+
+///
+/// Appends the symbols named by a package access to the given vector.
+///
+fn imported_symbols<'a>(access: &'a PackageAccess, symbols: &mut Vec<&'a ImportSymbol>) {
+    match access {
+        PackageAccess::Star(_) => {}
+        PackageAccess::SubPackage(package) => imported_symbols(&package.access, symbols),
+        PackageAccess::Symbol(symbol) => symbols.push(symbol),
+        PackageAccess::Multiple(accesses) => {
+            for access in accesses.iter() {
+                imported_symbols(access, symbols);
+            }
+        }
+    }
+}
+
+/// The names a program refers to outside of declarations.
+#[derive(Default)]
+struct UsedNames(HashSet<String>);
+
+impl Visitor for UsedNames {
+    fn visit_expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Identifier(identifier) => {
+                self.0.insert(identifier.name.clone());
+            }
+            Expression::CircuitInit(expression) => {
+                self.0.insert(expression.name.name.clone());
+            }
+            Expression::ArrayInit(expression) => {
+                for constant in expression.dimensions.const_parameters() {
+                    self.0.insert(constant.name.clone());
+                }
+            }
+            Expression::Match(expression) => {
+                for arm in expression.arms.iter() {
+                    if let MatchPattern::Variant(enum_, _, _) = &arm.pattern {
+                        self.0.insert(enum_.name.clone());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Circuit(identifier) => {
+                self.0.insert(identifier.name.clone());
+            }
+            Type::Array(_, dimensions) => {
+                for constant in dimensions.const_parameters() {
+                    self.0.insert(constant.name.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{LintContext, LintRule, Reporter};
+
+/// Reports variables declared `mut` that are never assigned to.
+///
+/// Calling a circuit function on a variable counts as an assignment, since the function may take `mut self`.
+pub struct UnusedMut;
+
+impl LintRule for UnusedMut {
+    fn name(&self) -> &'static str {
+        "unused_mut"
+    }
+
+    fn description(&self) -> &'static str {
+        "variables declared `mut` that are never modified"
+    }
+
+    fn check(&self, context: &LintContext, reporter: &mut Reporter) {
+        for function in context.functions.iter() {
+            for variable in function.variables.iter() {
+                if variable.mutable && variable.writes == 0 {
+                    let message = format!("variable `{}` does not need to be mutable", variable.identifier.name);
+
+                    reporter.report(message, &variable.identifier.span);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{LintContext, LintRule, Reporter, VariableKind};
+
+/// Reports function inputs, definitions, and match bindings whose value is never read.
+///
+/// Loop variables are not reported, since a loop that only repeats its body has no other way to be written.
+pub struct UnusedVariables;
+
+impl LintRule for UnusedVariables {
+    fn name(&self) -> &'static str {
+        "unused_variables"
+    }
+
+    fn description(&self) -> &'static str {
+        "variables whose value is never read"
+    }
+
+    fn check(&self, context: &LintContext, reporter: &mut Reporter) {
+        for function in context.functions.iter() {
+            for variable in function.variables.iter().filter(|variable| variable.reads == 0) {
+                let name = &variable.identifier.name;
+                let message = match variable.kind {
+                    VariableKind::Loop => continue,
+                    _ if variable.writes > 0 => format!("variable `{}` is assigned to, but never read", name),
+                    VariableKind::Input => format!("unused function input `{}`", name),
+                    VariableKind::Definition | VariableKind::MatchBinding => format!("unused variable `{}`", name),
+                };
+
+                reporter.report(message, &variable.identifier.span);
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{evaluate::ConstantEvaluator, walk_block, LintContext, LintRule, Reporter, Visitor};
+use leo_ast::Statement;
+
+/// Reports `for` loops whose range is known to be empty.
+pub struct ZeroIterationLoops;
+
+impl LintRule for ZeroIterationLoops {
+    fn name(&self) -> &'static str {
+        "zero_iteration_loops"
+    }
+
+    fn description(&self) -> &'static str {
+        "`for` loops whose range is empty, so the body never runs"
+    }
+
+    fn check(&self, context: &LintContext, reporter: &mut Reporter) {
+        for function in context.functions.iter() {
+            let mut loops = Loops {
+                evaluator: ConstantEvaluator::new(context.program, function),
+                reporter: &mut *reporter,
+            };
+
+            walk_block(&mut loops, &function.function.block);
+        }
+    }
+}
+
+struct Loops<'a, 'b> {
+    evaluator: ConstantEvaluator<'a>,
+    reporter: &'b mut Reporter,
+}
+
+impl Visitor for Loops<'_, '_> {
+    fn visit_statement(&mut self, statement: &Statement) {
+        if let Statement::Iteration(statement) = statement {
+            let start = self.evaluator.integer(&statement.start);
+            let stop = self.evaluator.integer(&statement.stop);
+
+            if let (Some(start), Some(stop)) = (start, stop) {
+                if start >= stop {
+                    let message = format!("this loop never runs, the range `{}..{}` is empty", start, stop);
+
+                    self.reporter.report(message, &statement.span);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,297 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The variables declared by a function and how they are used.
+
+use leo_ast::{
+    AssigneeAccess,
+    Block,
+    ConsoleFunction,
+    Declare,
+    Expression,
+    Function,
+    FunctionInput,
+    Identifier,
+    MatchPattern,
+    SpreadOrExpression,
+    Statement,
+};
+
+/// Where a variable is declared.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VariableKind {
+    /// A function input.
+    Input,
+    /// A `let` or `const` definition.
+    Definition,
+    /// The variable of a `for` loop.
+    Loop,
+    /// A payload bound by a match arm.
+    MatchBinding,
+}
+
+/// A variable declared in a function.
+#[derive(Clone, Debug)]
+pub struct Variable {
+    pub identifier: Identifier,
+    pub kind: VariableKind,
+    pub mutable: bool,
+    /// The number of times the value of the variable is read.
+    pub reads: usize,
+    /// The number of times the variable is assigned to or has a circuit function called on it.
+    pub writes: usize,
+    /// The variable of the same name that is in scope where this variable is declared.
+    pub shadows: Option<Identifier>,
+}
+
+/// The variables declared by a function, in declaration order.
+pub struct FunctionVariables<'a> {
+    pub function: &'a Function,
+    pub variables: Vec<Variable>,
+}
+
+impl<'a> FunctionVariables<'a> {
+    ///
+    /// Returns the variables declared by a function and counts how often each one is used.
+    ///
+    pub fn new(function: &'a Function) -> Self {
+        let mut collector = VariableCollector {
+            variables: vec![],
+            scopes: vec![vec![]],
+        };
+
+        for input in function.input.iter() {
+            if let FunctionInput::Variable(input) = input {
+                collector.declare(&input.identifier, VariableKind::Input, input.mutable);
+            }
+        }
+
+        collector.collect_block(&function.block);
+
+        Self {
+            function,
+            variables: collector.variables,
+        }
+    }
+
+    ///
+    /// Returns `true` if the function declares a variable with the given name.
+    ///
+    pub fn declares(&self, name: &str) -> bool {
+        self.variables.iter().any(|variable| variable.identifier.name == name)
+    }
+}
+
+/// Resolves each use of a name to the innermost variable in scope.
+struct VariableCollector {
+    variables: Vec<Variable>,
+    /// The indices of the variables declared in each enclosing scope, innermost last.
+    scopes: Vec<Vec<usize>>,
+}
+
+impl VariableCollector {
+    fn lookup(&self, name: &str) -> Option<usize> {
+        self.scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|index| self.variables[**index].identifier.name == name)
+            .copied()
+    }
+
+    fn declare(&mut self, identifier: &Identifier, kind: VariableKind, mutable: bool) {
+        let shadows = self
+            .lookup(&identifier.name)
+            .map(|index| self.variables[index].identifier.clone());
+
+        self.variables.push(Variable {
+            identifier: identifier.clone(),
+            kind,
+            mutable,
+            reads: 0,
+            writes: 0,
+            shadows,
+        });
+
+        let index = self.variables.len() - 1;
+        self.scopes.last_mut().unwrap().push(index);
+    }
+
+    fn in_scope(&mut self, collect: impl FnOnce(&mut Self)) {
+        self.scopes.push(vec![]);
+        collect(self);
+        self.scopes.pop();
+    }
+
+    fn collect_block(&mut self, block: &Block) {
+        self.in_scope(|collector| {
+            for statement in block.statements.iter() {
+                collector.collect_statement(statement);
+            }
+        });
+    }
+
+    fn collect_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Return(statement) => self.collect_expression(&statement.expression),
+            Statement::Definition(statement) => {
+                // The value is evaluated before the new variables are in scope.
+                self.collect_expression(&statement.value);
+
+                let constant = statement.declaration_type == Declare::Const;
+                for variable in statement.variable_names.iter() {
+                    self.declare(&variable.identifier, VariableKind::Definition, variable.mutable && !constant);
+                }
+            }
+            Statement::Assign(statement) => {
+                self.collect_expression(&statement.value);
+
+                for access in statement.assignee.accesses.iter() {
+                    match access {
+                        AssigneeAccess::ArrayRange(left, right) => {
+                            for expression in left.iter().chain(right.iter()) {
+                                self.collect_expression(expression);
+                            }
+                        }
+                        AssigneeAccess::ArrayIndex(index) => self.collect_expression(index),
+                        AssigneeAccess::Tuple(_, _) | AssigneeAccess::Member(_) => {}
+                    }
+                }
+
+                if let Some(index) = self.lookup(&statement.assignee.identifier.name) {
+                    self.variables[index].writes += 1;
+                }
+            }
+            Statement::Conditional(statement) => {
+                self.collect_expression(&statement.condition);
+                self.collect_block(&statement.block);
+
+                if let Some(next) = &statement.next {
+                    self.collect_statement(next);
+                }
+            }
+            Statement::Iteration(statement) => {
+                self.collect_expression(&statement.start);
+                self.collect_expression(&statement.stop);
+
+                self.in_scope(|collector| {
+                    collector.declare(&statement.variable, VariableKind::Loop, false);
+
+                    if let Some(condition) = &statement.condition {
+                        collector.collect_expression(condition);
+                    }
+
+                    collector.collect_block(&statement.block);
+                });
+            }
+            Statement::Console(statement) => match &statement.function {
+                ConsoleFunction::Assert(expression) => self.collect_expression(expression),
+                ConsoleFunction::Debug(string) | ConsoleFunction::Error(string) | ConsoleFunction::Log(string) => {
+                    for parameter in string.parameters.iter() {
+                        self.collect_expression(parameter);
+                    }
+                }
+            },
+            Statement::Expression(statement) => self.collect_expression(&statement.expression),
+            Statement::Block(block) => self.collect_block(block),
+        }
+    }
+
+    fn collect_expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Identifier(identifier) => {
+                if let Some(index) = self.lookup(&identifier.name) {
+                    self.variables[index].reads += 1;
+                }
+            }
+            Expression::Value(_) => {}
+            Expression::Binary(expression) => {
+                self.collect_expression(&expression.left);
+                self.collect_expression(&expression.right);
+            }
+            Expression::Unary(expression) => self.collect_expression(&expression.inner),
+            Expression::Cast(expression) => self.collect_expression(&expression.inner),
+            Expression::Conditional(expression) => {
+                self.collect_expression(&expression.condition);
+                self.collect_expression(&expression.if_true);
+                self.collect_expression(&expression.if_false);
+            }
+            Expression::Match(expression) => {
+                self.collect_expression(&expression.inner);
+
+                for arm in expression.arms.iter() {
+                    self.in_scope(|collector| {
+                        if let MatchPattern::Variant(_, _, Some(binding)) = &arm.pattern {
+                            collector.declare(binding, VariableKind::MatchBinding, false);
+                        }
+
+                        collector.collect_expression(&arm.expression);
+                    });
+                }
+            }
+            Expression::ArrayInline(expression) => {
+                for element in expression.elements.iter() {
+                    match element {
+                        SpreadOrExpression::Spread(element) | SpreadOrExpression::Expression(element) => {
+                            self.collect_expression(element)
+                        }
+                    }
+                }
+            }
+            Expression::ArrayInit(expression) => self.collect_expression(&expression.element),
+            Expression::ArrayAccess(expression) => {
+                self.collect_expression(&expression.array);
+                self.collect_expression(&expression.index);
+            }
+            Expression::ArrayRangeAccess(expression) => {
+                self.collect_expression(&expression.array);
+
+                for bound in expression.left.iter().chain(expression.right.iter()) {
+                    self.collect_expression(bound);
+                }
+            }
+            Expression::TupleInit(expression) => {
+                for element in expression.elements.iter() {
+                    self.collect_expression(element);
+                }
+            }
+            Expression::TupleAccess(expression) => self.collect_expression(&expression.tuple),
+            Expression::CircuitInit(expression) => {
+                for member in expression.members.iter() {
+                    self.collect_expression(&member.expression);
+                }
+            }
+            Expression::CircuitMemberAccess(expression) => self.collect_expression(&expression.circuit),
+            Expression::CircuitStaticFunctionAccess(expression) => self.collect_expression(&expression.circuit),
+            Expression::Call(expression) => {
+                // A circuit function called on a variable may take `mut self` and modify the variable.
+                if let Expression::CircuitMemberAccess(access) = expression.function.as_ref() {
+                    if let Expression::Identifier(identifier) = access.circuit.as_ref() {
+                        if let Some(index) = self.lookup(&identifier.name) {
+                            self.variables[index].writes += 1;
+                        }
+                    }
+                }
+
+                self.collect_expression(&expression.function);
+
+                for argument in expression.arguments.iter() {
+                    self.collect_expression(argument);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A read-only traversal of a Leo program.
+//!
+//! The `walk_*` functions visit a node, then walk its children in source order.
+
+use leo_ast::{
+    AssigneeAccess,
+    Block,
+    CircuitMember,
+    ConsoleFunction,
+    Expression,
+    Function,
+    FunctionInput,
+    Program,
+    SpreadOrExpression,
+    Statement,
+    Type,
+};
+
+/// Callbacks for the nodes of a program. Every callback does nothing by default.
+pub trait Visitor {
+    fn visit_statement(&mut self, _statement: &Statement) {}
+
+    fn visit_expression(&mut self, _expression: &Expression) {}
+
+    fn visit_type(&mut self, _type_: &Type) {}
+}
+
+///
+/// Walks the constants, enums, circuits, functions, and tests of a program.
+///
+pub fn walk_program<V: Visitor>(visitor: &mut V, program: &Program) {
+    for constant in program.constants.iter() {
+        walk_type(visitor, &constant.type_);
+        walk_expression(visitor, &constant.expression);
+    }
+
+    for enum_ in program.enums.values() {
+        for payload in enum_.variants.iter().filter_map(|variant| variant.payload.as_ref()) {
+            walk_type(visitor, payload);
+        }
+    }
+
+    for circuit in program.circuits.values() {
+        for member in circuit.members.iter() {
+            match member {
+                CircuitMember::CircuitVariable(_, type_) => walk_type(visitor, type_),
+                CircuitMember::CircuitFunction(function) => walk_function(visitor, function),
+            }
+        }
+    }
+
+    for function in program.functions.values() {
+        walk_function(visitor, function);
+    }
+
+    for test in program.tests.values() {
+        walk_function(visitor, &test.function);
+    }
+}
+
+///
+/// Walks the input types, output type, and body of a function.
+///
+pub fn walk_function<V: Visitor>(visitor: &mut V, function: &Function) {
+    for input in function.input.iter() {
+        if let FunctionInput::Variable(variable) = input {
+            walk_type(visitor, &variable.type_);
+        }
+    }
+
+    if let Some(output) = &function.output {
+        walk_type(visitor, output);
+    }
+
+    walk_block(visitor, &function.block);
+}
+
+pub fn walk_block<V: Visitor>(visitor: &mut V, block: &Block) {
+    for statement in block.statements.iter() {
+        walk_statement(visitor, statement);
+    }
+}
+
+pub fn walk_statement<V: Visitor>(visitor: &mut V, statement: &Statement) {
+    visitor.visit_statement(statement);
+
+    match statement {
+        Statement::Return(statement) => walk_expression(visitor, &statement.expression),
+        Statement::Definition(statement) => {
+            if let Some(type_) = &statement.type_ {
+                walk_type(visitor, type_);
+            }
+
+            walk_expression(visitor, &statement.value);
+        }
+        Statement::Assign(statement) => {
+            for access in statement.assignee.accesses.iter() {
+                match access {
+                    AssigneeAccess::ArrayRange(left, right) => {
+                        for expression in left.iter().chain(right.iter()) {
+                            walk_expression(visitor, expression);
+                        }
+                    }
+                    AssigneeAccess::ArrayIndex(index) => walk_expression(visitor, index),
+                    AssigneeAccess::Tuple(_, _) | AssigneeAccess::Member(_) => {}
+                }
+            }
+
+            walk_expression(visitor, &statement.value);
+        }
+        Statement::Conditional(statement) => {
+            walk_expression(visitor, &statement.condition);
+            walk_block(visitor, &statement.block);
+
+            if let Some(next) = &statement.next {
+                walk_statement(visitor, next);
+            }
+        }
+        Statement::Iteration(statement) => {
+            walk_expression(visitor, &statement.start);
+            walk_expression(visitor, &statement.stop);
+
+            if let Some(condition) = &statement.condition {
+                walk_expression(visitor, condition);
+            }
+
+            walk_block(visitor, &statement.block);
+        }
+        Statement::Console(statement) => match &statement.function {
+            ConsoleFunction::Assert(expression) => walk_expression(visitor, expression),
+            ConsoleFunction::Debug(string) | ConsoleFunction::Error(string) | ConsoleFunction::Log(string) => {
+                for parameter in string.parameters.iter() {
+                    walk_expression(visitor, parameter);
+                }
+            }
+        },
+        Statement::Expression(statement) => walk_expression(visitor, &statement.expression),
+        Statement::Block(block) => walk_block(visitor, block),
+    }
+}
+
+pub fn walk_expression<V: Visitor>(visitor: &mut V, expression: &Expression) {
+    visitor.visit_expression(expression);
+
+    match expression {
+        Expression::Identifier(_) | Expression::Value(_) => {}
+        Expression::Binary(expression) => {
+            walk_expression(visitor, &expression.left);
+            walk_expression(visitor, &expression.right);
+        }
+        Expression::Unary(expression) => walk_expression(visitor, &expression.inner),
+        Expression::Cast(expression) => {
+            walk_expression(visitor, &expression.inner);
+            walk_type(visitor, &expression.target_type);
+        }
+        Expression::Conditional(expression) => {
+            walk_expression(visitor, &expression.condition);
+            walk_expression(visitor, &expression.if_true);
+            walk_expression(visitor, &expression.if_false);
+        }
+        Expression::Match(expression) => {
+            walk_expression(visitor, &expression.inner);
+
+            for arm in expression.arms.iter() {
+                walk_expression(visitor, &arm.expression);
+            }
+        }
+        Expression::ArrayInline(expression) => {
+            for element in expression.elements.iter() {
+                match element {
+                    SpreadOrExpression::Spread(element) | SpreadOrExpression::Expression(element) => {
+                        walk_expression(visitor, element)
+                    }
+                }
+            }
+        }
+        Expression::ArrayInit(expression) => walk_expression(visitor, &expression.element),
+        Expression::ArrayAccess(expression) => {
+            walk_expression(visitor, &expression.array);
+            walk_expression(visitor, &expression.index);
+        }
+        Expression::ArrayRangeAccess(expression) => {
+            walk_expression(visitor, &expression.array);
+
+            for bound in expression.left.iter().chain(expression.right.iter()) {
+                walk_expression(visitor, bound);
+            }
+        }
+        Expression::TupleInit(expression) => {
+            for element in expression.elements.iter() {
+                walk_expression(visitor, element);
+            }
+        }
+        Expression::TupleAccess(expression) => walk_expression(visitor, &expression.tuple),
+        Expression::CircuitInit(expression) => {
+            for member in expression.members.iter() {
+                walk_expression(visitor, &member.expression);
+            }
+        }
+        Expression::CircuitMemberAccess(expression) => walk_expression(visitor, &expression.circuit),
+        Expression::CircuitStaticFunctionAccess(expression) => walk_expression(visitor, &expression.circuit),
+        Expression::Call(expression) => {
+            walk_expression(visitor, &expression.function);
+
+            for argument in expression.arguments.iter() {
+                walk_expression(visitor, argument);
+            }
+        }
+    }
+}
+
+pub fn walk_type<V: Visitor>(visitor: &mut V, type_: &Type) {
+    visitor.visit_type(type_);
+
+    match type_ {
+        Type::Array(element, _) => walk_type(visitor, element),
+        Type::Tuple(types) => {
+            for type_ in types.iter() {
+                walk_type(visitor, type_);
+            }
+        }
+        _ => {}
+    }
+}
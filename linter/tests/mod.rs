@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Ast, Input};
+use leo_grammar::Grammar;
+use leo_imports::ImportParser;
+use leo_linter::{LintContext, Linter};
+use leo_symbol_table::SymbolTable;
+
+use std::path::PathBuf;
+
+const TEST_PROGRAM_PATH: &str = "";
+const TEST_PROGRAM_NAME: &str = "test";
+
+/// A helper struct to test the lint rules.
+pub struct TestLinter {
+    program_string: &'static str,
+}
+
+impl TestLinter {
+    pub fn new(program_string: &'static str) -> Self {
+        Self { program_string }
+    }
+
+    ///
+    /// Returns the lines of the findings of the given rule.
+    ///
+    pub fn lines(self, rule: &str) -> Vec<usize> {
+        self.with_context(|context| {
+            Linter::default()
+                .run(context)
+                .into_iter()
+                .filter(|lint| lint.rule == rule)
+                .map(|lint| lint.error.line)
+                .collect()
+        })
+    }
+
+    ///
+    /// Returns the inferred type of every variable declared by the given function.
+    ///
+    pub fn types(self, function: &str) -> Vec<String> {
+        self.with_context(|context| {
+            context
+                .functions
+                .iter()
+                .filter(|variables| variables.function.identifier.name == function)
+                .flat_map(|variables| variables.variables.iter())
+                .map(|variable| match context.type_of(&variable.identifier) {
+                    Some(type_) => format!("{}: {}", variable.identifier, type_),
+                    None => format!("{}: unknown", variable.identifier),
+                })
+                .collect()
+        })
+    }
+
+    ///
+    /// Parses the program and calls the given function with its lint context.
+    ///
+    fn with_context<T>(self, f: impl FnOnce(&LintContext) -> T) -> T {
+        // Get test file path.
+        let file_path = PathBuf::from(TEST_PROGRAM_PATH);
+
+        // Get parser syntax tree.
+        let ast = Grammar::new(&file_path, self.program_string).unwrap();
+
+        // Get typed syntax tree.
+        let program = Ast::new(TEST_PROGRAM_NAME, &ast).into_repr();
+
+        // Resolve imports and create a symbol table for the program.
+        let imports = ImportParser::parse(&program).unwrap();
+        let symbol_table = SymbolTable::new(&program, &imports, &Input::new()).unwrap();
+
+        f(&LintContext::new(&program, &symbol_table))
+    }
+}
+
+#[test]
+fn test_unused_variables() {
+    let program_string = include_str!("unused_variables.leo");
+
+    assert_eq!(TestLinter::new(program_string).lines("unused_variables"), vec![1, 3, 4]);
+}
+
+#[test]
+fn test_unused_imports() {
+    let program_string = include_str!("unused_imports.leo");
+
+    assert_eq!(TestLinter::new(program_string).lines("unused_imports"), vec![1]);
+}
+
+#[test]
+fn test_used_imports() {
+    let program_string = include_str!("used_imports.leo");
+
+    assert!(TestLinter::new(program_string).lines("unused_imports").is_empty());
+}
+
+#[test]
+fn test_unused_mut() {
+    let program_string = include_str!("unused_mut.leo");
+
+    assert_eq!(TestLinter::new(program_string).lines("unused_mut"), vec![10]);
+}
+
+#[test]
+fn test_shadowed_identifiers() {
+    let program_string = include_str!("shadowed_identifiers.leo");
+
+    assert_eq!(TestLinter::new(program_string).lines("shadowed_identifiers"), vec![8, 9, 12]);
+}
+
+#[test]
+fn test_constant_conditions() {
+    let program_string = include_str!("constant_conditions.leo");
+
+    assert_eq!(TestLinter::new(program_string).lines("constant_conditions"), vec![5, 11, 14, 17]);
+}
+
+#[test]
+fn test_zero_iteration_loops() {
+    let program_string = include_str!("zero_iteration_loops.leo");
+
+    assert_eq!(TestLinter::new(program_string).lines("zero_iteration_loops"), vec![5, 8, 14]);
+}
+
+#[test]
+fn test_console_log_in_main() {
+    let program_string = include_str!("console_log_in_main.leo");
+
+    assert_eq!(TestLinter::new(program_string).lines("console_log_in_main"), vec![7, 9]);
+}
+
+#[test]
+fn test_clean() {
+    let program_string = include_str!("clean.leo");
+
+    for rule in Linter::default().rules().map(|(rule, _)| rule.name()) {
+        assert!(TestLinter::new(program_string).lines(rule).is_empty(), "{} reported a finding", rule);
+    }
+}
+
+#[test]
+fn test_typed_identifiers() {
+    let program_string = include_str!("typed_identifiers.leo");
+
+    assert_eq!(TestLinter::new(program_string).types("main"), vec![
+        "a: u32",
+        "flag: bool",
+        "b: u32",
+        "c: bool",
+        "d: [u8]",
+        "i: u32"
+    ]);
+}
@@ -0,0 +1,864 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The stable error codes of `leo-package` and their descriptions, as shown by `leo explain`.
+pub const ERROR_CODES: &[(&str, &str)] = &[
+    (
+        "E0600",
+        "The `imports` directory could not be created.
+
+Check that the package directory is writable.",
+    ),
+    (
+        "E0601",
+        "An entry of the `imports` directory could not be read.
+
+Check that the directory and its files can be read.",
+    ),
+    (
+        "E0602",
+        "A file in the `imports` directory has no extension.
+
+Imported packages are directories in `imports`.",
+    ),
+    (
+        "E0603",
+        "The type of an entry in the `imports` directory could not be determined.
+
+Check that the directory and its files can be read.",
+    ),
+    (
+        "E0604",
+        "An imported package is not in the `imports` directory.
+
+Add the package with `leo add` or copy it into the `imports` directory.",
+    ),
+    (
+        "E0605",
+        "The `imports` directory contains a file with an unexpected extension.
+
+Imported packages are directories in `imports`.",
+    ),
+    (
+        "E0606",
+        "The `imports` directory contains an entry of an unexpected type.
+
+Imported packages are directories in `imports`.",
+    ),
+    (
+        "E0607",
+        "The entries of the `imports` directory could not be read.
+
+Check that the directory exists and can be read.",
+    ),
+    (
+        "E0608",
+        "The `imports` directory could not be removed.
+
+Check that its files are not in use and that the package directory is writable.",
+    ),
+    (
+        "E0610",
+        "The `inputs` directory could not be created.
+
+Check that the package directory is writable.",
+    ),
+    (
+        "E0611",
+        "An entry of the `inputs` directory could not be read.
+
+Check that the directory and its files can be read.",
+    ),
+    (
+        "E0612",
+        "A file in the `inputs` directory has no extension.
+
+The `inputs` directory may only contain `.in` and `.state` files and directories of them.",
+    ),
+    (
+        "E0613",
+        "The name of a file in the `inputs` directory could not be read.
+
+Check that file names are valid UTF-8.",
+    ),
+    (
+        "E0614",
+        "The type of an entry in the `inputs` directory could not be determined.
+
+Check that the directory and its files can be read.",
+    ),
+    (
+        "E0615",
+        "The `inputs` directory contains a file with an unexpected extension.
+
+The `inputs` directory may only contain `.in` and `.state` files and directories of them.",
+    ),
+    (
+        "E0616",
+        "The `inputs` directory contains an entry of an unexpected type.
+
+The `inputs` directory may only contain `.in` and `.state` files and directories of them.",
+    ),
+    (
+        "E0617",
+        "The entries of the `inputs` directory could not be read.
+
+Check that the directory exists and can be read.",
+    ),
+    (
+        "E0620",
+        "An external crate failed while working with an input file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0621",
+        "An input file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0622",
+        "An input file could not be read.
+
+Check that the file exists in the `inputs` directory and can be read.",
+    ),
+    (
+        "E0623",
+        "An input file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0630",
+        "An external crate failed while working with a state file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0631",
+        "A state file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0632",
+        "A state file could not be read.
+
+Check that the file exists in the `inputs` directory and can be read.",
+    ),
+    (
+        "E0633",
+        "A state file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0640",
+        "An external crate failed while working with the build manifest.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0641",
+        "The build manifest could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0642",
+        "The build manifest could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0643",
+        "The build manifest could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0644",
+        "The build manifest could not be parsed.
+
+The file is not valid JSON. Run the command that writes it again to
+regenerate it.",
+    ),
+    (
+        "E0645",
+        "The build manifest could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0650",
+        "An external crate failed while working with the checksum file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0651",
+        "The checksum file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0652",
+        "The checksum file could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0653",
+        "The checksum file could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0654",
+        "The checksum file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0660",
+        "An external crate failed while working with the circuit file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0661",
+        "The circuit file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0662",
+        "The circuit file could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0663",
+        "The circuit file could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0664",
+        "The circuit file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0670",
+        "The `outputs` directory could not be created.
+
+Check that the package directory is writable.",
+    ),
+    (
+        "E0671",
+        "An entry of the `outputs` directory could not be read.
+
+Check that the directory and its files can be read.",
+    ),
+    (
+        "E0672",
+        "A file in the `outputs` directory has no extension.
+
+The `outputs` directory may only contain the files written by `leo build`, `leo setup`, and `leo prove`.",
+    ),
+    (
+        "E0673",
+        "The type of an entry in the `outputs` directory could not be determined.
+
+Check that the directory and its files can be read.",
+    ),
+    (
+        "E0674",
+        "The `outputs` directory contains a file with an unexpected extension.
+
+The `outputs` directory may only contain the files written by `leo build`, `leo setup`, and `leo prove`.",
+    ),
+    (
+        "E0675",
+        "The `outputs` directory contains an entry of an unexpected type.
+
+The `outputs` directory may only contain the files written by `leo build`, `leo setup`, and `leo prove`.",
+    ),
+    (
+        "E0676",
+        "The entries of the `outputs` directory could not be read.
+
+Check that the directory exists and can be read.",
+    ),
+    (
+        "E0677",
+        "The `outputs` directory could not be removed.
+
+Check that its files are not in use and that the package directory is writable.",
+    ),
+    (
+        "E0680",
+        "An external crate failed while working with an exported circuit file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0681",
+        "An exported circuit file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0682",
+        "An exported circuit file could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0683",
+        "An unknown export format was requested.
+
+`leo build --emit` accepts the formats `r1cs`, `wtns`, and `zkif`.",
+    ),
+    (
+        "E0690",
+        "An external crate failed while working with the constraint profile file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0691",
+        "The constraint profile file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0692",
+        "The constraint profile file could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0693",
+        "The constraint profile file could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0694",
+        "The constraint profile file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0700",
+        "An external crate failed while working with the proof file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0701",
+        "The proof file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0702",
+        "The proof file could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0703",
+        "The proof file could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0704",
+        "The proof file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0710",
+        "An external crate failed while working with the proving key file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0711",
+        "The proving key file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0712",
+        "The proving key file could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0713",
+        "The proving key file could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0714",
+        "The proving key file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0720",
+        "An external crate failed while working with the public inputs file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0721",
+        "The public inputs file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0722",
+        "The public inputs file could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0723",
+        "The public inputs file could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0724",
+        "The public inputs file is corrupted.
+
+The file could not be decoded. Run `leo prove` again to regenerate it.",
+    ),
+    (
+        "E0725",
+        "The public inputs file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0730",
+        "An external crate failed while working with the setup record.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0731",
+        "The setup record could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0732",
+        "The setup record could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0733",
+        "The setup record could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0734",
+        "The setup record could not be parsed.
+
+The file is not valid JSON. Run the command that writes it again to
+regenerate it.",
+    ),
+    (
+        "E0735",
+        "The setup record could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0740",
+        "An external crate failed while working with the ceremony transcript file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0741",
+        "The ceremony transcript file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0742",
+        "The ceremony transcript file could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0743",
+        "The ceremony transcript file could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0744",
+        "The ceremony transcript file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0750",
+        "An external crate failed while working with the verification key file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0751",
+        "The verification key file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0752",
+        "The verification key file could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0753",
+        "The verification key file could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0754",
+        "The verification key file is corrupted.
+
+The file could not be decoded. Run `leo setup` again to regenerate it.",
+    ),
+    (
+        "E0755",
+        "The verification key file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0760",
+        "An external crate failed while working with the witness file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0761",
+        "The witness file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0762",
+        "The witness file could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0763",
+        "The witness file could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0764",
+        "The witness file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0770",
+        "An external crate failed while working with a package.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0771",
+        "A file or directory of a new package could not be created.
+
+Check that the parent directory is writable.",
+    ),
+    (
+        "E0772",
+        "A package could not be initialized.
+
+The directory already contains package files, or the package name is invalid.
+Package names may only contain lowercase letters, numbers, and dashes.",
+    ),
+    (
+        "E0773",
+        "A file or directory of a package could not be removed.
+
+Check that the files of the package are not in use and that the package
+directory is writable.",
+    ),
+    (
+        "E0780",
+        "An external crate failed while working with the `.gitignore` file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0781",
+        "The `.gitignore` file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0782",
+        "The `.gitignore` file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0790",
+        "The package manifest `Leo.toml` could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0791",
+        "The metadata of the package manifest `Leo.toml` could not be read.
+
+Check that the file exists and can be read.",
+    ),
+    (
+        "E0792",
+        "The package manifest `Leo.toml` could not be opened.
+
+Check that the command is run inside a package directory that contains
+`Leo.toml`.",
+    ),
+    (
+        "E0793",
+        "The package manifest `Leo.toml` could not be parsed.
+
+The manifest must be valid TOML with a `[project]` section, for example:
+
+    [project]
+    name = \"hello-world\"
+    version = \"0.1.0\"",
+    ),
+    (
+        "E0794",
+        "The package manifest `Leo.toml` could not be read.
+
+Check that the file is readable and contains valid UTF-8 text.",
+    ),
+    (
+        "E0795",
+        "The package manifest `Leo.toml` could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0800",
+        "An external crate failed while working with the `README.md` file.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0801",
+        "The `README.md` file could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0802",
+        "The `README.md` file could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0810",
+        "An external crate failed while working with the package archive.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0811",
+        "The package archive could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0812",
+        "The package archive could not be read.
+
+Check that the file exists and can be read. Files in the `outputs` directory
+are written by earlier commands, so running the command that creates the file
+again may fix this error.",
+    ),
+    (
+        "E0813",
+        "The package archive could not be removed.
+
+Check that the file is not in use and that its directory is writable.",
+    ),
+    (
+        "E0814",
+        "The package archive could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0815",
+        "The package directory could not be traversed while creating the archive.
+
+Check that every file of the package can be read.",
+    ),
+    (
+        "E0816",
+        "The package archive could not be written.
+
+The message contains the error of the zip library.",
+    ),
+    (
+        "E0820",
+        "The `src` directory could not be created.
+
+Check that the package directory is writable.",
+    ),
+    (
+        "E0821",
+        "An entry of the `src` directory could not be read.
+
+Check that the directory and its files can be read.",
+    ),
+    (
+        "E0822",
+        "A file in the `src` directory has no extension.
+
+The `src` directory may only contain `.leo` files.",
+    ),
+    (
+        "E0823",
+        "The type of an entry in the `src` directory could not be determined.
+
+Check that the directory and its files can be read.",
+    ),
+    (
+        "E0824",
+        "The `src` directory contains a file with an unexpected extension.
+
+The `src` directory may only contain `.leo` files.",
+    ),
+    (
+        "E0825",
+        "The `src` directory contains an entry of an unexpected type.
+
+The `src` directory may only contain `.leo` files.",
+    ),
+    (
+        "E0826",
+        "The entries of the `src` directory could not be read.
+
+Check that the directory exists and can be read.",
+    ),
+    (
+        "E0830",
+        "An external crate failed while working with the library file `src/lib.leo`.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0831",
+        "The library file `src/lib.leo` could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0832",
+        "The library file `src/lib.leo` could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+    (
+        "E0840",
+        "An external crate failed while working with the main file `src/main.leo`.
+
+The message names the crate and the underlying error, usually an I/O error
+from the operating system.",
+    ),
+    (
+        "E0841",
+        "The main file `src/main.leo` could not be created.
+
+Check that its directory exists and is writable.",
+    ),
+    (
+        "E0842",
+        "The main file `src/main.leo` could not be written.
+
+Check that its directory is writable and that the disk is not full.",
+    ),
+];
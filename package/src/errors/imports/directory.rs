@@ -45,3 +45,22 @@ pub enum ImportsDirectoryError {
     #[error("removing: {}", _0)]
     Removing(io::Error),
 }
+
+impl ImportsDirectoryError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ImportsDirectoryError::Creating(_) => "E0600",
+            ImportsDirectoryError::GettingFileEntry(_) => "E0601",
+            ImportsDirectoryError::GettingFileExtension(_) => "E0602",
+            ImportsDirectoryError::GettingFileType(..) => "E0603",
+            ImportsDirectoryError::ImportDoesNotExist(_) => "E0604",
+            ImportsDirectoryError::InvalidFileExtension(..) => "E0605",
+            ImportsDirectoryError::InvalidFileType(..) => "E0606",
+            ImportsDirectoryError::Reading(_) => "E0607",
+            ImportsDirectoryError::Removing(_) => "E0608",
+        }
+    }
+}
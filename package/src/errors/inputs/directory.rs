@@ -50,3 +50,23 @@ pub enum InputsDirectoryError {
     #[error("{}", _0)]
     StateFileError(#[from] StateFileError),
 }
+
+impl InputsDirectoryError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            InputsDirectoryError::Creating(_) => "E0610",
+            InputsDirectoryError::GettingFileEntry(_) => "E0611",
+            InputsDirectoryError::GettingFileExtension(_) => "E0612",
+            InputsDirectoryError::GettingFileName(_) => "E0613",
+            InputsDirectoryError::GettingFileType(..) => "E0614",
+            InputsDirectoryError::InputFileError(error) => error.error_code(),
+            InputsDirectoryError::InvalidFileExtension(..) => "E0615",
+            InputsDirectoryError::InvalidFileType(..) => "E0616",
+            InputsDirectoryError::Reading(_) => "E0617",
+            InputsDirectoryError::StateFileError(error) => error.error_code(),
+        }
+    }
+}
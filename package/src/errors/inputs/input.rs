@@ -31,6 +31,20 @@ pub enum InputFileError {
     Writing(io::Error),
 }
 
+impl InputFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            InputFileError::Crate(..) => "E0620",
+            InputFileError::Creating(_) => "E0621",
+            InputFileError::FileReadError(_) => "E0622",
+            InputFileError::Writing(_) => "E0623",
+        }
+    }
+}
+
 impl From<std::io::Error> for InputFileError {
     fn from(error: std::io::Error) -> Self {
         InputFileError::Crate("std::io", error.to_string())
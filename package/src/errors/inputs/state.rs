@@ -31,6 +31,20 @@ pub enum StateFileError {
     Writing(io::Error),
 }
 
+impl StateFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            StateFileError::Crate(..) => "E0630",
+            StateFileError::Creating(_) => "E0631",
+            StateFileError::FileReadError(_) => "E0632",
+            StateFileError::Writing(_) => "E0633",
+        }
+    }
+}
+
 impl From<std::io::Error> for StateFileError {
     fn from(error: std::io::Error) -> Self {
         StateFileError::Crate("std::io", error.to_string())
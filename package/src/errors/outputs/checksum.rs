@@ -34,6 +34,21 @@ pub enum ChecksumFileError {
     Writing(io::Error),
 }
 
+impl ChecksumFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ChecksumFileError::Crate(..) => "E0650",
+            ChecksumFileError::Creating(_) => "E0651",
+            ChecksumFileError::FileReadError(_) => "E0652",
+            ChecksumFileError::FileRemovalError(_) => "E0653",
+            ChecksumFileError::Writing(_) => "E0654",
+        }
+    }
+}
+
 impl From<std::io::Error> for ChecksumFileError {
     fn from(error: std::io::Error) -> Self {
         ChecksumFileError::Crate("std::io", error.to_string())
@@ -34,6 +34,21 @@ pub enum CircuitFileError {
     Writing(io::Error),
 }
 
+impl CircuitFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            CircuitFileError::Crate(..) => "E0660",
+            CircuitFileError::Creating(_) => "E0661",
+            CircuitFileError::FileReadError(_) => "E0662",
+            CircuitFileError::FileRemovalError(_) => "E0663",
+            CircuitFileError::Writing(_) => "E0664",
+        }
+    }
+}
+
 impl From<std::io::Error> for CircuitFileError {
     fn from(error: std::io::Error) -> Self {
         CircuitFileError::Crate("std::io", error.to_string())
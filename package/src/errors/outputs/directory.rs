@@ -42,3 +42,21 @@ pub enum OutputsDirectoryError {
     #[error("removing: {}", _0)]
     Removing(io::Error),
 }
+
+impl OutputsDirectoryError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            OutputsDirectoryError::Creating(_) => "E0670",
+            OutputsDirectoryError::GettingFileEntry(_) => "E0671",
+            OutputsDirectoryError::GettingFileExtension(_) => "E0672",
+            OutputsDirectoryError::GettingFileType(..) => "E0673",
+            OutputsDirectoryError::InvalidFileExtension(..) => "E0674",
+            OutputsDirectoryError::InvalidFileType(..) => "E0675",
+            OutputsDirectoryError::Reading(_) => "E0676",
+            OutputsDirectoryError::Removing(_) => "E0677",
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{io, path::PathBuf};
+
+#[derive(Debug, Error)]
+pub enum ExportFileError {
+    #[error("{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[error("creating: {}", _0)]
+    Creating(io::Error),
+
+    #[error("Cannot remove the provided file - {:?}", _0)]
+    FileRemovalError(PathBuf),
+
+    #[error("Unknown export format `{}`, expected one of r1cs, wtns, zkif", _0)]
+    UnknownFormat(String),
+}
+
+impl ExportFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ExportFileError::Crate(..) => "E0680",
+            ExportFileError::Creating(_) => "E0681",
+            ExportFileError::FileRemovalError(_) => "E0682",
+            ExportFileError::UnknownFormat(_) => "E0683",
+        }
+    }
+}
+
+impl From<std::io::Error> for ExportFileError {
+    fn from(error: std::io::Error) -> Self {
+        ExportFileError::Crate("std::io", error.to_string())
+    }
+}
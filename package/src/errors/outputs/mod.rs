@@ -23,11 +23,32 @@ pub use checksum::*;
 pub mod directory;
 pub use self::directory::*;
 
+pub mod export;
+pub use export::*;
+
+pub mod build_manifest;
+pub use build_manifest::*;
+
+pub mod profile;
+pub use profile::*;
+
 pub mod proof;
 pub use proof::*;
 
 pub mod proving_key;
 pub use proving_key::*;
 
+pub mod public_inputs;
+pub use public_inputs::*;
+
+pub mod setup;
+pub use setup::*;
+
+pub mod transcript;
+pub use transcript::*;
+
 pub mod verification_key;
 pub use verification_key::*;
+
+pub mod witness;
+pub use witness::*;
@@ -34,6 +34,21 @@ pub enum ProofFileError {
     Writing(io::Error),
 }
 
+impl ProofFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ProofFileError::Crate(..) => "E0700",
+            ProofFileError::Creating(_) => "E0701",
+            ProofFileError::FileReadError(_) => "E0702",
+            ProofFileError::FileRemovalError(_) => "E0703",
+            ProofFileError::Writing(_) => "E0704",
+        }
+    }
+}
+
 impl From<std::io::Error> for ProofFileError {
     fn from(error: std::io::Error) -> Self {
         ProofFileError::Crate("std::io", error.to_string())
@@ -34,6 +34,21 @@ pub enum ProvingKeyFileError {
     Writing(io::Error),
 }
 
+impl ProvingKeyFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ProvingKeyFileError::Crate(..) => "E0710",
+            ProvingKeyFileError::Creating(_) => "E0711",
+            ProvingKeyFileError::FileReadError(_) => "E0712",
+            ProvingKeyFileError::FileRemovalError(_) => "E0713",
+            ProvingKeyFileError::Writing(_) => "E0714",
+        }
+    }
+}
+
 impl From<std::io::Error> for ProvingKeyFileError {
     fn from(error: std::io::Error) -> Self {
         ProvingKeyFileError::Crate("std::io", error.to_string())
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{io, path::PathBuf};
+
+#[derive(Debug, Error)]
+pub enum PublicInputsFileError {
+    #[error("{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[error("creating: {}", _0)]
+    Creating(io::Error),
+
+    #[error("Cannot read from the provided file path - {:?}", _0)]
+    FileReadError(PathBuf),
+
+    #[error("Cannot remove the provided file - {:?}", _0)]
+    FileRemovalError(PathBuf),
+
+    #[error("Public inputs file was corrupted")]
+    IncorrectPublicInputs,
+
+    #[error("writing: {}", _0)]
+    Writing(io::Error),
+}
+
+impl PublicInputsFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            PublicInputsFileError::Crate(..) => "E0720",
+            PublicInputsFileError::Creating(_) => "E0721",
+            PublicInputsFileError::FileReadError(_) => "E0722",
+            PublicInputsFileError::FileRemovalError(_) => "E0723",
+            PublicInputsFileError::IncorrectPublicInputs => "E0724",
+            PublicInputsFileError::Writing(_) => "E0725",
+        }
+    }
+}
+
+impl From<std::io::Error> for PublicInputsFileError {
+    fn from(error: std::io::Error) -> Self {
+        PublicInputsFileError::Crate("std::io", error.to_string())
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{io, path::PathBuf};
+
+#[derive(Debug, Error)]
+pub enum SetupFileError {
+    #[error("{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[error("creating: {}", _0)]
+    Creating(io::Error),
+
+    #[error("Cannot read from the provided file path - {:?}", _0)]
+    FileReadError(PathBuf),
+
+    #[error("Cannot remove the provided file - {:?}", _0)]
+    FileRemovalError(PathBuf),
+
+    #[error("Cannot parse the setup record {:?}: {}", _0, _1)]
+    Parsing(PathBuf, serde_json::Error),
+
+    #[error("writing: {}", _0)]
+    Writing(io::Error),
+}
+
+impl SetupFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            SetupFileError::Crate(..) => "E0730",
+            SetupFileError::Creating(_) => "E0731",
+            SetupFileError::FileReadError(_) => "E0732",
+            SetupFileError::FileRemovalError(_) => "E0733",
+            SetupFileError::Parsing(..) => "E0734",
+            SetupFileError::Writing(_) => "E0735",
+        }
+    }
+}
+
+impl From<std::io::Error> for SetupFileError {
+    fn from(error: std::io::Error) -> Self {
+        SetupFileError::Crate("std::io", error.to_string())
+    }
+}
+
+impl From<serde_json::Error> for SetupFileError {
+    fn from(error: serde_json::Error) -> Self {
+        SetupFileError::Crate("serde_json", error.to_string())
+    }
+}
@@ -37,6 +37,22 @@ pub enum VerificationKeyFileError {
     Writing(io::Error),
 }
 
+impl VerificationKeyFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            VerificationKeyFileError::Crate(..) => "E0750",
+            VerificationKeyFileError::Creating(_) => "E0751",
+            VerificationKeyFileError::FileReadError(_) => "E0752",
+            VerificationKeyFileError::FileRemovalError(_) => "E0753",
+            VerificationKeyFileError::IncorrectVerificationKey => "E0754",
+            VerificationKeyFileError::Writing(_) => "E0755",
+        }
+    }
+}
+
 impl From<std::io::Error> for VerificationKeyFileError {
     fn from(error: std::io::Error) -> Self {
         VerificationKeyFileError::Crate("std::io", error.to_string())
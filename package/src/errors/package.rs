@@ -30,6 +30,20 @@ pub enum PackageError {
     Removing(&'static str, io::Error),
 }
 
+impl PackageError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            PackageError::Crate(..) => "E0770",
+            PackageError::Creating(..) => "E0771",
+            PackageError::FailedToInitialize(..) => "E0772",
+            PackageError::Removing(..) => "E0773",
+        }
+    }
+}
+
 impl From<std::io::Error> for PackageError {
     fn from(error: std::io::Error) -> Self {
         PackageError::Crate("std::io", error.to_string())
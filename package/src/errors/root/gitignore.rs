@@ -28,6 +28,19 @@ pub enum GitignoreError {
     Writing(io::Error),
 }
 
+impl GitignoreError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            GitignoreError::Crate(..) => "E0780",
+            GitignoreError::Creating(_) => "E0781",
+            GitignoreError::Writing(_) => "E0782",
+        }
+    }
+}
+
 impl From<std::io::Error> for GitignoreError {
     fn from(error: std::io::Error) -> Self {
         GitignoreError::Crate("std::io", error.to_string())
@@ -36,3 +36,19 @@ pub enum ManifestError {
     #[error("`{}` writing: {}", _0, _1)]
     Writing(&'static str, io::Error),
 }
+
+impl ManifestError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ManifestError::Creating(..) => "E0790",
+            ManifestError::Metadata(..) => "E0791",
+            ManifestError::Opening(..) => "E0792",
+            ManifestError::Parsing(..) => "E0793",
+            ManifestError::Reading(..) => "E0794",
+            ManifestError::Writing(..) => "E0795",
+        }
+    }
+}
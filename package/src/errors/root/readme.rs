@@ -28,6 +28,19 @@ pub enum READMEError {
     Writing(io::Error),
 }
 
+impl READMEError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            READMEError::Crate(..) => "E0800",
+            READMEError::Creating(_) => "E0801",
+            READMEError::Writing(_) => "E0802",
+        }
+    }
+}
+
 impl From<std::io::Error> for READMEError {
     fn from(error: std::io::Error) -> Self {
         READMEError::Crate("std::io", error.to_string())
@@ -42,6 +42,23 @@ pub enum ZipFileError {
     ZipError(#[from] ZipError),
 }
 
+impl ZipFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ZipFileError::Crate(..) => "E0810",
+            ZipFileError::Creating(_) => "E0811",
+            ZipFileError::FileReadError(_) => "E0812",
+            ZipFileError::FileRemovalError(_) => "E0813",
+            ZipFileError::Writing(_) => "E0814",
+            ZipFileError::WalkDirError(_) => "E0815",
+            ZipFileError::ZipError(_) => "E0816",
+        }
+    }
+}
+
 impl From<std::io::Error> for ZipFileError {
     fn from(error: std::io::Error) -> Self {
         ZipFileError::Crate("std::io", error.to_string())
@@ -39,3 +39,20 @@ pub enum SourceDirectoryError {
     #[error("reading: {}", _0)]
     Reading(io::Error),
 }
+
+impl SourceDirectoryError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            SourceDirectoryError::Creating(_) => "E0820",
+            SourceDirectoryError::GettingFileEntry(_) => "E0821",
+            SourceDirectoryError::GettingFileExtension(_) => "E0822",
+            SourceDirectoryError::GettingFileType(..) => "E0823",
+            SourceDirectoryError::InvalidFileExtension(..) => "E0824",
+            SourceDirectoryError::InvalidFileType(..) => "E0825",
+            SourceDirectoryError::Reading(_) => "E0826",
+        }
+    }
+}
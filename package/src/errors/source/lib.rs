@@ -28,6 +28,19 @@ pub enum LibraryFileError {
     Writing(io::Error),
 }
 
+impl LibraryFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            LibraryFileError::Crate(..) => "E0830",
+            LibraryFileError::Creating(_) => "E0831",
+            LibraryFileError::Writing(_) => "E0832",
+        }
+    }
+}
+
 impl From<std::io::Error> for LibraryFileError {
     fn from(error: std::io::Error) -> Self {
         LibraryFileError::Crate("std::io", error.to_string())
@@ -28,6 +28,19 @@ pub enum MainFileError {
     Writing(io::Error),
 }
 
+impl MainFileError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            MainFileError::Crate(..) => "E0840",
+            MainFileError::Creating(_) => "E0841",
+            MainFileError::Writing(_) => "E0842",
+        }
+    }
+}
+
 impl From<std::io::Error> for MainFileError {
     fn from(error: std::io::Error) -> Self {
         MainFileError::Crate("std::io", error.to_string())
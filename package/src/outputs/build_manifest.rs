@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The build manifest file.
+//!
+//! Records a checksum of every file a build read, so later commands can tell what changed.
+
+use crate::{errors::BuildManifestFileError, outputs::OUTPUTS_DIRECTORY_NAME};
+
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    fmt,
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+pub static MANIFEST_FILE_EXTENSION: &str = ".manifest";
+
+/// A difference between two build manifests.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ManifestChange {
+    CompilerVersion { previous: String, current: String },
+    Added(String),
+    Modified(String),
+    Removed(String),
+}
+
+impl fmt::Display for ManifestChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ManifestChange::CompilerVersion { previous, current } => {
+                write!(f, "compiler version changed from {} to {}", previous, current)
+            }
+            ManifestChange::Added(file) => write!(f, "added {}", file),
+            ManifestChange::Modified(file) => write!(f, "modified {}", file),
+            ManifestChange::Removed(file) => write!(f, "removed {}", file),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BuildManifest {
+    pub compiler_version: String,
+    /// The fingerprint of the build, derived from the compiler version and every file checksum.
+    pub checksum: String,
+    /// The checksum of every source and input file, keyed by its path relative to the package.
+    pub files: BTreeMap<String, String>,
+}
+
+impl BuildManifest {
+    /// Returns every difference between this manifest and a previous one.
+    pub fn changes_since(&self, previous: &BuildManifest) -> Vec<ManifestChange> {
+        let mut changes = vec![];
+
+        if self.compiler_version != previous.compiler_version {
+            changes.push(ManifestChange::CompilerVersion {
+                previous: previous.compiler_version.clone(),
+                current: self.compiler_version.clone(),
+            });
+        }
+
+        for (file, checksum) in &self.files {
+            match previous.files.get(file) {
+                Some(previous_checksum) if previous_checksum == checksum => {}
+                Some(_) => changes.push(ManifestChange::Modified(file.clone())),
+                None => changes.push(ManifestChange::Added(file.clone())),
+            }
+        }
+
+        for file in previous.files.keys() {
+            if !self.files.contains_key(file) {
+                changes.push(ManifestChange::Removed(file.clone()));
+            }
+        }
+
+        changes
+    }
+
+    pub fn to_json_string(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&self)
+    }
+
+    pub fn from_json_string(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+#[derive(Deserialize)]
+pub struct BuildManifestFile {
+    pub package_name: String,
+}
+
+impl BuildManifestFile {
+    pub fn new(package_name: &str) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+        }
+    }
+
+    pub fn exists_at(&self, path: &Path) -> bool {
+        let path = self.setup_file_path(path);
+        path.exists()
+    }
+
+    /// Reads the build manifest from the given file path if it exists.
+    pub fn read_from(&self, path: &Path) -> Result<BuildManifest, BuildManifestFileError> {
+        let path = self.setup_file_path(path);
+
+        let string =
+            fs::read_to_string(&path).map_err(|_| BuildManifestFileError::FileReadError(path.clone().into_owned()))?;
+        BuildManifest::from_json_string(&string)
+            .map_err(|error| BuildManifestFileError::Parsing(path.into_owned(), error))
+    }
+
+    /// Writes the given build manifest to a file.
+    pub fn write_to(&self, path: &Path, manifest: &BuildManifest) -> Result<(), BuildManifestFileError> {
+        let path = self.setup_file_path(path);
+
+        let mut file = File::create(&path)?;
+        file.write_all(manifest.to_json_string()?.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Removes the build manifest at the given path if it exists. Returns `true` on success,
+    /// `false` if the file doesn't exist, and `Error` if the file system fails during operation.
+    pub fn remove(&self, path: &Path) -> Result<bool, BuildManifestFileError> {
+        let path = self.setup_file_path(path);
+        if !path.exists() {
+            return Ok(false);
+        }
+
+        fs::remove_file(&path).map_err(|_| BuildManifestFileError::FileRemovalError(path.into_owned()))?;
+        Ok(true)
+    }
+
+    fn setup_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+                path.to_mut().push(OUTPUTS_DIRECTORY_NAME);
+            }
+            path.to_mut().push(format!("{}{}", self.package_name, MANIFEST_FILE_EXTENSION));
+        }
+        path
+    }
+}
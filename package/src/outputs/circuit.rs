@@ -27,6 +27,7 @@ use std::{
 };
 
 pub static CIRCUIT_FILE_EXTENSION: &str = ".json";
+pub static CIRCUIT_BINARY_FILE_EXTENSION: &str = ".circuit.bin";
 
 #[derive(Deserialize)]
 pub struct CircuitFile {
@@ -41,20 +42,25 @@ impl CircuitFile {
     }
 
     pub fn exists_at(&self, path: &Path) -> bool {
-        let path = self.setup_file_path(path);
+        let path = self.setup_file_path(path, CIRCUIT_FILE_EXTENSION);
+        path.exists()
+    }
+
+    pub fn exists_at_binary(&self, path: &Path) -> bool {
+        let path = self.setup_file_path(path, CIRCUIT_BINARY_FILE_EXTENSION);
         path.exists()
     }
 
     /// Reads the serialized circuit from the given file path if it exists.
     pub fn read_from(&self, path: &Path) -> Result<String, CircuitFileError> {
-        let path = self.setup_file_path(path);
+        let path = self.setup_file_path(path, CIRCUIT_FILE_EXTENSION);
 
         Ok(fs::read_to_string(&path).map_err(|_| CircuitFileError::FileReadError(path.into_owned()))?)
     }
 
     /// Writes the given serialized circuit to a file.
     pub fn write_to(&self, path: &Path, circuit: String) -> Result<(), CircuitFileError> {
-        let path = self.setup_file_path(path);
+        let path = self.setup_file_path(path, CIRCUIT_FILE_EXTENSION);
 
         let mut file = File::create(&path)?;
         file.write_all(circuit.as_bytes())?;
@@ -62,26 +68,43 @@ impl CircuitFile {
         Ok(())
     }
 
+    /// Creates the binary serialized circuit file, truncating any previous one.
+    pub fn create_binary(&self, path: &Path) -> Result<File, CircuitFileError> {
+        let path = self.setup_file_path(path, CIRCUIT_BINARY_FILE_EXTENSION);
+
+        Ok(File::create(&path)?)
+    }
+
+    /// Opens the binary serialized circuit file for reading if it exists.
+    pub fn open_binary(&self, path: &Path) -> Result<File, CircuitFileError> {
+        let path = self.setup_file_path(path, CIRCUIT_BINARY_FILE_EXTENSION);
+
+        File::open(&path).map_err(|_| CircuitFileError::FileReadError(path.into_owned()))
+    }
+
     /// Removes the serialized circuit at the given path if it exists. Returns `true` on success,
     /// `false` if the file doesn't exist, and `Error` if the file system fails during operation.
     pub fn remove(&self, path: &Path) -> Result<bool, CircuitFileError> {
-        let path = self.setup_file_path(path);
-        if !path.exists() {
-            return Ok(false);
+        let mut removed = false;
+
+        for extension in &[CIRCUIT_FILE_EXTENSION, CIRCUIT_BINARY_FILE_EXTENSION] {
+            let path = self.setup_file_path(path, extension);
+            if path.exists() {
+                fs::remove_file(&path).map_err(|_| CircuitFileError::FileRemovalError(path.into_owned()))?;
+                removed = true;
+            }
         }
 
-        fs::remove_file(&path).map_err(|_| CircuitFileError::FileRemovalError(path.into_owned()))?;
-        Ok(true)
+        Ok(removed)
     }
 
-    fn setup_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+    fn setup_file_path<'a>(&self, path: &'a Path, extension: &str) -> Cow<'a, Path> {
         let mut path = Cow::from(path);
         if path.is_dir() {
             if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
                 path.to_mut().push(OUTPUTS_DIRECTORY_NAME);
             }
-            path.to_mut()
-                .push(format!("{}{}", self.package_name, CIRCUIT_FILE_EXTENSION));
+            path.to_mut().push(format!("{}{}", self.package_name, extension));
         }
         path
     }
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The circuit files exported to standard R1CS interchange formats.
+
+use crate::{errors::ExportFileError, outputs::OUTPUTS_DIRECTORY_NAME};
+
+use serde::Deserialize;
+use std::{
+    borrow::Cow,
+    fmt,
+    fs::{self, File},
+    path::Path,
+    str::FromStr,
+};
+
+/// A standard format the compiled circuit can be exported to.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// The iden3 `.r1cs` constraint system format.
+    R1cs,
+    /// The iden3 `.wtns` witness format.
+    Wtns,
+    /// The zkInterface flatbuffers format.
+    Zkif,
+}
+
+impl ExportFormat {
+    pub const ALL: &'static [ExportFormat] = &[ExportFormat::R1cs, ExportFormat::Wtns, ExportFormat::Zkif];
+    pub const NAMES: &'static [&'static str] = &["r1cs", "wtns", "zkif"];
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::R1cs => ".r1cs",
+            ExportFormat::Wtns => ".wtns",
+            ExportFormat::Zkif => ".zkif",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = ExportFileError;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "r1cs" => Ok(ExportFormat::R1cs),
+            "wtns" => Ok(ExportFormat::Wtns),
+            "zkif" => Ok(ExportFormat::Zkif),
+            _ => Err(ExportFileError::UnknownFormat(format.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.extension()[1..])
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ExportFile {
+    pub package_name: String,
+    pub format: ExportFormat,
+}
+
+impl ExportFile {
+    pub fn new(package_name: &str, format: ExportFormat) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+            format,
+        }
+    }
+
+    pub fn exists_at(&self, path: &Path) -> bool {
+        let path = self.setup_file_path(path);
+        path.exists()
+    }
+
+    /// Creates the exported circuit file, truncating any previous one.
+    pub fn create(&self, path: &Path) -> Result<File, ExportFileError> {
+        let path = self.setup_file_path(path);
+
+        Ok(File::create(&path)?)
+    }
+
+    /// Removes the exported circuit at the given path if it exists. Returns `true` on success,
+    /// `false` if the file doesn't exist, and `Error` if the file system fails during operation.
+    pub fn remove(&self, path: &Path) -> Result<bool, ExportFileError> {
+        let path = self.setup_file_path(path);
+        if !path.exists() {
+            return Ok(false);
+        }
+
+        fs::remove_file(&path).map_err(|_| ExportFileError::FileRemovalError(path.into_owned()))?;
+        Ok(true)
+    }
+
+    fn setup_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+                path.to_mut().push(OUTPUTS_DIRECTORY_NAME);
+            }
+            path.to_mut()
+                .push(format!("{}{}", self.package_name, self.format.extension()));
+        }
+        path
+    }
+}
@@ -23,11 +23,32 @@ pub use self::checksum::*;
 pub mod directory;
 pub use directory::*;
 
+pub mod profile;
+pub use self::profile::*;
+
+pub mod export;
+pub use self::export::*;
+
+pub mod build_manifest;
+pub use self::build_manifest::*;
+
 pub mod proof;
 pub use self::proof::*;
 
 pub mod proving_key;
 pub use self::proving_key::*;
 
+pub mod public_inputs;
+pub use self::public_inputs::*;
+
+pub mod setup;
+pub use self::setup::*;
+
+pub mod transcript;
+pub use self::transcript::*;
+
 pub mod verification_key;
 pub use self::verification_key::*;
+
+pub mod witness;
+pub use self::witness::*;
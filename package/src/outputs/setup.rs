@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The setup record file.
+//!
+//! Records where the proving and verification keys came from and which circuit they were made for.
+
+use crate::{errors::SetupFileError, outputs::OUTPUTS_DIRECTORY_NAME, root::Backend};
+
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+pub static SETUP_FILE_EXTENSION: &str = ".setup";
+
+/// How the proving and verification keys were generated.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SetupSource {
+    /// A setup sampled from a cryptographically secure random number generator.
+    Random,
+    /// A reproducible setup derived from a public seed. The keys are insecure and only fit for testing.
+    Seeded { seed: u64 },
+    /// Parameters generated outside of Leo, for example by an MPC ceremony.
+    Imported {
+        parameters_checksum: String,
+        transcript_checksum: String,
+    },
+}
+
+impl SetupSource {
+    /// Returns `true` if anyone can recompute the toxic waste of the setup.
+    pub fn is_insecure(&self) -> bool {
+        matches!(self, SetupSource::Seeded { .. })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SetupRecord {
+    /// The checksum of the program the keys were generated for.
+    pub circuit_checksum: String,
+    pub backend: Backend,
+    pub source: SetupSource,
+    pub proving_key_checksum: String,
+    pub verification_key_checksum: String,
+}
+
+impl SetupRecord {
+    pub fn to_json_string(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&self)
+    }
+
+    pub fn from_json_string(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SetupFile {
+    pub package_name: String,
+}
+
+impl SetupFile {
+    pub fn new(package_name: &str) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+        }
+    }
+
+    pub fn exists_at(&self, path: &Path) -> bool {
+        let path = self.setup_file_path(path);
+        path.exists()
+    }
+
+    /// Reads the setup record from the given file path if it exists.
+    pub fn read_from(&self, path: &Path) -> Result<SetupRecord, SetupFileError> {
+        let path = self.setup_file_path(path);
+
+        let string = fs::read_to_string(&path).map_err(|_| SetupFileError::FileReadError(path.clone().into_owned()))?;
+        SetupRecord::from_json_string(&string).map_err(|error| SetupFileError::Parsing(path.into_owned(), error))
+    }
+
+    /// Writes the given setup record to a file.
+    pub fn write_to(&self, path: &Path, record: &SetupRecord) -> Result<(), SetupFileError> {
+        let path = self.setup_file_path(path);
+
+        let mut file = File::create(&path)?;
+        file.write_all(record.to_json_string()?.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Removes the setup record at the given path if it exists. Returns `true` on success,
+    /// `false` if the file doesn't exist, and `Error` if the file system fails during operation.
+    pub fn remove(&self, path: &Path) -> Result<bool, SetupFileError> {
+        let path = self.setup_file_path(path);
+        if !path.exists() {
+            return Ok(false);
+        }
+
+        fs::remove_file(&path).map_err(|_| SetupFileError::FileRemovalError(path.into_owned()))?;
+        Ok(true)
+    }
+
+    fn setup_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+                path.to_mut().push(OUTPUTS_DIRECTORY_NAME);
+            }
+            path.to_mut().push(format!("{}{}", self.package_name, SETUP_FILE_EXTENSION));
+        }
+        path
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The serialized witness output file.
+
+use crate::{errors::WitnessFileError, outputs::OUTPUTS_DIRECTORY_NAME};
+
+use serde::Deserialize;
+use std::{
+    borrow::Cow,
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+pub static WITNESS_FILE_EXTENSION: &str = ".witness";
+pub static WITNESS_BINARY_FILE_EXTENSION: &str = ".witness.bin";
+
+#[derive(Deserialize)]
+pub struct WitnessFile {
+    pub package_name: String,
+}
+
+impl WitnessFile {
+    pub fn new(package_name: &str) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+        }
+    }
+
+    pub fn exists_at(&self, path: &Path) -> bool {
+        let path = self.setup_file_path(path, WITNESS_FILE_EXTENSION);
+        path.exists()
+    }
+
+    /// Reads the serialized witness from the given file path if it exists.
+    pub fn read_from(&self, path: &Path) -> Result<String, WitnessFileError> {
+        let path = self.setup_file_path(path, WITNESS_FILE_EXTENSION);
+
+        fs::read_to_string(&path).map_err(|_| WitnessFileError::FileReadError(path.into_owned()))
+    }
+
+    /// Writes the given serialized witness to a file.
+    pub fn write_to(&self, path: &Path, witness: String) -> Result<(), WitnessFileError> {
+        let path = self.setup_file_path(path, WITNESS_FILE_EXTENSION);
+
+        let mut file = File::create(&path)?;
+        file.write_all(witness.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Creates the binary serialized witness file, truncating any previous one.
+    pub fn create_binary(&self, path: &Path) -> Result<File, WitnessFileError> {
+        let path = self.setup_file_path(path, WITNESS_BINARY_FILE_EXTENSION);
+
+        Ok(File::create(&path)?)
+    }
+
+    /// Opens the binary serialized witness file for reading if it exists.
+    pub fn open_binary(&self, path: &Path) -> Result<File, WitnessFileError> {
+        let path = self.setup_file_path(path, WITNESS_BINARY_FILE_EXTENSION);
+
+        File::open(&path).map_err(|_| WitnessFileError::FileReadError(path.into_owned()))
+    }
+
+    /// Removes the serialized witness at the given path if it exists. Returns `true` on success,
+    /// `false` if the file doesn't exist, and `Error` if the file system fails during operation.
+    pub fn remove(&self, path: &Path) -> Result<bool, WitnessFileError> {
+        let mut removed = false;
+
+        for extension in &[WITNESS_FILE_EXTENSION, WITNESS_BINARY_FILE_EXTENSION] {
+            let path = self.setup_file_path(path, extension);
+            if path.exists() {
+                fs::remove_file(&path).map_err(|_| WitnessFileError::FileRemovalError(path.into_owned()))?;
+                removed = true;
+            }
+        }
+
+        Ok(removed)
+    }
+
+    fn setup_file_path<'a>(&self, path: &'a Path, extension: &str) -> Cow<'a, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+                path.to_mut().push(OUTPUTS_DIRECTORY_NAME);
+            }
+            path.to_mut().push(format!("{}{}", self.package_name, extension));
+        }
+        path
+    }
+}
@@ -16,13 +16,16 @@
 
 use crate::{errors::ManifestError, package::Package};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     convert::TryFrom,
+    fmt,
     fs::File,
     io::{Read, Write},
     path::Path,
+    str::FromStr,
 };
 
 pub const MANIFEST_FILENAME: &str = "Leo.toml";
@@ -32,10 +35,92 @@ pub struct Remote {
     pub author: String,
 }
 
+/// The proof system used to setup, prove, and verify a program.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Backend {
+    #[default]
+    #[serde(rename = "groth16")]
+    Groth16,
+    #[serde(rename = "gm17")]
+    GM17,
+}
+
+impl Backend {
+    pub const NAMES: &'static [&'static str] = &["groth16", "gm17"];
+
+    /// Returns the byte that identifies this backend in key and proof files.
+    pub fn id(self) -> u8 {
+        match self {
+            Backend::Groth16 => 0,
+            Backend::GM17 => 1,
+        }
+    }
+
+    /// Returns the backend identified by the given byte.
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Backend::Groth16),
+            1 => Some(Backend::GM17),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "groth16" => Ok(Backend::Groth16),
+            "gm17" => Ok(Backend::GM17),
+            _ => Err(s.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Backend::Groth16 => write!(f, "groth16"),
+            Backend::GM17 => write!(f, "gm17"),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Prover {
+    pub backend: Backend,
+}
+
+/// How `leo lint` reports the findings of a lint rule.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// The rule is not run.
+    Allow,
+    /// Findings are printed as warnings.
+    Warn,
+    /// Findings are printed as errors and fail the command.
+    Deny,
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LintLevel::Allow => write!(f, "allow"),
+            LintLevel::Warn => write!(f, "warn"),
+            LintLevel::Deny => write!(f, "deny"),
+        }
+    }
+}
+
 #[derive(Deserialize)]
 pub struct Manifest {
     pub project: Package,
     pub remote: Option<Remote>,
+    pub prover: Option<Prover>,
+    /// The level of each lint rule, keyed by rule name.
+    pub lint: Option<BTreeMap<String, LintLevel>>,
 }
 
 impl Manifest {
@@ -43,6 +128,8 @@ impl Manifest {
         Self {
             project: Package::new(package_name),
             remote: None,
+            prover: None,
+            lint: None,
         }
     }
 
@@ -78,6 +165,14 @@ impl Manifest {
         self.remote.clone()
     }
 
+    pub fn get_backend(&self) -> Backend {
+        self.prover.as_ref().map(|prover| prover.backend).unwrap_or_default()
+    }
+
+    pub fn get_lint_levels(&self) -> BTreeMap<String, LintLevel> {
+        self.lint.clone().unwrap_or_default()
+    }
+
     pub fn write_to(self, path: &Path) -> Result<(), ManifestError> {
         let mut path = Cow::from(path);
         if path.is_dir() {
@@ -17,7 +17,7 @@
 // Tests for package manifest
 
 use crate::test_dir;
-use leo_package::root::{Manifest, MANIFEST_FILENAME};
+use leo_package::root::{Backend, Manifest, MANIFEST_FILENAME};
 
 use std::{
     convert::TryFrom,
@@ -39,6 +39,14 @@ const NEW_REMOTE_FORMAT: &str = r#"
 author = "author"
 "#;
 
+const PROVER_FORMAT: &str = r#"[project]
+name = "test-package"
+version = "0.1.0"
+
+[prover]
+backend = "gm17"
+"#;
+
 const OLD_PROJECT_FORMAT: &str = "[package]";
 const NEW_PROJECT_FORMAT: &str = "[project]";
 
@@ -167,3 +175,24 @@ fn test_manifest_refactors() {
     // Check that the manifest file remote has been updated.
     assert!(remote_is_updated(&manifest_path));
 }
+
+#[test]
+fn test_manifest_prover_backend() {
+    let test_directory = test_dir();
+    let mut manifest_path = test_directory;
+    manifest_path.push(MANIFEST_FILENAME);
+
+    let mut file = File::create(&manifest_path).unwrap();
+    file.write_all(PROVER_FORMAT.as_bytes()).unwrap();
+
+    let manifest = Manifest::try_from(manifest_path.as_path()).unwrap();
+
+    assert_eq!(Backend::GM17, manifest.get_backend());
+}
+
+#[test]
+fn test_manifest_default_backend() {
+    let manifest = Manifest::new("test-package");
+
+    assert_eq!(Backend::Groth16, manifest.get_backend());
+}
@@ -18,6 +18,7 @@
 
 pub mod initialize;
 pub mod manifest;
+pub mod outputs;
 
 use lazy_static::lazy_static;
 use std::{
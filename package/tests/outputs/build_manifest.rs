@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for the build manifest
+
+use crate::test_dir;
+use leo_package::outputs::{BuildManifest, BuildManifestFile, ManifestChange, OutputsDirectory};
+
+fn build_manifest(compiler_version: &str, files: &[(&str, &str)]) -> BuildManifest {
+    BuildManifest {
+        compiler_version: compiler_version.to_string(),
+        checksum: "checksum".to_string(),
+        files: files
+            .iter()
+            .map(|(file, checksum)| (file.to_string(), checksum.to_string()))
+            .collect(),
+    }
+}
+
+#[test]
+fn test_build_manifest_round_trip() {
+    let test_directory = test_dir();
+    OutputsDirectory::create(&test_directory).unwrap();
+
+    let manifest = build_manifest("1.0.0", &[("src/main.leo", "a"), ("imports/bar/src/lib.leo", "b")]);
+
+    let manifest_file = BuildManifestFile::new("test-package");
+    manifest_file.write_to(&test_directory, &manifest).unwrap();
+
+    assert!(manifest_file.exists_at(&test_directory));
+    assert_eq!(manifest, manifest_file.read_from(&test_directory).unwrap());
+}
+
+#[test]
+fn test_build_manifest_changes() {
+    let previous = build_manifest("1.0.0", &[("src/main.leo", "a"), ("src/foo.leo", "b"), ("src/bar.leo", "c")]);
+    let current = build_manifest("1.0.1", &[("src/main.leo", "a"), ("src/foo.leo", "d"), ("src/baz.leo", "e")]);
+
+    assert_eq!(current.changes_since(&previous), vec![
+        ManifestChange::CompilerVersion {
+            previous: "1.0.0".to_string(),
+            current: "1.0.1".to_string(),
+        },
+        ManifestChange::Added("src/baz.leo".to_string()),
+        ManifestChange::Modified("src/foo.leo".to_string()),
+        ManifestChange::Removed("src/bar.leo".to_string()),
+    ]);
+    assert!(current.changes_since(&current).is_empty());
+}
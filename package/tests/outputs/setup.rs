@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for the setup record
+
+use crate::test_dir;
+use leo_package::{
+    outputs::{OutputsDirectory, SetupFile, SetupRecord, SetupSource},
+    root::Backend,
+};
+
+fn setup_record(source: SetupSource) -> SetupRecord {
+    SetupRecord {
+        circuit_checksum: "circuit".to_string(),
+        backend: Backend::Groth16,
+        source,
+        proving_key_checksum: "proving key".to_string(),
+        verification_key_checksum: "verification key".to_string(),
+    }
+}
+
+#[test]
+fn test_setup_record_round_trip() {
+    let test_directory = test_dir();
+    OutputsDirectory::create(&test_directory).unwrap();
+
+    let record = setup_record(SetupSource::Imported {
+        parameters_checksum: "parameters".to_string(),
+        transcript_checksum: "transcript".to_string(),
+    });
+
+    let setup_file = SetupFile::new("test-package");
+    setup_file.write_to(&test_directory, &record).unwrap();
+
+    assert!(setup_file.exists_at(&test_directory));
+    assert_eq!(record, setup_file.read_from(&test_directory).unwrap());
+}
+
+#[test]
+fn test_setup_record_seeded_is_insecure() {
+    assert!(setup_record(SetupSource::Seeded { seed: 7 }).source.is_insecure());
+    assert!(!setup_record(SetupSource::Random).source.is_insecure());
+}
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The stable error codes of `leo-symbol-table` and their descriptions, as shown by `leo explain`.
+pub const ERROR_CODES: &[(&str, &str)] = &[
+    (
+        "E0200",
+        "An imported core package does not exist.
+
+Core packages are imported from `core` by name, for example:
+
+    import core.unstable.blake2s.Blake2s;
+
+Check the spelling of the package path and of the imported circuit name.
+Core packages cannot be imported with `*`.",
+    ),
+    (
+        "E0201",
+        "Two circuits are defined with the same name.
+
+Circuits, enums, constants, and functions share a single namespace, so each
+name can only be defined once per program, including names brought in by
+imports:
+
+    circuit Foo {}
+    circuit Foo {}    // `Foo` is already defined",
+    ),
+    (
+        "E0202",
+        "A core package circuit could not be loaded.
+
+The symbols of an imported core package could not be resolved. This usually
+indicates a bug in the core package definitions rather than in the program.",
+    ),
+    (
+        "E0203",
+        "Two enums are defined with the same name.
+
+Enums share a namespace with circuits, constants, and functions, so an enum
+cannot reuse a name that the program or its imports already define:
+
+    enum Color { Red, Green }
+    circuit Color {}    // `Color` is already defined",
+    ),
+    (
+        "E0204",
+        "Two constants are defined with the same name.
+
+Each program constant must have a unique name, including constants brought
+in by imports:
+
+    const LIMIT: u32 = 10;
+    const LIMIT: u32 = 20;    // `LIMIT` is already defined",
+    ),
+    (
+        "E0205",
+        "Two functions are defined with the same name.
+
+Functions share a namespace with circuits, enums, and constants, so each
+function name can only be defined once per program:
+
+    function foo() {}
+    function foo() {}    // `foo` is already defined",
+    ),
+    (
+        "E0206",
+        "An imported package cannot be found.
+
+The package of an import statement must be a `.leo` file in the source
+directory or a package in the `imports` directory:
+
+    import lib.add;    // requires `src/lib.leo` or `imports/lib`",
+    ),
+    (
+        "E0207",
+        "An imported symbol is not defined by the imported file.
+
+The symbols of an import statement must be circuits, enums, constants, or
+functions defined by the imported file. Check the spelling of the symbol
+and that it is defined at the top level of the file.",
+    ),
+    (
+        "E0208",
+        "The type `Self` is used outside of a circuit.
+
+`Self` refers to the enclosing circuit, so it can only be used in circuit
+member types and circuit function signatures:
+
+    function foo() -> Self {}    // no enclosing circuit",
+    ),
+    (
+        "E0209",
+        "A type refers to a circuit that is not defined.
+
+Circuit types in member, constant, and function signatures must name a
+circuit that is defined by the program or brought in by an import:
+
+    circuit Foo {
+        b: Bar    // `Bar` is not defined
+    }",
+    ),
+    (
+        "E0210",
+        "An enum defines the same variant twice.
+
+Each variant of an enum must have a unique name:
+
+    enum Color { Red, Red }    // `Red` is already defined",
+    ),
+    (
+        "E0211",
+        "A const parameter cannot be inferred from the function inputs.
+
+The value of a const parameter is inferred from the length of an input
+array at each call, so every const parameter must be used as an array
+dimension of an input type:
+
+    function sum<const N: u32>(a: u32) {}          // `N` is not used
+    function sum<const N: u32>(a: [u32; N]) {}    // ok",
+    ),
+    (
+        "E0212",
+        "A type uses a const parameter that is not defined.
+
+An array dimension that is not a number must name a const parameter of the
+enclosing function:
+
+    function sum(a: [u32; N]) {}    // `N` is not a const parameter",
+    ),
+    (
+        "E0213",
+        "An enum has no variant with the given name.
+
+Enum variants are accessed with `::` and must be defined by the enum:
+
+    enum Color { Red, Green }
+
+    let c = Color::Blue;    // `Color` has no variant `Blue`",
+    ),
+    (
+        "E0214",
+        "A circuit has no member with the given name.
+
+Circuit variables and functions must be defined by the circuit before they
+are accessed. Check the spelling of the member name.",
+    ),
+    (
+        "E0215",
+        "A function other than `main` declares a public input.
+
+Only the inputs of the `main` function are part of the public inputs of the
+circuit, so only they can be marked `public`:
+
+    function foo(public a: u8) {}    // only `main` can have public inputs",
+    ),
+    (
+        "E0216",
+        "A type refers to a function that is not defined.
+
+Function calls must name a function that is defined by the program or
+brought in by an import. Check the spelling of the function name.",
+    ),
+];
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{TypeError, UserDefinedType};
-use leo_ast::{Error as FormattedError, ImportSymbol, Program, Span};
+use leo_ast::{sort_errors, Error as FormattedError, ImportSymbol, Program, Span};
 use leo_core::{CorePackageListError, LeoCorePackageError};
 
 use std::path::Path;
@@ -29,6 +29,9 @@ pub enum SymbolTableError {
     #[error("{}", _0)]
     Error(#[from] FormattedError),
 
+    #[error("{}", _0.iter().map(|error| error.to_string()).collect::<Vec<_>>().join("\n\n"))]
+    Errors(Vec<SymbolTableError>),
+
     #[error("{}", _0)]
     LeoCorePackageError(#[from] LeoCorePackageError),
 
@@ -37,6 +40,21 @@ pub enum SymbolTableError {
 }
 
 impl SymbolTableError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    /// A list of errors returns the code of its first error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            SymbolTableError::CorePackageListError(_) => "E0200",
+            SymbolTableError::Error(error) => error.code.unwrap_or_default(),
+            SymbolTableError::Errors(errors) => errors[0].error_code(),
+            SymbolTableError::LeoCorePackageError(_) => "E0202",
+            SymbolTableError::TypeError(error) => error.error_code(),
+        }
+    }
+
     ///
     /// Sets the filepath for the error stacktrace.
     ///
@@ -44,16 +62,43 @@ impl SymbolTableError {
         match self {
             SymbolTableError::CorePackageListError(error) => error.set_path(path),
             SymbolTableError::Error(error) => error.set_path(path),
+            SymbolTableError::Errors(errors) => errors.iter_mut().for_each(|error| error.set_path(path)),
             SymbolTableError::LeoCorePackageError(error) => error.set_path(path),
             SymbolTableError::TypeError(error) => error.set_path(path),
         }
     }
 
     ///
-    /// Returns a new formatted error with a given message and span information.
+    /// Returns an error for every independent error found by the symbol table.
     ///
-    fn new_from_span(message: String, span: Span) -> Self {
-        SymbolTableError::Error(FormattedError::new_from_span(message, span))
+    pub fn into_errors(self) -> Vec<SymbolTableError> {
+        match self {
+            SymbolTableError::Errors(errors) => errors.into_iter().flat_map(|error| error.into_errors()).collect(),
+            error => vec![error],
+        }
+    }
+
+    ///
+    /// Returns `Ok` if no errors were found, the error itself if only one error was found,
+    /// or all errors sorted by location.
+    ///
+    pub fn from_errors(errors: Vec<SymbolTableError>) -> Result<(), SymbolTableError> {
+        let mut errors = errors.into_iter().flat_map(|error| error.into_errors()).collect::<Vec<_>>();
+
+        sort_errors(&mut errors);
+
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(SymbolTableError::Errors(errors)),
+        }
+    }
+
+    ///
+    /// Returns a new formatted error with a given code, message, and span information.
+    ///
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        SymbolTableError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     ///
@@ -62,7 +107,25 @@ impl SymbolTableError {
     pub fn duplicate_circuit(variable: UserDefinedType) -> Self {
         let message = format!("Duplicate circuit definition found for `{}`", variable.identifier);
 
-        Self::new_from_span(message, variable.identifier.span)
+        Self::new_from_span("E0201", message, variable.identifier.span)
+    }
+
+    ///
+    /// Two enums have been defined with the same name.
+    ///
+    pub fn duplicate_enum(variable: UserDefinedType) -> Self {
+        let message = format!("Duplicate enum definition found for `{}`", variable.identifier);
+
+        Self::new_from_span("E0203", message, variable.identifier.span)
+    }
+
+    ///
+    /// Two constants have been defined with the same name.
+    ///
+    pub fn duplicate_constant(variable: UserDefinedType) -> Self {
+        let message = format!("Duplicate constant definition found for `{}`", variable.identifier);
+
+        Self::new_from_span("E0204", message, variable.identifier.span)
     }
 
     ///
@@ -71,7 +134,7 @@ impl SymbolTableError {
     pub fn duplicate_function(variable: UserDefinedType) -> Self {
         let message = format!("Duplicate function definition found for `{}`", variable.identifier);
 
-        Self::new_from_span(message, variable.identifier.span)
+        Self::new_from_span("E0205", message, variable.identifier.span)
     }
 
     ///
@@ -83,7 +146,7 @@ impl SymbolTableError {
             name
         );
 
-        Self::new_from_span(message, span.to_owned())
+        Self::new_from_span("E0206", message, span.to_owned())
     }
 
     ///
@@ -95,6 +158,6 @@ impl SymbolTableError {
             symbol, program.name
         );
 
-        Self::new_from_span(message, symbol.span.to_owned())
+        Self::new_from_span("E0207", message, symbol.span.to_owned())
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{Error as FormattedError, Identifier, Span};
+use leo_ast::{Error as FormattedError, FunctionInputVariable, Identifier, Span};
 
 use std::path::Path;
 
@@ -26,6 +26,15 @@ pub enum TypeError {
 }
 
 impl TypeError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            TypeError::Error(error) => error.code.unwrap_or_default(),
+        }
+    }
+
     ///
     /// Set the filepath for the error stacktrace.
     ///
@@ -36,10 +45,10 @@ impl TypeError {
     }
 
     ///
-    /// Return a new formatted error with a given message and span information.
+    /// Return a new formatted error with a given code, message, and span information.
     ///
-    fn new_from_span(message: String, span: Span) -> Self {
-        TypeError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        TypeError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     ///
@@ -48,7 +57,7 @@ impl TypeError {
     pub fn self_not_available(span: Span) -> Self {
         let message = "Type `Self` is only available in circuit definitions and circuit functions.".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0208", message, span)
     }
 
     ///
@@ -60,7 +69,46 @@ impl TypeError {
             identifier.name
         );
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0209", message, identifier.span)
+    }
+
+    ///
+    /// Found two enum variants with the same name.
+    ///
+    pub fn duplicate_enum_variant(identifier: Identifier) -> Self {
+        let message = format!("Duplicate enum variant definition found for `{}`.", identifier.name);
+
+        Self::new_from_span("E0210", message, identifier.span)
+    }
+
+    ///
+    /// Found a const parameter that is not used in an input type.
+    ///
+    pub fn uninferable_const_parameter(identifier: Identifier) -> Self {
+        let message = format!(
+            "Const parameter `{}` must be used as an array dimension in an input type.",
+            identifier.name
+        );
+
+        Self::new_from_span("E0211", message, identifier.span)
+    }
+
+    ///
+    /// Found an unknown const parameter name in an array dimension.
+    ///
+    pub fn undefined_const_parameter(identifier: Identifier) -> Self {
+        let message = format!("Const parameter `{}` is not defined.", identifier.name);
+
+        Self::new_from_span("E0212", message, identifier.span)
+    }
+
+    ///
+    /// Found an unknown enum variant name.
+    ///
+    pub fn undefined_enum_variant(enum_identifier: &Identifier, identifier: Identifier) -> Self {
+        let message = format!("Enum `{}` has no variant `{}`.", enum_identifier.name, identifier.name);
+
+        Self::new_from_span("E0213", message, identifier.span)
     }
 
     ///
@@ -69,7 +117,19 @@ impl TypeError {
     pub fn undefined_circuit_member(identifier: Identifier) -> Self {
         let message = format!("Circuit has no member `{}`.", identifier.name);
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0214", message, identifier.span)
+    }
+
+    ///
+    /// Found a public input outside of the `main` function.
+    ///
+    pub fn public_input(variable: &FunctionInputVariable) -> Self {
+        let message = format!(
+            "Function input `{}` cannot be public. Only the inputs of the `main` function can be public.",
+            variable.identifier.name
+        );
+
+        Self::new_from_span("E0215", message, variable.span.clone())
     }
 
     ///
@@ -81,6 +141,6 @@ impl TypeError {
             identifier.name
         );
 
-        Self::new_from_span(message, identifier.span)
+        Self::new_from_span("E0216", message, identifier.span)
     }
 }
@@ -14,8 +14,28 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CircuitType, CircuitVariableType, FunctionType, ImportedSymbols, SymbolTableError, UserDefinedType};
-use leo_ast::{Circuit, Function, Identifier, ImportStatement, ImportSymbol, Input, Package, Program};
+use crate::{
+    CircuitType,
+    CircuitVariableType,
+    EnumType,
+    FunctionType,
+    ImportedSymbols,
+    SymbolTableError,
+    Type,
+    UserDefinedType,
+};
+use leo_ast::{
+    Circuit,
+    Constant,
+    Enum,
+    Function,
+    Identifier,
+    ImportStatement,
+    ImportSymbol,
+    Input,
+    Package,
+    Program,
+};
 use leo_core::CorePackageList;
 use leo_imports::ImportParser;
 
@@ -29,7 +49,7 @@ pub const STATE_LEAF_VARIABLE_NAME: &str = "state_leaf";
 
 /// The symbol table for a Leo program.
 ///
-/// A symbol table has access to all function and circuit names in its parent's symbol table.
+/// A symbol table has access to all function, circuit, enum, and constant names in its parent's symbol table.
 /// A symbol table cannot access names in its child's symbol table.
 /// A child symbol table cannot access names in another sibling's symbol table.
 #[derive(Clone, Default)]
@@ -40,6 +60,12 @@ pub struct SymbolTable {
     /// Maps circuit name -> circuit type.
     circuits: IndexMap<String, CircuitType>,
 
+    /// Maps enum name -> enum type.
+    enums: IndexMap<String, EnumType>,
+
+    /// Maps constant name -> constant type.
+    constants: IndexMap<String, Type>,
+
     /// Maps function name -> function type.
     functions: IndexMap<String, FunctionType>,
 
@@ -56,6 +82,8 @@ impl SymbolTable {
     ///
     /// Checks that each circuit or function definition contains valid types.
     ///
+    /// Every name and definition is checked, so all independent errors are returned together.
+    ///
     pub fn new(
         program: &Program,
         import_parser: &ImportParser,
@@ -68,10 +96,12 @@ impl SymbolTable {
         table.insert_input(input)?;
 
         // Check for duplicate program and import names.
-        table.check_names(program, import_parser)?;
+        let names = table.check_names(program, import_parser);
 
         // Check for unknown or invalid types.
-        table.check_types(program)?;
+        let types = table.check_types(program);
+
+        SymbolTableError::from_errors(vec![names, types].into_iter().filter_map(Result::err).collect())?;
 
         Ok(table)
     }
@@ -104,6 +134,36 @@ impl SymbolTable {
         }
     }
 
+    ///
+    /// Insert an enum name into the symbol table from a given name and variable type.
+    ///
+    /// Returns an error if the enum name is a duplicate.
+    ///
+    pub fn insert_enum_name(&mut self, name: String, variable_type: UserDefinedType) -> Result<(), SymbolTableError> {
+        // Check that the enum name is unique.
+        match self.insert_name(name, variable_type) {
+            Some(duplicate) => Err(SymbolTableError::duplicate_enum(duplicate)),
+            None => Ok(()),
+        }
+    }
+
+    ///
+    /// Insert a constant name into the symbol table from a given name and variable type.
+    ///
+    /// Returns an error if the constant name is a duplicate.
+    ///
+    pub fn insert_constant_name(
+        &mut self,
+        name: String,
+        variable_type: UserDefinedType,
+    ) -> Result<(), SymbolTableError> {
+        // Check that the constant name is unique.
+        match self.insert_name(name, variable_type) {
+            Some(duplicate) => Err(SymbolTableError::duplicate_constant(duplicate)),
+            None => Ok(()),
+        }
+    }
+
     ///
     /// Insert a function name into the symbol table from a given name and variable type.
     ///
@@ -133,6 +193,30 @@ impl SymbolTable {
         self.circuits.insert(identifier.name, circuit_type)
     }
 
+    ///
+    /// Insert an enum definition into the symbol table from a given enum identifier and
+    /// enum type.
+    ///
+    /// If the symbol table did not have this name present, `None` is returned.
+    /// If the symbol table did have this name present, the enum type is updated, and the old
+    /// enum type is returned.
+    ///
+    pub fn insert_enum_type(&mut self, identifier: Identifier, enum_type: EnumType) -> Option<EnumType> {
+        self.enums.insert(identifier.name, enum_type)
+    }
+
+    ///
+    /// Insert a constant definition into the symbol table from a given constant identifier and
+    /// constant type.
+    ///
+    /// If the symbol table did not have this name present, `None` is returned.
+    /// If the symbol table did have this name present, the constant type is updated, and the old
+    /// constant type is returned.
+    ///
+    pub fn insert_constant_type(&mut self, identifier: Identifier, type_: Type) -> Option<Type> {
+        self.constants.insert(identifier.name, type_)
+    }
+
     ///
     /// Insert a function definition into the symbol table from a given identifier and
     /// function type.
@@ -169,6 +253,66 @@ impl SymbolTable {
         }
     }
 
+    ///
+    /// Returns a reference to the enum type corresponding to the name.
+    ///
+    /// If the symbol table did not have this name present, then the parent symbol table is checked.
+    /// If there is no parent symbol table, then `None` is returned.
+    ///
+    pub fn get_enum_type(&self, name: &str) -> Option<&EnumType> {
+        // Lookup name in symbol table.
+        match self.enums.get(name) {
+            Some(enum_type) => Some(enum_type),
+            None => {
+                // Lookup name in parent symbol table.
+                match &self.parent {
+                    Some(parent) => parent.get_enum_type(name),
+                    None => None,
+                }
+            }
+        }
+    }
+
+    ///
+    /// Returns a reference to the constant type corresponding to the name.
+    ///
+    /// If the symbol table did not have this name present, then the parent symbol table is checked.
+    /// If there is no parent symbol table, then `None` is returned.
+    ///
+    pub fn get_constant_type(&self, name: &str) -> Option<&Type> {
+        // Lookup name in symbol table.
+        match self.constants.get(name) {
+            Some(type_) => Some(type_),
+            None => {
+                // Lookup name in parent symbol table.
+                match &self.parent {
+                    Some(parent) => parent.get_constant_type(name),
+                    None => None,
+                }
+            }
+        }
+    }
+
+    ///
+    /// Returns a reference to the user defined type corresponding to the name.
+    ///
+    /// If the symbol table did not have this name present, then the parent symbol table is checked.
+    /// If there is no parent symbol table, then `None` is returned.
+    ///
+    pub fn get_user_defined_type(&self, name: &str) -> Option<&UserDefinedType> {
+        // Lookup name in symbol table.
+        match self.names.get(name) {
+            Some(user_defined) => Some(user_defined),
+            None => {
+                // Lookup name in parent symbol table.
+                match &self.parent {
+                    Some(parent) => parent.get_user_defined_type(name),
+                    None => None,
+                }
+            }
+        }
+    }
+
     ///
     /// Returns a reference to the function type corresponding to the name.
     ///
@@ -190,22 +334,26 @@ impl SymbolTable {
     }
 
     ///
-    /// Checks for duplicate import, circuit, and function names given a program.
+    /// Checks for duplicate import, circuit, enum, constant, and function names given a program.
     ///
-    /// If a circuit or function name has no duplicates, then it is inserted into the symbol table.
+    /// If a circuit, enum, constant, or function name has no duplicates, then it is inserted into the symbol table.
     /// Variables defined later in the unresolved program cannot have the same name.
     ///
     pub fn check_names(&mut self, program: &Program, import_parser: &ImportParser) -> Result<(), SymbolTableError> {
-        // Check unresolved program import names.
-        self.check_import_names(&program.imports, import_parser)?;
-
-        // Check unresolved program circuit names.
-        self.check_circuit_names(&program.circuits)?;
-
-        // Check unresolved program function names.
-        self.check_function_names(&program.functions)?;
-
-        Ok(())
+        let results = vec![
+            // Check unresolved program import names.
+            self.check_import_names(&program.imports, import_parser),
+            // Check unresolved program circuit names.
+            self.check_circuit_names(&program.circuits),
+            // Check unresolved program enum names.
+            self.check_enum_names(&program.enums),
+            // Check unresolved program constant names.
+            self.check_constant_names(&program.constants),
+            // Check unresolved program function names.
+            self.check_function_names(&program.functions),
+        ];
+
+        SymbolTableError::from_errors(results.into_iter().filter_map(Result::err).collect())
     }
 
     ///
@@ -215,13 +363,76 @@ impl SymbolTable {
     /// Types defined later in the program cannot have the same name.
     ///
     pub fn check_circuit_names(&mut self, circuits: &IndexMap<Identifier, Circuit>) -> Result<(), SymbolTableError> {
+        let mut errors = Vec::new();
+
         // Iterate over circuit names and definitions.
         for (identifier, circuit) in circuits.iter() {
+            let variable_type = UserDefinedType::from(circuit.clone());
+
             // Attempt to insert the circuit name into the symbol table.
-            self.insert_circuit_name(identifier.to_string(), UserDefinedType::from(circuit.clone()))?;
+            if let Err(error) = self.insert_circuit_name(identifier.to_string(), variable_type) {
+                errors.push(error);
+            }
         }
 
-        Ok(())
+        SymbolTableError::from_errors(errors)
+    }
+
+    ///
+    /// Checks for duplicate enum names given a hashmap of enums.
+    ///
+    /// If an enum name has no duplicates, then it is inserted into the symbol table.
+    /// Types defined later in the program cannot have the same name.
+    ///
+    pub fn check_enum_names(&mut self, enums: &IndexMap<Identifier, Enum>) -> Result<(), SymbolTableError> {
+        let mut errors = Vec::new();
+
+        // Iterate over enum names and definitions.
+        for (identifier, enum_) in enums.iter() {
+            // Attempt to insert the enum name into the symbol table.
+            if let Err(error) = self.insert_enum_name(identifier.to_string(), UserDefinedType::from(enum_.clone())) {
+                errors.push(error);
+            }
+        }
+
+        SymbolTableError::from_errors(errors)
+    }
+
+    ///
+    /// Checks for duplicate constant names given a list of constants.
+    ///
+    /// If a constant name has no duplicates, then it is inserted into the symbol table.
+    /// Types defined later in the program cannot have the same name.
+    ///
+    pub fn check_constant_names(&mut self, constants: &[Constant]) -> Result<(), SymbolTableError> {
+        let mut errors = Vec::new();
+
+        // Iterate over constant definitions.
+        for constant in constants.iter() {
+            let identifier = &constant.identifier;
+
+            // Resolve the declared type of the constant.
+            let type_ = match Type::new(self, constant.type_.clone(), constant.span.clone()) {
+                Ok(type_) => type_,
+                Err(error) => {
+                    errors.push(error.into());
+                    continue;
+                }
+            };
+
+            let variable_type = UserDefinedType {
+                identifier: identifier.clone(),
+                type_,
+                attribute: None,
+            };
+
+            // Attempt to insert the constant name into the symbol table.
+            if let Err(error) = self.insert_constant_name(identifier.to_string(), variable_type) {
+                errors.push(error);
+            }
+        }
+
+        SymbolTableError::from_errors(errors)
     }
 
     ///
@@ -231,13 +442,19 @@ impl SymbolTable {
     /// Types defined later in the program cannot have the same name.
     ///
     pub fn check_function_names(&mut self, functions: &IndexMap<Identifier, Function>) -> Result<(), SymbolTableError> {
+        let mut errors = Vec::new();
+
         // Iterate over function names and definitions.
         for (identifier, function) in functions.iter() {
+            let variable_type = UserDefinedType::from(function.clone());
+
             // Attempt to insert the function name into the symbol table.
-            self.insert_function_name(identifier.to_string(), UserDefinedType::from(function.clone()))?;
+            if let Err(error) = self.insert_function_name(identifier.to_string(), variable_type) {
+                errors.push(error);
+            }
         }
 
-        Ok(())
+        SymbolTableError::from_errors(errors)
     }
 
     ///
@@ -251,12 +468,16 @@ impl SymbolTable {
         imports: &[ImportStatement],
         import_parser: &ImportParser,
     ) -> Result<(), SymbolTableError> {
+        let mut errors = Vec::new();
+
         // Iterate over imported names.
         for import in imports {
-            self.check_import_statement(import, import_parser)?;
+            if let Err(error) = self.check_import_statement(import, import_parser) {
+                errors.push(error);
+            }
         }
 
-        Ok(())
+        SymbolTableError::from_errors(errors)
     }
 
     ///
@@ -328,12 +549,18 @@ impl SymbolTable {
         // Keep track of which import files have already been checked.
         let mut checked = IndexSet::new();
 
+        let mut errors = Vec::new();
+
         // Iterate over each imported symbol.
         for (name, symbol) in imported_symbols.symbols {
             // Find the imported program.
-            let program = import_parser
-                .get_import(&name)
-                .ok_or_else(|| SymbolTableError::unknown_package(&name, &symbol.span))?;
+            let program = match import_parser.get_import(&name) {
+                Some(program) => program,
+                None => {
+                    errors.push(SymbolTableError::unknown_package(&name, &symbol.span));
+                    continue;
+                }
+            };
 
             // Push the imported file's name to checked import files.
             if !checked.insert(name) {
@@ -342,16 +569,20 @@ impl SymbolTable {
             };
 
             // Check the imported program for duplicate types.
-            self.check_names(program, import_parser)?;
+            if let Err(error) = self.check_names(program, import_parser) {
+                errors.push(error);
+            }
 
             // Check the imported program for undefined types.
-            self.check_types(program)?;
+            if let Err(error) = self.check_types(program) {
+                errors.push(error);
+            }
 
             // Store the imported symbol.
             // self.insert_import_symbol(symbol, program)?; // TODO (collinc97) uncomment this line when public/private import scopes are implemented.
         }
 
-        Ok(())
+        SymbolTableError::from_errors(errors)
     }
 
     ///
@@ -363,6 +594,12 @@ impl SymbolTable {
             // Insert all program circuits.
             self.check_circuit_names(&program.circuits)?;
 
+            // Insert all program enums.
+            self.check_enum_names(&program.enums)?;
+
+            // Insert all program constants.
+            self.check_constant_names(&program.constants)?;
+
             // Insert all program functions.
             self.check_function_names(&program.functions)
         } else {
@@ -376,6 +613,29 @@ impl SymbolTable {
                     self.insert_circuit_name(identifier.to_string(), UserDefinedType::from(circuit.to_owned()))
                 }
                 None => {
+                    // Check if the imported symbol is an enum.
+                    if let Some(enum_) = program.enums.get(&symbol.symbol) {
+                        // Insert the imported enum.
+                        return self.insert_enum_name(identifier.to_string(), UserDefinedType::from(enum_.to_owned()));
+                    }
+
+                    // Check if the imported symbol is a constant.
+                    let constant = program
+                        .constants
+                        .iter()
+                        .find(|constant| constant.identifier == symbol.symbol);
+
+                    if let Some(constant) = constant {
+                        // Insert the imported constant.
+                        let variable_type = UserDefinedType {
+                            identifier: identifier.clone(),
+                            type_: Type::new(self, constant.type_.clone(), constant.span.clone())?,
+                            attribute: None,
+                        };
+
+                        return self.insert_constant_name(identifier.to_string(), variable_type);
+                    }
+
                     // Check if the imported symbol is a function.
                     match program.functions.get(&symbol.symbol) {
                         Some(function) => {
@@ -393,20 +653,25 @@ impl SymbolTable {
     }
 
     ///
-    /// Checks for unknown types in circuit and function definitions given an unresolved program.
+    /// Checks for unknown types in circuit, enum, constant, and function definitions given an unresolved program.
     ///
-    /// If a circuit or function definition only contains known types, then it is inserted into the
+    /// If a circuit, enum, constant, or function definition only contains known types, then it is inserted into the
     /// symbol table. Variables defined later in the unresolved program can lookup the definition and
     /// refer to its expected types.
     ///
     pub fn check_types(&mut self, program: &Program) -> Result<(), SymbolTableError> {
-        // Check unresolved program circuit definitions.
-        self.check_types_circuits(&program.circuits)?;
-
-        // Check unresolved program function definitions.
-        self.check_types_functions(&program.functions)?;
-
-        Ok(())
+        let results = vec![
+            // Check unresolved program circuit definitions.
+            self.check_types_circuits(&program.circuits),
+            // Check unresolved program enum definitions.
+            self.check_types_enums(&program.enums),
+            // Check unresolved program constant definitions.
+            self.check_types_constants(&program.constants),
+            // Check unresolved program function definitions.
+            self.check_types_functions(&program.functions),
+        ];
+
+        SymbolTableError::from_errors(results.into_iter().filter_map(Result::err).collect())
     }
 
     ///
@@ -417,19 +682,89 @@ impl SymbolTable {
     /// and refer to its expected types
     ///
     pub fn check_types_circuits(&mut self, circuits: &IndexMap<Identifier, Circuit>) -> Result<(), SymbolTableError> {
+        let mut errors = Vec::new();
+
         // Iterate over circuit names and definitions.
         for circuit in circuits.values() {
             // Get the identifier of the circuit.
             let identifier = circuit.circuit_name.clone();
 
             // Resolve unknown types in the circuit definition.
-            let circuit_type = CircuitType::new(self, circuit.clone())?;
+            let circuit_type = match CircuitType::new(self, circuit.clone()) {
+                Ok(circuit_type) => circuit_type,
+                Err(error) => {
+                    errors.push(error.into());
+                    continue;
+                }
+            };
 
             // Attempt to insert the circuit definition into the symbol table.
             self.insert_circuit_type(identifier, circuit_type);
         }
 
-        Ok(())
+        SymbolTableError::from_errors(errors)
+    }
+
+    ///
+    /// Checks for unknown types in an enum given a hashmap of enums.
+    ///
+    /// If an enum definition only contains known types, then it is inserted into the
+    /// symbol table. Variables defined later in the program can lookup the definition
+    /// and refer to its expected types
+    ///
+    pub fn check_types_enums(&mut self, enums: &IndexMap<Identifier, Enum>) -> Result<(), SymbolTableError> {
+        let mut errors = Vec::new();
+
+        // Iterate over enum names and definitions.
+        for enum_ in enums.values() {
+            // Get the identifier of the enum.
+            let identifier = enum_.enum_name.clone();
+
+            // Resolve unknown types in the enum definition.
+            let enum_type = match EnumType::new(self, enum_.clone()) {
+                Ok(enum_type) => enum_type,
+                Err(error) => {
+                    errors.push(error.into());
+                    continue;
+                }
+            };
+
+            // Attempt to insert the enum definition into the symbol table.
+            self.insert_enum_type(identifier, enum_type);
+        }
+
+        SymbolTableError::from_errors(errors)
+    }
+
+    ///
+    /// Checks for unknown types in a constant given a list of constants.
+    ///
+    /// If a constant definition only contains known types, then it is inserted into the
+    /// symbol table. Variables defined later in the program can lookup the definition
+    /// and refer to its expected type
+    ///
+    pub fn check_types_constants(&mut self, constants: &[Constant]) -> Result<(), SymbolTableError> {
+        let mut errors = Vec::new();
+
+        // Iterate over constant definitions.
+        for constant in constants.iter() {
+            // Get the identifier of the constant.
+            let identifier = constant.identifier.clone();
+
+            // Resolve unknown types in the constant definition.
+            let type_ = match Type::new(self, constant.type_.clone(), constant.span.clone()) {
+                Ok(type_) => type_,
+                Err(error) => {
+                    errors.push(error.into());
+                    continue;
+                }
+            };
+
+            // Attempt to insert the constant definition into the symbol table.
+            self.insert_constant_type(identifier, type_);
+        }
+
+        SymbolTableError::from_errors(errors)
     }
 
     ///
@@ -443,19 +778,27 @@ impl SymbolTable {
         &mut self,
         functions: &IndexMap<Identifier, Function>,
     ) -> Result<(), SymbolTableError> {
+        let mut errors = Vec::new();
+
         // Iterate over function names and definitions.
         for function in functions.values() {
             // Get the identifier of the function.
             let identifier = function.identifier.clone();
 
             // Resolve unknown types in the function definition.
-            let function_type = FunctionType::new(&self, function.clone())?;
+            let function_type = match FunctionType::new(&self, function.clone()) {
+                Ok(function_type) => function_type,
+                Err(error) => {
+                    errors.push(error.into());
+                    continue;
+                }
+            };
 
             // Attempt to insert the function definition into the symbol table.
             self.insert_function_type(identifier, function_type);
         }
 
-        Ok(())
+        SymbolTableError::from_errors(errors)
     }
 
     ///
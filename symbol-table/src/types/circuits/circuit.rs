@@ -46,9 +46,38 @@ impl CircuitType {
     ///
     pub fn new(table: &SymbolTable, unresolved: Circuit) -> Result<Self, TypeError> {
         let circuit_identifier = unresolved.circuit_name;
+        let circuit_const_parameters = unresolved.const_parameters;
         let mut variables = vec![];
         let mut functions = vec![];
 
+        // Check that the const parameters of the circuit can be inferred from its member variables.
+        let member_types = unresolved
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                CircuitMember::CircuitVariable(_, type_) => Some(type_),
+                CircuitMember::CircuitFunction(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        for parameter in circuit_const_parameters.iter() {
+            if !member_types
+                .iter()
+                .any(|type_| type_.const_parameters().contains(&&parameter.identifier))
+            {
+                return Err(TypeError::uninferable_const_parameter(parameter.identifier.clone()));
+            }
+        }
+
+        for identifier in member_types.iter().flat_map(|type_| type_.const_parameters()) {
+            if !circuit_const_parameters
+                .iter()
+                .any(|parameter| parameter.identifier.name == identifier.name)
+            {
+                return Err(TypeError::undefined_const_parameter(identifier.clone()));
+            }
+        }
+
         // Resolve the type of every circuit member.
         for member in unresolved.members {
             match member {
@@ -73,7 +102,12 @@ impl CircuitType {
                 }
                 CircuitMember::CircuitFunction(function) => {
                     // Resolve the type of the circuit member function.
-                    let function_type = FunctionType::from_circuit(table, circuit_identifier.clone(), function)?;
+                    let function_type = FunctionType::from_circuit(
+                        table,
+                        circuit_identifier.clone(),
+                        &circuit_const_parameters,
+                        function,
+                    )?;
 
                     // Store the circuit function type.
                     functions.push(function_type);
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{types::enums::EnumVariantType, SymbolTable, Type, TypeError};
+use leo_ast::{Enum, Identifier};
+
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+/// Stores enum definition details.
+///
+/// This type should be added to the enum symbol table for a resolved syntax tree.
+/// This is a user-defined type.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EnumType {
+    /// The name of the enum definition.
+    pub identifier: Identifier,
+
+    /// The enum variants in definition order.
+    pub variants: Vec<EnumVariantType>,
+}
+
+impl EnumType {
+    ///
+    /// Return a new `EnumType` from a given `Enum` definition.
+    ///
+    /// Performs a lookup in the given symbol table if an enum variant payload is a
+    /// user-defined type.
+    ///
+    pub fn new(table: &SymbolTable, unresolved: Enum) -> Result<Self, TypeError> {
+        let enum_identifier = unresolved.enum_name;
+        let mut variants: Vec<EnumVariantType> = vec![];
+
+        for variant in unresolved.variants {
+            // Check that the variant name is unique.
+            if variants
+                .iter()
+                .any(|existing| existing.identifier.name == variant.identifier.name)
+            {
+                return Err(TypeError::duplicate_enum_variant(variant.identifier));
+            }
+
+            // Resolve the type of the variant payload.
+            let payload = match variant.payload {
+                Some(type_) => Some(Type::new(table, type_, variant.span)?),
+                None => None,
+            };
+
+            variants.push(EnumVariantType {
+                identifier: variant.identifier,
+                payload,
+            });
+        }
+
+        Ok(EnumType {
+            identifier: enum_identifier,
+            variants,
+        })
+    }
+
+    ///
+    /// Returns the enum variant type given a variant identifier.
+    ///
+    pub fn variant_type(&self, identifier: &Identifier) -> Result<&EnumVariantType, TypeError> {
+        self.variants
+            .iter()
+            .find(|variant| variant.identifier.name == identifier.name)
+            .ok_or_else(|| TypeError::undefined_enum_variant(&self.identifier, identifier.clone()))
+    }
+}
+
+impl PartialEq for EnumType {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier.eq(&other.identifier)
+    }
+}
+
+impl Eq for EnumType {}
+
+impl Hash for EnumType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.identifier.hash(state);
+    }
+}
@@ -15,11 +15,12 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    types::functions::{FunctionInputType, FunctionOutputType},
+    types::functions::{FunctionInputType, FunctionInputVariableType, FunctionOutputType},
     SymbolTable,
+    Type,
     TypeError,
 };
-use leo_ast::{Function, Identifier};
+use leo_ast::{ConstParameter, Function, FunctionInput, Identifier, IntegerType};
 
 use serde::{Deserialize, Serialize};
 use std::hash::{Hash, Hasher};
@@ -33,6 +34,9 @@ pub struct FunctionType {
     /// The name of the function definition.
     pub identifier: Identifier,
 
+    /// The const parameters that are available in the function body.
+    pub const_parameters: Vec<FunctionInputType>,
+
     /// The function inputs.
     pub inputs: Vec<FunctionInputType>,
 
@@ -48,10 +52,16 @@ impl FunctionType {
     /// user-defined types.
     ///
     pub fn new(table: &SymbolTable, unresolved: Function) -> Result<Self, TypeError> {
+        let const_parameters = Self::const_parameters(&unresolved, &[])?;
         let mut inputs_resolved = Vec::with_capacity(unresolved.input.len());
 
         // Type check function inputs
         for input in unresolved.input {
+            // Only the inputs of the `main` function can be public
+            if unresolved.identifier.name != "main" {
+                Self::check_private_input(&input)?;
+            }
+
             let input = FunctionInputType::new(table, input)?;
             inputs_resolved.push(input);
         }
@@ -61,6 +71,7 @@ impl FunctionType {
 
         Ok(FunctionType {
             identifier: unresolved.identifier,
+            const_parameters,
             inputs: inputs_resolved,
             output,
         })
@@ -78,13 +89,17 @@ impl FunctionType {
     pub fn from_circuit(
         table: &SymbolTable,
         circuit_name: Identifier,
+        circuit_const_parameters: &[ConstParameter],
         unresolved_function: Function,
     ) -> Result<Self, TypeError> {
+        let const_parameters = Self::const_parameters(&unresolved_function, circuit_const_parameters)?;
         let function_identifier = unresolved_function.identifier;
         let mut inputs = Vec::with_capacity(unresolved_function.input.len());
 
         // Type check function inputs.
         for unresolved_input in unresolved_function.input {
+            Self::check_private_input(&unresolved_input)?;
+
             let input = FunctionInputType::new_from_circuit(table, unresolved_input, circuit_name.clone())?;
             inputs.push(input);
         }
@@ -99,11 +114,96 @@ impl FunctionType {
 
         Ok(FunctionType {
             identifier: function_identifier,
+            const_parameters,
             inputs,
             output,
         })
     }
 
+    ///
+    /// Returns an error if the given function input is public.
+    ///
+    fn check_private_input(input: &FunctionInput) -> Result<(), TypeError> {
+        match input {
+            FunctionInput::Variable(variable) if variable.public => Err(TypeError::public_input(variable)),
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Returns the const parameters that are available in the body of the given function.
+    ///
+    /// The values of const parameters are inferred from the function inputs, so every const parameter
+    /// of the function must be used as an array dimension in an input type.
+    /// The const parameters of a circuit are available in functions with a `self` input and in
+    /// static functions that use them in an input type.
+    ///
+    fn const_parameters(
+        unresolved_function: &Function,
+        circuit_const_parameters: &[ConstParameter],
+    ) -> Result<Vec<FunctionInputType>, TypeError> {
+        // Collect the const parameters used in the function input types.
+        let input_parameters = unresolved_function
+            .input
+            .iter()
+            .filter_map(|input| match input {
+                FunctionInput::Variable(variable) => Some(variable.type_.const_parameters()),
+                _ => None,
+            })
+            .flatten()
+            .map(|identifier| identifier.name.as_str())
+            .collect::<Vec<_>>();
+
+        let mut const_parameters = Vec::new();
+
+        for parameter in unresolved_function.const_parameters.iter() {
+            if !input_parameters.contains(&parameter.identifier.name.as_str()) {
+                return Err(TypeError::uninferable_const_parameter(parameter.identifier.clone()));
+            }
+
+            const_parameters.push(parameter);
+        }
+
+        for parameter in circuit_const_parameters {
+            if unresolved_function.contains_self() || input_parameters.contains(&parameter.identifier.name.as_str()) {
+                const_parameters.push(parameter);
+            }
+        }
+
+        // Check that every array dimension in the function signature is an available const parameter.
+        let signature_types = unresolved_function
+            .input
+            .iter()
+            .filter_map(|input| match input {
+                FunctionInput::Variable(variable) => Some(&variable.type_),
+                _ => None,
+            })
+            .chain(unresolved_function.output.iter());
+
+        for type_ in signature_types {
+            for identifier in type_.const_parameters() {
+                if !const_parameters
+                    .iter()
+                    .any(|parameter| parameter.identifier.name == identifier.name)
+                {
+                    return Err(TypeError::undefined_const_parameter(identifier.clone()));
+                }
+            }
+        }
+
+        Ok(const_parameters
+            .into_iter()
+            .map(|parameter| {
+                FunctionInputType::Variable(FunctionInputVariableType {
+                    identifier: parameter.identifier.clone(),
+                    type_: Type::IntegerType(IntegerType::U32),
+                    attribute: None,
+                    span: parameter.span.clone(),
+                })
+            })
+            .collect())
+    }
+
     ///
     /// Resolve a function definition and insert it into the given symbol table.
     ///
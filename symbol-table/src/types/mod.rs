@@ -17,6 +17,9 @@
 pub mod circuits;
 pub use self::circuits::*;
 
+pub mod enums;
+pub use self::enums::*;
+
 pub mod functions;
 pub use self::functions::*;
 
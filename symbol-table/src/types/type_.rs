@@ -28,6 +28,7 @@ pub enum Type {
     // Data types
     Address,
     Boolean,
+    Char,
     Field,
     Group,
     IntegerType(IntegerType),
@@ -38,6 +39,7 @@ pub enum Type {
 
     // User defined types
     Circuit(Identifier),
+    Enum(Identifier),
     Function(Identifier),
 
     // Unknown type variables
@@ -54,6 +56,7 @@ impl Type {
         Ok(match type_ {
             UnresolvedType::Address => Type::Address,
             UnresolvedType::Boolean => Type::Boolean,
+            UnresolvedType::Char => Type::Char,
             UnresolvedType::Field => Type::Field,
             UnresolvedType::Group => Type::Group,
             UnresolvedType::IntegerType(integer) => Type::IntegerType(integer),
@@ -72,13 +75,23 @@ impl Type {
                 Type::Tuple(tuple_types)
             }
 
-            UnresolvedType::Circuit(identifier) => {
+            UnresolvedType::Circuit(identifier) | UnresolvedType::Enum(identifier) => {
                 // Lookup the circuit type in the symbol table
-                let circuit_type = table
-                    .get_circuit_type(&identifier.name)
-                    .ok_or_else(|| TypeError::undefined_circuit(identifier))?;
+                if let Some(circuit_type) = table.get_circuit_type(&identifier.name) {
+                    return Ok(Type::Circuit(circuit_type.identifier.clone()));
+                }
+
+                // Lookup the enum type in the symbol table
+                if let Some(enum_type) = table.get_enum_type(&identifier.name) {
+                    return Ok(Type::Enum(enum_type.identifier.clone()));
+                }
 
-                Type::Circuit(circuit_type.identifier.clone())
+                // Lookup a circuit or enum name whose definition has not been checked yet
+                match table.get_user_defined_type(&identifier.name).map(|user_defined| &user_defined.type_) {
+                    Some(Type::Circuit(name)) => Type::Circuit(name.clone()),
+                    Some(Type::Enum(name)) => Type::Enum(name.clone()),
+                    _ => return Err(TypeError::undefined_circuit(identifier)),
+                }
             }
 
             UnresolvedType::SelfType => {
@@ -162,6 +175,41 @@ impl Type {
         types
     }
 
+    /// Returns a list of types that support bitwise and shift operations.
+    pub fn bitwise_types() -> Vec<Type> {
+        let mut types = Vec::new();
+
+        types.extend_from_slice(&Self::unsigned_integer_types());
+        types.extend_from_slice(&Self::signed_integer_types());
+
+        types
+    }
+
+    /// Returns a list of types that can be cast to the given type.
+    pub fn cast_types(type_: &Type) -> Vec<Type> {
+        let mut types = Vec::new();
+
+        match type_ {
+            Type::IntegerType(IntegerType::U32) => {
+                types.extend_from_slice(&Self::bitwise_types());
+                types.extend_from_slice(&[Type::Field, Type::Boolean, Type::Char]);
+            }
+            Type::IntegerType(_) => {
+                types.extend_from_slice(&Self::bitwise_types());
+                types.extend_from_slice(&[Type::Field, Type::Boolean]);
+            }
+            Type::Field => {
+                types.extend_from_slice(&Self::bitwise_types());
+                types.push(Type::Field);
+            }
+            Type::Boolean => types.push(Type::Boolean),
+            Type::Char => types.extend_from_slice(&[Type::IntegerType(IntegerType::U32), Type::Char]),
+            _ => {}
+        }
+
+        types
+    }
+
     /// Returns a list of possible index types (u8, u16, u32).
     pub fn index_types() -> Vec<Type> {
         let index_types = [
@@ -203,6 +251,7 @@ impl fmt::Display for Type {
         match &self {
             Type::Address => write!(f, "address"),
             Type::Boolean => write!(f, "bool"),
+            Type::Char => write!(f, "char"),
             Type::Field => write!(f, "field"),
             Type::Group => write!(f, "group"),
             Type::IntegerType(integer_type) => write!(f, "{}", integer_type),
@@ -215,6 +264,7 @@ impl fmt::Display for Type {
             }
 
             Type::Circuit(identifier) => write!(f, "circuit {}", identifier),
+            Type::Enum(identifier) => write!(f, "enum {}", identifier),
             Type::Function(identifier) => write!(f, "function {}", identifier),
             Type::TypeVariable(type_variable) => write!(f, "{}", type_variable),
         }
@@ -226,6 +276,7 @@ impl PartialEq for Type {
         match (self, other) {
             (Type::Address, Type::Address) => true,
             (Type::Boolean, Type::Boolean) => true,
+            (Type::Char, Type::Char) => true,
             (Type::Field, Type::Field) => true,
             (Type::Group, Type::Group) => true,
             (Type::IntegerType(integer_type1), Type::IntegerType(integer_type2)) => integer_type1.eq(integer_type2),
@@ -241,6 +292,7 @@ impl PartialEq for Type {
 
             (Type::Tuple(types1), Type::Tuple(types2)) => types1.eq(types2),
             (Type::Circuit(identifier1), Type::Circuit(identifier2)) => identifier1.eq(identifier2),
+            (Type::Enum(identifier1), Type::Enum(identifier2)) => identifier1.eq(identifier2),
             (Type::Function(identifier1), Type::Function(identifier2)) => identifier1.eq(identifier2),
             (Type::TypeVariable(variable1), Type::TypeVariable(variable2)) => variable1.eq(variable2),
             _ => false,
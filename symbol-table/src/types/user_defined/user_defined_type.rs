@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 use crate::{Attribute, FunctionInputVariableType, Type};
-use leo_ast::{Circuit, Function, Identifier};
+use leo_ast::{Circuit, Enum, Function, Identifier};
 
 use std::{
     fmt,
@@ -23,7 +23,7 @@ use std::{
 
 /// Stores information for a user defined type.
 ///
-/// User defined types include circuits and functions in a Leo program.
+/// User defined types include circuits, enums, and functions in a Leo program.
 #[derive(Clone, Debug)]
 pub struct UserDefinedType {
     pub identifier: Identifier,
@@ -43,6 +43,18 @@ impl From<Circuit> for UserDefinedType {
     }
 }
 
+impl From<Enum> for UserDefinedType {
+    fn from(value: Enum) -> Self {
+        let identifier = value.enum_name;
+
+        UserDefinedType {
+            identifier: identifier.clone(),
+            type_: Type::Enum(identifier),
+            attribute: None,
+        }
+    }
+}
+
 impl From<Function> for UserDefinedType {
     fn from(value: Function) -> Self {
         let identifier = value.identifier;
@@ -16,7 +16,7 @@
 
 pub mod symbol_table;
 
-use leo_ast::{Ast, Input};
+use leo_ast::{formatted_source, Ast, Input};
 use leo_grammar::Grammar;
 use leo_symbol_table::{SymbolTable, SymbolTableError};
 
@@ -66,6 +66,39 @@ impl TestSymbolTable {
         let _symbol_table = SymbolTable::new(&program, &import_parser, &input).unwrap();
     }
 
+    ///
+    /// Parse the Leo syntax tree into a symbol table.
+    ///
+    /// Expect the given number of independent errors, sorted by location.
+    ///
+    pub fn expect_errors(self, count: usize) {
+        // Get program.
+        let program = self.ast.into_repr();
+
+        // Create empty import parser.
+        let import_parser = ImportParser::default();
+
+        // Create empty input.
+        let input = Input::new();
+
+        // Create new symbol table and expect errors.
+        let errors = match SymbolTable::new(&program, &import_parser, &input) {
+            Ok(_) => panic!("Expected symbol table errors"),
+            Err(error) => error.into_errors(),
+        };
+
+        let lines = errors
+            .iter()
+            .map(|error| formatted_source(error).unwrap().line)
+            .collect::<Vec<_>>();
+
+        let mut sorted = lines.clone();
+        sorted.sort_unstable();
+
+        assert_eq!(errors.len(), count);
+        assert_eq!(lines, sorted);
+    }
+
     ///
     /// Parse the Leo syntax tree into a symbol table.
     ///
@@ -73,3 +73,17 @@ fn test_undefined_circuit() {
 
     resolver.expect_pass_two_error();
 }
+
+///
+/// Defines a circuit, a constant, and a function that each use an undefined circuit.
+///
+/// Expected output: three TypeErrors sorted by location
+/// Message: "Type circuit `Baz` must be defined before it is used in an expression."
+///
+#[test]
+fn test_multiple_errors() {
+    let program_string = include_str!("multiple_errors.leo");
+    let resolver = TestSymbolTable::new(program_string);
+
+    resolver.expect_errors(3);
+}
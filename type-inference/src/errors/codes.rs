@@ -0,0 +1,315 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The stable error codes of `leo-type-inference` and their descriptions, as shown by `leo explain`.
+pub const ERROR_CODES: &[(&str, &str)] = &[
+    (
+        "E0300",
+        "An index is applied to a value that is not an array.
+
+Only arrays can be indexed with `[]`:
+
+    let a = 1u8;
+    let b = a[0];    // `u8` is not an array",
+    ),
+    (
+        "E0301",
+        "The `Self` keyword is used outside of a circuit.
+
+`Self` refers to the enclosing circuit, so it can only be used inside a
+circuit function:
+
+    function main() {
+        let a = Self {};    // no enclosing circuit
+    }",
+    ),
+    (
+        "E0302",
+        "A variable is defined twice in the same scope.
+
+Variables must have a unique name within the block that defines them.
+Choose a different name or assign to the existing variable:
+
+    let a = 1u8;
+    let a = 2u8;    // `a` is already defined",
+    ),
+    (
+        "E0303",
+        "An enum variant is given the wrong payload.
+
+A variant that declares a payload type must be constructed with a value of
+that type, and a variant without a payload must be constructed without one:
+
+    enum Shape { Circle(u32), Empty }
+
+    let a = Shape::Circle;          // expects a `u32` payload
+    let b = Shape::Empty(1u32);     // does not have a payload",
+    ),
+    (
+        "E0304",
+        "An array has no elements.
+
+Leo arrays have a fixed, non-zero length:
+
+    let a: [u8; 0] = [];    // arrays cannot be empty",
+    ),
+    (
+        "E0305",
+        "A value is cast to a type that does not support casts.
+
+The `as` operator only converts values to integer, field, boolean, and
+character types:
+
+    let a = 1u8 as group;    // cannot cast to `group`",
+    ),
+    (
+        "E0306",
+        "A circuit member is accessed on a value that is not a circuit.
+
+The `.` and `::` member accesses are only valid on circuit values and
+circuit names:
+
+    let a = 1u8;
+    let b = a.x;    // `u8` is not a circuit",
+    ),
+    (
+        "E0307",
+        "An expression is called that is not a function.
+
+Only functions and circuit functions can be called:
+
+    let a = 1u8;
+    let b = a();    // `a` is not a function",
+    ),
+    (
+        "E0308",
+        "A tuple index is not a number.
+
+The elements of a tuple are accessed with a number literal:
+
+    let a = (1u8, 2u8);
+    let b = a.i;    // tuple indices must be numbers",
+    ),
+    (
+        "E0309",
+        "A non-static circuit member is accessed with `::`.
+
+Circuit variables and functions that take `self` belong to a circuit
+value, so they are accessed with `.`:
+
+    let p = Point { x: 1u32, y: 2u32 };
+    let a = p.x;           // ok
+    let b = Point::x;      // must use `.`",
+    ),
+    (
+        "E0310",
+        "The spread operator is applied to a value that is not an array.
+
+The spread operator `...` inserts the elements of an array into another
+array:
+
+    let a = [1u8, 2u8];
+    let b = [...a, 3u8];    // ok
+    let c = [...1u8];       // `u8` is not an array",
+    ),
+    (
+        "E0311",
+        "A static circuit member is accessed with `.`.
+
+Static circuit functions do not take `self`, so they are accessed through
+the circuit name with `::`:
+
+    let a = Point::new();    // ok
+    let b = p.new();         // must use `::`",
+    ),
+    (
+        "E0312",
+        "A circuit is initialized with the wrong number of variables.
+
+A circuit expression must give a value to every variable of the circuit:
+
+    circuit Point { x: u32, y: u32 }
+
+    let p = Point { x: 1u32 };    // missing `y`",
+    ),
+    (
+        "E0313",
+        "A match arm names a variant of another enum.
+
+Every arm of a `match` must name a variant of the enum of the matched
+value:
+
+    match color {
+        Color::Red => 1u8,
+        Shape::Circle => 2u8,    // expected a variant of `Color`
+    }",
+    ),
+    (
+        "E0314",
+        "A match does not cover every variant of the enum.
+
+A `match` must have an arm for each variant of the matched enum, or a
+wildcard arm `_` that covers the remaining variants.",
+    ),
+    (
+        "E0315",
+        "A function is called with the wrong number of inputs.
+
+The number of arguments of a call must match the number of inputs of the
+function, not counting its const parameters:
+
+    function add(a: u8, b: u8) -> u8 { return a + b }
+
+    let c = add(1u8);    // expected 2 inputs",
+    ),
+    (
+        "E0316",
+        "The `self` keyword is used outside of a circuit function.
+
+`self` refers to the circuit value that a circuit function is called on,
+so it can only be used in circuit functions that take `self` as their
+first input.",
+    ),
+    (
+        "E0317",
+        "A static circuit function is called with `.`.
+
+Static circuit functions do not take `self`, so they are called through
+the circuit name:
+
+    let a = Point::new();    // ok
+    let b = p.new();         // must use `::`",
+    ),
+    (
+        "E0318",
+        "A tuple index is applied to a value that is not a tuple.
+
+Only tuples can be accessed with `.0`, `.1`, and so on:
+
+    let a = 1u8;
+    let b = a.0;    // `u8` is not a tuple",
+    ),
+    (
+        "E0319",
+        "An expression refers to a circuit that is not defined.
+
+Circuit expressions and static accesses must name a circuit that is defined
+by the program or brought in by an import:
+
+    let a = Foo {};    // `Foo` is not defined",
+    ),
+    (
+        "E0320",
+        "A circuit has no function with the given name.
+
+Circuit function calls must name a function defined by the circuit:
+
+    let p = Point::origin();    // `Point` has no function `origin`",
+    ),
+    (
+        "E0321",
+        "An expression refers to an enum that is not defined.
+
+Enum variants are accessed through the name of an enum that is defined by
+the program or brought in by an import:
+
+    let c = Color::Red;    // `Color` is not defined",
+    ),
+    (
+        "E0322",
+        "A call refers to a function that is not defined.
+
+Function calls must name a function that is defined by the program or
+brought in by an import:
+
+    let a = foo();    // `foo` is not defined",
+    ),
+    (
+        "E0323",
+        "An expression refers to a variable that is not defined.
+
+Variables must be defined before they are used and are only visible in the
+block that defines them and its nested blocks:
+
+    if a {
+        let b = 1u8;
+    }
+    let c = b;    // `b` is not in scope",
+    ),
+    (
+        "E0324",
+        "A match arm can never be selected.
+
+An arm is unreachable when an earlier arm already covers its variant, or
+when it follows a wildcard arm `_`. Remove the arm or move the wildcard arm
+to the end of the match.",
+    ),
+    (
+        "E0325",
+        "Several variables are defined from a value that is not a tuple.
+
+A definition of several variables must be given a tuple with one value for
+each variable:
+
+    let (a, b) = 1u8;    // expected a tuple",
+    ),
+    (
+        "E0326",
+        "A definition has a different number of variables than values.
+
+A tuple definition must name one variable for each value of the tuple:
+
+    let (a, b) = (1u8, 2u8, 3u8);    // expected 2 values, found 3",
+    ),
+    (
+        "E0327",
+        "An expression has a different type than expected.
+
+The type of an expression must match the type that its context requires,
+for example the declared type of a variable or the output type of a
+function:
+
+    function main() -> u32 {
+        let a: bool = 1u8;    // expected `bool`, found `u8`
+        return true           // expected `u32`, found `bool`
+    }",
+    ),
+    (
+        "E0328",
+        "An operand has a type that the operator does not support.
+
+Operators restrict the types of their operands. For example, `+` requires
+integer, field, or group values, and `&&` requires boolean values:
+
+    let a = true + false;    // `bool` does not support `+`",
+    ),
+    (
+        "E0329",
+        "A type membership could not be resolved.
+
+Type inference could not solve a constraint that restricts a type variable
+to a set of types. This usually indicates a bug in type inference rather
+than in the program.",
+    ),
+    (
+        "E0330",
+        "A function has two inputs with the same name.
+
+Each const parameter and input of a function must have a unique name:
+
+    function add(a: u8, a: u8) -> u8 {}    // `a` is defined twice",
+    ),
+];
@@ -37,6 +37,18 @@ pub enum FrameError {
 }
 
 impl FrameError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            FrameError::Error(error) => error.code.unwrap_or_default(),
+            FrameError::ScopeError(error) => error.error_code(),
+            FrameError::TypeAssertionError(error) => error.error_code(),
+            FrameError::TypeError(error) => error.error_code(),
+        }
+    }
+
     ///
     /// Set the filepath for the error stacktrace
     ///
@@ -50,10 +62,10 @@ impl FrameError {
     }
 
     ///
-    /// Return a new formatted error with a given message and span information
+    /// Return a new formatted error with a given code, message, and span information
     ///
-    fn new_from_span(message: String, span: &Span) -> Self {
-        FrameError::Error(FormattedError::new_from_span(message, span.to_owned()))
+    fn new_from_span(code: &'static str, message: String, span: &Span) -> Self {
+        FrameError::Error(FormattedError::new_from_span_with_code(code, message, span.to_owned()))
     }
 
     ///
@@ -62,7 +74,7 @@ impl FrameError {
     pub fn array_access(actual: &Type, span: &Span) -> Self {
         let message = format!("Cannot access the index of non-array type `{}`.", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0300", message, span)
     }
 
     ///
@@ -71,7 +83,7 @@ impl FrameError {
     pub fn circuit_self(span: &Span) -> Self {
         let message = "The `Self` keyword is only valid inside a circuit context.".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0301", message, span)
     }
 
     ///
@@ -80,7 +92,22 @@ impl FrameError {
     pub fn duplicate_variable(name: &str, span: &Span) -> Self {
         let message = format!("Duplicate variable definition found for `{}`", name);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0302", message, span)
+    }
+
+    ///
+    /// An enum variant was constructed or matched with the wrong payload.
+    ///
+    pub fn enum_variant_payload(enum_identifier: &Identifier, variant: &Identifier, payload: &Option<Type>) -> Self {
+        let message = match payload {
+            Some(type_) => format!(
+                "Enum variant `{}::{}` expects a payload of type `{}`.",
+                enum_identifier, variant, type_
+            ),
+            None => format!("Enum variant `{}::{}` does not have a payload.", enum_identifier, variant),
+        };
+
+        Self::new_from_span("E0303", message, &variant.span)
     }
 
     ///
@@ -91,7 +118,16 @@ impl FrameError {
     pub fn empty_array(span: &Span) -> Self {
         let message = "Cannot create an empty array in a Leo program.".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0304", message, span)
+    }
+
+    ///
+    /// Attempted to cast a value to a type that does not support casts.
+    ///
+    pub fn invalid_cast(type_: &Type, span: &Span) -> Self {
+        let message = format!("Cannot cast a value to type `{}`.", type_);
+
+        Self::new_from_span("E0305", message, span)
     }
 
     ///
@@ -100,7 +136,7 @@ impl FrameError {
     pub fn invalid_circuit(type_: Type, span: &Span) -> Self {
         let message = format!("Expected a circuit type. Found type `{}`.", type_);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0306", message, span)
     }
 
     ///
@@ -109,7 +145,7 @@ impl FrameError {
     pub fn invalid_function(expression: &Expression, span: &Span) -> Self {
         let message = format!("Expected a function name. Found expression `{}`.", expression);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0307", message, span)
     }
 
     ///
@@ -118,7 +154,7 @@ impl FrameError {
     pub fn invalid_index(actual: String, span: &Span) -> Self {
         let message = format!("Expected constant number for index, found `{}`", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0308", message, span)
     }
 
     ///
@@ -127,7 +163,7 @@ impl FrameError {
     pub fn invalid_member_access(identifier: &Identifier) -> Self {
         let message = format!("non-static member `{}` must be accessed using `.` syntax.", identifier);
 
-        Self::new_from_span(message, &identifier.span)
+        Self::new_from_span("E0309", message, &identifier.span)
     }
 
     ///
@@ -139,7 +175,7 @@ impl FrameError {
             actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0310", message, span)
     }
 
     ///
@@ -148,7 +184,7 @@ impl FrameError {
     pub fn invalid_static_access(identifier: &Identifier) -> Self {
         let message = format!("Static member `{}` must be accessed using `::` syntax.", identifier);
 
-        Self::new_from_span(message, &identifier.span)
+        Self::new_from_span("E0311", message, &identifier.span)
     }
 
     ///
@@ -157,7 +193,28 @@ impl FrameError {
     pub fn num_circuit_variables(expected: usize, actual: usize, span: &Span) -> Self {
         let message = format!("Circuit expected {} variables, found {} variables.", expected, actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0312", message, span)
+    }
+
+    ///
+    /// Found a match arm pattern from a different enum than the previous arms.
+    ///
+    pub fn mismatched_match_enum(expected: &Identifier, actual: &Identifier) -> Self {
+        let message = format!(
+            "Expected a variant of enum `{}`, found a variant of enum `{}`.",
+            expected, actual
+        );
+
+        Self::new_from_span("E0313", message, &actual.span)
+    }
+
+    ///
+    /// The arms of a match expression do not cover every enum variant.
+    ///
+    pub fn non_exhaustive_match(missing: &[String], span: &Span) -> Self {
+        let message = format!("Match expression does not cover variants `{}`.", missing.join("`, `"));
+
+        Self::new_from_span("E0314", message, span)
     }
 
     ///
@@ -169,7 +226,7 @@ impl FrameError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0315", message, span)
     }
 
     ///
@@ -178,7 +235,7 @@ impl FrameError {
     pub fn self_not_available(span: &Span) -> Self {
         let message = "keyword `self` is only available in static circuit functions.".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0316", message, span)
     }
 
     ///
@@ -190,7 +247,7 @@ impl FrameError {
             identifier.name
         );
 
-        Self::new_from_span(message, &identifier.span)
+        Self::new_from_span("E0317", message, &identifier.span)
     }
 
     ///
@@ -199,7 +256,7 @@ impl FrameError {
     pub fn tuple_access(actual: &Type, span: &Span) -> Self {
         let message = format!("Cannot access the index of non-tuple type `{}`.", actual);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0318", message, span)
     }
 
     ///
@@ -208,7 +265,7 @@ impl FrameError {
     pub fn undefined_circuit(identifier: &Identifier) -> Self {
         let message = format!("The circuit `{}` is not defined.", identifier);
 
-        Self::new_from_span(message, &identifier.span)
+        Self::new_from_span("E0319", message, &identifier.span)
     }
 
     ///
@@ -217,7 +274,16 @@ impl FrameError {
     pub fn undefined_circuit_function(identifier: &Identifier) -> Self {
         let message = format!("The circuit function `{}` is not defined.", identifier);
 
-        Self::new_from_span(message, &identifier.span)
+        Self::new_from_span("E0320", message, &identifier.span)
+    }
+
+    ///
+    /// Attempted to access a variant of an enum that is not defined in the current context.
+    ///
+    pub fn undefined_enum(identifier: &Identifier) -> Self {
+        let message = format!("The enum `{}` is not defined.", identifier);
+
+        Self::new_from_span("E0321", message, &identifier.span)
     }
 
     ///
@@ -226,7 +292,7 @@ impl FrameError {
     pub fn undefined_function(identifier: &Identifier) -> Self {
         let message = format!("The function `{}` is not defined.", identifier);
 
-        Self::new_from_span(message, &identifier.span)
+        Self::new_from_span("E0322", message, &identifier.span)
     }
 
     ///
@@ -235,7 +301,16 @@ impl FrameError {
     pub fn undefined_variable(identifier: &Identifier) -> Self {
         let message = format!("The variable `{}` is not defined.", identifier);
 
-        Self::new_from_span(message, &identifier.span)
+        Self::new_from_span("E0323", message, &identifier.span)
+    }
+
+    ///
+    /// Found a match arm that can never be selected.
+    ///
+    pub fn unreachable_match_arm(span: &Span) -> Self {
+        let message = "This match arm is unreachable.".to_string();
+
+        Self::new_from_span("E0324", message, span)
     }
 
     ///
@@ -244,7 +319,7 @@ impl FrameError {
     pub fn not_enough_values(span: &Span) -> Self {
         let message = "Expected a tuple type for multiple defined variables".to_string();
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0325", message, span)
     }
 
     ///
@@ -256,6 +331,6 @@ impl FrameError {
             expected, actual
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0326", message, span)
     }
 }
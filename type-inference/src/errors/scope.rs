@@ -30,6 +30,16 @@ pub enum ScopeError {
 }
 
 impl ScopeError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            ScopeError::Error(error) => error.code.unwrap_or_default(),
+            ScopeError::VariableTableError(error) => error.error_code(),
+        }
+    }
+
     ///
     /// Set the filepath for the error stacktrace.
     ///
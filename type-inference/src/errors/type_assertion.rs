@@ -29,6 +29,15 @@ pub enum TypeAssertionError {
 }
 
 impl TypeAssertionError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            TypeAssertionError::Error(error) => error.code.unwrap_or_default(),
+        }
+    }
+
     ///
     /// Set the filepath for the error stacktrace.
     ///
@@ -39,10 +48,10 @@ impl TypeAssertionError {
     }
 
     ///
-    /// Returns a new formatted error with a given message and span information.
+    /// Returns a new formatted error with a given code, message, and span information.
     ///
-    fn new_from_span(message: String, span: &Span) -> Self {
-        TypeAssertionError::Error(FormattedError::new_from_span(message, span.to_owned()))
+    fn new_from_span(code: &'static str, message: String, span: &Span) -> Self {
+        TypeAssertionError::Error(FormattedError::new_from_span_with_code(code, message, span.to_owned()))
     }
 
     ///
@@ -51,7 +60,7 @@ impl TypeAssertionError {
     pub fn equality_failed(left: &Type, right: &Type, span: &Span) -> Self {
         let message = format!("Mismatched types. Expected type `{}`, found type `{}`.", left, right);
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0327", message, span)
     }
 
     ///
@@ -63,7 +72,7 @@ impl TypeAssertionError {
             given, set
         );
 
-        Self::new_from_span(message, span)
+        Self::new_from_span("E0328", message, span)
     }
 
     ///
@@ -72,6 +81,6 @@ impl TypeAssertionError {
     pub fn membership_pairs(membership: &TypeMembership) -> Self {
         let message = "Cannot generate a type variable -> type pair for the given type membership".to_string();
 
-        Self::new_from_span(message, membership.span())
+        Self::new_from_span("E0329", message, membership.span())
     }
 }
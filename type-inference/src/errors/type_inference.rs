@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::FrameError;
-use leo_ast::Error as FormattedError;
+use leo_ast::{sort_errors, Error as FormattedError};
 
 use std::path::Path;
 
@@ -25,18 +25,61 @@ pub enum TypeInferenceError {
     #[error("{}", _0)]
     Error(#[from] FormattedError),
 
+    #[error("{}", _0.iter().map(|error| error.to_string()).collect::<Vec<_>>().join("\n\n"))]
+    Errors(Vec<TypeInferenceError>),
+
     #[error("{}", _0)]
     FrameError(#[from] FrameError),
 }
 
 impl TypeInferenceError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    /// A list of errors returns the code of its first error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            TypeInferenceError::Error(error) => error.code.unwrap_or_default(),
+            TypeInferenceError::Errors(errors) => errors[0].error_code(),
+            TypeInferenceError::FrameError(error) => error.error_code(),
+        }
+    }
+
     ///
     /// Set the filepath for the error stacktrace.
     ///
     pub fn set_path(&mut self, path: &Path) {
         match self {
             TypeInferenceError::Error(error) => error.set_path(path),
+            TypeInferenceError::Errors(errors) => errors.iter_mut().for_each(|error| error.set_path(path)),
             TypeInferenceError::FrameError(error) => error.set_path(path),
         }
     }
+
+    ///
+    /// Returns an error for every independent error found by type inference.
+    ///
+    pub fn into_errors(self) -> Vec<TypeInferenceError> {
+        match self {
+            TypeInferenceError::Errors(errors) => errors.into_iter().flat_map(|error| error.into_errors()).collect(),
+            error => vec![error],
+        }
+    }
+
+    ///
+    /// Returns `Ok` if no errors were found, the error itself if only one error was found,
+    /// or all errors sorted by location.
+    ///
+    pub fn from_errors(errors: Vec<TypeInferenceError>) -> Result<(), TypeInferenceError> {
+        let mut errors = errors.into_iter().flat_map(|error| error.into_errors()).collect::<Vec<_>>();
+
+        sort_errors(&mut errors);
+
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(TypeInferenceError::Errors(errors)),
+        }
+    }
 }
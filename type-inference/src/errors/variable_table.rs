@@ -26,6 +26,15 @@ pub enum VariableTableError {
 }
 
 impl VariableTableError {
+    ///
+    /// Returns the stable error code of the error.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            VariableTableError::Error(error) => error.code.unwrap_or_default(),
+        }
+    }
+
     ///
     /// Set the filepath for the error stacktrace
     ///
@@ -36,10 +45,10 @@ impl VariableTableError {
     }
 
     ///
-    /// Return a new formatted error with a given message and span information
+    /// Return a new formatted error with a given code, message, and span information
     ///
-    fn new_from_span(message: String, span: Span) -> Self {
-        VariableTableError::Error(FormattedError::new_from_span(message, span))
+    fn new_from_span(code: &'static str, message: String, span: Span) -> Self {
+        VariableTableError::Error(FormattedError::new_from_span_with_code(code, message, span))
     }
 
     ///
@@ -48,6 +57,6 @@ impl VariableTableError {
     pub fn duplicate_function_input(name: &str, span: &Span) -> Self {
         let message = format!("Duplicate function input `{}`found in function signature.", name);
 
-        Self::new_from_span(message, span.clone())
+        Self::new_from_span("E0330", message, span.clone())
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{FrameError, Scope, TypeAssertion};
+use crate::{FrameError, IdentifierUse, Scope, TypeAssertion, TypedIdentifier};
 use leo_ast::{
     expression::*,
     statements::*,
@@ -23,6 +23,7 @@ use leo_ast::{
     AssigneeAccess,
     Block,
     CircuitVariableDefinition,
+    Constant,
     Expression,
     Function,
     Identifier,
@@ -31,7 +32,16 @@ use leo_ast::{
     Span,
     SpreadOrExpression,
 };
-use leo_symbol_table::{CircuitType, FunctionType, SymbolTable, Type, TypeVariable};
+use leo_symbol_table::{
+    CircuitType,
+    EnumType,
+    FunctionOutputType,
+    FunctionType,
+    SymbolTable,
+    Type,
+    TypeError,
+    TypeVariable,
+};
 
 /// A vector of `TypeAssertion` predicates created from a function body.
 #[derive(Clone)]
@@ -42,6 +52,7 @@ pub struct Frame {
     pub block: Block,
     pub type_assertions: Vec<TypeAssertion>,
     pub user_defined_types: SymbolTable,
+    pub typed_identifiers: Vec<TypedIdentifier>,
 }
 
 impl Frame {
@@ -62,12 +73,16 @@ impl Frame {
         // Create a new scope for the function variables.
         let mut scope = Scope::new(parent_scope);
 
-        // Initialize function inputs as variables.
+        // Initialize function const parameters and inputs as variables.
+        scope.insert_function_inputs(&function_type.const_parameters)?;
         scope.insert_function_inputs(&function_type.inputs)?;
 
         // Create new list of scopes for frame.
         let scopes = vec![scope];
 
+        // Record function const parameters and inputs as variable definitions.
+        let typed_identifiers = Self::function_input_definitions(&function_type);
+
         // Create new frame struct.
         // Update variables when encountering let/const variable definitions.
         let mut frame = Self {
@@ -77,6 +92,7 @@ impl Frame {
             block: function.block,
             type_assertions: vec![],
             user_defined_types,
+            typed_identifiers,
         };
 
         // Create type assertions for function statements
@@ -102,12 +118,16 @@ impl Frame {
         // Create a new scope for the function variables.
         let mut scope = Scope::new(Some(parent_scope));
 
-        // Initialize function inputs as variables.
+        // Initialize function const parameters and inputs as variables.
+        scope.insert_function_inputs(&function_type.const_parameters)?;
         scope.insert_function_inputs(&function_type.inputs)?;
 
         // Create new list of scopes for frame.
         let scopes = vec![scope];
 
+        // Record function const parameters and inputs as variable definitions.
+        let typed_identifiers = Self::function_input_definitions(&function_type);
+
         // Create new frame struct.
         // Update variables when encountering let/const variable definitions.
         let mut frame = Self {
@@ -117,6 +137,7 @@ impl Frame {
             block: function.block,
             type_assertions: Vec::new(),
             user_defined_types,
+            typed_identifiers,
         };
 
         // Create type assertions for function statements
@@ -125,6 +146,64 @@ impl Frame {
         Ok(frame)
     }
 
+    ///
+    /// Collects vector of `TypeAssertion` predicates from a constant definition.
+    ///
+    /// The constant expression is checked as the return value of a function without inputs.
+    ///
+    pub fn new_constant(constant: Constant, user_defined_types: SymbolTable) -> Result<Self, FrameError> {
+        let name = &constant.identifier.name;
+
+        // Get constant type from symbol table.
+        let type_ = user_defined_types.get_constant_type(name).unwrap().clone();
+
+        let function_type = FunctionType {
+            identifier: constant.identifier.clone(),
+            const_parameters: Vec::new(),
+            inputs: Vec::new(),
+            output: FunctionOutputType { type_ },
+        };
+
+        let block = Block {
+            statements: vec![Statement::Return(ReturnStatement {
+                expression: constant.expression,
+                span: constant.span.clone(),
+            })],
+            span: constant.span,
+        };
+
+        // Create new frame struct.
+        let mut frame = Self {
+            function_type,
+            self_type: None,
+            scopes: vec![Scope::default()],
+            block,
+            type_assertions: Vec::new(),
+            user_defined_types,
+            typed_identifiers: Vec::new(),
+        };
+
+        // Create type assertions for the constant expression
+        frame.parse_statements()?;
+
+        Ok(frame)
+    }
+
+    ///
+    /// Returns the const parameters and inputs of a function as variable definitions.
+    ///
+    /// The `self` keyword input is skipped since it does not define a new variable.
+    ///
+    fn function_input_definitions(function_type: &FunctionType) -> Vec<TypedIdentifier> {
+        function_type
+            .const_parameters
+            .iter()
+            .chain(function_type.inputs.iter())
+            .filter(|input| !input.is_self())
+            .map(|input| TypedIdentifier::new(input.identifier().clone(), input.type_(), IdentifierUse::Definition))
+            .collect()
+    }
+
     ///
     /// Pushes a new variable `Scope` to the list of scopes in the current `Frame`.
     ///
@@ -142,13 +221,20 @@ impl Frame {
     ///
     /// Insert a variable into the symbol table in the current scope.
     ///
-    fn insert_variable(&mut self, name: String, type_: Type, span: &Span) -> Result<(), FrameError> {
+    fn insert_variable(&mut self, identifier: &Identifier, type_: Type, span: &Span) -> Result<(), FrameError> {
+        // Record the variable definition.
+        self.typed_identifiers.push(TypedIdentifier::new(
+            identifier.clone(),
+            type_.clone(),
+            IdentifierUse::Definition,
+        ));
+
         // Modify the current scope.
         let scope = self.scopes.last_mut().unwrap();
 
         // Insert the variable name -> type.
-        match scope.variables.insert(name.clone(), type_) {
-            Some(_type) => Err(FrameError::duplicate_variable(&name, span)),
+        match scope.variables.insert(identifier.name.clone(), type_) {
+            Some(_type) => Err(FrameError::duplicate_variable(&identifier.name, span)),
             None => Ok(()),
         }
     }
@@ -164,6 +250,31 @@ impl Frame {
         scope.get_variable(name)
     }
 
+    ///
+    /// Returns an error if an array dimension is not a const parameter of the current function.
+    ///
+    fn check_const_parameters(&self, identifiers: Vec<&Identifier>) -> Result<(), FrameError> {
+        for identifier in identifiers {
+            if !self
+                .function_type
+                .const_parameters
+                .iter()
+                .any(|parameter| parameter.identifier() == identifier)
+            {
+                return Err(TypeError::undefined_const_parameter(identifier.clone()).into());
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Get a constant's type from the user defined types in the current scope.
+    ///
+    fn get_constant(&self, name: &str) -> Option<&Type> {
+        self.user_defined_types.get_constant_type(name)
+    }
+
     ///
     /// Get a function's type from the user defined types in the current scope.
     ///
@@ -178,6 +289,13 @@ impl Frame {
         self.user_defined_types.get_circuit_type(name)
     }
 
+    ///
+    /// Get an enum's type from the user defined types in the current scope.
+    ///
+    fn get_enum(&self, name: &str) -> Option<&EnumType> {
+        self.user_defined_types.get_enum_type(name)
+    }
+
     ///
     /// Creates a new equality type assertion between the given types.
     ///
@@ -214,6 +332,27 @@ impl Frame {
         self.assert_membership(given.clone(), integer_types, span)
     }
 
+    ///
+    /// Creates a new membership type assertion between a given and the set of bitwise types.
+    ///
+    fn assert_bitwise(&mut self, given: &Type, span: &Span) {
+        let bitwise_types = Type::bitwise_types();
+
+        self.assert_membership(given.clone(), bitwise_types, span)
+    }
+
+    ///
+    /// Creates a new type assertion that the given type is a shift amount.
+    ///
+    /// A shift amount can be an integer of any unsigned type. An implicit shift amount is a `u32`.
+    ///
+    fn assert_shift_amount(&mut self, given: &Type, span: &Span) {
+        match given {
+            Type::TypeVariable(_) => self.assert_equal(Type::IntegerType(IntegerType::U32), given.clone(), span),
+            _ => self.assert_membership(given.clone(), Type::unsigned_integer_types().to_vec(), span),
+        }
+    }
+
     ///
     /// Creates a new membership type assertion between a given and the set of index types.
     ///
@@ -278,6 +417,9 @@ impl Frame {
 
         // Check if an explicit type is given.
         if let Some(type_) = statement.type_.clone() {
+            // Check the array dimensions of the explicit type.
+            self.check_const_parameters(type_.const_parameters())?;
+
             // Check the expected type.
             let expected_type = match self.self_type {
                 Some(ref circuit_type) => Type::new_from_circuit(
@@ -298,7 +440,7 @@ impl Frame {
         if statement.variable_names.len() == 1 {
             // Insert variable into symbol table
             let variable = statement.variable_names[0].clone();
-            self.insert_variable(variable.identifier.name, actual_type, &statement.span)?;
+            self.insert_variable(&variable.identifier, actual_type, &statement.span)?;
         } else {
             // Expect a tuple type.
             let types = match actual_type {
@@ -317,7 +459,7 @@ impl Frame {
 
             // Insert variables into symbol table
             for (variable, type_) in statement.variable_names.iter().zip(types) {
-                self.insert_variable(variable.identifier.name.clone(), type_, &statement.span)?;
+                self.insert_variable(&variable.identifier, type_, &statement.span)?;
             }
         }
 
@@ -334,8 +476,15 @@ impl Frame {
         // Parse expression type.
         let expression_type = self.parse_expression(&statement.value)?;
 
-        // Assert that the assignee_type == expression_type.
-        self.assert_equal(assignee_type, expression_type, &statement.span);
+        match statement.operation {
+            // Assert that the assignee can be shifted by the expression.
+            AssignOperation::Shl | AssignOperation::Shr => {
+                self.assert_bitwise(&assignee_type, &statement.span);
+                self.assert_shift_amount(&expression_type, &statement.span);
+            }
+            // Assert that the assignee_type == expression_type.
+            _ => self.assert_equal(assignee_type, expression_type, &statement.span),
+        }
 
         Ok(())
     }
@@ -423,7 +572,7 @@ impl Frame {
     fn parse_iteration(&mut self, statement: &IterationStatement) -> Result<(), FrameError> {
         // Insert variable into symbol table with u32 type.
         let u32_type = Type::IntegerType(IntegerType::U32);
-        let _expect_none = self.insert_variable(statement.variable.name.to_owned(), u32_type.clone(), &statement.span);
+        let _expect_none = self.insert_variable(&statement.variable, u32_type.clone(), &statement.span);
 
         // Parse `from` and `to` expressions.
         let from_type = self.parse_expression(&statement.start)?;
@@ -433,6 +582,13 @@ impl Frame {
         self.assert_equal(u32_type.clone(), from_type, &statement.span);
         self.assert_equal(u32_type, to_type, &statement.span);
 
+        // Assert that the optional `while` condition is a boolean type.
+        if let Some(condition) = &statement.condition {
+            let condition_type = self.parse_expression(condition)?;
+
+            self.assert_equal(Type::Boolean, condition_type, &statement.span);
+        }
+
         // Parse block of statements.
         self.parse_block(&statement.block)
     }
@@ -463,6 +619,7 @@ impl Frame {
 
             // Explicit types
             Value(ValueExpression::Boolean(_, _)) => Ok(Type::Boolean),
+            Value(ValueExpression::Char(_, _)) => Ok(Type::Char),
             Value(ValueExpression::Address(_, _)) => Ok(Type::Address),
             Value(ValueExpression::Field(_, _)) => Ok(Type::Field),
             Value(ValueExpression::Group(_)) => Ok(Type::Group),
@@ -472,17 +629,27 @@ impl Frame {
             Value(ValueExpression::Integer(integer_type, _, _)) => Ok(Type::IntegerType(integer_type.clone())),
 
             Binary(binary) => match binary.op.class() {
-                BinaryOperationClass::Numeric => {
-                    self.parse_integer_binary_expression(&binary.left, &binary.right, &binary.span)
-                }
+                BinaryOperationClass::Numeric => match binary.op {
+                    BinaryOperation::Rem => {
+                        self.parse_remainder_binary_expression(&binary.left, &binary.right, &binary.span)
+                    }
+                    _ => self.parse_integer_binary_expression(&binary.left, &binary.right, &binary.span),
+                },
                 BinaryOperationClass::Boolean => {
                     self.parse_boolean_binary_expression(&binary.left, &binary.right, &binary.span)
                 }
+                BinaryOperationClass::Bitwise => match binary.op {
+                    BinaryOperation::Shl | BinaryOperation::Shr => {
+                        self.parse_shift_binary_expression(&binary.left, &binary.right, &binary.span)
+                    }
+                    _ => self.parse_bitwise_binary_expression(&binary.left, &binary.right, &binary.span),
+                },
             },
             Unary(unary) => match &unary.op {
                 UnaryOperation::Negate => self.parse_negate_expression(&unary.inner, &unary.span),
-                UnaryOperation::Not => self.parse_boolean_expression(&unary.inner, &unary.span),
+                UnaryOperation::Not => self.parse_not_expression(&unary.inner, &unary.span),
             },
+            Cast(cast) => self.parse_cast_expression(&cast.inner, &cast.target_type, &cast.span),
 
             Conditional(conditional) => self.parse_conditional_expression(
                 &conditional.condition,
@@ -490,6 +657,7 @@ impl Frame {
                 &conditional.if_false,
                 &conditional.span,
             ),
+            Match(match_) => self.parse_match_expression(&match_.inner, &match_.arms, &match_.span),
 
             ArrayInline(array_inline) => self.parse_array(&array_inline.elements, &array_inline.span),
             ArrayInit(array_init) => {
@@ -535,7 +703,7 @@ impl Frame {
     ///
     /// Returns the type of the identifier in the symbol table.
     ///
-    fn parse_identifier(&self, identifier: &Identifier) -> Result<Type, FrameError> {
+    fn parse_identifier(&mut self, identifier: &Identifier) -> Result<Type, FrameError> {
         // Check Self type.
         if identifier.is_self() {
             // Check for frame circuit self type.
@@ -545,9 +713,21 @@ impl Frame {
             return Ok(Type::Circuit(circuit_type.identifier));
         }
 
-        // Check variable symbol table.
-        if let Some(type_) = self.get_variable(&identifier.name) {
-            return Ok(type_.to_owned());
+        // Check variable and constant symbol tables.
+        let variable_type = self
+            .get_variable(&identifier.name)
+            .or_else(|| self.get_constant(&identifier.name))
+            .cloned();
+
+        if let Some(type_) = variable_type {
+            // Record the variable reference.
+            self.typed_identifiers.push(TypedIdentifier::new(
+                identifier.clone(),
+                type_.clone(),
+                IdentifierUse::Reference,
+            ));
+
+            return Ok(type_);
         };
 
         // Check function symbol table.
@@ -610,6 +790,96 @@ impl Frame {
         Ok(type_)
     }
 
+    ///
+    /// Returns the target `Type` of a cast expression.
+    ///
+    /// Asserts that the `Type` of the expression can be cast to the target `Type`.
+    ///
+    fn parse_cast_expression(
+        &mut self,
+        expression: &Expression,
+        target_type: &leo_ast::Type,
+        span: &Span,
+    ) -> Result<Type, FrameError> {
+        // Get the expression type.
+        let type_ = self.parse_expression(expression)?;
+
+        // Get the target type.
+        let target_type = Type::new(&self.user_defined_types, target_type.clone(), span.clone())?;
+
+        let cast_types = Type::cast_types(&target_type);
+
+        if cast_types.is_empty() {
+            return Err(FrameError::invalid_cast(&target_type, span));
+        }
+
+        match type_ {
+            // An implicit number is given the target type.
+            Type::TypeVariable(_) => self.assert_equal(target_type.clone(), type_, span),
+            _ => self.assert_membership(type_, cast_types, span),
+        }
+
+        Ok(target_type)
+    }
+
+    ///
+    /// Returns the `Type` of the expression after the remainder operation.
+    ///
+    /// Asserts that the `Type` is a signed or unsigned integer.
+    ///
+    fn parse_remainder_binary_expression(
+        &mut self,
+        left: &Expression,
+        right: &Expression,
+        span: &Span,
+    ) -> Result<Type, FrameError> {
+        let type_ = self.parse_binary_expression(left, right, span)?;
+
+        // The remainder is defined for the same integer types as the bitwise operations.
+        self.assert_bitwise(&type_, span);
+
+        Ok(type_)
+    }
+
+    ///
+    /// Returns the `Type` of the expression after the bitwise operation.
+    ///
+    /// Asserts that the `Type` is a signed or unsigned integer.
+    ///
+    fn parse_bitwise_binary_expression(
+        &mut self,
+        left: &Expression,
+        right: &Expression,
+        span: &Span,
+    ) -> Result<Type, FrameError> {
+        let type_ = self.parse_binary_expression(left, right, span)?;
+
+        // Assert that the type is a signed or unsigned integer.
+        self.assert_bitwise(&type_, span);
+
+        Ok(type_)
+    }
+
+    ///
+    /// Returns the `Type` of the expression after the shift operation.
+    ///
+    /// Asserts that the shifted `Type` is a signed or unsigned integer and the shift amount is an unsigned integer.
+    ///
+    fn parse_shift_binary_expression(
+        &mut self,
+        left: &Expression,
+        right: &Expression,
+        span: &Span,
+    ) -> Result<Type, FrameError> {
+        let type_ = self.parse_expression(left)?;
+        let amount_type = self.parse_expression(right)?;
+
+        self.assert_bitwise(&type_, span);
+        self.assert_shift_amount(&amount_type, span);
+
+        Ok(type_)
+    }
+
     ///
     /// Returns the `Boolean` type if the expression is a `Boolean` type.
     ///
@@ -626,6 +896,22 @@ impl Frame {
         Ok(boolean_type)
     }
 
+    ///
+    /// Returns the `Type` of the expression being inverted. Must be a boolean or a bitwise type.
+    ///
+    fn parse_not_expression(&mut self, expression: &Expression, span: &Span) -> Result<Type, FrameError> {
+        // Parse the expression type.
+        let type_ = self.parse_expression(expression)?;
+
+        // Assert that this value is a boolean or can be flipped bitwise.
+        let mut not_types = vec![Type::Boolean];
+        not_types.extend(Type::bitwise_types());
+
+        self.assert_membership(type_.clone(), not_types, span);
+
+        Ok(type_)
+    }
+
     ///
     /// Returns the `Type` of the expression being negated. Must be a negative integer type.
     ///
@@ -775,6 +1061,9 @@ impl Frame {
             return Err(FrameError::empty_array(span));
         }
 
+        // Check the array dimensions that refer to const parameters.
+        self.check_const_parameters(dimensions.const_parameters())?;
+
         // Return array type.
         Ok(Type::Array(Box::new(element_type)))
     }
@@ -914,7 +1203,17 @@ impl Frame {
         let circuit_type = self.parse_circuit_name(type_, span)?;
 
         // Look for member with matching name.
-        Ok(circuit_type.member_type(&identifier)?)
+        let member_type = circuit_type.member_type(&identifier)?;
+        let circuit_identifier = circuit_type.identifier.clone();
+
+        // Record the circuit member access.
+        self.typed_identifiers.push(TypedIdentifier::new(
+            identifier.clone(),
+            member_type.clone(),
+            IdentifierUse::CircuitMember(circuit_identifier),
+        ));
+
+        Ok(member_type)
     }
 
     ///
@@ -942,12 +1241,192 @@ impl Frame {
         identifier: &Identifier,
         span: &Span,
     ) -> Result<Type, FrameError> {
+        // Construct an enum variant without a payload.
+        if let Some(enum_identifier) = self.parse_enum_name(expression) {
+            return self.parse_enum_variant(&enum_identifier, identifier, None, span);
+        }
+
         // Parse the circuit name.
         let type_ = self.parse_expression(expression)?;
 
         self.parse_circuit_member_access(type_, identifier, span)
     }
 
+    ///
+    /// Returns the enum identifier if the given expression names an enum.
+    ///
+    fn parse_enum_name(&self, expression: &Expression) -> Option<Identifier> {
+        match expression {
+            Expression::Identifier(identifier) if self.get_variable(&identifier.name).is_none() => {
+                self.get_enum(&identifier.name).map(|enum_type| enum_type.identifier.clone())
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the type of an enum variant constructed with an optional payload.
+    ///
+    fn parse_enum_variant(
+        &mut self,
+        enum_identifier: &Identifier,
+        variant: &Identifier,
+        arguments: Option<&[Expression]>,
+        span: &Span,
+    ) -> Result<Type, FrameError> {
+        // Get enum type.
+        let enum_type = self
+            .get_enum(&enum_identifier.name)
+            .cloned()
+            .ok_or_else(|| FrameError::undefined_enum(enum_identifier))?;
+
+        // Find enum variant by identifier.
+        let variant_type = enum_type.variant_type(variant)?;
+
+        // Assert the payload is the variant payload type.
+        match (&variant_type.payload, arguments) {
+            (Some(payload_type), Some(arguments)) => {
+                if arguments.len() != 1 {
+                    return Err(FrameError::num_inputs(1, arguments.len(), span));
+                }
+
+                let actual_type = self.parse_expression(&arguments[0])?;
+
+                self.assert_equal(payload_type.to_owned(), actual_type, span);
+            }
+            (None, None) => {}
+            (payload, _) => return Err(FrameError::enum_variant_payload(&enum_type.identifier, variant, payload)),
+        }
+
+        Ok(Type::Enum(enum_type.identifier))
+    }
+
+    ///
+    /// Returns the type of a match expression.
+    ///
+    /// Checks that every arm matches a variant of the same enum, that no arm is unreachable,
+    /// and that the arms cover every variant of the enum.
+    ///
+    fn parse_match_expression(
+        &mut self,
+        expression: &Expression,
+        arms: &[MatchArm],
+        span: &Span,
+    ) -> Result<Type, FrameError> {
+        // Parse the matched expression.
+        let expression_type = self.parse_expression(expression)?;
+
+        let mut enum_type: Option<EnumType> = None;
+        let mut matched_variants: Vec<String> = Vec::new();
+        let mut wildcard = false;
+        let mut arm_types = Vec::with_capacity(arms.len());
+
+        for arm in arms {
+            // No arm can follow a wildcard pattern.
+            if wildcard {
+                return Err(FrameError::unreachable_match_arm(arm.pattern.span()));
+            }
+
+            // Create a new scope for the payload binding.
+            let scope = Scope::new(self.scopes.last().cloned());
+            self.push_scope(scope);
+
+            match &arm.pattern {
+                MatchPattern::Variant(enum_identifier, variant, binding) => {
+                    // Every pattern must match a variant of the same enum.
+                    let arm_enum_type = match &enum_type {
+                        Some(enum_type) => {
+                            if enum_type.identifier.name != enum_identifier.name {
+                                return Err(FrameError::mismatched_match_enum(
+                                    &enum_type.identifier,
+                                    enum_identifier,
+                                ));
+                            }
+
+                            enum_type.to_owned()
+                        }
+                        None => {
+                            let arm_enum_type = self
+                                .get_enum(&enum_identifier.name)
+                                .cloned()
+                                .ok_or_else(|| FrameError::undefined_enum(enum_identifier))?;
+
+                            // Assert the matched expression is the enum type.
+                            let expected_type = Type::Enum(arm_enum_type.identifier.clone());
+                            self.assert_equal(expected_type, expression_type.clone(), span);
+
+                            enum_type = Some(arm_enum_type.clone());
+
+                            arm_enum_type
+                        }
+                    };
+
+                    let variant_type = arm_enum_type.variant_type(variant)?;
+
+                    // A variant that has already been matched is unreachable.
+                    if matched_variants.contains(&variant.name) {
+                        return Err(FrameError::unreachable_match_arm(&variant.span));
+                    }
+
+                    matched_variants.push(variant.name.clone());
+
+                    // Insert the payload binding into the arm scope.
+                    // The binding may shadow a variable of the enclosing scope.
+                    match (&variant_type.payload, binding) {
+                        (Some(payload_type), Some(binding)) => {
+                            self.typed_identifiers.push(TypedIdentifier::new(
+                                binding.clone(),
+                                payload_type.to_owned(),
+                                IdentifierUse::Definition,
+                            ));
+
+                            let scope = self.scopes.last_mut().unwrap();
+                            scope.insert_variable(binding.name.clone(), payload_type.to_owned());
+                        }
+                        (None, None) => {}
+                        (payload, _) => {
+                            return Err(FrameError::enum_variant_payload(
+                                &arm_enum_type.identifier,
+                                variant,
+                                payload,
+                            ));
+                        }
+                    }
+                }
+                MatchPattern::Wildcard(_) => wildcard = true,
+            }
+
+            // Parse the arm expression.
+            arm_types.push(self.parse_expression(&arm.expression)?);
+
+            // Pop out of the arm scope.
+            let _scope = self.pop_scope();
+        }
+
+        // Check that every variant is matched.
+        if let (Some(enum_type), false) = (&enum_type, wildcard) {
+            let missing = enum_type
+                .variants
+                .iter()
+                .filter(|variant| !matched_variants.contains(&variant.identifier.name))
+                .map(|variant| format!("{}::{}", enum_type.identifier, variant.identifier))
+                .collect::<Vec<_>>();
+
+            if !missing.is_empty() {
+                return Err(FrameError::non_exhaustive_match(&missing, span));
+            }
+        }
+
+        // Assert that every arm has the same type.
+        let first_type = arm_types[0].clone();
+
+        for arm_type in arm_types.into_iter().skip(1) {
+            self.assert_equal(first_type.clone(), arm_type, span);
+        }
+
+        Ok(first_type)
+    }
+
     ///
     /// Returns a `CircuitType` given a circuit expression.
     ///
@@ -1002,7 +1481,7 @@ impl Frame {
         expression: &Expression,
         identifier: &Identifier,
         span: &Span,
-    ) -> Result<&FunctionType, FrameError> {
+    ) -> Result<FunctionType, FrameError> {
         // Parse circuit name.
         let type_ = self.parse_expression(expression)?;
 
@@ -1010,9 +1489,20 @@ impl Frame {
         let circuit_type = self.parse_circuit_name(type_, span)?;
 
         // Find circuit function by identifier.
-        circuit_type
+        let function_type = circuit_type
             .member_function_type(identifier)
-            .ok_or_else(|| FrameError::undefined_circuit_function(identifier))
+            .cloned()
+            .ok_or_else(|| FrameError::undefined_circuit_function(identifier))?;
+        let circuit_identifier = circuit_type.identifier.clone();
+
+        // Record the circuit function access.
+        self.typed_identifiers.push(TypedIdentifier::new(
+            identifier.clone(),
+            Type::Function(function_type.identifier.clone()),
+            IdentifierUse::CircuitMember(circuit_identifier),
+        ));
+
+        Ok(function_type)
     }
 
     ///
@@ -1039,7 +1529,7 @@ impl Frame {
         }
 
         // Return the function type.
-        Ok(function_type.to_owned())
+        Ok(function_type)
     }
 
     ///
@@ -1059,6 +1549,13 @@ impl Frame {
             }
         }
 
+        // Construct an enum variant with a payload.
+        if let Expression::CircuitStaticFunctionAccess(access) = expression {
+            if let Some(enum_identifier) = self.parse_enum_name(&access.circuit) {
+                return self.parse_enum_variant(&enum_identifier, &access.name, Some(inputs), span);
+            }
+        }
+
         // Parse the function name.
         let function_type = self.parse_function_name(expression, span)?;
 
@@ -1101,8 +1598,11 @@ impl Frame {
     ///
     /// Returns `Ok` if all `TypeAssertions` can be solved successfully.
     ///
-    pub(crate) fn check(self) -> Result<(), FrameError> {
+    /// Returns the typed identifiers of the frame with all solved `TypeVariable`s substituted.
+    ///
+    pub(crate) fn check(self) -> Result<Vec<TypedIdentifier>, FrameError> {
         let mut unsolved = self.type_assertions;
+        let mut typed_identifiers = self.typed_identifiers;
 
         // Solve all type equality assertions first.
         let mut unsolved_membership = Vec::new();
@@ -1136,6 +1636,10 @@ impl Frame {
                     for original in &mut unsolved_membership {
                         original.substitute(pair.first(), pair.second())
                     }
+
+                    for typed_identifier in &mut typed_identifiers {
+                        typed_identifier.substitute(pair.first(), pair.second())
+                    }
                 }
             }
         }
@@ -1149,6 +1653,6 @@ impl Frame {
             type_assertion.evaluate()?;
         }
 
-        Ok(())
+        Ok(typed_identifiers)
     }
 }
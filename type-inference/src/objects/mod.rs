@@ -22,3 +22,6 @@ pub use self::scope::*;
 
 pub mod variable_table;
 pub use self::variable_table::*;
+
+pub mod typed_identifier;
+pub use self::typed_identifier::*;
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2020 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::Identifier;
+use leo_symbol_table::{Type, TypeVariable};
+
+/// How an identifier is used in a function body.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdentifierUse {
+    /// The identifier defines a variable.
+    Definition,
+
+    /// The identifier refers to a variable or constant.
+    Reference,
+
+    /// The identifier accesses a member of the named circuit.
+    CircuitMember(Identifier),
+}
+
+/// An identifier in a function body and its type.
+///
+/// Frames record typed identifiers so that editor tooling can show the inferred type of a
+/// variable or circuit member.
+#[derive(Clone, Debug)]
+pub struct TypedIdentifier {
+    pub identifier: Identifier,
+    pub type_: Type,
+    pub use_: IdentifierUse,
+}
+
+impl TypedIdentifier {
+    ///
+    /// Returns a new `TypedIdentifier` from a given identifier, type, and use.
+    ///
+    pub fn new(identifier: Identifier, type_: Type, use_: IdentifierUse) -> Self {
+        Self {
+            identifier,
+            type_,
+            use_,
+        }
+    }
+
+    ///
+    /// Substitutes the given type for the given type variable in the identifier type.
+    ///
+    pub fn substitute(&mut self, variable: &TypeVariable, type_: &Type) {
+        self.type_.substitute(variable, type_)
+    }
+}
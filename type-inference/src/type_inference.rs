@@ -14,18 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Frame, Scope, TypeInferenceError};
-use leo_ast::{Circuit, CircuitMember, Function, Program};
+use crate::{Frame, FrameError, Scope, TypeInferenceError, TypedIdentifier};
+use leo_ast::{Circuit, CircuitMember, Constant, Function, Program};
 use leo_symbol_table::SymbolTable;
 
 /// A type inference check for a Leo program.
 ///
 /// A [`TypeInference`] type stores a stack of frames. A new frame is created for every
-/// function. Frames store type assertions that assert an expression is a type.
+/// constant and function. Frames store type assertions that assert an expression is a type.
 /// Calling the `check()` method on a [`TypeInference`] checks that all type assertions are satisfied.
+///
+/// Frames are independent, so a frame that fails to parse is recorded as an error and the remaining
+/// frames are still checked.
 pub struct TypeInference {
     table: SymbolTable,
     frames: Vec<Frame>,
+    errors: Vec<TypeInferenceError>,
 }
 
 impl TypeInference {
@@ -33,39 +37,95 @@ impl TypeInference {
     /// Creates and runs a new `TypeInference` check on a given program and symbol table.
     ///
     /// Evaluates all `TypeAssertion` predicates.
+    /// Returns the first error of every constant and function that fails to type check.
     ///
     #[allow(clippy::new_ret_no_self)]
     pub fn new(program: &Program, symbol_table: SymbolTable) -> Result<(), TypeInferenceError> {
         let mut type_inference = Self {
             table: symbol_table,
             frames: Vec::new(),
+            errors: Vec::new(),
         };
 
-        type_inference.parse_program(program)?;
+        type_inference.parse_program(program);
 
         type_inference.check()
     }
 
+    ///
+    /// Returns the typed identifiers of every constant, circuit function, and function in a program.
+    ///
+    /// Unlike `new`, a frame that fails to parse or solve is skipped instead of returning an error,
+    /// so editor tooling can show the types found in the rest of the program.
+    ///
+    pub fn typed_identifiers(program: &Program, symbol_table: SymbolTable) -> Vec<TypedIdentifier> {
+        let table = &symbol_table;
+
+        let constants = program
+            .constants
+            .iter()
+            .map(|constant| Frame::new_constant(constant.to_owned(), table.clone()));
+
+        let circuit_functions = program.circuits.values().flat_map(|circuit| {
+            let circuit_type = table.get_circuit_type(&circuit.circuit_name.name).unwrap().clone();
+
+            circuit.members.iter().filter_map(move |member| match member {
+                CircuitMember::CircuitFunction(function) => Some(Frame::new_circuit_function(
+                    function.to_owned(),
+                    circuit_type.clone(),
+                    Scope::default(),
+                    table.clone(),
+                )),
+                CircuitMember::CircuitVariable(_, _) => None,
+            })
+        });
+
+        let functions = program
+            .functions
+            .values()
+            .map(|function| Frame::new_function(function.to_owned(), None, None, table.clone()));
+
+        constants
+            .chain(circuit_functions)
+            .chain(functions)
+            .filter_map(|frame| frame.ok())
+            .filter_map(|frame| frame.check().ok())
+            .flatten()
+            .collect()
+    }
+
     ///
     /// Collects a vector of `TypeAssertion` predicates from a program.
     ///
-    fn parse_program(&mut self, program: &Program) -> Result<(), TypeInferenceError> {
+    fn parse_program(&mut self, program: &Program) {
+        // Parse constants in program context.
+        self.parse_constants(program.constants.iter());
+
         // Parse circuit types in program context.
-        self.parse_circuits(program.circuits.iter().map(|(_identifier, circuit)| circuit))?;
+        self.parse_circuits(program.circuits.iter().map(|(_identifier, circuit)| circuit));
 
         // Parse functions in program context.
         self.parse_functions(program.functions.iter().map(|(_identifier, function)| function))
     }
 
+    ///
+    /// Collects a vector of `TypeAssertion` predicates from a vector of constants.
+    ///
+    fn parse_constants<'a>(&mut self, constants: impl Iterator<Item = &'a Constant>) {
+        for constant in constants {
+            let frame = Frame::new_constant(constant.to_owned(), self.table.clone());
+
+            self.push_frame(frame);
+        }
+    }
+
     ///
     /// Collects a vector of `Frames`s from a vector of circuit functions.
     ///
-    fn parse_circuits<'a>(&mut self, circuits: impl Iterator<Item = &'a Circuit>) -> Result<(), TypeInferenceError> {
+    fn parse_circuits<'a>(&mut self, circuits: impl Iterator<Item = &'a Circuit>) {
         for circuit in circuits {
-            self.parse_circuit(circuit)?;
+            self.parse_circuit(circuit);
         }
-
-        Ok(())
     }
 
     ///
@@ -73,7 +133,7 @@ impl TypeInference {
     ///
     /// Each frame collects a vector of `TypeAssertion` predicates from each function.
     ///
-    fn parse_circuit(&mut self, circuit: &Circuit) -> Result<(), TypeInferenceError> {
+    fn parse_circuit(&mut self, circuit: &Circuit) {
         let name = &circuit.circuit_name.name;
 
         // Get circuit type from circuit symbol table.
@@ -90,35 +150,39 @@ impl TypeInference {
                     circuit_type.clone(),
                     Scope::default(),
                     self.table.clone(),
-                )?;
+                );
 
-                self.frames.push(frame)
+                self.push_frame(frame)
             }
         }
-
-        Ok(())
     }
 
     ///
     /// Collects a vector of `TypeAssertion` predicates from a vector of functions.
     ///
-    fn parse_functions<'a>(&mut self, functions: impl Iterator<Item = &'a Function>) -> Result<(), TypeInferenceError> {
+    fn parse_functions<'a>(&mut self, functions: impl Iterator<Item = &'a Function>) {
         for function in functions {
-            self.parse_function(function)?;
+            self.parse_function(function);
         }
-
-        Ok(())
     }
 
     ///
     /// Collects a vector of `TypeAssertion` predicates from a function.
     ///
-    fn parse_function(&mut self, function: &Function) -> Result<(), TypeInferenceError> {
-        let frame = Frame::new_function(function.to_owned(), None, None, self.table.clone())?;
+    fn parse_function(&mut self, function: &Function) {
+        let frame = Frame::new_function(function.to_owned(), None, None, self.table.clone());
 
-        self.frames.push(frame);
+        self.push_frame(frame);
+    }
 
-        Ok(())
+    ///
+    /// Stores a parsed frame, or records the error of a frame that failed to parse.
+    ///
+    fn push_frame(&mut self, frame: Result<Frame, FrameError>) {
+        match frame {
+            Ok(frame) => self.frames.push(frame),
+            Err(error) => self.errors.push(error.into()),
+        }
     }
 
     ///
@@ -127,12 +191,17 @@ impl TypeInference {
     /// Will attempt to substitute a `Type` for all `TypeVariable`s.
     /// Returns a `LeoResolvedAst` if all `TypeAssertion` predicates are true.
     /// Returns ERROR if a `TypeAssertion` predicate is false or a solution does not exist.
+    /// Every frame is checked, and the errors of all frames are returned sorted by location.
     ///
     pub fn check(self) -> Result<(), TypeInferenceError> {
+        let mut errors = self.errors;
+
         for frame in self.frames {
-            frame.check()?;
+            if let Err(error) = frame.check() {
+                errors.push(error.into());
+            }
         }
 
-        Ok(())
+        TypeInferenceError::from_errors(errors)
     }
 }
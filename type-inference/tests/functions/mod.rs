@@ -23,3 +23,19 @@ fn test_invalid_function() {
 
     check.expect_error();
 }
+
+#[test]
+fn test_multiple_invalid_functions() {
+    let program_string = include_str!("multiple_invalid_functions.leo");
+    let check = TestTypeInference::new(program_string);
+
+    check.expect_errors(4);
+}
+
+#[test]
+fn test_conflicting_return_types() {
+    let program_string = include_str!("conflicting_return_types.leo");
+    let check = TestTypeInference::new(program_string);
+
+    check.expect_error();
+}
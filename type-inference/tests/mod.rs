@@ -23,7 +23,7 @@ pub mod variables;
 use leo_grammar::Grammar;
 use leo_type_inference::TypeInference;
 
-use leo_ast::{Ast, Input, Program};
+use leo_ast::{formatted_source, Ast, Input, Program};
 use leo_imports::ImportParser;
 use leo_symbol_table::SymbolTable;
 use std::path::PathBuf;
@@ -69,6 +69,23 @@ impl TestTypeInference {
     pub fn expect_error(self) {
         assert!(TypeInference::new(&self.program, self.symbol_table).is_err());
     }
+
+    pub fn expect_errors(self, count: usize) {
+        let errors = TypeInference::new(&self.program, self.symbol_table)
+            .unwrap_err()
+            .into_errors();
+
+        let lines = errors
+            .iter()
+            .map(|error| formatted_source(error).unwrap().line)
+            .collect::<Vec<_>>();
+
+        let mut sorted = lines.clone();
+        sorted.sort_unstable();
+
+        assert_eq!(errors.len(), count);
+        assert_eq!(lines, sorted);
+    }
 }
 
 #[test]